@@ -0,0 +1,299 @@
+//! `momento-bench` drives a mix of cache and topic operations against a Momento cache and
+//! reports throughput, latency percentiles and errors, to help size clients and choose
+//! `with_num_connections`.
+//!
+//! ```text
+//! MOMENTO_API_KEY=... momento-bench --cache my-cache --mix get:80,set:20 \
+//!     --concurrency 64 --num-connections 4 --duration 60
+//! ```
+//!
+//! Without `--rate`, each of the `--concurrency` workers runs operations back to back. With
+//! `--rate`, operations are started on a fixed schedule shared by the workers, and latency is
+//! measured from when an operation was due rather than when a worker got to it, so a client
+//! falling behind shows up in the percentiles instead of hiding in a lower throughput.
+//!
+//! Operations during `--warmup` are not measured. Reads of keys no write has reached yet are
+//! misses, which are measured like any other success. `--json` prints the report as JSON, for
+//! comparing runs in CI.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use clap::{Parser, ValueEnum};
+use hdrhistogram::CreationError;
+use momento::config::grpc_configuration::GrpcConfiguration;
+use momento::config::transport_strategy::TransportStrategy;
+use momento::{CacheClient, CredentialProvider, TopicClient};
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use tokio::sync::{mpsc, Mutex};
+use tokio::time::Instant;
+
+use crate::stats::Recorder;
+use crate::workload::{Mix, Operation, Workload};
+
+mod stats;
+mod workload;
+
+/// A prebuilt configuration, or a custom one built from `--deadline-millis` and `--keep-alive`.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Preset {
+    Laptop,
+    InRegion,
+    LowLatency,
+    Lambda,
+    Custom,
+}
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Benchmark Momento cache and topic operations")]
+struct Options {
+    /// The cache to run operations against.
+    #[arg(long, env = "MOMENTO_CACHE_NAME")]
+    cache: String,
+
+    /// The environment variable holding the Momento API key.
+    #[arg(long, default_value = "MOMENTO_API_KEY")]
+    api_key_env_var: String,
+
+    /// The client configuration to use.
+    #[arg(long, value_enum, default_value_t = Preset::InRegion)]
+    configuration: Preset,
+
+    /// The RPC deadline of a custom configuration, in milliseconds.
+    #[arg(long, required_if_eq("configuration", "custom"))]
+    deadline_millis: Option<u64>,
+
+    /// Send keep-alive pings in a custom configuration.
+    #[arg(long)]
+    keep_alive: bool,
+
+    /// The number of connections the cache client opens.
+    #[arg(long)]
+    num_connections: Option<usize>,
+
+    /// The operations to run and their weights. Operations are get, set, get-batch, list-push,
+    /// list-fetch, dictionary-set, dictionary-get, set-add, sorted-set-put and publish.
+    #[arg(long, default_value = "get:80,set:20")]
+    mix: Mix,
+
+    /// How many operations may be in flight at once.
+    #[arg(long, default_value_t = 64)]
+    concurrency: usize,
+
+    /// The target rate in operations per second. Unlimited if not given.
+    #[arg(long)]
+    rate: Option<f64>,
+
+    /// How long to measure for, in seconds.
+    #[arg(long, default_value_t = 60)]
+    duration: u64,
+
+    /// How long to run before measuring, in seconds.
+    #[arg(long, default_value_t = 5)]
+    warmup: u64,
+
+    /// How many distinct keys of each kind to spread operations over.
+    #[arg(long, default_value_t = 10_000)]
+    key_space: usize,
+
+    /// The size of written values, in bytes.
+    #[arg(long, default_value_t = 100)]
+    value_size: usize,
+
+    /// How many keys each get-batch reads.
+    #[arg(long, default_value_t = 10)]
+    batch_size: usize,
+
+    /// The topic publish operations publish to.
+    #[arg(long, default_value = "momento-bench")]
+    topic: String,
+
+    /// The TTL of written items, in seconds.
+    #[arg(long, default_value_t = 600)]
+    ttl: u64,
+
+    /// Print the report as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+impl Options {
+    fn transport_strategy(&self) -> Option<TransportStrategy> {
+        let deadline = Duration::from_millis(self.deadline_millis?);
+        let mut grpc_configuration = GrpcConfiguration::builder().deadline(deadline);
+        if self.keep_alive {
+            grpc_configuration = grpc_configuration
+                .keep_alive_while_idle(true)
+                .keep_alive_interval(Duration::from_secs(5))
+                .keep_alive_timeout(Duration::from_secs(1));
+        }
+        Some(
+            TransportStrategy::builder()
+                .grpc_configuration(grpc_configuration)
+                .build(),
+        )
+    }
+
+    fn cache_configuration(&self) -> momento::cache::Configuration {
+        use momento::cache::configurations::*;
+        match (self.configuration, self.transport_strategy()) {
+            (Preset::Custom, Some(transport_strategy)) => momento::cache::Configuration::builder()
+                .transport_strategy(transport_strategy)
+                .build(),
+            (Preset::Laptop, _) => Laptop::latest().into(),
+            (Preset::LowLatency, _) => LowLatency::latest().into(),
+            (Preset::Lambda, _) => Lambda::latest().into(),
+            (Preset::InRegion | Preset::Custom, _) => InRegion::latest().into(),
+        }
+    }
+
+    fn topics_configuration(&self) -> momento::topics::Configuration {
+        use momento::topics::configurations::*;
+        match (self.configuration, self.transport_strategy()) {
+            (Preset::Custom, Some(transport_strategy)) => momento::topics::Configuration::builder()
+                .transport_strategy(transport_strategy)
+                .build(),
+            (Preset::Laptop, _) => Laptop::latest().into(),
+            (Preset::LowLatency, _) => LowLatency::latest().into(),
+            (Preset::Lambda, _) => Lambda::latest().into(),
+            (Preset::InRegion | Preset::Custom, _) => InRegion::latest().into(),
+        }
+    }
+}
+
+/// Start operations at `rate` per second from `start` until `end`, handing workers the instant
+/// each operation was due.
+fn schedule(rate: f64, start: Instant, end: Instant, capacity: usize) -> mpsc::Receiver<Instant> {
+    let (sender, receiver) = mpsc::channel(capacity);
+    let interval = Duration::from_secs_f64(1.0 / rate);
+    tokio::spawn(async move {
+        for index in 0u32.. {
+            let due = start + interval * index;
+            if due >= end {
+                break;
+            }
+            if due > Instant::now() {
+                tokio::time::sleep_until(due).await;
+            }
+            if sender.send(due).await.is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+async fn run_worker(
+    workload: Arc<Workload>,
+    mix: Mix,
+    schedule: Option<Arc<Mutex<mpsc::Receiver<Instant>>>>,
+    measure_from: Instant,
+    end: Instant,
+) -> Result<Recorder, CreationError> {
+    let mut recorder = Recorder::default();
+    let mut rng = SmallRng::from_rng(&mut rand::rng());
+    loop {
+        let due = match &schedule {
+            Some(schedule) => match schedule.lock().await.recv().await {
+                Some(due) => due,
+                None => break,
+            },
+            None => {
+                let now = Instant::now();
+                if now >= end {
+                    break;
+                }
+                now
+            }
+        };
+        let operation = mix.choose(&mut rng);
+        let result = workload.execute(operation, &mut rng).await;
+        if due >= measure_from {
+            recorder.record(
+                operation,
+                due.elapsed(),
+                result.err().map(|error| error.error_code),
+            )?;
+        }
+    }
+    Ok(recorder)
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+    let options = Options::parse();
+    if options.rate.is_some_and(|rate| rate <= 0.0) {
+        anyhow::bail!("--rate must be positive");
+    }
+    if options.concurrency == 0 || options.key_space == 0 {
+        anyhow::bail!("--concurrency and --key-space must be positive");
+    }
+
+    let credential_provider = CredentialProvider::from_env_var(&options.api_key_env_var)?;
+    let mut cache_client = CacheClient::builder()
+        .default_ttl(Duration::from_secs(options.ttl))
+        .configuration(options.cache_configuration())
+        .credential_provider(credential_provider.clone());
+    if let Some(num_connections) = options.num_connections {
+        cache_client = cache_client.with_num_connections(num_connections);
+    }
+    let topic_client = if options.mix.contains(Operation::Publish) {
+        Some(
+            TopicClient::builder()
+                .configuration(options.topics_configuration())
+                .credential_provider(credential_provider)
+                .build()?,
+        )
+    } else {
+        None
+    };
+    let workload = Arc::new(Workload {
+        cache_client: cache_client.build()?,
+        topic_client,
+        cache_name: options.cache.clone(),
+        topic: options.topic.clone(),
+        key_space: options.key_space,
+        batch_size: options.batch_size,
+        value: vec![b'x'; options.value_size],
+    });
+
+    let start = Instant::now();
+    let measure_from = start + Duration::from_secs(options.warmup);
+    let end = measure_from + Duration::from_secs(options.duration);
+    let schedule = options
+        .rate
+        .map(|rate| Arc::new(Mutex::new(schedule(rate, start, end, options.concurrency))));
+    log::info!(
+        "running {} with {} workers for {}s after a {}s warmup",
+        options.mix,
+        options.concurrency,
+        options.duration,
+        options.warmup
+    );
+
+    let workers: Vec<_> = (0..options.concurrency)
+        .map(|_| {
+            tokio::spawn(run_worker(
+                workload.clone(),
+                options.mix.clone(),
+                schedule.clone(),
+                measure_from,
+                end,
+            ))
+        })
+        .collect();
+    let mut recorder = Recorder::default();
+    for worker in workers {
+        recorder.merge(worker.await??);
+    }
+
+    let report = recorder.report(Duration::from_secs(options.duration))?;
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print!("{report}");
+    }
+    Ok(())
+}
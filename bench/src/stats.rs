@@ -0,0 +1,255 @@
+//! Recording latencies and errors, and reporting them as text or JSON.
+
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use hdrhistogram::{CreationError, Histogram};
+use momento::MomentoErrorCode;
+use serde::Serialize;
+
+use crate::workload::Operation;
+
+/// The slowest latency the histograms track, in microseconds. Slower operations are recorded
+/// as this.
+const MAX_LATENCY_MICROS: u64 = 60 * 1_000_000;
+
+/// Latencies and errors of one operation.
+struct OperationStats {
+    latencies: Histogram<u64>,
+    errors: BTreeMap<String, u64>,
+}
+
+impl OperationStats {
+    fn new() -> Result<Self, CreationError> {
+        Ok(Self {
+            latencies: Histogram::new_with_bounds(1, MAX_LATENCY_MICROS, 3)?,
+            errors: BTreeMap::new(),
+        })
+    }
+}
+
+/// Collects the results of the operations one worker runs. Each worker has its own recorder,
+/// and they are merged once the run is over.
+#[derive(Default)]
+pub struct Recorder {
+    operations: BTreeMap<Operation, OperationStats>,
+}
+
+impl Recorder {
+    /// Record an operation. Only the latencies of successful operations are kept.
+    pub fn record(
+        &mut self,
+        operation: Operation,
+        latency: Duration,
+        error: Option<MomentoErrorCode>,
+    ) -> Result<(), CreationError> {
+        let stats = match self.operations.entry(operation) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(OperationStats::new()?),
+        };
+        match error {
+            None => {
+                let micros = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+                stats.latencies.saturating_record(micros.max(1));
+            }
+            Some(error_code) => *stats.errors.entry(format!("{error_code:?}")).or_default() += 1,
+        }
+        Ok(())
+    }
+
+    pub fn merge(&mut self, other: Recorder) {
+        for (operation, other) in other.operations {
+            let stats = match self.operations.entry(operation) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    entry.insert(other);
+                    continue;
+                }
+            };
+            // Both histograms have the same bounds, so adding cannot fail.
+            let _ = stats.latencies.add(&other.latencies);
+            for (error, count) in other.errors {
+                *stats.errors.entry(error).or_default() += count;
+            }
+        }
+    }
+
+    /// Summarise the run, which measured operations for `elapsed`.
+    pub fn report(&self, elapsed: Duration) -> Result<Report, CreationError> {
+        let mut total = OperationStats::new()?;
+        let mut operations = BTreeMap::new();
+        for (operation, stats) in &self.operations {
+            let _ = total.latencies.add(&stats.latencies);
+            for (error, count) in &stats.errors {
+                *total.errors.entry(error.clone()).or_default() += count;
+            }
+            operations.insert(
+                operation.name().to_string(),
+                OperationReport::new(stats, elapsed),
+            );
+        }
+        Ok(Report {
+            duration_secs: elapsed.as_secs_f64(),
+            total: OperationReport::new(&total, elapsed),
+            operations,
+        })
+    }
+}
+
+/// The results of a run.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub duration_secs: f64,
+    pub total: OperationReport,
+    pub operations: BTreeMap<String, OperationReport>,
+}
+
+/// The results of one operation, or of all of them.
+#[derive(Debug, Serialize)]
+pub struct OperationReport {
+    pub successes: u64,
+    /// Errors by [MomentoErrorCode].
+    pub errors: BTreeMap<String, u64>,
+    /// Successful operations per second.
+    pub throughput: f64,
+    pub latency_micros: Latencies,
+}
+
+/// Latency percentiles of successful operations, in microseconds.
+#[derive(Debug, Serialize)]
+pub struct Latencies {
+    pub min: u64,
+    pub mean: f64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+    pub p99_9: u64,
+    pub p99_99: u64,
+    pub max: u64,
+}
+
+impl OperationReport {
+    fn new(stats: &OperationStats, elapsed: Duration) -> Self {
+        let latencies = &stats.latencies;
+        let successes = latencies.len();
+        let seconds = elapsed.as_secs_f64();
+        Self {
+            successes,
+            errors: stats.errors.clone(),
+            throughput: if seconds > 0.0 {
+                successes as f64 / seconds
+            } else {
+                0.0
+            },
+            latency_micros: Latencies {
+                min: if successes == 0 { 0 } else { latencies.min() },
+                mean: latencies.mean(),
+                p50: latencies.value_at_quantile(0.5),
+                p90: latencies.value_at_quantile(0.9),
+                p99: latencies.value_at_quantile(0.99),
+                p99_9: latencies.value_at_quantile(0.999),
+                p99_99: latencies.value_at_quantile(0.9999),
+                max: latencies.max(),
+            },
+        }
+    }
+
+    fn error_count(&self) -> u64 {
+        self.errors.values().sum()
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<16} {:>10} {:>8} {:>10} {:>9} {:>9} {:>9} {:>9} {:>9}",
+            "operation",
+            "ok",
+            "errors",
+            "ops/s",
+            "p50 ms",
+            "p90 ms",
+            "p99 ms",
+            "p99.9 ms",
+            "max ms"
+        )?;
+        let rows = self
+            .operations
+            .iter()
+            .map(|(name, report)| (name.as_str(), report))
+            .chain(std::iter::once(("total", &self.total)));
+        for (name, report) in rows {
+            let latency = &report.latency_micros;
+            let millis = |micros: u64| micros as f64 / 1000.0;
+            writeln!(
+                f,
+                "{:<16} {:>10} {:>8} {:>10.1} {:>9.3} {:>9.3} {:>9.3} {:>9.3} {:>9.3}",
+                name,
+                report.successes,
+                report.error_count(),
+                report.throughput,
+                millis(latency.p50),
+                millis(latency.p90),
+                millis(latency.p99),
+                millis(latency.p99_9),
+                millis(latency.max),
+            )?;
+        }
+        for (error, count) in &self.total.errors {
+            writeln!(f, "{error}: {count}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_recorders_into_a_report() -> Result<(), CreationError> {
+        let mut first = Recorder::default();
+        for millis in 1..=100 {
+            first.record(Operation::Get, Duration::from_millis(millis), None)?;
+        }
+        first.record(
+            Operation::Get,
+            Duration::from_secs(1),
+            Some(MomentoErrorCode::TimeoutError),
+        )?;
+        let mut second = Recorder::default();
+        second.record(Operation::Set, Duration::from_millis(5), None)?;
+        second.record(
+            Operation::Set,
+            Duration::from_millis(5),
+            Some(MomentoErrorCode::TimeoutError),
+        )?;
+        second.record(
+            Operation::Set,
+            Duration::from_millis(5),
+            Some(MomentoErrorCode::LimitExceededError),
+        )?;
+        first.merge(second);
+
+        let report = first.report(Duration::from_secs(10))?;
+        let get = &report.operations["get"];
+        assert_eq!(get.successes, 100);
+        assert_eq!(get.throughput, 10.0);
+        assert_eq!(get.errors["TimeoutError"], 1);
+        assert!((50_000..=50_100).contains(&get.latency_micros.p50));
+        assert!((100_000..=100_100).contains(&get.latency_micros.max));
+
+        assert_eq!(report.operations["set"].successes, 1);
+        assert_eq!(report.total.successes, 101);
+        assert_eq!(report.total.errors["TimeoutError"], 2);
+        assert_eq!(report.total.errors["LimitExceededError"], 1);
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["operations"]["get"]["successes"], 100);
+        assert!(report.to_string().contains("\ntotal "));
+        Ok(())
+    }
+}
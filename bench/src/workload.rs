@@ -0,0 +1,275 @@
+//! The operations a benchmark runs and the mix they are drawn from.
+
+use std::fmt;
+use std::str::FromStr;
+
+use momento::cache::{ListPushBackRequest, MomentoRequest};
+use momento::{CacheClient, MomentoResult, TopicClient};
+use rand::Rng;
+
+/// How many elements each benchmark list is kept to, so `list-fetch` stays comparable.
+const MAX_LIST_LEN: u32 = 100;
+
+/// How many fields each benchmark dictionary and set has.
+const COLLECTION_FIELDS: usize = 100;
+
+/// An operation the benchmark can run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Operation {
+    Get,
+    Set,
+    GetBatch,
+    ListPush,
+    ListFetch,
+    DictionarySet,
+    DictionaryGet,
+    SetAdd,
+    SortedSetPut,
+    Publish,
+}
+
+impl Operation {
+    const ALL: [Operation; 10] = [
+        Operation::Get,
+        Operation::Set,
+        Operation::GetBatch,
+        Operation::ListPush,
+        Operation::ListFetch,
+        Operation::DictionarySet,
+        Operation::DictionaryGet,
+        Operation::SetAdd,
+        Operation::SortedSetPut,
+        Operation::Publish,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Operation::Get => "get",
+            Operation::Set => "set",
+            Operation::GetBatch => "get-batch",
+            Operation::ListPush => "list-push",
+            Operation::ListFetch => "list-fetch",
+            Operation::DictionarySet => "dictionary-set",
+            Operation::DictionaryGet => "dictionary-get",
+            Operation::SetAdd => "set-add",
+            Operation::SortedSetPut => "sorted-set-put",
+            Operation::Publish => "publish",
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Operation::ALL
+            .into_iter()
+            .find(|operation| operation.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Operation::ALL.iter().map(|op| op.name()).collect();
+                format!(
+                    "unknown operation '{name}', expected one of {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Weighted operations, written as `get:80,set:20`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mix {
+    /// Each operation with the running total of the weights up to and including it.
+    cumulative: Vec<(Operation, u32)>,
+}
+
+impl Mix {
+    pub fn contains(&self, operation: Operation) -> bool {
+        self.cumulative.iter().any(|(op, _)| *op == operation)
+    }
+
+    /// Pick an operation in proportion to its weight.
+    pub fn choose(&self, rng: &mut impl Rng) -> Operation {
+        let total = self.cumulative.last().map_or(0, |(_, total)| *total);
+        let pick = rng.random_range(0..total);
+        self.cumulative
+            .iter()
+            .find(|(_, cumulative)| pick < *cumulative)
+            .map_or(Operation::Get, |(operation, _)| *operation)
+    }
+}
+
+impl FromStr for Mix {
+    type Err = String;
+
+    fn from_str(mix: &str) -> Result<Self, Self::Err> {
+        let mut cumulative = Vec::new();
+        let mut total = 0u32;
+        for entry in mix
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+        {
+            let (operation, weight) = match entry.split_once(':') {
+                Some((operation, weight)) => (
+                    operation.trim().parse::<Operation>()?,
+                    weight
+                        .trim()
+                        .parse::<u32>()
+                        .map_err(|_| format!("invalid weight in '{entry}'"))?,
+                ),
+                None => (entry.parse::<Operation>()?, 1),
+            };
+            if cumulative.iter().any(|(op, _)| *op == operation) {
+                return Err(format!("'{}' appears more than once", operation.name()));
+            }
+            if weight > 0 {
+                total = total
+                    .checked_add(weight)
+                    .ok_or_else(|| "weights are too large".to_string())?;
+                cumulative.push((operation, total));
+            }
+        }
+        if total == 0 {
+            return Err("the mix needs at least one operation with a weight".to_string());
+        }
+        Ok(Mix { cumulative })
+    }
+}
+
+impl fmt::Display for Mix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut previous = 0;
+        for (index, (operation, cumulative)) in self.cumulative.iter().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{}:{}", operation.name(), cumulative - previous)?;
+            previous = *cumulative;
+        }
+        Ok(())
+    }
+}
+
+/// The clients and parameters operations are run with.
+pub struct Workload {
+    pub cache_client: CacheClient,
+    pub topic_client: Option<TopicClient>,
+    pub cache_name: String,
+    pub topic: String,
+    pub key_space: usize,
+    pub batch_size: usize,
+    pub value: Vec<u8>,
+}
+
+impl Workload {
+    fn key(&self, kind: &str, rng: &mut impl Rng) -> String {
+        format!(
+            "momento-bench-{kind}-{}",
+            rng.random_range(0..self.key_space)
+        )
+    }
+
+    fn field(rng: &mut impl Rng) -> String {
+        format!("field-{}", rng.random_range(0..COLLECTION_FIELDS))
+    }
+
+    /// Run one operation on a random key.
+    pub async fn execute(&self, operation: Operation, rng: &mut impl Rng) -> MomentoResult<()> {
+        let cache_name = self.cache_name.as_str();
+        let client = &self.cache_client;
+        match operation {
+            Operation::Get => {
+                client.get(cache_name, self.key("item", rng)).await?;
+            }
+            Operation::Set => {
+                client
+                    .set(cache_name, self.key("item", rng), self.value.clone())
+                    .await?;
+            }
+            Operation::GetBatch => {
+                let keys: Vec<String> = (0..self.batch_size)
+                    .map(|_| self.key("item", rng))
+                    .collect();
+                client.get_batch(cache_name, keys).await?;
+            }
+            Operation::ListPush => {
+                ListPushBackRequest::new(cache_name, self.key("list", rng), self.value.clone())
+                    .truncate_front_to_size(MAX_LIST_LEN)
+                    .send(client)
+                    .await?;
+            }
+            Operation::ListFetch => {
+                client.list_fetch(cache_name, self.key("list", rng)).await?;
+            }
+            Operation::DictionarySet => {
+                client
+                    .dictionary_set_field(
+                        cache_name,
+                        self.key("dictionary", rng),
+                        Self::field(rng),
+                        self.value.clone(),
+                    )
+                    .await?;
+            }
+            Operation::DictionaryGet => {
+                client
+                    .dictionary_get_field(cache_name, self.key("dictionary", rng), Self::field(rng))
+                    .await?;
+            }
+            Operation::SetAdd => {
+                client
+                    .set_add_elements(cache_name, self.key("set", rng), vec![Self::field(rng)])
+                    .await?;
+            }
+            Operation::SortedSetPut => {
+                let score = rng.random_range(0.0..1000.0);
+                client
+                    .sorted_set_put_element(
+                        cache_name,
+                        self.key("sorted-set", rng),
+                        Self::field(rng),
+                        score,
+                    )
+                    .await?;
+            }
+            Operation::Publish => {
+                if let Some(topic_client) = &self.topic_client {
+                    topic_client
+                        .publish(cache_name, self.topic.as_str(), self.value.clone())
+                        .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn parses_and_formats_mixes() {
+        let mix: Mix = "get:80, set:20,publish:0,list-fetch".parse().unwrap();
+        assert_eq!(mix.to_string(), "get:80,set:20,list-fetch:1");
+        assert!(mix.contains(Operation::ListFetch));
+        assert!(!mix.contains(Operation::Publish));
+
+        assert!("get:80,get:20".parse::<Mix>().is_err());
+        assert!("get:x".parse::<Mix>().is_err());
+        assert!("fetch:1".parse::<Mix>().is_err());
+        assert!("get:0".parse::<Mix>().is_err());
+    }
+
+    #[test]
+    fn chooses_operations_by_weight() {
+        let mix: Mix = "get:3,set:1".parse().unwrap();
+        let mut rng = SmallRng::seed_from_u64(7);
+        let gets = (0..10_000)
+            .filter(|_| mix.choose(&mut rng) == Operation::Get)
+            .count();
+        assert!((7_000..8_000).contains(&gets), "{gets} gets");
+    }
+}
@@ -0,0 +1,446 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::Parser;
+use syn::{
+    Error, Expr, FnArg, GenericArgument, Ident, ItemFn, LitBool, LitStr, Pat, PathArguments,
+    ReturnType, Type,
+};
+
+/// The arguments of a `#[cached(...)]` attribute.
+#[derive(Default)]
+struct Arguments {
+    cache: Option<LitStr>,
+    ttl: Option<LitStr>,
+    key: Option<LitStr>,
+    client: Option<LitStr>,
+    cache_negative: bool,
+    result: Option<LitBool>,
+}
+
+impl Arguments {
+    fn parse(attr: TokenStream) -> syn::Result<Self> {
+        let mut arguments = Self::default();
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("cache") {
+                arguments.cache = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("ttl") {
+                arguments.ttl = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("key") {
+                arguments.key = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("client") {
+                arguments.client = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("cache_negative") {
+                arguments.cache_negative = meta.value()?.parse::<LitBool>()?.value;
+            } else if meta.path.is_ident("result") {
+                arguments.result = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error(
+                    "expected one of `cache`, `ttl`, `key`, `client`, `cache_negative` or `result`",
+                ));
+            }
+            Ok(())
+        });
+        parser.parse2(attr)?;
+        Ok(arguments)
+    }
+}
+
+/// How the function's return value is cached.
+struct Output {
+    /// The type of the cached value.
+    value: Type,
+    /// Whether the return type is a `Result`, of which only `Ok` values are cached.
+    is_result: bool,
+    /// Whether the cached value is an `Option`, whose `None` is a negative result.
+    is_option: bool,
+}
+
+impl Output {
+    /// `result` overrides whether the return type is treated as a `Result`. By default only
+    /// `Result` and `MomentoResult` are, so type aliases must set it explicitly.
+    fn parse(output: &ReturnType, result: Option<&LitBool>) -> syn::Result<Self> {
+        let ty = match output {
+            ReturnType::Type(_, ty) => ty.as_ref(),
+            ReturnType::Default => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "#[cached] functions must return a value",
+                ))
+            }
+        };
+        let is_result = match result {
+            Some(result) => result.value,
+            None => matches!(
+                last_segment_ident(ty),
+                Some(ident) if ident == "Result" || ident == "MomentoResult"
+            ),
+        };
+        let value = if is_result {
+            first_type_argument(ty).ok_or_else(|| {
+                Error::new_spanned(ty, "expected a Result with an Ok type argument")
+            })?
+        } else {
+            ty.clone()
+        };
+        let is_option = matches!(last_segment_ident(&value), Some(ident) if ident == "Option");
+        Ok(Self {
+            value,
+            is_result,
+            is_option,
+        })
+    }
+}
+
+pub(crate) fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let arguments = Arguments::parse(attr)?;
+    let function: ItemFn = syn::parse2(item)?;
+    let signature = &function.sig;
+
+    if signature.asyncness.is_none() {
+        return Err(Error::new_spanned(
+            signature.fn_token,
+            "#[cached] can only be used on async functions",
+        ));
+    }
+    let cache = arguments.cache.as_ref().ok_or_else(|| {
+        Error::new(
+            Span::call_site(),
+            "#[cached] requires a cache name, e.g. #[cached(cache = \"my-cache\")]",
+        )
+    })?;
+
+    let mut parameters = Vec::new();
+    let mut client_parameter = None;
+    for input in &signature.inputs {
+        match input {
+            FnArg::Receiver(receiver) => {
+                return Err(Error::new_spanned(
+                    receiver,
+                    "#[cached] does not support methods taking `self`",
+                ))
+            }
+            FnArg::Typed(typed) => match typed.pat.as_ref() {
+                Pat::Ident(pat) => {
+                    if client_parameter.is_none() && is_cache_client(&typed.ty) {
+                        client_parameter = Some(pat.ident.clone());
+                    } else {
+                        parameters.push(pat.ident.clone());
+                    }
+                }
+                pat => {
+                    return Err(Error::new_spanned(
+                        pat,
+                        "#[cached] requires every argument to be a plain identifier",
+                    ))
+                }
+            },
+        }
+    }
+
+    let client: Expr = match (&arguments.client, &client_parameter) {
+        (Some(client), _) => client.parse()?,
+        (None, Some(client)) => syn::parse_quote!(#client),
+        (None, None) => {
+            return Err(Error::new_spanned(
+                &signature.inputs,
+                "#[cached] needs a CacheClient: add a `&CacheClient` argument or set `client = \"...\"`",
+            ))
+        }
+    };
+
+    let ttl = match &arguments.ttl {
+        Some(ttl) => {
+            let millis =
+                parse_ttl(&ttl.value()).map_err(|message| Error::new_spanned(ttl, message))?;
+            quote!(::std::option::Option::Some(::std::time::Duration::from_millis(#millis)))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+
+    let name = &signature.ident;
+    let key = match &arguments.key {
+        Some(template) => {
+            let names = template_names(&template.value())
+                .map_err(|message| Error::new_spanned(template, message))?;
+            let mut arguments = Vec::new();
+            for name in names {
+                let ident = Ident::new(&name, template.span());
+                if !parameters.contains(&ident) && client_parameter.as_ref() != Some(&ident) {
+                    return Err(Error::new_spanned(
+                        template,
+                        format!(
+                            "the key template refers to `{}`, which is not an argument",
+                            name
+                        ),
+                    ));
+                }
+                arguments.push(quote!(#ident = #ident));
+            }
+            quote!(::std::option::Option::Some(
+                ::std::format!(#template, #(#arguments),*)
+            ))
+        }
+        None => {
+            let path = quote!(::std::concat!(
+                ::std::module_path!(),
+                "::",
+                ::std::stringify!(#name)
+            ));
+            quote!(::momento::__private::cache_key(#path, &(#(&#parameters,)*)))
+        }
+    };
+
+    let output = Output::parse(&signature.output, arguments.result.as_ref())?;
+    let value_type = &output.value;
+    let cacheable = if output.is_option && !arguments.cache_negative {
+        quote!(__momento_value.is_some())
+    } else {
+        quote!(true)
+    };
+    let hit = if output.is_result {
+        quote!(::std::result::Result::Ok(__momento_cached))
+    } else {
+        quote!(__momento_cached)
+    };
+    let store = if output.is_result {
+        quote! {
+            if let ::std::result::Result::Ok(__momento_value) = &__momento_result {
+                if #cacheable {
+                    ::momento::__private::cached_set(&__momento_client, #cache, &__momento_key, __momento_value, #ttl).await;
+                }
+            }
+        }
+    } else {
+        quote! {
+            let __momento_value = &__momento_result;
+            if #cacheable {
+                ::momento::__private::cached_set(&__momento_client, #cache, &__momento_key, __momento_value, #ttl).await;
+            }
+        }
+    };
+
+    let attributes = &function.attrs;
+    let visibility = &function.vis;
+    let body = &function.block;
+    let return_type = match &signature.output {
+        ReturnType::Type(_, ty) => ty,
+        ReturnType::Default => unreachable!("functions without a return value were rejected"),
+    };
+
+    // The body runs as an async block within the function, rather than as a separate function,
+    // so that it can use `Self` and the generics of an enclosing impl. The key is built before
+    // the block takes ownership of the arguments.
+    Ok(quote! {
+        #(#attributes)*
+        #visibility #signature {
+            let __momento_client = ::std::clone::Clone::clone(&#client);
+            let __momento_key: ::std::option::Option<::std::string::String> = #key;
+            let __momento_body =
+                ::momento::__private::returning::<#return_type, _>(async move #body);
+            let __momento_key = match __momento_key {
+                ::std::option::Option::Some(key) => key,
+                ::std::option::Option::None => return __momento_body.await,
+            };
+            if let ::std::option::Option::Some(__momento_cached) =
+                ::momento::__private::cached_get::<#value_type>(&__momento_client, #cache, &__momento_key).await
+            {
+                return #hit;
+            }
+            let __momento_result = __momento_body.await;
+            #store
+            __momento_result
+        }
+    })
+}
+
+/// Returns true if `ty` is `CacheClient` or a reference to it.
+fn is_cache_client(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => is_cache_client(&reference.elem),
+        ty => matches!(last_segment_ident(ty), Some(ident) if ident == "CacheClient"),
+    }
+}
+
+fn last_segment_ident(ty: &Type) -> Option<&Ident> {
+    match ty {
+        Type::Path(path) => path.path.segments.last().map(|segment| &segment.ident),
+        _ => None,
+    }
+}
+
+fn first_type_argument(ty: &Type) -> Option<Type> {
+    let segment = match ty {
+        Type::Path(path) => path.path.segments.last()?,
+        _ => return None,
+    };
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => {
+            arguments.args.iter().find_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parse a TTL such as `"30s"` into milliseconds.
+fn parse_ttl(ttl: &str) -> Result<u64, String> {
+    let ttl = ttl.trim();
+    let split = ttl.find(|c: char| !c.is_ascii_digit()).unwrap_or(ttl.len());
+    let (amount, unit) = ttl.split_at(split);
+    let amount: u64 = amount.parse().map_err(|_| {
+        format!(
+            "invalid ttl `{}`: expected a number followed by a unit",
+            ttl
+        )
+    })?;
+    let multiplier = match unit.trim() {
+        "ms" => 1,
+        "s" => 1_000,
+        "m" => 60_000,
+        "h" => 3_600_000,
+        "d" => 86_400_000,
+        _ => {
+            return Err(format!(
+                "invalid ttl `{}`: expected a unit of ms, s, m, h or d",
+                ttl
+            ))
+        }
+    };
+    amount
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("invalid ttl `{}`: too large", ttl))
+}
+
+/// The argument names referred to by a key template, in order of first use.
+fn template_names(template: &str) -> Result<Vec<String>, String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            return Err(format!("unclosed `{{` in key template `{}`", template))
+                        }
+                    }
+                }
+                let name = placeholder.split(':').next().unwrap_or_default().trim();
+                if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                    return Err(format!(
+                        "key template placeholders must name an argument, found `{{{}}}`",
+                        placeholder
+                    ));
+                }
+                if !names.iter().any(|existing| existing == name) {
+                    names.push(name.to_string());
+                }
+            }
+            '}' => return Err(format!("unmatched `}}` in key template `{}`", template)),
+            _ => {}
+        }
+    }
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ttl() {
+        assert_eq!(parse_ttl("250ms"), Ok(250));
+        assert_eq!(parse_ttl("30s"), Ok(30_000));
+        assert_eq!(parse_ttl("5m"), Ok(300_000));
+        assert_eq!(parse_ttl("2h"), Ok(7_200_000));
+        assert_eq!(parse_ttl("1d"), Ok(86_400_000));
+        assert!(parse_ttl("30").is_err());
+        assert!(parse_ttl("s").is_err());
+        assert!(parse_ttl("5 weeks").is_err());
+    }
+
+    #[test]
+    fn test_template_names() {
+        assert_eq!(
+            template_names("user:{id}:{region}:{id}"),
+            Ok(vec!["id".to_string(), "region".to_string()])
+        );
+        assert_eq!(template_names("{{literal}}"), Ok(vec![]));
+        assert_eq!(template_names("{id:>8}"), Ok(vec!["id".to_string()]));
+        assert!(template_names("user:{id").is_err());
+        assert!(template_names("user:{}").is_err());
+        assert!(template_names("user:}").is_err());
+    }
+
+    #[test]
+    fn test_output() -> syn::Result<()> {
+        let output = Output::parse(&syn::parse_quote!(-> MomentoResult<Option<User>>), None)?;
+        assert!(output.is_result);
+        assert!(output.is_option);
+
+        let output = Output::parse(&syn::parse_quote!(-> std::result::Result<u64, Error>), None)?;
+        assert!(output.is_result);
+
+        let output = Output::parse(&syn::parse_quote!(-> Vec<u8>), None)?;
+        assert!(!output.is_result);
+        assert!(!output.is_option);
+
+        assert!(Output::parse(&ReturnType::Default, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_result_override() -> syn::Result<()> {
+        // Types which merely end in `Result` are cached as-is unless marked as a result.
+        let output = Output::parse(&syn::parse_quote!(-> SearchResult<u64>), None)?;
+        assert!(!output.is_result);
+
+        let output = Output::parse(
+            &syn::parse_quote!(-> ApiResult<Option<u64>>),
+            Some(&syn::parse_quote!(true)),
+        )?;
+        assert!(output.is_result);
+        assert!(output.is_option);
+
+        let output = Output::parse(
+            &syn::parse_quote!(-> Result<u64, Error>),
+            Some(&syn::parse_quote!(false)),
+        )?;
+        assert!(!output.is_result);
+
+        assert!(Output::parse(&syn::parse_quote!(-> u64), Some(&syn::parse_quote!(true))).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_cache_client() {
+        assert!(is_cache_client(&syn::parse_quote!(&CacheClient)));
+        assert!(is_cache_client(&syn::parse_quote!(&momento::CacheClient)));
+        assert!(is_cache_client(&syn::parse_quote!(CacheClient)));
+        assert!(!is_cache_client(&syn::parse_quote!(&TopicClient)));
+    }
+
+    #[test]
+    fn test_rejects_sync_functions() {
+        let result = expand(
+            quote!(cache = "cache"),
+            quote!(
+                fn get(client: &CacheClient) -> u64 {
+                    1
+                }
+            ),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,71 @@
+//! Procedural macros for the Momento client SDK.
+//!
+//! Enable the `macros` feature of the `momento` crate and use the macros from there, e.g.
+//! `#[momento::cached(...)]`, rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+
+mod cached;
+
+/// Memoizes an async function in a Momento cache.
+///
+/// The function reads through a [CacheClient](https://docs.rs/momento/latest/momento/struct.CacheClient.html):
+/// on a hit the cached value is returned without calling the function, and on a miss the function
+/// is called and its return value is stored. Values are serialized as JSON, so the cached type
+/// must implement `serde::Serialize` and `serde::de::DeserializeOwned`.
+///
+/// # Arguments
+///
+/// * `cache` - The name of the cache to use. Required.
+/// * `ttl` - How long values are cached, as a number followed by `ms`, `s`, `m`, `h` or `d`, e.g.
+///   `"30s"`. Defaults to the client's default TTL.
+/// * `key` - A template for the cache key. `{name}` is replaced with the argument `name`,
+///   formatted with `Display`. Defaults to the function's path followed by its arguments
+///   serialized as JSON, which requires every argument other than the client to implement
+///   `serde::Serialize`.
+/// * `client` - An expression for the `CacheClient` to use. Defaults to the first argument of type
+///   `CacheClient` or `&CacheClient`.
+/// * `cache_negative` - Whether to cache `None`. Defaults to `false`.
+/// * `result` - Whether the return type is a `Result`. Defaults to `true` for `Result<T, E>` and
+///   `MomentoResult<T>` and `false` for any other type; set it for type aliases of `Result`.
+///
+/// # Return values
+///
+/// If the return type is `Result<T, E>` or `MomentoResult<T>`, or `result = true` is set, only
+/// `Ok` values are cached; errors are returned to the caller and the next call tries again. Any
+/// other return type is cached as-is.
+///
+/// If the cached value is an `Option`, `None` is considered a negative result and is not cached
+/// unless `cache_negative = true`.
+///
+/// Failing to read from or write to the cache never fails the call; the function is called as if
+/// the cache missed.
+///
+/// # Example
+/// ```ignore
+/// use momento::{CacheClient, MomentoResult};
+///
+/// #[momento::cached(cache = "users", ttl = "5m", key = "user:{id}")]
+/// async fn get_user(client: &CacheClient, id: u64) -> MomentoResult<Option<User>> {
+///     load_user_from_database(id).await
+/// }
+/// ```
+///
+/// A type alias of `Result` must be marked with `result = true`:
+/// ```ignore
+/// type ApiResult<T> = Result<T, ApiError>;
+///
+/// #[momento::cached(cache = "users", key = "user:{id}", result = true)]
+/// async fn fetch_user(client: &CacheClient, id: u64) -> ApiResult<User> {
+///     call_user_api(id).await
+/// }
+/// ```
+///
+/// Associated functions can use `Self` and the generics of their impl, but methods taking `self`
+/// are not supported.
+#[proc_macro_attribute]
+pub fn cached(attr: TokenStream, item: TokenStream) -> TokenStream {
+    cached::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
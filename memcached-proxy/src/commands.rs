@@ -0,0 +1,763 @@
+//! Translating memcached requests to `CacheClient` calls.
+
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use momento::cache::{
+    GetResponse, IncrementRequest, ItemGetTtlResponse, MomentoRequest, SetIfAbsentRequest,
+    SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse, SetIfPresentRequest,
+    SetIfPresentResponse, SetRequest, UpdateTtlResponse,
+};
+use momento::{CacheClient, MomentoError};
+
+use crate::item::Item;
+use crate::protocol::{MetaFlags, Request, StoreMode};
+
+/// Expiry times above this many seconds are Unix timestamps rather than relative times.
+const MAX_RELATIVE_EXPTIME: i64 = 60 * 60 * 24 * 30;
+
+/// How many times a read-modify-write is retried when the item changes underneath it.
+const MAX_UPDATE_ATTEMPTS: usize = 16;
+
+/// An error reply.
+#[derive(Debug, PartialEq, Eq)]
+pub enum CommandError {
+    /// The request was invalid, sent as `CLIENT_ERROR`.
+    Client(String),
+    /// The request could not be served, sent as `SERVER_ERROR`.
+    Server(String),
+}
+
+impl CommandError {
+    fn unsupported_flag(command: &str, flag: u8) -> Self {
+        Self::Client(format!(
+            "the '{}' flag of '{command}' is not supported by momento-memcached-proxy",
+            flag as char
+        ))
+    }
+
+    fn write(&self, out: &mut Vec<u8>) {
+        let line = match self {
+            Self::Client(message) => format!("CLIENT_ERROR {message}\r\n"),
+            Self::Server(message) => format!("SERVER_ERROR {message}\r\n"),
+        };
+        out.extend_from_slice(line.as_bytes());
+    }
+}
+
+impl From<MomentoError> for CommandError {
+    fn from(error: MomentoError) -> Self {
+        Self::Server(format!("momento {:?}: {}", error.error_code, error.message))
+    }
+}
+
+/// When an item should expire, from a memcached expiry time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Expiry {
+    /// An expiry time of 0, meaning never. Momento items always expire, so these get the
+    /// default TTL.
+    Default,
+    After(Duration),
+    /// A negative expiry time or one in the past: the item expires immediately.
+    Expired,
+}
+
+impl Expiry {
+    fn new(exptime: i64, now: SystemTime) -> Self {
+        if exptime < 0 {
+            return Expiry::Expired;
+        }
+        if exptime == 0 {
+            return Expiry::Default;
+        }
+        if exptime <= MAX_RELATIVE_EXPTIME {
+            return Expiry::After(Duration::from_secs(exptime as u64));
+        }
+        match (UNIX_EPOCH + Duration::from_secs(exptime as u64)).duration_since(now) {
+            Ok(remaining) if !remaining.is_zero() => Expiry::After(remaining),
+            _ => Expiry::Expired,
+        }
+    }
+
+    fn parse(exptime: Option<&str>) -> Result<Self, CommandError> {
+        let exptime = match exptime {
+            Some(exptime) => exptime.parse().map_err(|_| {
+                CommandError::Client("bad token in command line format".to_string())
+            })?,
+            None => 0,
+        };
+        Ok(Expiry::new(exptime, SystemTime::now()))
+    }
+
+    /// The TTL to store an item with, or `None` for the client's default TTL.
+    fn ttl(self) -> Option<Duration> {
+        match self {
+            Expiry::After(ttl) => Some(ttl),
+            Expiry::Default | Expiry::Expired => None,
+        }
+    }
+}
+
+/// The result of a storage command.
+enum StoreOutcome {
+    Stored(Item),
+    NotStored,
+    /// The item's CAS token did not match.
+    Exists,
+    /// There was no item to compare the CAS token of.
+    NotFound,
+}
+
+/// Apply `incr` or `decr` to an item's value. As in memcached, incrementing wraps at 2^64 and
+/// decrementing stops at 0.
+fn apply_delta(data: &[u8], delta: u64, decrement: bool) -> Result<u64, CommandError> {
+    let value = parse_counter(data)?;
+    Ok(if decrement {
+        value.saturating_sub(delta)
+    } else {
+        value.wrapping_add(delta)
+    })
+}
+
+/// Read an item's value as the unsigned number `incr` and `decr` work on.
+fn parse_counter(data: &[u8]) -> Result<u64, CommandError> {
+    std::str::from_utf8(data)
+        .ok()
+        .and_then(|value| value.trim_end().parse().ok())
+        .ok_or_else(|| {
+            CommandError::Client("cannot increment or decrement non-numeric value".to_string())
+        })
+}
+
+/// Fail if a meta command was given a flag it does not support.
+fn check_flags(command: &str, flags: &MetaFlags, supported: &[u8]) -> Result<(), CommandError> {
+    match flags.iter().find(|(flag, _)| !supported.contains(flag)) {
+        Some((flag, _)) => Err(CommandError::unsupported_flag(command, flag)),
+        None => Ok(()),
+    }
+}
+
+/// The flags a meta command returns: the key, opaque, CAS token, flags, size and TTL, in the
+/// order the client asked for them.
+fn return_flags(key: &[u8], flags: &MetaFlags, item: Option<&Item>, ttl: Option<u64>) -> Vec<u8> {
+    let mut returned = Vec::new();
+    for (flag, token) in flags.iter() {
+        let value = match (flag, item) {
+            (b'k', _) => Bytes::copy_from_slice(key),
+            (b'O', _) => Bytes::copy_from_slice(token.as_bytes()),
+            (b'c', Some(item)) => Bytes::from(item.cas.to_string()),
+            (b'f', Some(item)) => Bytes::from(item.flags.to_string()),
+            (b's', Some(item)) => Bytes::from(item.data.len().to_string()),
+            (b't', _) => match ttl {
+                Some(ttl) => Bytes::from(ttl.to_string()),
+                None => continue,
+            },
+            _ => continue,
+        };
+        returned.push(b' ');
+        returned.push(flag);
+        returned.extend_from_slice(&value);
+    }
+    returned
+}
+
+fn write_status(out: &mut Vec<u8>, status: &str, returned: &[u8]) {
+    out.extend_from_slice(status.as_bytes());
+    out.extend_from_slice(returned);
+    out.extend_from_slice(b"\r\n");
+}
+
+/// Runs requests against a cache.
+#[derive(Clone)]
+pub struct Commands {
+    cache_client: CacheClient,
+    cache_name: String,
+    default_ttl: Duration,
+}
+
+impl Commands {
+    pub fn new(cache_client: CacheClient, cache_name: String, default_ttl: Duration) -> Self {
+        Self {
+            cache_client,
+            cache_name,
+            default_ttl,
+        }
+    }
+
+    /// Run a request, writing its reply to `out`. [Request::Quit] is left to the connection.
+    pub async fn execute(&self, request: Request, out: &mut Vec<u8>) {
+        let result = match request {
+            Request::Get { keys, cas, touch } => self.get(keys, cas, touch, out).await,
+            Request::Store {
+                mode,
+                key,
+                flags,
+                exptime,
+                data,
+                noreply,
+            } => {
+                let expiry = Expiry::new(exptime, SystemTime::now());
+                self.store(key, mode, Item::new(flags, data), expiry)
+                    .await
+                    .map(|outcome| {
+                        if !noreply {
+                            out.extend_from_slice(match outcome {
+                                StoreOutcome::Stored(_) => b"STORED\r\n",
+                                StoreOutcome::NotStored => b"NOT_STORED\r\n",
+                                StoreOutcome::Exists => b"EXISTS\r\n",
+                                StoreOutcome::NotFound => b"NOT_FOUND\r\n",
+                            })
+                        }
+                    })
+            }
+            Request::Delete { key, noreply } => self.delete(&key).await.map(|deleted| {
+                if !noreply {
+                    out.extend_from_slice(if deleted {
+                        b"DELETED\r\n"
+                    } else {
+                        b"NOT_FOUND\r\n"
+                    })
+                }
+            }),
+            Request::Arithmetic {
+                key,
+                delta,
+                decrement,
+                noreply,
+            } => self.arithmetic(&key, delta, decrement).await.map(|item| {
+                if !noreply {
+                    match item {
+                        Some(item) => {
+                            out.extend_from_slice(&item.data);
+                            out.extend_from_slice(b"\r\n");
+                        }
+                        None => out.extend_from_slice(b"NOT_FOUND\r\n"),
+                    }
+                }
+            }),
+            Request::Touch {
+                key,
+                exptime,
+                noreply,
+            } => self
+                .touch(&key, Expiry::new(exptime, SystemTime::now()))
+                .await
+                .map(|touched| {
+                    if !noreply {
+                        out.extend_from_slice(if touched {
+                            b"TOUCHED\r\n"
+                        } else {
+                            b"NOT_FOUND\r\n"
+                        })
+                    }
+                }),
+            Request::MetaGet { key, flags } => self.meta_get(&key, &flags, out).await,
+            Request::MetaSet { key, data, flags } => self.meta_set(key, data, &flags, out).await,
+            Request::MetaDelete { key, flags } => self.meta_delete(&key, &flags, out).await,
+            Request::MetaArithmetic { key, flags } => self.meta_arithmetic(&key, &flags, out).await,
+            Request::MetaNoop => {
+                out.extend_from_slice(b"MN\r\n");
+                Ok(())
+            }
+            Request::Version => {
+                out.extend_from_slice(
+                    format!("VERSION {}\r\n", env!("CARGO_PKG_VERSION")).as_bytes(),
+                );
+                Ok(())
+            }
+            Request::Verbosity { noreply } => {
+                if !noreply {
+                    out.extend_from_slice(b"OK\r\n");
+                }
+                Ok(())
+            }
+            Request::Quit => Ok(()),
+        };
+        if let Err(error) = result {
+            error.write(out);
+        }
+    }
+
+    /// `get`, `gets`, `gat` and `gats`. The keys are fetched concurrently.
+    async fn get(
+        &self,
+        keys: Vec<Bytes>,
+        cas: bool,
+        touch: Option<i64>,
+        out: &mut Vec<u8>,
+    ) -> Result<(), CommandError> {
+        let touch = touch.map(|exptime| Expiry::new(exptime, SystemTime::now()));
+        let items =
+            futures::future::try_join_all(keys.iter().map(|key| self.fetch(key, touch))).await?;
+        for (key, item) in keys.iter().zip(items) {
+            if let Some(item) = item {
+                out.extend_from_slice(b"VALUE ");
+                out.extend_from_slice(key);
+                out.extend_from_slice(format!(" {} {}", item.flags, item.data.len()).as_bytes());
+                if cas {
+                    out.extend_from_slice(format!(" {}", item.cas).as_bytes());
+                }
+                out.extend_from_slice(b"\r\n");
+                out.extend_from_slice(&item.data);
+                out.extend_from_slice(b"\r\n");
+            }
+        }
+        out.extend_from_slice(b"END\r\n");
+        Ok(())
+    }
+
+    /// Read an item, first updating its TTL if `touch` is given.
+    async fn fetch(&self, key: &[u8], touch: Option<Expiry>) -> Result<Option<Item>, CommandError> {
+        if let Some(expiry) = touch {
+            if !self.touch(key, expiry).await? || expiry == Expiry::Expired {
+                return Ok(None);
+            }
+        }
+        Ok(self.read(key).await?.map(Item::decode))
+    }
+
+    /// The raw value of an item.
+    async fn read(&self, key: &[u8]) -> Result<Option<Vec<u8>>, CommandError> {
+        match self
+            .cache_client
+            .get(&self.cache_name, key.to_vec())
+            .await?
+        {
+            response @ GetResponse::Hit { .. } => Ok(Some(Vec::<u8>::try_from(response)?)),
+            GetResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Store an item according to `mode`. `append` and `prepend` keep the existing item's flags
+    /// and TTL, as in memcached.
+    async fn store(
+        &self,
+        key: Bytes,
+        mode: StoreMode,
+        item: Item,
+        expiry: Expiry,
+    ) -> Result<StoreOutcome, CommandError> {
+        let cache_name = self.cache_name.as_str();
+        let value = item.encode();
+        let ttl = expiry.ttl();
+        let outcome = match mode {
+            StoreMode::Set => {
+                SetRequest::new(cache_name, key.to_vec(), value)
+                    .ttl(ttl)
+                    .send(&self.cache_client)
+                    .await?;
+                StoreOutcome::Stored(item)
+            }
+            StoreMode::Add => {
+                match SetIfAbsentRequest::new(cache_name, key.to_vec(), value)
+                    .ttl(ttl)
+                    .send(&self.cache_client)
+                    .await?
+                {
+                    SetIfAbsentResponse::Stored => StoreOutcome::Stored(item),
+                    SetIfAbsentResponse::NotStored => StoreOutcome::NotStored,
+                }
+            }
+            StoreMode::Replace => {
+                match SetIfPresentRequest::new(cache_name, key.to_vec(), value)
+                    .ttl(ttl)
+                    .send(&self.cache_client)
+                    .await?
+                {
+                    SetIfPresentResponse::Stored => StoreOutcome::Stored(item),
+                    SetIfPresentResponse::NotStored => StoreOutcome::NotStored,
+                }
+            }
+            StoreMode::Append | StoreMode::Prepend => {
+                let updated = self
+                    .update(&key, |current| {
+                        let mut data = Vec::with_capacity(current.data.len() + item.data.len());
+                        if mode == StoreMode::Append {
+                            data.extend_from_slice(&current.data);
+                            data.extend_from_slice(&item.data);
+                        } else {
+                            data.extend_from_slice(&item.data);
+                            data.extend_from_slice(&current.data);
+                        }
+                        Ok(Item::new(current.flags, data))
+                    })
+                    .await?;
+                return Ok(match updated {
+                    Some(updated) => StoreOutcome::Stored(updated),
+                    None => StoreOutcome::NotStored,
+                });
+            }
+            StoreMode::Cas(expected) => {
+                let current = match self.read(&key).await? {
+                    Some(current) => current,
+                    None => return Ok(StoreOutcome::NotFound),
+                };
+                if Item::decode(current.clone()).cas != expected {
+                    return Ok(StoreOutcome::Exists);
+                }
+                match SetIfEqualRequest::new(cache_name, key.to_vec(), value, current)
+                    .ttl(ttl)
+                    .send(&self.cache_client)
+                    .await?
+                {
+                    SetIfEqualResponse::Stored => StoreOutcome::Stored(item),
+                    SetIfEqualResponse::NotStored => StoreOutcome::Exists,
+                }
+            }
+        };
+        if expiry == Expiry::Expired && matches!(outcome, StoreOutcome::Stored(_)) {
+            self.cache_client.delete(cache_name, key.to_vec()).await?;
+        }
+        Ok(outcome)
+    }
+
+    /// Replace an item with `update(current)`, keeping its remaining TTL. The write is made with
+    /// `set_if_equal`, and retried if another client changed the item in the meantime. Returns
+    /// `None` if there is no item.
+    async fn update(
+        &self,
+        key: &[u8],
+        mut update: impl FnMut(&Item) -> Result<Item, CommandError>,
+    ) -> Result<Option<Item>, CommandError> {
+        for _ in 0..MAX_UPDATE_ATTEMPTS {
+            let current = match self.read(key).await? {
+                Some(current) => current,
+                None => return Ok(None),
+            };
+            let remaining_ttl = match self.remaining_ttl(key).await? {
+                Some(remaining_ttl) => remaining_ttl,
+                None => return Ok(None),
+            };
+            let updated = update(&Item::decode(current.clone()))?;
+            let response = SetIfEqualRequest::new(
+                self.cache_name.as_str(),
+                key.to_vec(),
+                updated.encode(),
+                current,
+            )
+            .ttl(remaining_ttl)
+            .send(&self.cache_client)
+            .await?;
+            if response == SetIfEqualResponse::Stored {
+                return Ok(Some(updated));
+            }
+        }
+        Err(CommandError::Server(
+            "item was modified concurrently too many times".to_string(),
+        ))
+    }
+
+    /// How long an item has left, to carry over when it is rewritten. Returns `None` if there is
+    /// no item.
+    async fn remaining_ttl(&self, key: &[u8]) -> Result<Option<Duration>, CommandError> {
+        Ok(
+            match self
+                .cache_client
+                .item_get_ttl(&self.cache_name, key.to_vec())
+                .await?
+            {
+                ItemGetTtlResponse::Hit { remaining_ttl } => {
+                    Some(remaining_ttl.max(Duration::from_millis(1)))
+                }
+                ItemGetTtlResponse::Miss => None,
+            },
+        )
+    }
+
+    /// `incr` and `decr`. Counters are stored without a header, as [Item::counter]s, so they are
+    /// updated with Momento's `increment`, keeping their remaining TTL. Momento's counters are
+    /// signed, so a `decr` that goes below 0 is put back to 0 afterwards, where memcached stops.
+    /// Items still behind a header, items with client flags and counters past `i64::MAX` are
+    /// updated with [Commands::update] instead, and those without flags are written back as
+    /// counters.
+    async fn arithmetic(
+        &self,
+        key: &[u8],
+        delta: u64,
+        decrement: bool,
+    ) -> Result<Option<Item>, CommandError> {
+        let current = match self.read(key).await? {
+            Some(current) => Item::decode(current),
+            None => return Ok(None),
+        };
+        let value = parse_counter(&current.data)?;
+        let amount = match (i64::try_from(value), i64::try_from(delta)) {
+            (Ok(signed), Ok(delta))
+                if current == Item::counter(value)
+                    && (decrement || signed.checked_add(delta).is_some()) =>
+            {
+                if decrement {
+                    -delta
+                } else {
+                    delta
+                }
+            }
+            _ => {
+                return self
+                    .update(key, |current| {
+                        let value = apply_delta(&current.data, delta, decrement)?;
+                        Ok(if current.flags == 0 {
+                            Item::counter(value)
+                        } else {
+                            Item::new(current.flags, value.to_string())
+                        })
+                    })
+                    .await
+            }
+        };
+
+        let remaining_ttl = match self.remaining_ttl(key).await? {
+            Some(remaining_ttl) => remaining_ttl,
+            None => return Ok(None),
+        };
+        let value = IncrementRequest::new(self.cache_name.as_str(), key.to_vec(), amount)
+            .ttl(remaining_ttl)
+            .send(&self.cache_client)
+            .await?
+            .value;
+        if let Ok(value) = u64::try_from(value) {
+            return Ok(Some(Item::counter(value)));
+        }
+        // If this is not stored, another client has changed the counter since, and its write
+        // stands.
+        SetIfEqualRequest::new(
+            self.cache_name.as_str(),
+            key.to_vec(),
+            "0",
+            value.to_string(),
+        )
+        .ttl(remaining_ttl)
+        .send(&self.cache_client)
+        .await?;
+        Ok(Some(Item::counter(0)))
+    }
+
+    /// Momento does not report whether a delete removed anything, so the key is checked first.
+    async fn delete(&self, key: &[u8]) -> Result<bool, CommandError> {
+        let exists: Vec<bool> = self
+            .cache_client
+            .keys_exist(&self.cache_name, vec![key.to_vec()])
+            .await?
+            .into();
+        if exists.first() == Some(&true) {
+            self.cache_client
+                .delete(&self.cache_name, key.to_vec())
+                .await?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    async fn touch(&self, key: &[u8], expiry: Expiry) -> Result<bool, CommandError> {
+        let ttl = match expiry {
+            Expiry::Expired => return self.delete(key).await,
+            Expiry::Default => self.default_ttl,
+            Expiry::After(ttl) => ttl,
+        };
+        match self
+            .cache_client
+            .update_ttl(&self.cache_name, key.to_vec(), ttl)
+            .await?
+        {
+            UpdateTtlResponse::Set => Ok(true),
+            UpdateTtlResponse::Miss => Ok(false),
+        }
+    }
+
+    /// `mg key [flags]`. `q` hides misses.
+    async fn meta_get(
+        &self,
+        key: &[u8],
+        flags: &MetaFlags,
+        out: &mut Vec<u8>,
+    ) -> Result<(), CommandError> {
+        check_flags("mg", flags, b"cfkOqstvT")?;
+        let touch = match flags.token(b'T') {
+            Some(exptime) => Some(Expiry::parse(Some(exptime))?),
+            None => None,
+        };
+        let item = self.fetch(key, touch).await?;
+        let ttl = match (&item, flags.has(b't')) {
+            (Some(_), true) => match self
+                .cache_client
+                .item_get_ttl(&self.cache_name, key.to_vec())
+                .await?
+            {
+                ItemGetTtlResponse::Hit { remaining_ttl } => Some(remaining_ttl.as_secs()),
+                ItemGetTtlResponse::Miss => None,
+            },
+            _ => None,
+        };
+        match item {
+            Some(item) => {
+                let returned = return_flags(key, flags, Some(&item), ttl);
+                if flags.has(b'v') {
+                    write_status(out, &format!("VA {}", item.data.len()), &returned);
+                    out.extend_from_slice(&item.data);
+                    out.extend_from_slice(b"\r\n");
+                } else {
+                    write_status(out, "HD", &returned);
+                }
+            }
+            None if flags.has(b'q') => {}
+            None => write_status(out, "EN", &return_flags(key, flags, None, None)),
+        }
+        Ok(())
+    }
+
+    /// `ms key datalen [flags]`. The mode flag `M` selects set (`S`), add (`E`), append (`A`),
+    /// prepend (`P`) or replace (`R`), and `C` compares the CAS token. `q` hides `HD`.
+    async fn meta_set(
+        &self,
+        key: Bytes,
+        data: Bytes,
+        flags: &MetaFlags,
+        out: &mut Vec<u8>,
+    ) -> Result<(), CommandError> {
+        check_flags("ms", flags, b"cCFkOqTM")?;
+        let invalid = |flag: char| CommandError::Client(format!("bad token in '{flag}' flag"));
+        let mode = match (flags.token(b'M'), flags.token(b'C')) {
+            (None | Some("S" | "s"), None) => StoreMode::Set,
+            (None | Some("S" | "s"), Some(cas)) => {
+                StoreMode::Cas(cas.parse().map_err(|_| invalid('C'))?)
+            }
+            (Some(_), Some(_)) => {
+                return Err(CommandError::Client(
+                    "the 'C' flag of 'ms' is only supported in set mode".to_string(),
+                ))
+            }
+            (Some("E" | "e"), None) => StoreMode::Add,
+            (Some("A" | "a"), None) => StoreMode::Append,
+            (Some("P" | "p"), None) => StoreMode::Prepend,
+            (Some("R" | "r"), None) => StoreMode::Replace,
+            (Some(_), None) => return Err(invalid('M')),
+        };
+        let client_flags = match flags.token(b'F') {
+            Some(client_flags) => client_flags.parse().map_err(|_| invalid('F'))?,
+            None => 0,
+        };
+        let expiry = Expiry::parse(flags.token(b'T'))?;
+
+        let outcome = self
+            .store(key.clone(), mode, Item::new(client_flags, data), expiry)
+            .await?;
+        let (status, item) = match &outcome {
+            StoreOutcome::Stored(_) if flags.has(b'q') => return Ok(()),
+            StoreOutcome::Stored(item) => ("HD", Some(item)),
+            StoreOutcome::NotStored => ("NS", None),
+            StoreOutcome::Exists => ("EX", None),
+            StoreOutcome::NotFound => ("NF", None),
+        };
+        write_status(out, status, &return_flags(&key, flags, item, None));
+        Ok(())
+    }
+
+    /// `md key [flags]`. `q` hides `HD` and `NF`.
+    async fn meta_delete(
+        &self,
+        key: &[u8],
+        flags: &MetaFlags,
+        out: &mut Vec<u8>,
+    ) -> Result<(), CommandError> {
+        check_flags("md", flags, b"kOq")?;
+        let status = if self.delete(key).await? { "HD" } else { "NF" };
+        if !flags.has(b'q') {
+            write_status(out, status, &return_flags(key, flags, None, None));
+        }
+        Ok(())
+    }
+
+    /// `ma key [flags]`. The mode flag `M` selects increment (`I` or `+`) or decrement (`D` or
+    /// `-`), and `D` sets the delta, 1 by default. `q` hides `HD` and `NF`.
+    async fn meta_arithmetic(
+        &self,
+        key: &[u8],
+        flags: &MetaFlags,
+        out: &mut Vec<u8>,
+    ) -> Result<(), CommandError> {
+        check_flags("ma", flags, b"cDkMOqv")?;
+        let decrement = match flags.token(b'M') {
+            None | Some("I" | "i" | "+") => false,
+            Some("D" | "d" | "-") => true,
+            Some(_) => return Err(CommandError::Client("bad token in 'M' flag".to_string())),
+        };
+        let delta = match flags.token(b'D') {
+            Some(delta) => delta
+                .parse()
+                .map_err(|_| CommandError::Client("bad token in 'D' flag".to_string()))?,
+            None => 1,
+        };
+
+        match self.arithmetic(key, delta, decrement).await? {
+            Some(item) if flags.has(b'v') => {
+                let returned = return_flags(key, flags, Some(&item), None);
+                write_status(out, &format!("VA {}", item.data.len()), &returned);
+                out.extend_from_slice(&item.data);
+                out.extend_from_slice(b"\r\n");
+            }
+            _ if flags.has(b'q') => {}
+            Some(item) => write_status(out, "HD", &return_flags(key, flags, Some(&item), None)),
+            None => write_status(out, "NF", &return_flags(key, flags, None, None)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_memcached_expiry_times() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(Expiry::new(0, now), Expiry::Default);
+        assert_eq!(Expiry::new(-1, now), Expiry::Expired);
+        assert_eq!(Expiry::new(60, now), Expiry::After(Duration::from_secs(60)));
+        assert_eq!(
+            Expiry::new(MAX_RELATIVE_EXPTIME, now),
+            Expiry::After(Duration::from_secs(MAX_RELATIVE_EXPTIME as u64))
+        );
+        assert_eq!(
+            Expiry::new(1_700_000_090, now),
+            Expiry::After(Duration::from_secs(90))
+        );
+        assert_eq!(Expiry::new(1_600_000_000, now), Expiry::Expired);
+    }
+
+    #[test]
+    fn applies_deltas_like_memcached() {
+        assert_eq!(apply_delta(b"41", 1, false), Ok(42));
+        assert_eq!(apply_delta(b"10 ", 3, true), Ok(7));
+        assert_eq!(apply_delta(b"2", 5, true), Ok(0));
+        assert_eq!(
+            apply_delta(u64::MAX.to_string().as_bytes(), 2, false),
+            Ok(1)
+        );
+        assert!(apply_delta(b"-1", 1, false).is_err());
+        assert!(apply_delta(b"abc", 1, false).is_err());
+    }
+
+    #[test]
+    fn returns_requested_meta_flags_in_order() {
+        let item = Item {
+            flags: 7,
+            cas: 99,
+            data: Bytes::from("hello"),
+        };
+        let mut buffer = bytes::BytesMut::from(&b"mg key s Oxyz v c k f t\r\n"[..]);
+        let flags = match crate::protocol::parse_request(&mut buffer) {
+            Ok(Some(Request::MetaGet { flags, .. })) => flags,
+            other => panic!("expected a meta get, got {other:?}"),
+        };
+        assert_eq!(
+            return_flags(b"key", &flags, Some(&item), Some(30)),
+            b" s5 Oxyz c99 kkey f7 t30".to_vec()
+        );
+        assert_eq!(
+            return_flags(b"key", &flags, None, None),
+            b" Oxyz kkey".to_vec()
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! Serving one client connection.
+
+use bytes::BytesMut;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::commands::Commands;
+use crate::protocol::{parse_request, Request};
+
+/// Serve the client until it disconnects or sends `quit`. Pipelined requests are run in order
+/// and their replies written together.
+pub async fn serve(commands: Commands, mut stream: TcpStream) -> std::io::Result<()> {
+    let mut buffer = BytesMut::with_capacity(16 * 1024);
+    let mut out = Vec::new();
+    loop {
+        loop {
+            match parse_request(&mut buffer) {
+                Ok(Some(Request::Quit)) => return stream.write_all(&out).await,
+                Ok(Some(request)) => commands.execute(request, &mut out).await,
+                Ok(None) => break,
+                Err(error) => {
+                    error.write(&mut out);
+                    if error.is_fatal() {
+                        return stream.write_all(&out).await;
+                    }
+                }
+            }
+        }
+        if !out.is_empty() {
+            stream.write_all(&out).await?;
+            out.clear();
+        }
+        if stream.read_buf(&mut buffer).await? == 0 {
+            return Ok(());
+        }
+    }
+}
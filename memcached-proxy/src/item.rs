@@ -0,0 +1,137 @@
+//! The header stored in front of each value, holding its memcached flags and CAS token.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+
+/// Marks a value as written by this proxy, and the version of the header that follows.
+const MAGIC: [u8; 2] = [0xfe, 0x01];
+
+/// The magic bytes, the flags and the CAS token.
+const HEADER_LEN: usize = MAGIC.len() + 4 + 8;
+
+/// An item as seen by memcached clients.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Item {
+    pub flags: u32,
+    pub cas: u64,
+    pub data: Bytes,
+}
+
+impl Item {
+    /// A new version of an item, with a fresh CAS token.
+    pub fn new(flags: u32, data: impl Into<Bytes>) -> Self {
+        Self {
+            flags,
+            cas: next_cas(),
+            data: data.into(),
+        }
+    }
+
+    /// A counter, as written by `incr` and `decr`. It has no flags, so it is stored without a
+    /// header where Momento's `increment` can update it, and like other such values its CAS
+    /// token is 0.
+    pub fn counter(value: u64) -> Self {
+        Self {
+            flags: 0,
+            cas: 0,
+            data: Bytes::from(value.to_string()),
+        }
+    }
+
+    /// Decode a value read from the cache. Values written by other Momento clients have no
+    /// header, and are read as having no flags and a CAS token of 0.
+    pub fn decode(value: Vec<u8>) -> Self {
+        if value.len() < HEADER_LEN || value[..MAGIC.len()] != MAGIC {
+            return Self {
+                flags: 0,
+                cas: 0,
+                data: Bytes::from(value),
+            };
+        }
+        let mut flags = [0; 4];
+        flags.copy_from_slice(&value[MAGIC.len()..MAGIC.len() + 4]);
+        let mut cas = [0; 8];
+        cas.copy_from_slice(&value[MAGIC.len() + 4..HEADER_LEN]);
+        Self {
+            flags: u32::from_be_bytes(flags),
+            cas: u64::from_be_bytes(cas),
+            data: Bytes::from(value).slice(HEADER_LEN..),
+        }
+    }
+
+    /// Encode the item as a value to store in the cache. Items with neither flags nor a CAS
+    /// token, such as counters, are stored as they are.
+    pub fn encode(&self) -> Vec<u8> {
+        if self.flags == 0 && self.cas == 0 {
+            return self.data.to_vec();
+        }
+        let mut value = Vec::with_capacity(HEADER_LEN + self.data.len());
+        value.extend_from_slice(&MAGIC);
+        value.extend_from_slice(&self.flags.to_be_bytes());
+        value.extend_from_slice(&self.cas.to_be_bytes());
+        value.extend_from_slice(&self.data);
+        value
+    }
+}
+
+/// CAS tokens count up from the time the proxy started, so tokens from proxies sharing a cache
+/// are unlikely to collide. 0 is never issued, as it is the token of values without a header.
+fn next_cas() -> u64 {
+    static NEXT: OnceLock<AtomicU64> = OnceLock::new();
+    NEXT.get_or_init(|| {
+        let start = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since_epoch| since_epoch.as_nanos() as u64)
+            .unwrap_or_default();
+        AtomicU64::new(start.max(1))
+    })
+    .fetch_add(1, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_flags_cas_and_data() {
+        let item = Item::new(0xdead_beef, "hello");
+        assert_ne!(item.cas, 0);
+        assert_eq!(Item::decode(item.encode()), item);
+
+        let empty = Item::new(0, Bytes::new());
+        assert_eq!(Item::decode(empty.encode()), empty);
+    }
+
+    #[test]
+    fn counters_have_no_header() {
+        let counter = Item::counter(42);
+        assert_eq!(counter.encode(), b"42".to_vec());
+        assert_eq!(Item::decode(counter.encode()), counter);
+    }
+
+    #[test]
+    fn new_items_get_new_cas_tokens() {
+        assert_ne!(Item::new(0, "a").cas, Item::new(0, "a").cas);
+    }
+
+    #[test]
+    fn values_without_a_header_have_no_flags() {
+        for value in [
+            &b"12"[..],
+            b"\xfe\x01short",
+            b"a value written by another client",
+        ] {
+            assert_eq!(
+                Item::decode(value.to_vec()),
+                Item {
+                    flags: 0,
+                    cas: 0,
+                    data: Bytes::copy_from_slice(value),
+                }
+            );
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! `momento-memcached-proxy` accepts memcached clients speaking the text or meta protocol on a
+//! local port and translates their commands to Momento cache operations.
+//!
+//! ```text
+//! MOMENTO_API_KEY=... momento-memcached-proxy --cache my-cache --listen 127.0.0.1:11211
+//! printf 'set greeting 0 60 5\r\nhello\r\nget greeting\r\n' | nc 127.0.0.1 11211
+//! ```
+//!
+//! `get`, `set`, `add`, `replace`, `delete` and `touch` map to `get`, `set`, `set_if_absent`,
+//! `set_if_present`, `delete` and `update_ttl`, as do the meta commands `mg`, `ms`, `md` and
+//! `ma`. Each value is stored behind a small header holding the item's client flags and CAS
+//! token, so `cas` compares the token and then writes with `set_if_equal` against the value it
+//! read. `append` and `prepend` read the item and write it back the same way, retrying if another
+//! client changed it in between.
+//!
+//! Counters are stored without the header, so `incr` and `decr` map to Momento's `increment`. The
+//! first `incr` or `decr` on a value written with `set` rewrites it as a counter. Counters have a
+//! CAS token of 0. Counters with client flags keep the header, so they are read and written back
+//! like `append`, as are counters past 2^63 - 1, which Momento cannot hold as a signed number.
+//!
+//! Momento items always expire, so an expiry time of 0 means the default TTL rather than never.
+//! Values written by other Momento clients have no header and are read with flags and a CAS
+//! token of 0.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use clap::Parser;
+use momento::{CacheClient, CredentialProvider};
+use tokio::net::TcpListener;
+
+use crate::commands::Commands;
+
+mod commands;
+mod connection;
+mod item;
+mod protocol;
+
+#[derive(Parser, Debug)]
+#[command(
+    version,
+    about = "Serve the memcached protocol on top of a Momento cache"
+)]
+struct Options {
+    /// The address to accept memcached clients on.
+    #[arg(long, default_value = "127.0.0.1:11211")]
+    listen: SocketAddr,
+
+    /// The cache to store items in.
+    #[arg(long, env = "MOMENTO_CACHE_NAME")]
+    cache: String,
+
+    /// The environment variable holding the Momento API key.
+    #[arg(long, default_value = "MOMENTO_API_KEY")]
+    api_key_env_var: String,
+
+    /// The TTL of items stored with an expiry time of 0, in seconds.
+    #[arg(long, default_value_t = 3600)]
+    default_ttl: u64,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+    let options = Options::parse();
+
+    let default_ttl = Duration::from_secs(options.default_ttl);
+    let cache_client = CacheClient::builder()
+        .default_ttl(default_ttl)
+        .configuration(momento::cache::configurations::InRegion::latest())
+        .credential_provider(CredentialProvider::from_env_var(&options.api_key_env_var)?)
+        .build()?;
+    let commands = Commands::new(cache_client, options.cache, default_ttl);
+
+    let listener = TcpListener::bind(options.listen).await?;
+    log::info!("accepting memcached clients on {}", options.listen);
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (stream, peer) = accepted?;
+                let commands = commands.clone();
+                tokio::spawn(async move {
+                    log::debug!("{peer} connected");
+                    if let Err(error) = connection::serve(commands, stream).await {
+                        log::debug!("{peer} disconnected: {error}");
+                    }
+                });
+            }
+            _ = tokio::signal::ctrl_c() => {
+                log::info!("shutting down");
+                return Ok(());
+            }
+        }
+    }
+}
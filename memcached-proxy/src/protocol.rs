@@ -0,0 +1,455 @@
+//! Parsing requests in the memcached text and meta protocols.
+
+use bytes::{Buf, Bytes, BytesMut};
+
+/// The longest command line a client may send, which bounds how many keys one `get` may have.
+const MAX_LINE_LEN: usize = 64 * 1024;
+
+/// The longest key memcached accepts.
+const MAX_KEY_LEN: usize = 250;
+
+/// The largest value a client may store, matching memcached's default `-I 1m`.
+const MAX_ITEM_SIZE: usize = 1024 * 1024;
+
+/// A request from a client.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Request {
+    /// `get`, `gets`, `gat` and `gats`. `touch` is the new expiry time of `gat` and `gats`.
+    Get {
+        keys: Vec<Bytes>,
+        cas: bool,
+        touch: Option<i64>,
+    },
+    /// `set`, `add`, `replace`, `append`, `prepend` and `cas`.
+    Store {
+        mode: StoreMode,
+        key: Bytes,
+        flags: u32,
+        exptime: i64,
+        data: Bytes,
+        noreply: bool,
+    },
+    Delete {
+        key: Bytes,
+        noreply: bool,
+    },
+    /// `incr` and `decr`.
+    Arithmetic {
+        key: Bytes,
+        delta: u64,
+        decrement: bool,
+        noreply: bool,
+    },
+    Touch {
+        key: Bytes,
+        exptime: i64,
+        noreply: bool,
+    },
+    MetaGet {
+        key: Bytes,
+        flags: MetaFlags,
+    },
+    MetaSet {
+        key: Bytes,
+        data: Bytes,
+        flags: MetaFlags,
+    },
+    MetaDelete {
+        key: Bytes,
+        flags: MetaFlags,
+    },
+    MetaArithmetic {
+        key: Bytes,
+        flags: MetaFlags,
+    },
+    MetaNoop,
+    Version,
+    Verbosity {
+        noreply: bool,
+    },
+    Quit,
+}
+
+/// How a storage command treats an existing item.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoreMode {
+    Set,
+    Add,
+    Replace,
+    Append,
+    Prepend,
+    /// Store only if the item's CAS token is still the given one.
+    Cas(u64),
+}
+
+/// The flags of a meta command, each a single character optionally followed by a token, e.g.
+/// `v`, `T30` or `Oopaque`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MetaFlags(Vec<(u8, String)>);
+
+impl MetaFlags {
+    pub fn has(&self, flag: u8) -> bool {
+        self.0.iter().any(|(name, _)| *name == flag)
+    }
+
+    pub fn token(&self, flag: u8) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(name, _)| *name == flag)
+            .map(|(_, token)| token.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &str)> {
+        self.0.iter().map(|(name, token)| (*name, token.as_str()))
+    }
+}
+
+/// A request that cannot be served.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ProtocolError {
+    /// The command is not one the proxy knows. The client is sent `ERROR`.
+    UnknownCommand,
+    /// The command is malformed. The client is sent a `CLIENT_ERROR` and may carry on.
+    Client(String),
+    /// The client cannot be kept in step with, for example because it sent a line or value that
+    /// is too long. The client is sent this error line and disconnected.
+    Fatal(String),
+}
+
+impl ProtocolError {
+    fn bad_format() -> Self {
+        ProtocolError::Client("bad command line format".to_string())
+    }
+
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, ProtocolError::Fatal(_))
+    }
+
+    pub fn write(&self, out: &mut Vec<u8>) {
+        match self {
+            ProtocolError::UnknownCommand => out.extend_from_slice(b"ERROR\r\n"),
+            ProtocolError::Client(message) => {
+                out.extend_from_slice(format!("CLIENT_ERROR {message}\r\n").as_bytes())
+            }
+            ProtocolError::Fatal(line) => {
+                out.extend_from_slice(line.as_bytes());
+                out.extend_from_slice(b"\r\n");
+            }
+        }
+    }
+}
+
+/// Parse one request from the start of `buffer`, consuming it. Returns `Ok(None)` if the buffer
+/// does not yet contain a complete request. A malformed request is consumed too, so the next
+/// call parses the request after it.
+pub fn parse_request(buffer: &mut BytesMut) -> Result<Option<Request>, ProtocolError> {
+    let newline = match buffer.iter().position(|byte| *byte == b'\n') {
+        Some(newline) => newline,
+        None if buffer.len() > MAX_LINE_LEN => {
+            return Err(ProtocolError::Fatal(
+                "CLIENT_ERROR line too long".to_string(),
+            ))
+        }
+        None => return Ok(None),
+    };
+    let line = &buffer[..newline];
+    let line = line.strip_suffix(b"\r").unwrap_or(line);
+    let tokens: Vec<Bytes> = line
+        .split(|byte| *byte == b' ')
+        .filter(|token| !token.is_empty())
+        .map(Bytes::copy_from_slice)
+        .collect();
+
+    let data = match data_length(&tokens) {
+        Ok(Some(length)) if length > MAX_ITEM_SIZE => {
+            return Err(ProtocolError::Fatal(
+                "SERVER_ERROR object too large for cache".to_string(),
+            ))
+        }
+        Ok(Some(length)) => {
+            if buffer.len() < newline + 1 + length + 2 {
+                return Ok(None);
+            }
+            buffer.advance(newline + 1);
+            let data = buffer.split_to(length).freeze();
+            if buffer.split_to(2)[..] != b"\r\n"[..] {
+                return Err(ProtocolError::Client("bad data chunk".to_string()));
+            }
+            Some(data)
+        }
+        Ok(None) => {
+            buffer.advance(newline + 1);
+            None
+        }
+        Err(error) => {
+            buffer.advance(newline + 1);
+            return Err(error);
+        }
+    };
+    request(tokens, data).map(Some)
+}
+
+/// The length of the data block following the command line, if the command has one.
+fn data_length(tokens: &[Bytes]) -> Result<Option<usize>, ProtocolError> {
+    let position = match tokens.first().map(|name| &name[..]) {
+        Some(b"set" | b"add" | b"replace" | b"append" | b"prepend" | b"cas") => 4,
+        Some(b"ms") => 2,
+        _ => return Ok(None),
+    };
+    match tokens.get(position) {
+        Some(length) => number(length).map(Some),
+        None => Err(ProtocolError::bad_format()),
+    }
+}
+
+fn request(tokens: Vec<Bytes>, data: Option<Bytes>) -> Result<Request, ProtocolError> {
+    let mut tokens = tokens.into_iter();
+    let name = match tokens.next() {
+        Some(name) => name,
+        None => return Err(ProtocolError::UnknownCommand),
+    };
+    let mut arguments: Vec<Bytes> = tokens.collect();
+    let noreply = arguments.last().is_some_and(|last| &last[..] == b"noreply");
+    let request = match &name[..] {
+        b"get" | b"gets" if !arguments.is_empty() => Request::Get {
+            keys: keys(arguments)?,
+            cas: &name[..] == b"gets",
+            touch: None,
+        },
+        b"gat" | b"gats" if arguments.len() >= 2 => Request::Get {
+            touch: Some(number(&arguments[0])?),
+            keys: keys(arguments.split_off(1))?,
+            cas: &name[..] == b"gats",
+        },
+        b"set" | b"add" | b"replace" | b"append" | b"prepend" | b"cas" => {
+            let (expected, mode) = match &name[..] {
+                b"set" => (4, StoreMode::Set),
+                b"add" => (4, StoreMode::Add),
+                b"replace" => (4, StoreMode::Replace),
+                b"append" => (4, StoreMode::Append),
+                b"prepend" => (4, StoreMode::Prepend),
+                _ => (5, StoreMode::Cas(number(argument(&arguments, 4)?)?)),
+            };
+            expect(&arguments, expected, noreply)?;
+            Request::Store {
+                mode,
+                key: key(&arguments[0])?,
+                flags: number(&arguments[1])?,
+                exptime: number(&arguments[2])?,
+                data: data.unwrap_or_default(),
+                noreply,
+            }
+        }
+        b"delete" => {
+            expect(&arguments, 1, noreply)?;
+            Request::Delete {
+                key: key(&arguments[0])?,
+                noreply,
+            }
+        }
+        b"incr" | b"decr" => {
+            expect(&arguments, 2, noreply)?;
+            Request::Arithmetic {
+                key: key(&arguments[0])?,
+                delta: number(&arguments[1]).map_err(|_| {
+                    ProtocolError::Client("invalid numeric delta argument".to_string())
+                })?,
+                decrement: &name[..] == b"decr",
+                noreply,
+            }
+        }
+        b"touch" => {
+            expect(&arguments, 2, noreply)?;
+            Request::Touch {
+                key: key(&arguments[0])?,
+                exptime: number(&arguments[1])?,
+                noreply,
+            }
+        }
+        b"mg" | b"md" | b"ma" => {
+            let key = key(argument(&arguments, 0)?)?;
+            let flags = meta_flags(&arguments[1..]);
+            match &name[..] {
+                b"mg" => Request::MetaGet { key, flags },
+                b"md" => Request::MetaDelete { key, flags },
+                _ => Request::MetaArithmetic { key, flags },
+            }
+        }
+        b"ms" => Request::MetaSet {
+            key: key(&arguments[0])?,
+            data: data.unwrap_or_default(),
+            flags: meta_flags(&arguments[2..]),
+        },
+        b"mn" => Request::MetaNoop,
+        b"version" => Request::Version,
+        b"verbosity" => {
+            expect(&arguments, 1, noreply)?;
+            Request::Verbosity { noreply }
+        }
+        b"quit" => Request::Quit,
+        b"get" | b"gets" | b"gat" | b"gats" => return Err(ProtocolError::bad_format()),
+        _ => return Err(ProtocolError::UnknownCommand),
+    };
+    Ok(request)
+}
+
+/// Fail unless there are `expected` arguments, plus `noreply` if it was given.
+fn expect(arguments: &[Bytes], expected: usize, noreply: bool) -> Result<(), ProtocolError> {
+    if arguments.len() == expected + usize::from(noreply) {
+        Ok(())
+    } else {
+        Err(ProtocolError::bad_format())
+    }
+}
+
+fn argument(arguments: &[Bytes], index: usize) -> Result<&Bytes, ProtocolError> {
+    arguments.get(index).ok_or_else(ProtocolError::bad_format)
+}
+
+fn key(key: &Bytes) -> Result<Bytes, ProtocolError> {
+    if key.len() > MAX_KEY_LEN {
+        return Err(ProtocolError::Client("key too long".to_string()));
+    }
+    Ok(key.clone())
+}
+
+fn keys(keys: Vec<Bytes>) -> Result<Vec<Bytes>, ProtocolError> {
+    keys.iter().map(key).collect()
+}
+
+fn number<T: std::str::FromStr>(token: &[u8]) -> Result<T, ProtocolError> {
+    std::str::from_utf8(token)
+        .ok()
+        .and_then(|token| token.parse().ok())
+        .ok_or_else(ProtocolError::bad_format)
+}
+
+fn meta_flags(tokens: &[Bytes]) -> MetaFlags {
+    MetaFlags(
+        tokens
+            .iter()
+            .map(|token| (token[0], String::from_utf8_lossy(&token[1..]).into_owned()))
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &[u8]) -> (Result<Option<Request>, ProtocolError>, usize) {
+        let mut buffer = BytesMut::from(input);
+        let result = parse_request(&mut buffer);
+        (result, buffer.len())
+    }
+
+    #[test]
+    fn parses_retrieval_commands() {
+        let (result, remaining) = parse(b"gets a b\r\ngat 30 c\n");
+        assert_eq!(
+            result,
+            Ok(Some(Request::Get {
+                keys: vec![Bytes::from("a"), Bytes::from("b")],
+                cas: true,
+                touch: None,
+            }))
+        );
+        assert_eq!(remaining, 9);
+
+        let (result, _) = parse(b"gat 30 c\n");
+        assert_eq!(
+            result,
+            Ok(Some(Request::Get {
+                keys: vec![Bytes::from("c")],
+                cas: false,
+                touch: Some(30),
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_storage_commands_with_their_data() {
+        let (result, remaining) = parse(b"cas key 5 60 4 99 noreply\r\n\r\nab\r\nversion\r\n");
+        assert_eq!(
+            result,
+            Ok(Some(Request::Store {
+                mode: StoreMode::Cas(99),
+                key: Bytes::from("key"),
+                flags: 5,
+                exptime: 60,
+                data: Bytes::from("\r\nab"),
+                noreply: true,
+            }))
+        );
+        assert_eq!(remaining, 9);
+    }
+
+    #[test]
+    fn waits_for_incomplete_requests() {
+        for input in [
+            &b"get key"[..],
+            b"set key 0 0 5\r\nhel",
+            b"set key 0 0 5\r\nhello",
+        ] {
+            let (result, remaining) = parse(input);
+            assert_eq!(result, Ok(None));
+            assert_eq!(remaining, input.len());
+        }
+    }
+
+    #[test]
+    fn consumes_malformed_requests() {
+        let (result, remaining) = parse(b"set key 0 0 2\r\nabc\r\n");
+        assert_eq!(
+            result,
+            Err(ProtocolError::Client("bad data chunk".to_string()))
+        );
+        assert_eq!(remaining, 1);
+
+        assert_eq!(
+            parse(b"incr key\r\n"),
+            (Err(ProtocolError::bad_format()), 0)
+        );
+        assert_eq!(
+            parse(b"flush_all\r\n"),
+            (Err(ProtocolError::UnknownCommand), 0)
+        );
+        let long_key = format!("get {}\r\n", "k".repeat(251));
+        assert!(matches!(
+            parse(long_key.as_bytes()),
+            (Err(ProtocolError::Client(_)), 0)
+        ));
+    }
+
+    #[test]
+    fn rejects_oversized_values_and_lines() {
+        assert!(parse(b"set key 0 0 2000000\r\n").0.unwrap_err().is_fatal());
+        assert!(parse(&vec![b'a'; MAX_LINE_LEN + 1])
+            .0
+            .unwrap_err()
+            .is_fatal());
+    }
+
+    #[test]
+    fn parses_meta_commands() {
+        let (result, _) = parse(b"ms key 2 T30 F5 MA q\r\nhi\r\n");
+        let flags = match result {
+            Ok(Some(Request::MetaSet { key, data, flags })) => {
+                assert_eq!((key, data), (Bytes::from("key"), Bytes::from("hi")));
+                flags
+            }
+            other => panic!("expected a meta set, got {other:?}"),
+        };
+        assert_eq!(flags.token(b'T'), Some("30"));
+        assert_eq!(flags.token(b'M'), Some("A"));
+        assert!(flags.has(b'q'));
+        assert!(!flags.has(b'v'));
+
+        assert!(matches!(
+            parse(b"mg key v c Oabc\r\n").0,
+            Ok(Some(Request::MetaGet { .. }))
+        ));
+        assert_eq!(parse(b"mn\r\n").0, Ok(Some(Request::MetaNoop)));
+        assert_eq!(parse(b"mg\r\n").0, Err(ProtocolError::bad_format()));
+    }
+}
@@ -0,0 +1,931 @@
+//! Translating Redis commands on keys to `CacheClient` calls.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use bytes::Bytes;
+use momento::cache::{
+    DictionaryFetchResponse, DictionaryGetFieldResponse, DictionaryGetFieldsResponse, GetResponse,
+    ItemGetTtlResponse, ListFetchRequest, ListLengthResponse, ListPopFrontResponse, MomentoRequest,
+    ScoreBound, SetFetchResponse, SetIfAbsentRequest, SetIfAbsentResponse, SetIfPresentRequest,
+    SetIfPresentResponse, SetRequest, SortedSetFetchByScoreRequest, SortedSetFetchResponse,
+    SortedSetGetRankResponse, SortedSetGetScoreResponse, SortedSetGetScoresResponse,
+    SortedSetOrder, UpdateTtlResponse,
+};
+use momento::{CacheClient, MomentoError, TopicClient};
+
+use crate::resp::Frame;
+
+/// The result of running a command: a reply, or an error to send to the client.
+pub type CommandResult = Result<Frame, CommandError>;
+
+/// An error reply. The message starts with an error code such as `ERR`, as in Redis.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CommandError(pub String);
+
+impl CommandError {
+    fn syntax() -> Self {
+        Self("ERR syntax error".to_string())
+    }
+
+    fn not_an_integer() -> Self {
+        Self("ERR value is not an integer or out of range".to_string())
+    }
+
+    fn not_a_float() -> Self {
+        Self("ERR value is not a valid float".to_string())
+    }
+
+    fn unsupported_option(command: &str, option: &str) -> Self {
+        Self(format!(
+            "ERR the '{option}' option of '{command}' is not supported by momento-redis-proxy"
+        ))
+    }
+}
+
+impl From<MomentoError> for CommandError {
+    fn from(error: MomentoError) -> Self {
+        Self(format!(
+            "ERR momento {:?}: {}",
+            error.error_code, error.message
+        ))
+    }
+}
+
+impl From<CommandError> for Frame {
+    fn from(error: CommandError) -> Self {
+        Frame::Error(error.0)
+    }
+}
+
+/// The arguments of a command, consumed in order.
+pub struct Arguments {
+    name: String,
+    arguments: std::vec::IntoIter<Bytes>,
+}
+
+impl Arguments {
+    /// Split a command into its lower-cased name and its arguments. Returns `None` for an empty
+    /// command.
+    pub fn new(command: Vec<Bytes>) -> Option<Self> {
+        let mut arguments = command.into_iter();
+        let name = String::from_utf8_lossy(&arguments.next()?).to_ascii_lowercase();
+        Some(Self { name, arguments })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn len(&self) -> usize {
+        self.arguments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn wrong_number(&self) -> CommandError {
+        CommandError(format!(
+            "ERR wrong number of arguments for '{}' command",
+            self.name
+        ))
+    }
+
+    /// Fail unless between `min` and `max` arguments remain.
+    pub fn expect(&self, min: usize, max: usize) -> Result<(), CommandError> {
+        if (min..=max).contains(&self.len()) {
+            Ok(())
+        } else {
+            Err(self.wrong_number())
+        }
+    }
+
+    pub fn bytes(&mut self) -> Result<Bytes, CommandError> {
+        let wrong_number = self.wrong_number();
+        self.arguments.next().ok_or(wrong_number)
+    }
+
+    pub fn string(&mut self) -> Result<String, CommandError> {
+        Ok(String::from_utf8_lossy(&self.bytes()?).into_owned())
+    }
+
+    /// The next argument upper-cased, for matching options.
+    pub fn option(&mut self) -> Result<String, CommandError> {
+        Ok(self.string()?.to_ascii_uppercase())
+    }
+
+    pub fn integer(&mut self) -> Result<i64, CommandError> {
+        self.string()?
+            .parse()
+            .map_err(|_| CommandError::not_an_integer())
+    }
+
+    pub fn float(&mut self) -> Result<f64, CommandError> {
+        parse_float(&self.string()?).ok_or_else(CommandError::not_a_float)
+    }
+
+    pub fn rest(&mut self) -> Vec<Bytes> {
+        self.arguments.by_ref().collect()
+    }
+
+    fn rest_as_vecs(&mut self) -> Vec<Vec<u8>> {
+        self.arguments
+            .by_ref()
+            .map(|value| value.to_vec())
+            .collect()
+    }
+}
+
+fn parse_float(value: &str) -> Option<f64> {
+    match value.to_ascii_lowercase().as_str() {
+        "inf" | "+inf" => Some(f64::INFINITY),
+        "-inf" => Some(f64::NEG_INFINITY),
+        value => value.parse().ok().filter(|value: &f64| !value.is_nan()),
+    }
+}
+
+/// A score bound for `ZRANGEBYSCORE`: `-inf`, `+inf`, `1.5` or `(1.5` for an exclusive bound.
+fn parse_score_bound(value: &str) -> Result<Option<ScoreBound>, CommandError> {
+    let invalid = || CommandError("ERR min or max is not a float".to_string());
+    let (exclusive, score) = match value.strip_prefix('(') {
+        Some(score) => (true, score),
+        None => (false, value),
+    };
+    let score = parse_float(score).ok_or_else(invalid)?;
+    if score.is_infinite() {
+        return Ok(None);
+    }
+    Ok(Some(if exclusive {
+        ScoreBound::Exclusive(score)
+    } else {
+        ScoreBound::Inclusive(score)
+    }))
+}
+
+/// Convert an inclusive Redis range to a start and exclusive end, where `None` means the end of
+/// the collection. Returns `None` if the range is empty.
+fn exclusive_range(start: i64, stop: i64) -> Result<Option<(i32, Option<i32>)>, CommandError> {
+    if start > stop && (start < 0) == (stop < 0) {
+        return Ok(None);
+    }
+    let start = i32::try_from(start).map_err(|_| CommandError::not_an_integer())?;
+    let end = match stop {
+        -1 => None,
+        stop => Some(i32::try_from(stop + 1).map_err(|_| CommandError::not_an_integer())?),
+    };
+    Ok(Some((start, end)))
+}
+
+fn bulk(value: Vec<u8>) -> Frame {
+    Frame::Bulk(Bytes::from(value))
+}
+
+fn bulk_array(values: Vec<Vec<u8>>) -> Frame {
+    Frame::Array(values.into_iter().map(bulk).collect())
+}
+
+fn count(values: usize) -> Frame {
+    Frame::Integer(values as i64)
+}
+
+/// The distinct values, in sorted order. Redis counts a member or field given twice once.
+fn distinct(mut values: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    values.sort();
+    values.dedup();
+    values
+}
+
+/// Runs key-space and pub/sub publish commands against a single cache.
+#[derive(Clone, Debug)]
+pub struct Commands {
+    cache_client: CacheClient,
+    topic_client: TopicClient,
+    cache_name: String,
+}
+
+impl Commands {
+    pub fn new(cache_client: CacheClient, topic_client: TopicClient, cache_name: String) -> Self {
+        Self {
+            cache_client,
+            topic_client,
+            cache_name,
+        }
+    }
+
+    pub fn topic_client(&self) -> &TopicClient {
+        &self.topic_client
+    }
+
+    pub fn cache_name(&self) -> &str {
+        &self.cache_name
+    }
+
+    /// Run a command. Returns `None` if the command is not one of the supported key commands.
+    pub async fn execute(&self, arguments: &mut Arguments) -> Option<CommandResult> {
+        let result = match arguments.name() {
+            "get" => self.get(arguments).await,
+            "set" => self.set(arguments).await,
+            "del" => self.del(arguments).await,
+            "exists" => self.exists(arguments).await,
+            "incr" => self.increment(arguments, Some(1)).await,
+            "decr" => self.increment(arguments, Some(-1)).await,
+            "incrby" => self.increment(arguments, None).await,
+            "decrby" => self.decrement_by(arguments).await,
+            "expire" => self.expire(arguments, Duration::from_secs(1)).await,
+            "pexpire" => self.expire(arguments, Duration::from_millis(1)).await,
+            "ttl" => self.ttl(arguments, Duration::from_secs(1)).await,
+            "pttl" => self.ttl(arguments, Duration::from_millis(1)).await,
+            "hset" => self.hset(arguments).await,
+            "hget" => self.hget(arguments).await,
+            "hgetall" => self.hgetall(arguments).await,
+            "hincrby" => self.hincrby(arguments).await,
+            "lpush" => self.push(arguments, true).await,
+            "rpush" => self.push(arguments, false).await,
+            "lrange" => self.lrange(arguments).await,
+            "lpop" => self.lpop(arguments).await,
+            "llen" => self.llen(arguments).await,
+            "sadd" => self.sadd(arguments).await,
+            "smembers" => self.smembers(arguments).await,
+            "srem" => self.srem(arguments).await,
+            "zadd" => self.zadd(arguments).await,
+            "zrange" => self.zrange(arguments).await,
+            "zrangebyscore" => self.zrangebyscore(arguments).await,
+            "zrank" => self.zrank(arguments).await,
+            "zscore" => self.zscore(arguments).await,
+            "zincrby" => self.zincrby(arguments).await,
+            "publish" => self.publish(arguments).await,
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    async fn get(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(1, 1)?;
+        let key = arguments.bytes()?;
+        match self
+            .cache_client
+            .get(&self.cache_name, key.to_vec())
+            .await?
+        {
+            response @ GetResponse::Hit { .. } => Ok(bulk(Vec::<u8>::try_from(response)?)),
+            GetResponse::Miss => Ok(Frame::Null),
+        }
+    }
+
+    /// `SET key value [NX | XX] [EX seconds | PX milliseconds]`.
+    async fn set(&self, arguments: &mut Arguments) -> CommandResult {
+        if arguments.len() < 2 {
+            arguments.expect(2, 2)?;
+        }
+        let key = arguments.bytes()?.to_vec();
+        let value = arguments.bytes()?.to_vec();
+        let mut condition = None;
+        let mut ttl = None;
+        while !arguments.is_empty() {
+            let option = arguments.option()?;
+            match option.as_str() {
+                "NX" | "XX" if condition.is_none() => condition = Some(option),
+                "EX" | "PX" if ttl.is_none() => {
+                    let amount = u64::try_from(arguments.integer()?)
+                        .ok()
+                        .filter(|amount| *amount > 0)
+                        .ok_or_else(|| {
+                            CommandError("ERR invalid expire time in 'set' command".to_string())
+                        })?;
+                    ttl = Some(if option == "EX" {
+                        Duration::from_secs(amount)
+                    } else {
+                        Duration::from_millis(amount)
+                    });
+                }
+                "GET" | "KEEPTTL" | "EXAT" | "PXAT" => {
+                    return Err(CommandError::unsupported_option("set", &option))
+                }
+                _ => return Err(CommandError::syntax()),
+            }
+        }
+
+        let stored = match condition.as_deref() {
+            Some("NX") => matches!(
+                SetIfAbsentRequest::new(&self.cache_name, key, value)
+                    .ttl(ttl)
+                    .send(&self.cache_client)
+                    .await?,
+                SetIfAbsentResponse::Stored
+            ),
+            Some(_) => matches!(
+                SetIfPresentRequest::new(&self.cache_name, key, value)
+                    .ttl(ttl)
+                    .send(&self.cache_client)
+                    .await?,
+                SetIfPresentResponse::Stored
+            ),
+            None => {
+                SetRequest::new(&self.cache_name, key, value)
+                    .ttl(ttl)
+                    .send(&self.cache_client)
+                    .await?;
+                true
+            }
+        };
+        Ok(if stored { Frame::ok() } else { Frame::Null })
+    }
+
+    async fn existing_keys(&self, keys: &[Vec<u8>]) -> Result<usize, CommandError> {
+        let exists: Vec<bool> = self
+            .cache_client
+            .keys_exist(&self.cache_name, keys.to_vec())
+            .await?
+            .into();
+        Ok(exists.into_iter().filter(|exists| *exists).count())
+    }
+
+    /// Momento does not report whether a delete removed anything, so the keys are checked first.
+    async fn del(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(1, usize::MAX)?;
+        let mut keys = arguments.rest_as_vecs();
+        keys.sort();
+        keys.dedup();
+        let deleted = self.existing_keys(&keys).await?;
+        for key in keys {
+            self.cache_client.delete(&self.cache_name, key).await?;
+        }
+        Ok(count(deleted))
+    }
+
+    async fn exists(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(1, usize::MAX)?;
+        let keys = arguments.rest_as_vecs();
+        Ok(count(self.existing_keys(&keys).await?))
+    }
+
+    async fn increment(&self, arguments: &mut Arguments, amount: Option<i64>) -> CommandResult {
+        let expected = if amount.is_some() { 1 } else { 2 };
+        arguments.expect(expected, expected)?;
+        let key = arguments.bytes()?.to_vec();
+        let amount = match amount {
+            Some(amount) => amount,
+            None => arguments.integer()?,
+        };
+        let response = self
+            .cache_client
+            .increment(&self.cache_name, key, amount)
+            .await?;
+        Ok(Frame::Integer(response.value))
+    }
+
+    async fn decrement_by(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(2, 2)?;
+        let key = arguments.bytes()?.to_vec();
+        let amount = arguments
+            .integer()?
+            .checked_neg()
+            .ok_or_else(CommandError::not_an_integer)?;
+        let response = self
+            .cache_client
+            .increment(&self.cache_name, key, amount)
+            .await?;
+        Ok(Frame::Integer(response.value))
+    }
+
+    /// `EXPIRE key seconds`. As in Redis, a non-positive TTL deletes the key.
+    async fn expire(&self, arguments: &mut Arguments, unit: Duration) -> CommandResult {
+        arguments.expect(2, 3)?;
+        let key = arguments.bytes()?.to_vec();
+        let amount = arguments.integer()?;
+        if !arguments.is_empty() {
+            let option = arguments.option()?;
+            return Err(CommandError::unsupported_option(arguments.name(), &option));
+        }
+        if amount <= 0 {
+            let existed = self.existing_keys(std::slice::from_ref(&key)).await?;
+            self.cache_client.delete(&self.cache_name, key).await?;
+            return Ok(count(existed));
+        }
+        let ttl = u32::try_from(amount)
+            .map(|amount| unit * amount)
+            .map_err(|_| CommandError::not_an_integer())?;
+        match self
+            .cache_client
+            .update_ttl(&self.cache_name, key, ttl)
+            .await?
+        {
+            UpdateTtlResponse::Set => Ok(Frame::Integer(1)),
+            UpdateTtlResponse::Miss => Ok(Frame::Integer(0)),
+        }
+    }
+
+    /// `TTL key`. Every Momento item has a TTL, so this never returns -1.
+    async fn ttl(&self, arguments: &mut Arguments, unit: Duration) -> CommandResult {
+        arguments.expect(1, 1)?;
+        let key = arguments.bytes()?.to_vec();
+        match self
+            .cache_client
+            .item_get_ttl(&self.cache_name, key)
+            .await?
+        {
+            ItemGetTtlResponse::Hit { remaining_ttl } => {
+                // Round to the nearest unit, as Redis does.
+                let remaining = (remaining_ttl + unit / 2).as_nanos() / unit.as_nanos();
+                Ok(Frame::Integer(i64::try_from(remaining).unwrap_or(i64::MAX)))
+            }
+            ItemGetTtlResponse::Miss => Ok(Frame::Integer(-2)),
+        }
+    }
+
+    async fn existing_fields(
+        &self,
+        key: &[u8],
+        fields: Vec<Vec<u8>>,
+    ) -> Result<usize, CommandError> {
+        match self
+            .cache_client
+            .dictionary_get_fields(&self.cache_name, key.to_vec(), fields)
+            .await?
+        {
+            DictionaryGetFieldsResponse::Hit { responses, .. } => Ok(responses
+                .iter()
+                .filter(|response| matches!(response, DictionaryGetFieldResponse::Hit { .. }))
+                .count()),
+            DictionaryGetFieldsResponse::Miss => Ok(0),
+        }
+    }
+
+    /// `HSET key field value [field value ...]`. Momento does not report which fields are new,
+    /// so the fields are checked first.
+    async fn hset(&self, arguments: &mut Arguments) -> CommandResult {
+        if arguments.len() < 3 || arguments.len().is_multiple_of(2) {
+            return Err(arguments.wrong_number());
+        }
+        let key = arguments.bytes()?.to_vec();
+        let mut fields = Vec::with_capacity(arguments.len() / 2);
+        while !arguments.is_empty() {
+            fields.push((arguments.bytes()?.to_vec(), arguments.bytes()?.to_vec()));
+        }
+        let names = distinct(fields.iter().map(|(field, _)| field.clone()).collect());
+        let added = names.len() - self.existing_fields(&key, names).await?;
+        self.cache_client
+            .dictionary_set_fields(&self.cache_name, key, fields)
+            .await?;
+        Ok(count(added))
+    }
+
+    async fn hget(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(2, 2)?;
+        let key = arguments.bytes()?.to_vec();
+        let field = arguments.bytes()?.to_vec();
+        match self
+            .cache_client
+            .dictionary_get_field(&self.cache_name, key, field)
+            .await?
+        {
+            response @ DictionaryGetFieldResponse::Hit { .. } => {
+                Ok(bulk(Vec::<u8>::try_from(response)?))
+            }
+            DictionaryGetFieldResponse::Miss => Ok(Frame::Null),
+        }
+    }
+
+    async fn hgetall(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(1, 1)?;
+        let key = arguments.bytes()?.to_vec();
+        match self
+            .cache_client
+            .dictionary_fetch(&self.cache_name, key)
+            .await?
+        {
+            response @ DictionaryFetchResponse::Hit { .. } => {
+                let fields: HashMap<Vec<u8>, Vec<u8>> = response.try_into()?;
+                Ok(Frame::Map(
+                    fields
+                        .into_iter()
+                        .map(|(field, value)| (bulk(field), bulk(value)))
+                        .collect(),
+                ))
+            }
+            DictionaryFetchResponse::Miss => Ok(Frame::Map(Vec::new())),
+        }
+    }
+
+    async fn hincrby(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(3, 3)?;
+        let key = arguments.bytes()?.to_vec();
+        let field = arguments.bytes()?.to_vec();
+        let amount = arguments.integer()?;
+        let response = self
+            .cache_client
+            .dictionary_increment(&self.cache_name, key, field, amount)
+            .await?;
+        Ok(Frame::Integer(response.value))
+    }
+
+    /// `LPUSH` and `RPUSH`. Momento does not return the new length, so it is read afterwards.
+    async fn push(&self, arguments: &mut Arguments, front: bool) -> CommandResult {
+        arguments.expect(2, usize::MAX)?;
+        let key = arguments.bytes()?.to_vec();
+        let mut values = arguments.rest_as_vecs();
+        if front {
+            // LPUSH inserts the values one after the other, so the last ends up first.
+            values.reverse();
+            self.cache_client
+                .list_concatenate_front(&self.cache_name, key.clone(), values)
+                .await?;
+        } else {
+            self.cache_client
+                .list_concatenate_back(&self.cache_name, key.clone(), values)
+                .await?;
+        }
+        self.list_length(key).await
+    }
+
+    async fn list_length(&self, key: Vec<u8>) -> CommandResult {
+        match self.cache_client.list_length(&self.cache_name, key).await? {
+            ListLengthResponse::Hit { length } => Ok(Frame::Integer(i64::from(length))),
+            ListLengthResponse::Miss => Ok(Frame::Integer(0)),
+        }
+    }
+
+    async fn llen(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(1, 1)?;
+        let key = arguments.bytes()?.to_vec();
+        self.list_length(key).await
+    }
+
+    async fn lrange(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(3, 3)?;
+        let key = arguments.bytes()?.to_vec();
+        let (start, end) = match exclusive_range(arguments.integer()?, arguments.integer()?)? {
+            Some(range) => range,
+            None => return Ok(Frame::Array(Vec::new())),
+        };
+        let response = ListFetchRequest::new(&self.cache_name, key)
+            .start_index(start)
+            .end_index(end)
+            .send(&self.cache_client)
+            .await?;
+        Ok(bulk_array(
+            Vec::<Vec<u8>>::try_from(response).unwrap_or_default(),
+        ))
+    }
+
+    /// `LPOP key [count]`. With a count, elements are popped one at a time.
+    async fn lpop(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(1, 2)?;
+        let key = arguments.bytes()?.to_vec();
+        let count = if arguments.is_empty() {
+            None
+        } else {
+            Some(
+                usize::try_from(arguments.integer()?)
+                    .map_err(|_| CommandError::not_an_integer())?,
+            )
+        };
+
+        let mut popped = Vec::new();
+        for _ in 0..count.unwrap_or(1) {
+            match self
+                .cache_client
+                .list_pop_front(&self.cache_name, key.clone())
+                .await?
+            {
+                response @ ListPopFrontResponse::Hit { .. } => {
+                    popped.push(Vec::<u8>::try_from(response)?)
+                }
+                ListPopFrontResponse::Miss => break,
+            }
+        }
+        Ok(match count {
+            None => popped.pop().map(bulk).unwrap_or(Frame::Null),
+            // As in Redis, a count of 0 returns an empty array if the list exists.
+            Some(0) => match self.list_length(key).await? {
+                Frame::Integer(0) => Frame::Null,
+                _ => Frame::Array(Vec::new()),
+            },
+            Some(_) if popped.is_empty() => Frame::Null,
+            Some(_) => bulk_array(popped),
+        })
+    }
+
+    /// How many of `members` are in the set. Momento cannot check set membership, so the set is
+    /// fetched.
+    async fn existing_members(
+        &self,
+        key: &[u8],
+        members: &[Vec<u8>],
+    ) -> Result<usize, CommandError> {
+        match self
+            .cache_client
+            .set_fetch(&self.cache_name, key.to_vec())
+            .await?
+        {
+            response @ SetFetchResponse::Hit { .. } => {
+                let existing = Vec::<Vec<u8>>::try_from(response)?;
+                Ok(members
+                    .iter()
+                    .filter(|member| existing.contains(member))
+                    .count())
+            }
+            SetFetchResponse::Miss => Ok(0),
+        }
+    }
+
+    /// `SADD key member [member ...]`. Momento does not report which members are new, so the
+    /// set is checked first.
+    async fn sadd(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(2, usize::MAX)?;
+        let key = arguments.bytes()?.to_vec();
+        let members = distinct(arguments.rest_as_vecs());
+        let added = members.len() - self.existing_members(&key, &members).await?;
+        self.cache_client
+            .set_add_elements(&self.cache_name, key, members)
+            .await?;
+        Ok(count(added))
+    }
+
+    async fn smembers(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(1, 1)?;
+        let key = arguments.bytes()?.to_vec();
+        match self.cache_client.set_fetch(&self.cache_name, key).await? {
+            response @ SetFetchResponse::Hit { .. } => {
+                Ok(bulk_array(Vec::<Vec<u8>>::try_from(response)?))
+            }
+            SetFetchResponse::Miss => Ok(Frame::Array(Vec::new())),
+        }
+    }
+
+    /// `SREM key member [member ...]`. The set is checked first to count the members removed.
+    async fn srem(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(2, usize::MAX)?;
+        let key = arguments.bytes()?.to_vec();
+        let members = distinct(arguments.rest_as_vecs());
+        let removed = self.existing_members(&key, &members).await?;
+        self.cache_client
+            .set_remove_elements(&self.cache_name, key, members)
+            .await?;
+        Ok(count(removed))
+    }
+
+    async fn existing_scored_members(
+        &self,
+        key: &[u8],
+        members: Vec<Vec<u8>>,
+    ) -> Result<usize, CommandError> {
+        match self
+            .cache_client
+            .sorted_set_get_scores(&self.cache_name, key.to_vec(), members)
+            .await?
+        {
+            SortedSetGetScoresResponse::Hit { responses, .. } => Ok(responses
+                .iter()
+                .filter(|response| matches!(response, SortedSetGetScoreResponse::Hit { .. }))
+                .count()),
+            SortedSetGetScoresResponse::Miss => Ok(0),
+        }
+    }
+
+    /// `ZADD key score member [score member ...]`. The sorted set is checked first to count the
+    /// members added.
+    async fn zadd(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(3, usize::MAX)?;
+        let key = arguments.bytes()?.to_vec();
+        let mut elements = Vec::with_capacity(arguments.len() / 2);
+        while !arguments.is_empty() {
+            let score = arguments.string()?;
+            let score = match parse_float(&score) {
+                Some(score) => score,
+                None if ["NX", "XX", "GT", "LT", "CH", "INCR"]
+                    .contains(&score.to_ascii_uppercase().as_str()) =>
+                {
+                    return Err(CommandError::unsupported_option(
+                        "zadd",
+                        &score.to_ascii_uppercase(),
+                    ))
+                }
+                None => return Err(CommandError::not_a_float()),
+            };
+            if arguments.is_empty() {
+                return Err(CommandError::syntax());
+            }
+            elements.push((arguments.bytes()?.to_vec(), score));
+        }
+        let members = distinct(elements.iter().map(|(member, _)| member.clone()).collect());
+        let added = members.len() - self.existing_scored_members(&key, members).await?;
+        self.cache_client
+            .sorted_set_put_elements(&self.cache_name, key, elements)
+            .await?;
+        Ok(count(added))
+    }
+
+    /// `ZRANGE key start stop [REV] [WITHSCORES]`, by rank only.
+    async fn zrange(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(3, 5)?;
+        let key = arguments.bytes()?.to_vec();
+        let range = exclusive_range(arguments.integer()?, arguments.integer()?)?;
+        let mut order = SortedSetOrder::Ascending;
+        let mut with_scores = false;
+        while !arguments.is_empty() {
+            match arguments.option()?.as_str() {
+                "REV" => order = SortedSetOrder::Descending,
+                "WITHSCORES" => with_scores = true,
+                option @ ("BYSCORE" | "BYLEX" | "LIMIT") => {
+                    return Err(CommandError::unsupported_option("zrange", option))
+                }
+                _ => return Err(CommandError::syntax()),
+            }
+        }
+        let (start, end) = match range {
+            Some(range) => range,
+            None => return Ok(Frame::Array(Vec::new())),
+        };
+        let response = self
+            .cache_client
+            .sorted_set_fetch_by_rank(&self.cache_name, key, order, Some(start), end)
+            .await?;
+        Ok(sorted_set_elements(response, with_scores))
+    }
+
+    /// `ZRANGEBYSCORE key min max [WITHSCORES] [LIMIT offset count]`.
+    async fn zrangebyscore(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(3, 6)?;
+        let key = arguments.bytes()?.to_vec();
+        let min = parse_score_bound(&arguments.string()?)?;
+        let max = parse_score_bound(&arguments.string()?)?;
+        let mut request = SortedSetFetchByScoreRequest::new(&self.cache_name, key)
+            .min_score(min)
+            .max_score(max);
+        let mut with_scores = false;
+        while !arguments.is_empty() {
+            match arguments.option()?.as_str() {
+                "WITHSCORES" => with_scores = true,
+                "LIMIT" => {
+                    let offset = u32::try_from(arguments.integer()?)
+                        .map_err(|_| CommandError::not_an_integer())?;
+                    let count = i32::try_from(arguments.integer()?)
+                        .map_err(|_| CommandError::not_an_integer())?;
+                    request = request.offset(offset);
+                    if count >= 0 {
+                        request = request.count(count);
+                    }
+                }
+                _ => return Err(CommandError::syntax()),
+            }
+        }
+        let response = request.send(&self.cache_client).await?;
+        Ok(sorted_set_elements(response, with_scores))
+    }
+
+    async fn zrank(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(2, 2)?;
+        let key = arguments.bytes()?.to_vec();
+        let member = arguments.bytes()?.to_vec();
+        match self
+            .cache_client
+            .sorted_set_get_rank(&self.cache_name, key, member)
+            .await?
+        {
+            SortedSetGetRankResponse::Hit { rank } => {
+                Ok(Frame::Integer(i64::try_from(rank).unwrap_or(i64::MAX)))
+            }
+            SortedSetGetRankResponse::Miss => Ok(Frame::Null),
+        }
+    }
+
+    async fn zscore(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(2, 2)?;
+        let key = arguments.bytes()?.to_vec();
+        let member = arguments.bytes()?.to_vec();
+        match self
+            .cache_client
+            .sorted_set_get_score(&self.cache_name, key, member)
+            .await?
+        {
+            SortedSetGetScoreResponse::Hit { score } => Ok(Frame::Double(score)),
+            SortedSetGetScoreResponse::Miss => Ok(Frame::Null),
+        }
+    }
+
+    async fn zincrby(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(3, 3)?;
+        let key = arguments.bytes()?.to_vec();
+        let increment = arguments.float()?;
+        let member = arguments.bytes()?.to_vec();
+        let response = self
+            .cache_client
+            .sorted_set_increment_score(&self.cache_name, key, member, increment)
+            .await?;
+        Ok(Frame::Double(response.score))
+    }
+
+    /// `PUBLISH channel message`. Momento does not report how many subscribers received the
+    /// message, so this always returns 0.
+    async fn publish(&self, arguments: &mut Arguments) -> CommandResult {
+        arguments.expect(2, 2)?;
+        let channel = arguments.string()?;
+        let message = arguments.bytes()?.to_vec();
+        match String::from_utf8(message) {
+            Ok(text) => {
+                self.topic_client
+                    .publish(&self.cache_name, channel, text)
+                    .await?
+            }
+            Err(error) => {
+                self.topic_client
+                    .publish(&self.cache_name, channel, error.into_bytes())
+                    .await?
+            }
+        };
+        Ok(Frame::Integer(0))
+    }
+}
+
+fn sorted_set_elements(response: SortedSetFetchResponse, with_scores: bool) -> Frame {
+    let elements = match response {
+        SortedSetFetchResponse::Hit { value } => value.elements,
+        SortedSetFetchResponse::Miss => Vec::new(),
+    };
+    let mut frames = Vec::with_capacity(elements.len() * if with_scores { 2 } else { 1 });
+    for (member, score) in elements {
+        frames.push(bulk(member));
+        if with_scores {
+            frames.push(Frame::Double(score));
+        }
+    }
+    Frame::Array(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(command: &[&str]) -> Arguments {
+        Arguments::new(
+            command
+                .iter()
+                .map(|arg| Bytes::from(arg.to_string()))
+                .collect(),
+        )
+        .expect("command is not empty")
+    }
+
+    #[test]
+    fn arguments_are_consumed_in_order() {
+        let mut args = arguments(&["GeT", "key", "12", "-inf", "nx"]);
+        assert_eq!(args.name(), "get");
+        assert_eq!(args.len(), 4);
+        assert_eq!(args.bytes(), Ok(Bytes::from("key")));
+        assert_eq!(args.integer(), Ok(12));
+        assert_eq!(args.float(), Ok(f64::NEG_INFINITY));
+        assert_eq!(args.option(), Ok("NX".to_string()));
+        assert_eq!(
+            args.bytes(),
+            Err(CommandError(
+                "ERR wrong number of arguments for 'get' command".to_string()
+            ))
+        );
+        assert!(Arguments::new(Vec::new()).is_none());
+    }
+
+    #[test]
+    fn invalid_numbers_are_rejected() {
+        assert_eq!(
+            arguments(&["incrby", "1.5"]).integer(),
+            Err(CommandError::not_an_integer())
+        );
+        assert_eq!(
+            arguments(&["zincrby", "nan"]).float(),
+            Err(CommandError::not_a_float())
+        );
+    }
+
+    #[test]
+    fn distinct_values_are_counted_once() {
+        let values = vec![b"b".to_vec(), b"a".to_vec(), b"b".to_vec()];
+        assert_eq!(distinct(values), vec![b"a".to_vec(), b"b".to_vec()]);
+    }
+
+    #[test]
+    fn score_bounds() {
+        assert_eq!(parse_score_bound("-inf"), Ok(None));
+        assert_eq!(parse_score_bound("+inf"), Ok(None));
+        assert_eq!(
+            parse_score_bound("1.5"),
+            Ok(Some(ScoreBound::Inclusive(1.5)))
+        );
+        assert_eq!(
+            parse_score_bound("(2"),
+            Ok(Some(ScoreBound::Exclusive(2.0)))
+        );
+        assert!(parse_score_bound("(abc").is_err());
+    }
+
+    #[test]
+    fn inclusive_ranges_become_exclusive() {
+        assert_eq!(exclusive_range(0, -1), Ok(Some((0, None))));
+        assert_eq!(exclusive_range(1, 3), Ok(Some((1, Some(4)))));
+        assert_eq!(exclusive_range(-3, -2), Ok(Some((-3, Some(-1)))));
+        assert_eq!(exclusive_range(3, 1), Ok(None));
+        assert_eq!(exclusive_range(-1, -3), Ok(None));
+    }
+}
@@ -0,0 +1,308 @@
+//! Serving one client connection: connection state, pub/sub and dispatch to [Commands].
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use bytes::{Bytes, BytesMut};
+use futures::StreamExt;
+use momento::topics::ValueKind;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+
+use crate::commands::{Arguments, CommandError, CommandResult, Commands};
+use crate::resp::{parse_command, Frame};
+
+/// How many encoded replies and messages may be queued for a slow client.
+const OUTBOUND_CAPACITY: usize = 1024;
+
+/// Encodes frames for the client in its current protocol version and queues them for writing.
+#[derive(Clone)]
+struct Outbound {
+    sender: mpsc::Sender<Vec<u8>>,
+    resp3: Arc<AtomicBool>,
+}
+
+impl Outbound {
+    /// Returns `false` once the client has gone away.
+    async fn send(&self, frame: Frame) -> bool {
+        let mut encoded = Vec::new();
+        frame.encode(self.resp3.load(Ordering::Relaxed), &mut encoded);
+        self.sender.send(encoded).await.is_ok()
+    }
+}
+
+pub struct Connection {
+    commands: Commands,
+    outbound: Outbound,
+    subscriptions: HashMap<String, JoinHandle<()>>,
+}
+
+impl Connection {
+    /// Serve the client until it disconnects or sends `QUIT`.
+    pub async fn serve(commands: Commands, stream: TcpStream) -> std::io::Result<()> {
+        let (mut reader, mut writer) = stream.into_split();
+        let (sender, mut receiver) = mpsc::channel::<Vec<u8>>(OUTBOUND_CAPACITY);
+        let write_task = tokio::spawn(async move {
+            while let Some(encoded) = receiver.recv().await {
+                if writer.write_all(&encoded).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        let mut connection = Connection {
+            commands,
+            outbound: Outbound {
+                sender,
+                resp3: Arc::new(AtomicBool::new(false)),
+            },
+            subscriptions: HashMap::new(),
+        };
+        let result = connection.read_commands(&mut reader).await;
+
+        connection.unsubscribe_all();
+        drop(connection);
+        let _ = write_task.await;
+        result
+    }
+
+    async fn read_commands(
+        &mut self,
+        reader: &mut tokio::net::tcp::OwnedReadHalf,
+    ) -> std::io::Result<()> {
+        let mut buffer = BytesMut::with_capacity(16 * 1024);
+        loop {
+            loop {
+                let command = match parse_command(&mut buffer) {
+                    Ok(Some(command)) => command,
+                    Ok(None) => break,
+                    Err(error) => {
+                        self.outbound
+                            .send(Frame::error(format!("ERR {error}")))
+                            .await;
+                        return Ok(());
+                    }
+                };
+                let mut arguments = match Arguments::new(command) {
+                    Some(arguments) => arguments,
+                    None => continue,
+                };
+                if arguments.name() == "quit" {
+                    self.outbound.send(Frame::ok()).await;
+                    return Ok(());
+                }
+                let reply = match self.execute(&mut arguments).await {
+                    Ok(Some(frame)) => frame,
+                    Ok(None) => continue,
+                    Err(error) => error.into(),
+                };
+                if !self.outbound.send(reply).await {
+                    return Ok(());
+                }
+            }
+            if reader.read_buf(&mut buffer).await? == 0 {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Run a command. Returns `Ok(None)` if its replies have already been sent.
+    async fn execute(&mut self, arguments: &mut Arguments) -> Result<Option<Frame>, CommandError> {
+        let resp2_subscribed =
+            !self.subscriptions.is_empty() && !self.outbound.resp3.load(Ordering::Relaxed);
+        if resp2_subscribed
+            && !matches!(
+                arguments.name(),
+                "subscribe" | "unsubscribe" | "ping" | "quit" | "reset"
+            )
+        {
+            return Err(CommandError(format!(
+                "ERR Can't execute '{}': only SUBSCRIBE / UNSUBSCRIBE / PING / QUIT / RESET are \
+                 allowed in this context",
+                arguments.name()
+            )));
+        }
+
+        match arguments.name() {
+            "ping" => {
+                arguments.expect(0, 1)?;
+                let message = if arguments.is_empty() {
+                    None
+                } else {
+                    Some(arguments.bytes()?)
+                };
+                Ok(Some(match (resp2_subscribed, message) {
+                    (true, message) => Frame::Array(vec![
+                        Frame::bulk("pong"),
+                        Frame::Bulk(message.unwrap_or_default()),
+                    ]),
+                    (false, Some(message)) => Frame::Bulk(message),
+                    (false, None) => Frame::Simple("PONG".to_string()),
+                }))
+            }
+            "echo" => {
+                arguments.expect(1, 1)?;
+                Ok(Some(Frame::Bulk(arguments.bytes()?)))
+            }
+            "hello" => self.hello(arguments).map(Some),
+            "select" => {
+                arguments.expect(1, 1)?;
+                match arguments.integer()? {
+                    0 => Ok(Some(Frame::ok())),
+                    _ => Err(CommandError("ERR DB index is out of range".to_string())),
+                }
+            }
+            "reset" => {
+                self.unsubscribe_all();
+                self.outbound.resp3.store(false, Ordering::Relaxed);
+                Ok(Some(Frame::Simple("RESET".to_string())))
+            }
+            // Accepted so client libraries can set their name and version on connect.
+            "client" => Ok(Some(Frame::ok())),
+            "command" => Ok(Some(Frame::Array(Vec::new()))),
+            "subscribe" => {
+                arguments.expect(1, usize::MAX)?;
+                self.subscribe(arguments.rest()).await;
+                Ok(None)
+            }
+            "unsubscribe" => {
+                self.unsubscribe(arguments.rest()).await;
+                Ok(None)
+            }
+            _ => match self.commands.execute(arguments).await {
+                Some(result) => result.map(Some),
+                None => Err(CommandError(format!(
+                    "ERR unknown or unsupported command '{}'; momento-redis-proxy supports GET, \
+                     SET, DEL, EXISTS, INCR[BY], DECR[BY], [P]EXPIRE, [P]TTL, HSET, HGET, \
+                     HGETALL, HINCRBY, LPUSH, RPUSH, LRANGE, LPOP, LLEN, SADD, SMEMBERS, SREM, \
+                     ZADD, ZRANGE, ZRANGEBYSCORE, ZRANK, ZSCORE, ZINCRBY, PUBLISH and SUBSCRIBE",
+                    arguments.name()
+                ))),
+            },
+        }
+    }
+
+    /// `HELLO [protover]`: switch protocol versions and describe the server.
+    fn hello(&mut self, arguments: &mut Arguments) -> CommandResult {
+        if !arguments.is_empty() {
+            match arguments.integer() {
+                Ok(2) => self.outbound.resp3.store(false, Ordering::Relaxed),
+                Ok(3) => self.outbound.resp3.store(true, Ordering::Relaxed),
+                _ => {
+                    return Err(CommandError(
+                        "NOPROTO unsupported protocol version".to_string(),
+                    ))
+                }
+            }
+            if !arguments.is_empty() {
+                return Err(CommandError(
+                    "ERR HELLO options are not supported by momento-redis-proxy".to_string(),
+                ));
+            }
+        }
+        let protocol = if self.outbound.resp3.load(Ordering::Relaxed) {
+            3
+        } else {
+            2
+        };
+        Ok(Frame::Map(vec![
+            (Frame::bulk("server"), Frame::bulk("momento-redis-proxy")),
+            (
+                Frame::bulk("version"),
+                Frame::bulk(env!("CARGO_PKG_VERSION")),
+            ),
+            (Frame::bulk("proto"), Frame::Integer(protocol)),
+            (Frame::bulk("mode"), Frame::bulk("standalone")),
+            (Frame::bulk("role"), Frame::bulk("master")),
+            (Frame::bulk("modules"), Frame::Array(Vec::new())),
+        ]))
+    }
+
+    async fn subscribe(&mut self, channels: Vec<Bytes>) {
+        for channel in channels {
+            let channel = String::from_utf8_lossy(&channel).into_owned();
+            if !self.subscriptions.contains_key(&channel) {
+                let subscription = self
+                    .commands
+                    .topic_client()
+                    .subscribe(self.commands.cache_name(), channel.as_str())
+                    .await;
+                let subscription = match subscription {
+                    Ok(subscription) => subscription,
+                    Err(error) => {
+                        self.outbound.send(CommandError::from(error).into()).await;
+                        continue;
+                    }
+                };
+                let outbound = self.outbound.clone();
+                let topic = channel.clone();
+                let forward = tokio::spawn(async move {
+                    let mut subscription = subscription;
+                    while let Some(item) = subscription.next().await {
+                        let payload = match item.kind {
+                            ValueKind::Text(text) => Bytes::from(text),
+                            ValueKind::Binary(binary) => Bytes::from(binary),
+                        };
+                        let message = Frame::Push(vec![
+                            Frame::bulk("message"),
+                            Frame::bulk(topic.clone()),
+                            Frame::Bulk(payload),
+                        ]);
+                        if !outbound.send(message).await {
+                            break;
+                        }
+                    }
+                });
+                self.subscriptions.insert(channel.clone(), forward);
+            }
+            self.outbound
+                .send(Frame::Push(vec![
+                    Frame::bulk("subscribe"),
+                    Frame::bulk(channel),
+                    Frame::Integer(self.subscriptions.len() as i64),
+                ]))
+                .await;
+        }
+    }
+
+    async fn unsubscribe(&mut self, channels: Vec<Bytes>) {
+        let channels: Vec<String> = if channels.is_empty() {
+            self.subscriptions.keys().cloned().collect()
+        } else {
+            channels
+                .iter()
+                .map(|channel| String::from_utf8_lossy(channel).into_owned())
+                .collect()
+        };
+        if channels.is_empty() {
+            self.outbound
+                .send(Frame::Push(vec![
+                    Frame::bulk("unsubscribe"),
+                    Frame::Null,
+                    Frame::Integer(0),
+                ]))
+                .await;
+        }
+        for channel in channels {
+            if let Some(forward) = self.subscriptions.remove(&channel) {
+                forward.abort();
+            }
+            self.outbound
+                .send(Frame::Push(vec![
+                    Frame::bulk("unsubscribe"),
+                    Frame::bulk(channel),
+                    Frame::Integer(self.subscriptions.len() as i64),
+                ]))
+                .await;
+        }
+    }
+
+    fn unsubscribe_all(&mut self) {
+        for (_, forward) in self.subscriptions.drain() {
+            forward.abort();
+        }
+    }
+}
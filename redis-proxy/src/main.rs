@@ -0,0 +1,89 @@
+//! `momento-redis-proxy` accepts Redis clients speaking RESP2 or RESP3 on a local port and
+//! translates their commands to Momento cache and topic operations.
+//!
+//! ```text
+//! MOMENTO_API_KEY=... momento-redis-proxy --cache my-cache --listen 127.0.0.1:6379
+//! redis-cli set greeting hello ex 60
+//! ```
+//!
+//! Strings map to scalar items, hashes to dictionaries, lists, sets and sorted sets to their
+//! Momento counterparts, and `PUBLISH`/`SUBSCRIBE` to topics in the same cache. `SET` with `NX`
+//! or `XX` uses `set_if_absent` or `set_if_present`. Other commands, and options of supported
+//! commands without a Momento equivalent, are rejected with an error naming them.
+//!
+//! Momento does not report how many elements a write added or removed, so `DEL`, `HSET`,
+//! `SADD`, `SREM` and `ZADD` check which keys, fields or members exist before writing. The
+//! check and the write are not atomic. `PUBLISH` returns 0.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use clap::Parser;
+use momento::{CacheClient, CredentialProvider, TopicClient};
+use tokio::net::TcpListener;
+
+use crate::commands::Commands;
+use crate::connection::Connection;
+
+mod commands;
+mod connection;
+mod resp;
+
+#[derive(Parser, Debug)]
+#[command(version, about = "Serve the Redis protocol on top of a Momento cache")]
+struct Options {
+    /// The address to accept Redis clients on.
+    #[arg(long, default_value = "127.0.0.1:6379")]
+    listen: SocketAddr,
+
+    /// The cache to store items in and publish to.
+    #[arg(long, env = "MOMENTO_CACHE_NAME")]
+    cache: String,
+
+    /// The environment variable holding the Momento API key.
+    #[arg(long, default_value = "MOMENTO_API_KEY")]
+    api_key_env_var: String,
+
+    /// The TTL of items written without an explicit expiry, in seconds.
+    #[arg(long, default_value_t = 3600)]
+    default_ttl: u64,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    env_logger::init();
+    let options = Options::parse();
+
+    let credential_provider = CredentialProvider::from_env_var(&options.api_key_env_var)?;
+    let cache_client = CacheClient::builder()
+        .default_ttl(Duration::from_secs(options.default_ttl))
+        .configuration(momento::cache::configurations::InRegion::latest())
+        .credential_provider(credential_provider.clone())
+        .build()?;
+    let topic_client = TopicClient::builder()
+        .configuration(momento::topics::configurations::InRegion::latest())
+        .credential_provider(credential_provider)
+        .build()?;
+    let commands = Commands::new(cache_client, topic_client, options.cache);
+
+    let listener = TcpListener::bind(options.listen).await?;
+    log::info!("accepting redis clients on {}", options.listen);
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (stream, peer) = accepted?;
+                let commands = commands.clone();
+                tokio::spawn(async move {
+                    log::debug!("{peer} connected");
+                    if let Err(error) = Connection::serve(commands, stream).await {
+                        log::debug!("{peer} disconnected: {error}");
+                    }
+                });
+            }
+            _ = tokio::signal::ctrl_c() => {
+                log::info!("shutting down");
+                return Ok(());
+            }
+        }
+    }
+}
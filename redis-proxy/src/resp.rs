@@ -0,0 +1,301 @@
+//! Parsing commands from and encoding replies to RESP2 and RESP3 clients.
+
+use bytes::{Buf, Bytes, BytesMut};
+
+/// The largest bulk string a client may send, matching Redis' default `proto-max-bulk-len`.
+const MAX_BULK_LEN: usize = 512 * 1024 * 1024;
+
+/// The most arguments a single command may have.
+const MAX_ARGUMENTS: usize = 1024 * 1024;
+
+/// A reply to a client.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Frame {
+    Simple(String),
+    Error(String),
+    Integer(i64),
+    Bulk(Bytes),
+    Double(f64),
+    Array(Vec<Frame>),
+    Map(Vec<(Frame, Frame)>),
+    /// An out-of-band message, such as a message on a subscribed channel.
+    Push(Vec<Frame>),
+    Null,
+}
+
+impl Frame {
+    pub fn ok() -> Self {
+        Frame::Simple("OK".to_string())
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Frame::Error(message.into())
+    }
+
+    pub fn bulk(value: impl Into<Bytes>) -> Self {
+        Frame::Bulk(value.into())
+    }
+
+    /// Encode the frame for a client speaking RESP3 if `resp3` is set, otherwise RESP2.
+    ///
+    /// RESP2 has no doubles, maps or pushes, so they are sent as bulk strings, flattened arrays
+    /// and arrays respectively.
+    pub fn encode(&self, resp3: bool, out: &mut Vec<u8>) {
+        match self {
+            Frame::Simple(value) => line(out, b'+', value),
+            Frame::Error(message) => line(out, b'-', message),
+            Frame::Integer(value) => line(out, b':', &value.to_string()),
+            Frame::Bulk(value) => {
+                line(out, b'$', &value.len().to_string());
+                out.extend_from_slice(value);
+                out.extend_from_slice(b"\r\n");
+            }
+            Frame::Double(value) if resp3 => line(out, b',', &format_double(*value)),
+            Frame::Double(value) => Frame::bulk(format_double(*value)).encode(resp3, out),
+            Frame::Array(frames) => {
+                line(out, b'*', &frames.len().to_string());
+                frames.iter().for_each(|frame| frame.encode(resp3, out));
+            }
+            Frame::Map(pairs) => {
+                if resp3 {
+                    line(out, b'%', &pairs.len().to_string());
+                } else {
+                    line(out, b'*', &(pairs.len() * 2).to_string());
+                }
+                for (key, value) in pairs {
+                    key.encode(resp3, out);
+                    value.encode(resp3, out);
+                }
+            }
+            Frame::Push(frames) => {
+                line(
+                    out,
+                    if resp3 { b'>' } else { b'*' },
+                    &frames.len().to_string(),
+                );
+                frames.iter().for_each(|frame| frame.encode(resp3, out));
+            }
+            Frame::Null if resp3 => out.extend_from_slice(b"_\r\n"),
+            Frame::Null => out.extend_from_slice(b"$-1\r\n"),
+        }
+    }
+}
+
+fn line(out: &mut Vec<u8>, prefix: u8, value: &str) {
+    out.push(prefix);
+    out.extend_from_slice(value.as_bytes());
+    out.extend_from_slice(b"\r\n");
+}
+
+/// Format a double the way Redis does, e.g. `1`, `1.5` or `-inf`.
+pub fn format_double(value: f64) -> String {
+    if value.is_nan() {
+        "nan".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+/// A client sent something which is not valid RESP. The connection should be closed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ProtocolError(pub String);
+
+impl std::fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Protocol error: {}", self.0)
+    }
+}
+
+/// Parse one command from the start of `buffer`, consuming it. Returns `Ok(None)` if the buffer
+/// does not yet contain a complete command.
+///
+/// Commands are normally arrays of bulk strings, but inline commands such as `PING\r\n`, as sent
+/// by `telnet`, are accepted too.
+pub fn parse_command(buffer: &mut BytesMut) -> Result<Option<Vec<Bytes>>, ProtocolError> {
+    let mut cursor = Cursor {
+        buffer: &buffer[..],
+        position: 0,
+    };
+    let arguments = match buffer.first() {
+        None => return Ok(None),
+        Some(b'*') => cursor.array()?,
+        Some(_) => cursor.inline(),
+    };
+    match arguments {
+        Some(arguments) => {
+            let consumed = cursor.position;
+            buffer.advance(consumed);
+            Ok(Some(arguments))
+        }
+        None => Ok(None),
+    }
+}
+
+struct Cursor<'a> {
+    buffer: &'a [u8],
+    position: usize,
+}
+
+impl Cursor<'_> {
+    /// The next line without its `\r\n`, or `None` if it is incomplete.
+    fn line(&mut self) -> Option<&[u8]> {
+        let rest = &self.buffer[self.position..];
+        let end = rest.windows(2).position(|window| window == b"\r\n")?;
+        self.position += end + 2;
+        Some(&rest[..end])
+    }
+
+    fn length(&mut self, prefix: u8, max: usize) -> Result<Option<usize>, ProtocolError> {
+        let line = match self.line() {
+            Some(line) => line,
+            None => return Ok(None),
+        };
+        if line.first() != Some(&prefix) {
+            return Err(ProtocolError(format!(
+                "expected '{}', got '{}'",
+                prefix as char,
+                line.first().map(|byte| *byte as char).unwrap_or(' ')
+            )));
+        }
+        let length = std::str::from_utf8(&line[1..])
+            .ok()
+            .and_then(|length| length.parse::<usize>().ok())
+            .filter(|length| *length <= max);
+        match length {
+            Some(length) => Ok(Some(length)),
+            None => Err(ProtocolError(format!("invalid {} length", prefix as char))),
+        }
+    }
+
+    fn array(&mut self) -> Result<Option<Vec<Bytes>>, ProtocolError> {
+        let count = match self.length(b'*', MAX_ARGUMENTS)? {
+            Some(count) => count,
+            None => return Ok(None),
+        };
+        let mut arguments = Vec::with_capacity(count.min(64));
+        for _ in 0..count {
+            let len = match self.length(b'$', MAX_BULK_LEN)? {
+                Some(len) => len,
+                None => return Ok(None),
+            };
+            if self.buffer.len() < self.position + len + 2 {
+                return Ok(None);
+            }
+            let start = self.position;
+            if &self.buffer[start + len..start + len + 2] != b"\r\n" {
+                return Err(ProtocolError(
+                    "bulk string not terminated by CRLF".to_string(),
+                ));
+            }
+            arguments.push(Bytes::copy_from_slice(&self.buffer[start..start + len]));
+            self.position += len + 2;
+        }
+        Ok(Some(arguments))
+    }
+
+    fn inline(&mut self) -> Option<Vec<Bytes>> {
+        let line = self.line()?;
+        Some(
+            line.split(|byte| byte.is_ascii_whitespace())
+                .filter(|argument| !argument.is_empty())
+                .map(Bytes::copy_from_slice)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &[u8]) -> (Result<Option<Vec<Bytes>>, ProtocolError>, usize) {
+        let mut buffer = BytesMut::from(input);
+        let result = parse_command(&mut buffer);
+        (result, buffer.len())
+    }
+
+    fn encode(frame: Frame, resp3: bool) -> String {
+        let mut out = Vec::new();
+        frame.encode(resp3, &mut out);
+        String::from_utf8(out).expect("test frames are utf-8")
+    }
+
+    #[test]
+    fn parses_arrays_of_bulk_strings() {
+        let (result, remaining) = parse(b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n*1\r\n");
+        assert_eq!(
+            result,
+            Ok(Some(vec![Bytes::from("GET"), Bytes::from("key")]))
+        );
+        assert_eq!(remaining, 4);
+    }
+
+    #[test]
+    fn waits_for_incomplete_commands() {
+        for input in [
+            &b"*2\r\n$3\r\nGET\r\n"[..],
+            b"*2\r\n$3\r\nGET\r\n$3\r\nke",
+            b"*2",
+            b"PING",
+        ] {
+            let (result, remaining) = parse(input);
+            assert_eq!(result, Ok(None));
+            assert_eq!(remaining, input.len());
+        }
+    }
+
+    #[test]
+    fn parses_inline_commands() {
+        let (result, remaining) = parse(b"SET  key value\r\n");
+        assert_eq!(
+            result,
+            Ok(Some(vec![
+                Bytes::from("SET"),
+                Bytes::from("key"),
+                Bytes::from("value")
+            ]))
+        );
+        assert_eq!(remaining, 0);
+    }
+
+    #[test]
+    fn binary_values_are_preserved() {
+        let (result, _) = parse(b"*1\r\n$4\r\n\r\n\x00\xff\r\n");
+        assert_eq!(result, Ok(Some(vec![Bytes::from_static(b"\r\n\x00\xff")])));
+    }
+
+    #[test]
+    fn rejects_malformed_commands() {
+        assert!(parse(b"*1\r\n:3\r\n").0.is_err());
+        assert!(parse(b"*x\r\n").0.is_err());
+        assert!(parse(b"*1\r\n$3\r\nGETX\r\n").0.is_err());
+    }
+
+    #[test]
+    fn encodes_resp2_and_resp3() {
+        let map = Frame::Map(vec![(Frame::bulk("field"), Frame::Double(1.5))]);
+        assert_eq!(
+            encode(map.clone(), false),
+            "*2\r\n$5\r\nfield\r\n$3\r\n1.5\r\n"
+        );
+        assert_eq!(encode(map, true), "%1\r\n$5\r\nfield\r\n,1.5\r\n");
+
+        assert_eq!(encode(Frame::Null, false), "$-1\r\n");
+        assert_eq!(encode(Frame::Null, true), "_\r\n");
+
+        let push = Frame::Push(vec![Frame::bulk("message"), Frame::Integer(1)]);
+        assert_eq!(encode(push.clone(), false), "*2\r\n$7\r\nmessage\r\n:1\r\n");
+        assert_eq!(encode(push, true), ">2\r\n$7\r\nmessage\r\n:1\r\n");
+
+        assert_eq!(encode(Frame::ok(), true), "+OK\r\n");
+        assert_eq!(encode(Frame::error("ERR nope"), false), "-ERR nope\r\n");
+    }
+
+    #[test]
+    fn formats_doubles_like_redis() {
+        assert_eq!(format_double(1.0), "1");
+        assert_eq!(format_double(-2.25), "-2.25");
+        assert_eq!(format_double(f64::INFINITY), "inf");
+        assert_eq!(format_double(f64::NEG_INFINITY), "-inf");
+    }
+}
@@ -1,12 +1,14 @@
 use std::time::Duration;
 
 use momento::cache::configurations;
-use momento::{CacheClient, MomentoResult};
-use momento_test_util::{get_test_cache_name, get_test_credential_provider};
+use momento::storage::configurations as storage_configurations;
+use momento::{CacheClient, MomentoResult, StorageClient};
+use momento_test_util::{get_test_cache_name, get_test_credential_provider, get_test_store_name};
 
 #[tokio::main]
 async fn main() -> MomentoResult<()> {
     let cache_name = get_test_cache_name();
+    let store_name = get_test_store_name();
 
     let credential_provider = get_test_credential_provider();
 
@@ -19,5 +21,13 @@ async fn main() -> MomentoResult<()> {
     println!("Creating cache {}", cache_name.clone());
     cache_client.create_cache(cache_name.clone()).await?;
 
+    let storage_client = StorageClient::builder()
+        .configuration(storage_configurations::Laptop::latest())
+        .credential_provider(credential_provider)
+        .build()?;
+
+    println!("Creating store {}", store_name.clone());
+    storage_client.create_store(store_name.clone()).await?;
+
     Ok(())
 }
@@ -1,7 +1,8 @@
 use std::time::Duration;
 
 use momento::cache::configurations;
-use momento::CacheClient;
+use momento::storage::configurations as storage_configurations;
+use momento::{CacheClient, StorageClient};
 use momento_test_util::{get_test_cache_name, get_test_credential_provider, get_test_store_name};
 
 #[tokio::main]
@@ -24,4 +25,16 @@ async fn main() {
         .delete_cache(cache_name.clone())
         .await
         .expect("cache cannot be deleted");
+
+    let storage_client = StorageClient::builder()
+        .configuration(storage_configurations::Laptop::latest())
+        .credential_provider(credential_provider)
+        .build()
+        .expect("storage client cannot be created");
+
+    println!("Deleting store {}", store_name.clone());
+    storage_client
+        .delete_store(store_name.clone())
+        .await
+        .expect("store cannot be deleted");
 }
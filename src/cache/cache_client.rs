@@ -7,8 +7,19 @@ use momento_protos::control_client::scs_control_client::ScsControlClient;
 use tonic::codegen::InterceptedService;
 use tonic::transport::Channel;
 
+#[cfg(feature = "tower")]
+use crate::grpc::channel_readiness::ChannelReadiness;
+#[cfg(feature = "tower")]
+use crate::MomentoError;
+#[cfg(feature = "tower")]
+use std::future::Future;
+#[cfg(feature = "tower")]
+use std::pin::Pin;
+#[cfg(feature = "tower")]
+use std::task::{Context, Poll};
+
 use crate::cache::{
-    Configuration, CreateCacheRequest, CreateCacheResponse, DecreaseTtlRequest,
+    Cache, Configuration, CreateCacheRequest, CreateCacheResponse, DecreaseTtlRequest,
     DecreaseTtlResponse, DeleteCacheRequest, DeleteCacheResponse, DeleteRequest, DeleteResponse,
     DictionaryFetchRequest, DictionaryFetchResponse, DictionaryGetFieldRequest,
     DictionaryGetFieldResponse, DictionaryGetFieldsRequest, DictionaryGetFieldsResponse,
@@ -17,18 +28,21 @@ use crate::cache::{
     DictionaryRemoveFieldsRequest, DictionaryRemoveFieldsResponse, DictionarySetFieldRequest,
     DictionarySetFieldResponse, DictionarySetFieldsRequest, DictionarySetFieldsResponse,
     FlushCacheRequest, FlushCacheResponse, GetBatchRequest, GetBatchResponse, GetRequest,
-    GetResponse, IncreaseTtlRequest, IncreaseTtlResponse, IncrementRequest, IncrementResponse,
-    IntoDictionaryFieldValuePairs, IntoSortedSetElements, ItemGetTtlRequest, ItemGetTtlResponse,
-    ItemGetTypeRequest, ItemGetTypeResponse, KeyExistsRequest, KeyExistsResponse, KeysExistRequest,
-    KeysExistResponse, ListCachesRequest, ListCachesResponse, ListConcatenateBackRequest,
-    ListConcatenateBackResponse, ListConcatenateFrontRequest, ListConcatenateFrontResponse,
-    ListFetchRequest, ListFetchResponse, ListLengthRequest, ListLengthResponse, ListPopBackRequest,
-    ListPopBackResponse, ListPopFrontRequest, ListPopFrontResponse, ListPushBackRequest,
-    ListPushBackResponse, ListPushFrontRequest, ListPushFrontResponse, ListRemoveValueRequest,
-    ListRemoveValueResponse, MomentoRequest, SetAddElementsRequest, SetAddElementsResponse,
+    GetResponse, GetWithHashRequest, GetWithHashResponse, IncreaseTtlRequest, IncreaseTtlResponse,
+    IncrementRequest, IncrementResponse, IntoDictionaryFieldValuePairs, IntoSortedSetElements,
+    ItemGetTtlRequest, ItemGetTtlResponse, ItemGetTypeRequest, ItemGetTypeResponse,
+    KeyExistsRequest, KeyExistsResponse, KeysExistRequest, KeysExistResponse, ListCachesRequest,
+    ListCachesResponse, ListConcatenateBackRequest, ListConcatenateBackResponse,
+    ListConcatenateFrontRequest, ListConcatenateFrontResponse, ListFetchRequest, ListFetchResponse,
+    ListLengthRequest, ListLengthResponse, ListPopBackRequest, ListPopBackResponse,
+    ListPopFrontRequest, ListPopFrontResponse, ListPushBackRequest, ListPushBackResponse,
+    ListPushFrontRequest, ListPushFrontResponse, ListRemoveValueRequest, ListRemoveValueResponse,
+    MomentoRequest, NamespacedCacheClient, SetAddElementsRequest, SetAddElementsResponse,
     SetBatchRequest, SetBatchResponse, SetFetchRequest, SetFetchResponse,
-    SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse, SetIfAbsentRequest, SetIfAbsentResponse,
-    SetIfEqualRequest, SetIfEqualResponse, SetIfNotEqualRequest, SetIfNotEqualResponse,
+    SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse, SetIfAbsentOrHashEqualRequest,
+    SetIfAbsentOrHashEqualResponse, SetIfAbsentRequest, SetIfAbsentResponse, SetIfEqualRequest,
+    SetIfEqualResponse, SetIfNotEqualRequest, SetIfNotEqualResponse,
+    SetIfPresentAndHashEqualRequest, SetIfPresentAndHashEqualResponse,
     SetIfPresentAndNotEqualRequest, SetIfPresentAndNotEqualResponse, SetIfPresentRequest,
     SetIfPresentResponse, SetRemoveElementsRequest, SetRemoveElementsResponse, SetRequest,
     SetResponse, SortedSetFetchByRankRequest, SortedSetFetchByScoreRequest, SortedSetFetchResponse,
@@ -38,11 +52,15 @@ use crate::cache::{
     SortedSetLengthResponse, SortedSetOrder, SortedSetPutElementRequest,
     SortedSetPutElementResponse, SortedSetPutElementsRequest, SortedSetPutElementsResponse,
     SortedSetRemoveElementsRequest, SortedSetRemoveElementsResponse, SortedSetUnionStoreRequest,
-    SortedSetUnionStoreResponse, UpdateTtlRequest, UpdateTtlResponse,
+    SortedSetUnionStoreResponse, UpdateTtlRequest, UpdateTtlResponse, UpdateWithRequest,
+    UpdateWithResponse,
 };
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptionKeyRing};
 use crate::grpc::header_interceptor::HeaderInterceptor;
 
 use crate::cache::cache_client_builder::{CacheClientBuilder, NeedsDefaultTtl};
+use crate::cache::messages::data::scalar::get::Value;
 use crate::cache::messages::data::sorted_set::sorted_set_increment_score::{
     SortedSetIncrementScoreRequest, SortedSetIncrementScoreResponse,
 };
@@ -85,6 +103,8 @@ pub struct CacheClient {
     control_client: ScsControlClient<InterceptedService<Channel, HeaderInterceptor>>,
     configuration: Configuration,
     item_default_ttl: Duration,
+    #[cfg(feature = "tower")]
+    readiness: ChannelReadiness,
 }
 
 static NEXT_DATA_CLIENT_INDEX: AtomicUsize = AtomicUsize::new(0);
@@ -125,6 +145,50 @@ impl CacheClient {
         CacheClientBuilder(NeedsDefaultTtl(()))
     }
 
+    /// Returns a [Cache] handle for the cache with the given name, so that the cache name does
+    /// not need to be passed to every call.
+    ///
+    /// The handle is cheap to create and clone, and shares this client's connections.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::convert::TryInto;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let cache = cache_client.cache(&cache_name);
+    /// cache.set("key", "value").await?;
+    ///
+    /// let item: String = cache.get("key").await?.try_into().expect("I stored a string!");
+    /// assert_eq!(item, "value");
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn cache(&self, cache_name: impl Into<String>) -> Cache {
+        Cache::new(self.clone(), cache_name)
+    }
+
+    /// Returns a [NamespacedCacheClient] which adds `prefix` to every key and collection name, so
+    /// that many tenants can share a cache without their keys colliding.
+    ///
+    /// See [NamespacedCacheClient] for details.
+    pub fn namespaced(&self, prefix: impl Into<String>) -> NamespacedCacheClient {
+        NamespacedCacheClient::new(self.clone(), prefix)
+    }
+
+    /// Returns an [EncryptedCacheClient] which encrypts values with the keys in `key_ring` before
+    /// they are sent to the cache, and decrypts them when they are read.
+    ///
+    /// See [EncryptedCacheClient] for details.
+    #[cfg(feature = "encryption")]
+    pub fn encrypted(&self, key_ring: EncryptionKeyRing) -> EncryptedCacheClient {
+        EncryptedCacheClient::new(self.clone(), key_ring)
+    }
+
     /* public API */
 
     /// Creates a cache with the given name.
@@ -395,6 +459,49 @@ impl CacheClient {
         request.send(self).await
     }
 
+    /// Gets an item and the hash of its value from a Momento Cache
+    ///
+    /// The returned hash can be used with [set_if_present_and_hash_equal](CacheClient::set_if_present_and_hash_equal)
+    /// and [set_if_absent_or_hash_equal](CacheClient::set_if_absent_or_hash_equal) to perform a
+    /// compare-and-swap without resending the previous value.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - name of cache
+    /// * `key` - key of entry within the cache.
+    ///
+    /// # Examples
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// use std::convert::TryInto;
+    /// use momento::cache::GetWithHashResponse;
+    /// # cache_client.set(&cache_name, "key", "value").await?;
+    ///
+    /// let (item, hash): (String, Vec<u8>) = match(cache_client.get_with_hash(&cache_name, "key").await?) {
+    ///     GetWithHashResponse::Hit { value, hash } => (value.try_into().expect("I stored a string!"), hash),
+    ///     GetWithHashResponse::Miss => return Err(anyhow::Error::msg("cache miss"))
+    /// };
+    /// # assert_eq!(item, "value");
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to get an item using a [GetWithHashRequest].
+    ///
+    /// For more examples of handling the response, see [GetWithHashResponse].
+    pub async fn get_with_hash(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetWithHashResponse> {
+        let request = GetWithHashRequest::new(cache_name, key);
+        request.send(self).await
+    }
+
     /// Gets a batch of items from a Momento Cache
     ///
     /// # Arguments
@@ -2297,6 +2404,196 @@ impl CacheClient {
         request.send(self).await
     }
 
+    /// Associates the given key with the given value if the key is present in the cache
+    /// and the hash of its value is equal to the supplied `hash_equal` value.
+    ///
+    /// The hash of an item can be retrieved with [get_with_hash](CacheClient::get_with_hash).
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache to create.
+    /// * `key` - key of the item whose value we are setting
+    /// * `value` - data to store
+    /// * `hash_equal` - hash to compare to the hash of the cached value
+    ///
+    /// # Optional Arguments
+    /// If you use [send_request](CacheClient::send_request) to conditionally set an item using an
+    /// [SetIfPresentAndHashEqualRequest], you can also provide the following optional arguments:
+    ///
+    /// * `ttl` - The time-to-live for the item. If not provided, the client's default time-to-live is used.
+    ///
+    /// # Example
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::{GetWithHashResponse, SetIfPresentAndHashEqualResponse};
+    /// use momento::MomentoErrorCode;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    /// # cache_client.set(&cache_name, "key", "cached-value").await?;
+    ///
+    /// let hash = match cache_client.get_with_hash(&cache_name, "key").await? {
+    ///     GetWithHashResponse::Hit { hash, .. } => hash,
+    ///     GetWithHashResponse::Miss => return Err(anyhow::Error::msg("cache miss")),
+    /// };
+    ///
+    /// match cache_client.set_if_present_and_hash_equal(&cache_name, "key", "new-value", hash).await {
+    ///     Ok(response) => match response {
+    ///         SetIfPresentAndHashEqualResponse::Stored { .. } => println!("Value stored"),
+    ///         SetIfPresentAndHashEqualResponse::NotStored => println!("Value not stored"),
+    ///     }
+    ///     Err(e) => if let MomentoErrorCode::CacheNotFoundError = e.error_code {
+    ///         println!("Cache not found: {}", &cache_name);
+    ///     } else {
+    ///         eprintln!("Error setting value in cache {}: {}", &cache_name, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to conditionally set an item using a [SetIfPresentAndHashEqualRequest].
+    pub async fn set_if_present_and_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndHashEqualResponse> {
+        let request = SetIfPresentAndHashEqualRequest::new(cache_name, key, value, hash_equal);
+        request.send(self).await
+    }
+
+    /// Associates the given key with the given value if the key does not already exist in the
+    /// cache or the hash of the value in the cache is equal to the supplied `hash_equal` value.
+    ///
+    /// The hash of an item can be retrieved with [get_with_hash](CacheClient::get_with_hash).
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache to create.
+    /// * `key` - key of the item whose value we are setting
+    /// * `value` - data to store
+    /// * `hash_equal` - hash to compare to the hash of the cached value
+    ///
+    /// # Optional Arguments
+    /// If you use [send_request](CacheClient::send_request) to conditionally set an item using an
+    /// [SetIfAbsentOrHashEqualRequest], you can also provide the following optional arguments:
+    ///
+    /// * `ttl` - The time-to-live for the item. If not provided, the client's default time-to-live is used.
+    ///
+    /// # Example
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::cache::{GetWithHashResponse, SetIfAbsentOrHashEqualResponse};
+    /// use momento::MomentoErrorCode;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let hash = match cache_client.get_with_hash(&cache_name, "key").await? {
+    ///     GetWithHashResponse::Hit { hash, .. } => hash,
+    ///     GetWithHashResponse::Miss => vec![],
+    /// };
+    ///
+    /// match cache_client.set_if_absent_or_hash_equal(&cache_name, "key", "new-value", hash).await {
+    ///     Ok(response) => match response {
+    ///         SetIfAbsentOrHashEqualResponse::Stored { .. } => println!("Value stored"),
+    ///         SetIfAbsentOrHashEqualResponse::NotStored => println!("Value not stored"),
+    ///     }
+    ///     Err(e) => if let MomentoErrorCode::CacheNotFoundError = e.error_code {
+    ///         println!("Cache not found: {}", &cache_name);
+    ///     } else {
+    ///         eprintln!("Error setting value in cache {}: {}", &cache_name, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to conditionally set an item using a [SetIfAbsentOrHashEqualRequest].
+    pub async fn set_if_absent_or_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrHashEqualResponse> {
+        let request = SetIfAbsentOrHashEqualRequest::new(cache_name, key, value, hash_equal);
+        request.send(self).await
+    }
+
+    /// Atomically updates an item with a read-modify-write loop.
+    ///
+    /// The current value of the item, or `None` if it is absent, is passed to `update_fn`, which
+    /// returns the new value to store or `None` to delete the item. The new value is written with
+    /// [set_if_equal](CacheClient::set_if_equal) or [set_if_absent](CacheClient::set_if_absent),
+    /// and the whole cycle is retried with backoff if another client modified the item in the
+    /// meantime. If the update still conflicts after the maximum number of attempts, an error with
+    /// [FailedPreconditionError](crate::MomentoErrorCode::FailedPreconditionError) is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - name of cache
+    /// * `key` - key of the item to update
+    /// * `ttl` - The time-to-live for the item. If `None`, the client's default time-to-live is used.
+    /// * `update_fn` - function computing the new value from the current value
+    ///
+    /// # Optional Arguments
+    /// If you use [send_request](CacheClient::send_request) to update an item using an
+    /// [UpdateWithRequest], you can also provide the following optional arguments:
+    ///
+    /// * `max_attempts` - The maximum number of read-modify-write attempts. Defaults to 10.
+    /// * `backoff` - The initial and maximum delay between attempts.
+    ///
+    /// # Example
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use momento::cache::UpdateWithResponse;
+    /// use momento::MomentoErrorCode;
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let response = cache_client
+    ///     .update_with(&cache_name, "key", Duration::from_secs(60), |current| {
+    ///         let mut value: Vec<u8> = current.map(Into::into).unwrap_or_default();
+    ///         value.extend_from_slice(b"!");
+    ///         Some(value)
+    ///     })
+    ///     .await;
+    ///
+    /// match response {
+    ///     Ok(UpdateWithResponse::Stored) => println!("Value updated"),
+    ///     Ok(UpdateWithResponse::Deleted) => println!("Value deleted"),
+    ///     Ok(UpdateWithResponse::NotStored) => println!("Value was absent and left unset"),
+    ///     Err(e) => if let MomentoErrorCode::FailedPreconditionError = e.error_code {
+    ///         println!("Too much contention on key");
+    ///     } else {
+    ///         eprintln!("Error updating value in cache {}: {}", &cache_name, e);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](CacheClient::send_request) method to update an item using an [UpdateWithRequest]
+    /// which will allow you to set [optional arguments](UpdateWithRequest#optional-arguments) as well.
+    pub async fn update_with(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: impl Into<Option<Duration>>,
+        update_fn: impl FnMut(Option<Value>) -> Option<Vec<u8>> + Send,
+    ) -> MomentoResult<UpdateWithResponse> {
+        let request = UpdateWithRequest::new(cache_name, key, update_fn).ttl(ttl);
+        request.send(self).await
+    }
+
     /// Gets the number of elements in the given list.
     ///
     /// # Arguments
@@ -2696,9 +2993,17 @@ impl CacheClient {
             control_client,
             configuration,
             item_default_ttl,
+            #[cfg(feature = "tower")]
+            readiness: ChannelReadiness::default(),
         }
     }
 
+    #[cfg(feature = "tower")]
+    pub(crate) fn with_channel_readiness(mut self, readiness: ChannelReadiness) -> Self {
+        self.readiness = readiness;
+        self
+    }
+
     pub(crate) fn expand_ttl_ms(&self, ttl: Option<Duration>) -> MomentoResult<u64> {
         let ttl = ttl.unwrap_or(self.item_default_ttl);
         utils::is_ttl_valid(ttl)?;
@@ -2724,3 +3029,48 @@ impl CacheClient {
         self.data_clients[next_index].clone()
     }
 }
+
+/// Sends any cache [MomentoRequest] as a [tower::Service], so tower middleware such as timeouts,
+/// retries, load shedding, buffering and concurrency limits can wrap cache calls.
+///
+/// `poll_ready` reports whether the client's data channels can accept another request.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use momento::cache::{GetRequest, GetResponse};
+/// use tower::{ServiceBuilder, ServiceExt};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let service = ServiceBuilder::new()
+///     .concurrency_limit(64)
+///     .service(cache_client);
+///
+/// let response: GetResponse = service
+///     .oneshot(GetRequest::new(&cache_name, "key"))
+///     .await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[cfg(feature = "tower")]
+impl<R> tower::Service<R> for CacheClient
+where
+    R: MomentoRequest + Send + 'static,
+    R::Response: Send + 'static,
+{
+    type Response = R::Response;
+    type Error = MomentoError;
+    type Future = Pin<Box<dyn Future<Output = MomentoResult<R::Response>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<MomentoResult<()>> {
+        self.readiness.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        let client = self.clone();
+        Box::pin(async move { request.send(&client).await })
+    }
+}
@@ -1,4 +1,6 @@
 use crate::cache::Configuration;
+#[cfg(feature = "tower")]
+use crate::grpc::channel_readiness::ChannelReadiness;
 use crate::grpc::header_interceptor::HeaderInterceptor;
 use crate::{utils, CacheClient, CredentialProvider, MomentoResult};
 use std::time::Duration;
@@ -114,6 +116,8 @@ impl CacheClientBuilder<ReadyToBuild> {
             .collect();
 
         let data_channels = data_channels_result?;
+        #[cfg(feature = "tower")]
+        let readiness = ChannelReadiness::new(data_channels.clone());
 
         let control_channel = utils::connect_channel_lazily_configurable(
             &self.0.credential_provider.control_endpoint,
@@ -142,11 +146,14 @@ impl CacheClientBuilder<ReadyToBuild> {
                 .collect();
         let control_client = ScsControlClient::new(control_interceptor);
 
-        Ok(CacheClient::new(
+        let client = CacheClient::new(
             data_clients,
             control_client,
             self.0.configuration,
             self.0.default_ttl,
-        ))
+        );
+        #[cfg(feature = "tower")]
+        let client = client.with_channel_readiness(readiness);
+        Ok(client)
     }
 }
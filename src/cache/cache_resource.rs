@@ -0,0 +1,357 @@
+use std::time::Duration;
+
+use crate::cache::messages::data::scalar::get::Value;
+use crate::cache::{
+    CollectionTtl, DecreaseTtlRequest, DecreaseTtlResponse, DeleteRequest, DeleteResponse,
+    Dictionary, FlushCacheRequest, FlushCacheResponse, GetBatchRequest, GetBatchResponse,
+    GetRequest, GetResponse, GetWithHashRequest, GetWithHashResponse, IncreaseTtlRequest,
+    IncreaseTtlResponse, IncrementRequest, IncrementResponse, ItemGetTtlRequest,
+    ItemGetTtlResponse, ItemGetTypeRequest, ItemGetTypeResponse, KeyExistsRequest,
+    KeyExistsResponse, KeysExistRequest, KeysExistResponse, List, MomentoRequest, Set,
+    SetBatchRequest, SetBatchResponse, SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse,
+    SetIfAbsentOrHashEqualRequest, SetIfAbsentOrHashEqualResponse, SetIfAbsentRequest,
+    SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse, SetIfNotEqualRequest,
+    SetIfNotEqualResponse, SetIfPresentAndHashEqualRequest, SetIfPresentAndHashEqualResponse,
+    SetIfPresentAndNotEqualRequest, SetIfPresentAndNotEqualResponse, SetIfPresentRequest,
+    SetIfPresentResponse, SetRequest, SetResponse, SortedSet, UpdateTtlRequest, UpdateTtlResponse,
+    UpdateWithRequest, UpdateWithResponse,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{CacheClient, IntoBytes, MomentoResult};
+
+/// Represents a single cache, so that the cache name does not need to be passed to every call.
+///
+/// Obtain a `Cache` with [CacheClient::cache]. It exposes the scalar operations of the
+/// [CacheClient] and hands out [List], [Dictionary], [Set] and [SortedSet] handles for
+/// working with collections in the cache.
+///
+/// Collection handles inherit the cache's default [CollectionTtl], which can be changed with
+/// [with_collection_ttl](Cache::with_collection_ttl).
+///
+/// Because [set](Cache::set) stores a scalar item, the handle for a set collection is obtained
+/// with [set_collection](Cache::set_collection).
+///
+/// # Example
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use std::time::Duration;
+/// use momento::cache::CollectionTtl;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let cache = cache_client.cache(&cache_name);
+/// cache.set("key", "value").await?;
+/// let value: String = cache.get("key").await?.try_into()?;
+/// # assert_eq!(value, "value");
+///
+/// let list = cache
+///     .list("my-list")
+///     .with_ttl(CollectionTtl::refresh_on_update(Duration::from_secs(60)));
+/// list.push_back("a").await?;
+/// list.push_back("b").await?;
+/// let values: Vec<String> = list.fetch().await?.try_into()?;
+/// # assert_eq!(values, vec!["a", "b"]);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cache {
+    client: CacheClient,
+    cache_name: String,
+    collection_ttl: CollectionTtl,
+}
+
+impl Cache {
+    /// The name of the cache.
+    pub fn name(&self) -> &str {
+        &self.cache_name
+    }
+
+    /// The client used to send requests.
+    pub fn client(&self) -> &CacheClient {
+        &self.client
+    }
+
+    /// The default [CollectionTtl] for collection handles created from this cache.
+    pub fn collection_ttl(&self) -> CollectionTtl {
+        self.collection_ttl
+    }
+
+    /// Return a new handle whose collection handles default to the provided [CollectionTtl].
+    pub fn with_collection_ttl(mut self, collection_ttl: CollectionTtl) -> Self {
+        self.collection_ttl = collection_ttl;
+        self
+    }
+
+    /// Returns a [List] handle for the list with the given name in this cache.
+    pub fn list(&self, list_name: impl IntoBytes) -> List {
+        List::new(self.clone(), list_name.into_bytes())
+    }
+
+    /// Returns a [Dictionary] handle for the dictionary with the given name in this cache.
+    pub fn dictionary(&self, dictionary_name: impl IntoBytes) -> Dictionary {
+        Dictionary::new(self.clone(), dictionary_name.into_bytes())
+    }
+
+    /// Returns a [Set] handle for the set with the given name in this cache.
+    pub fn set_collection(&self, set_name: impl IntoBytes) -> Set {
+        Set::new(self.clone(), set_name.into_bytes())
+    }
+
+    /// Returns a [SortedSet] handle for the sorted set with the given name in this cache.
+    pub fn sorted_set(&self, sorted_set_name: impl IntoBytes) -> SortedSet {
+        SortedSet::new(self.clone(), sorted_set_name.into_bytes())
+    }
+
+    /// Flushes all data from the cache. See [CacheClient::flush_cache].
+    pub async fn flush(&self) -> MomentoResult<FlushCacheResponse> {
+        FlushCacheRequest::new(self.name()).send(&self.client).await
+    }
+
+    /// Sets an item in the cache. See [CacheClient::set].
+    pub async fn set(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetResponse> {
+        SetRequest::new(self.name(), key, value)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets a batch of items in the cache. See [CacheClient::set_batch].
+    pub async fn set_batch<K: IntoBytes, V: IntoBytes>(
+        &self,
+        items: impl IntoIterator<Item = (K, V)>,
+    ) -> MomentoResult<SetBatchResponse> {
+        SetBatchRequest::new(self.name(), items)
+            .send(&self.client)
+            .await
+    }
+
+    /// Gets an item from the cache. See [CacheClient::get].
+    pub async fn get(&self, key: impl IntoBytes) -> MomentoResult<GetResponse> {
+        GetRequest::new(self.name(), key).send(&self.client).await
+    }
+
+    /// Gets an item and the hash of its value from the cache. See [CacheClient::get_with_hash].
+    pub async fn get_with_hash(&self, key: impl IntoBytes) -> MomentoResult<GetWithHashResponse> {
+        GetWithHashRequest::new(self.name(), key)
+            .send(&self.client)
+            .await
+    }
+
+    /// Gets a batch of items from the cache. See [CacheClient::get_batch].
+    pub async fn get_batch(&self, keys: impl IntoBytesIterable) -> MomentoResult<GetBatchResponse> {
+        GetBatchRequest::new(self.name(), keys)
+            .send(&self.client)
+            .await
+    }
+
+    /// Deletes an item from the cache. See [CacheClient::delete].
+    pub async fn delete(&self, key: impl IntoBytes) -> MomentoResult<DeleteResponse> {
+        DeleteRequest::new(self.name(), key)
+            .send(&self.client)
+            .await
+    }
+
+    /// Checks if an item exists in the cache. See [CacheClient::key_exists].
+    pub async fn key_exists(&self, key: impl IntoBytes) -> MomentoResult<KeyExistsResponse> {
+        KeyExistsRequest::new(self.name(), key)
+            .send(&self.client)
+            .await
+    }
+
+    /// Checks if multiple items exist in the cache. See [CacheClient::keys_exist].
+    pub async fn keys_exist(
+        &self,
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<KeysExistResponse> {
+        KeysExistRequest::new(self.name(), keys)
+            .send(&self.client)
+            .await
+    }
+
+    /// Adds an integer quantity to an item in the cache. See [CacheClient::increment].
+    pub async fn increment(
+        &self,
+        key: impl IntoBytes,
+        amount: i64,
+    ) -> MomentoResult<IncrementResponse> {
+        IncrementRequest::new(self.name(), key, amount)
+            .send(&self.client)
+            .await
+    }
+
+    /// Gets the type of an item in the cache. See [CacheClient::item_get_type].
+    pub async fn item_get_type(&self, key: impl IntoBytes) -> MomentoResult<ItemGetTypeResponse> {
+        ItemGetTypeRequest::new(self.name(), key)
+            .send(&self.client)
+            .await
+    }
+
+    /// Gets the remaining time-to-live of an item in the cache. See [CacheClient::item_get_ttl].
+    pub async fn item_get_ttl(&self, key: impl IntoBytes) -> MomentoResult<ItemGetTtlResponse> {
+        ItemGetTtlRequest::new(self.name(), key)
+            .send(&self.client)
+            .await
+    }
+
+    /// Overwrites the time-to-live of an item in the cache. See [CacheClient::update_ttl].
+    pub async fn update_ttl(
+        &self,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<UpdateTtlResponse> {
+        UpdateTtlRequest::new(self.name(), key, ttl)
+            .send(&self.client)
+            .await
+    }
+
+    /// Increases the time-to-live of an item in the cache. See [CacheClient::increase_ttl].
+    pub async fn increase_ttl(
+        &self,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<IncreaseTtlResponse> {
+        IncreaseTtlRequest::new(self.name(), key, ttl)
+            .send(&self.client)
+            .await
+    }
+
+    /// Decreases the time-to-live of an item in the cache. See [CacheClient::decrease_ttl].
+    pub async fn decrease_ttl(
+        &self,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<DecreaseTtlResponse> {
+        DecreaseTtlRequest::new(self.name(), key, ttl)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if the key is not already present. See [CacheClient::set_if_absent].
+    pub async fn set_if_absent(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentResponse> {
+        SetIfAbsentRequest::new(self.name(), key, value)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if the key is already present. See [CacheClient::set_if_present].
+    pub async fn set_if_present(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentResponse> {
+        SetIfPresentRequest::new(self.name(), key, value)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if its current value is equal to `equal`. See [CacheClient::set_if_equal].
+    pub async fn set_if_equal(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfEqualResponse> {
+        SetIfEqualRequest::new(self.name(), key, value, equal)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if it is absent or its current value is not equal to `not_equal`.
+    /// See [CacheClient::set_if_not_equal].
+    pub async fn set_if_not_equal(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfNotEqualResponse> {
+        SetIfNotEqualRequest::new(self.name(), key, value, not_equal)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if it is present and its current value is not equal to `not_equal`.
+    /// See [CacheClient::set_if_present_and_not_equal].
+    pub async fn set_if_present_and_not_equal(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndNotEqualResponse> {
+        SetIfPresentAndNotEqualRequest::new(self.name(), key, value, not_equal)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if it is absent or its current value is equal to `equal`.
+    /// See [CacheClient::set_if_absent_or_equal].
+    pub async fn set_if_absent_or_equal(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrEqualResponse> {
+        SetIfAbsentOrEqualRequest::new(self.name(), key, value, equal)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if it is present and the hash of its value is equal to `hash_equal`.
+    /// See [CacheClient::set_if_present_and_hash_equal].
+    pub async fn set_if_present_and_hash_equal(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndHashEqualResponse> {
+        SetIfPresentAndHashEqualRequest::new(self.name(), key, value, hash_equal)
+            .send(&self.client)
+            .await
+    }
+
+    /// Sets an item if it is absent or the hash of its value is equal to `hash_equal`.
+    /// See [CacheClient::set_if_absent_or_hash_equal].
+    pub async fn set_if_absent_or_hash_equal(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrHashEqualResponse> {
+        SetIfAbsentOrHashEqualRequest::new(self.name(), key, value, hash_equal)
+            .send(&self.client)
+            .await
+    }
+
+    /// Atomically updates an item with a read-modify-write loop. See [CacheClient::update_with].
+    pub async fn update_with(
+        &self,
+        key: impl IntoBytes,
+        ttl: impl Into<Option<Duration>>,
+        update_fn: impl FnMut(Option<Value>) -> Option<Vec<u8>> + Send,
+    ) -> MomentoResult<UpdateWithResponse> {
+        UpdateWithRequest::new(self.name(), key, update_fn)
+            .ttl(ttl)
+            .send(&self.client)
+            .await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(client: CacheClient, cache_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            cache_name: cache_name.into(),
+            collection_ttl: CollectionTtl::default(),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Runtime support for the `#[momento::cached]` attribute. Not part of the public API.
+
+use std::future::Future;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::cache::{GetResponse, MomentoRequest, SetRequest};
+use crate::CacheClient;
+
+/// Returns `body` as it is. The body of a cached function runs as an async block passed through
+/// here, which gives the block the function's return type so that `?` converts errors as it
+/// would in the function.
+pub fn returning<T, F: Future<Output = T>>(body: F) -> F {
+    body
+}
+
+/// Build the default cache key for a call from the function's path and its arguments.
+///
+/// Returns `None` if the arguments cannot be serialized, in which case the call is not cached.
+pub fn cache_key<A: Serialize>(function: &str, arguments: &A) -> Option<String> {
+    match serde_json::to_string(arguments) {
+        Ok(arguments) => Some(format!("{function}:{arguments}")),
+        Err(error) => {
+            log::debug!("not caching call to {function}, failed to serialize arguments: {error}");
+            None
+        }
+    }
+}
+
+/// Read a cached return value. Errors and values which cannot be decoded are treated as misses.
+pub async fn cached_get<T: DeserializeOwned>(
+    client: &CacheClient,
+    cache_name: &str,
+    key: &str,
+) -> Option<T> {
+    match client.get(cache_name, key).await {
+        Ok(GetResponse::Hit { value }) => match serde_json::from_slice(&value.raw_item) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                log::debug!("ignoring cached value for {key} which failed to decode: {error}");
+                None
+            }
+        },
+        Ok(GetResponse::Miss) => None,
+        Err(error) => {
+            log::debug!("failed to read cached value for {key}: {error}");
+            None
+        }
+    }
+}
+
+/// Store a return value. Errors are logged and otherwise ignored.
+pub async fn cached_set<T: Serialize>(
+    client: &CacheClient,
+    cache_name: &str,
+    key: &str,
+    value: &T,
+    ttl: Option<Duration>,
+) {
+    let value = match serde_json::to_vec(value) {
+        Ok(value) => value,
+        Err(error) => {
+            log::debug!("not caching value for {key}, failed to serialize it: {error}");
+            return;
+        }
+    };
+    if let Err(error) = SetRequest::new(cache_name, key, value)
+        .ttl(ttl)
+        .send(client)
+        .await
+    {
+        log::debug!("failed to cache value for {key}: {error}");
+    }
+}
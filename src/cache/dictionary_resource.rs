@@ -0,0 +1,161 @@
+use crate::cache::{
+    Cache, CollectionTtl, DeleteRequest, DeleteResponse, DictionaryFetchRequest,
+    DictionaryFetchResponse, DictionaryGetFieldRequest, DictionaryGetFieldResponse,
+    DictionaryGetFieldsRequest, DictionaryGetFieldsResponse, DictionaryIncrementRequest,
+    DictionaryIncrementResponse, DictionaryLengthRequest, DictionaryLengthResponse,
+    DictionaryRemoveFieldRequest, DictionaryRemoveFieldResponse, DictionaryRemoveFieldsRequest,
+    DictionaryRemoveFieldsResponse, DictionarySetFieldRequest, DictionarySetFieldResponse,
+    DictionarySetFieldsRequest, DictionarySetFieldsResponse, IntoDictionaryFieldValuePairs,
+    MomentoRequest,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{IntoBytes, MomentoResult};
+
+/// Represents a dictionary in a cache.
+///
+/// Obtain a `Dictionary` with [Cache::dictionary]. Every write uses the handle's
+/// [CollectionTtl], which defaults to the TTL of the [Cache] it was created from.
+#[derive(Clone, Debug)]
+pub struct Dictionary {
+    cache: Cache,
+    dictionary_name: Vec<u8>,
+    collection_ttl: CollectionTtl,
+}
+
+impl Dictionary {
+    /// The name of the dictionary.
+    pub fn name(&self) -> &[u8] {
+        &self.dictionary_name
+    }
+
+    /// The cache containing the dictionary.
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// The [CollectionTtl] used when writing to the dictionary.
+    pub fn collection_ttl(&self) -> CollectionTtl {
+        self.collection_ttl
+    }
+
+    /// Return a new handle which writes to the dictionary with the provided [CollectionTtl].
+    pub fn with_ttl(mut self, collection_ttl: CollectionTtl) -> Self {
+        self.collection_ttl = collection_ttl;
+        self
+    }
+
+    /// Deletes the dictionary.
+    pub async fn delete(&self) -> MomentoResult<DeleteResponse> {
+        DeleteRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Fetches all fields of the dictionary.
+    /// See [CacheClient::dictionary_fetch](crate::CacheClient::dictionary_fetch).
+    pub async fn fetch(&self) -> MomentoResult<DictionaryFetchResponse> {
+        DictionaryFetchRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets a single field of the dictionary.
+    /// See [CacheClient::dictionary_get_field](crate::CacheClient::dictionary_get_field).
+    pub async fn get_field(
+        &self,
+        field: impl IntoBytes,
+    ) -> MomentoResult<DictionaryGetFieldResponse> {
+        DictionaryGetFieldRequest::new(self.cache.name(), self.name(), field)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets multiple fields of the dictionary.
+    /// See [CacheClient::dictionary_get_fields](crate::CacheClient::dictionary_get_fields).
+    pub async fn get_fields<F: IntoBytesIterable + Clone>(
+        &self,
+        fields: F,
+    ) -> MomentoResult<DictionaryGetFieldsResponse<F>> {
+        DictionaryGetFieldsRequest::new(self.cache.name(), self.name(), fields)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds an integer quantity to a field of the dictionary.
+    /// See [CacheClient::dictionary_increment](crate::CacheClient::dictionary_increment).
+    pub async fn increment(
+        &self,
+        field: impl IntoBytes,
+        amount: i64,
+    ) -> MomentoResult<DictionaryIncrementResponse> {
+        DictionaryIncrementRequest::new(self.cache.name(), self.name(), field, amount)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets the number of fields in the dictionary.
+    /// See [CacheClient::dictionary_length](crate::CacheClient::dictionary_length).
+    pub async fn length(&self) -> MomentoResult<DictionaryLengthResponse> {
+        DictionaryLengthRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Removes a field from the dictionary.
+    /// See [CacheClient::dictionary_remove_field](crate::CacheClient::dictionary_remove_field).
+    pub async fn remove_field(
+        &self,
+        field: impl IntoBytes,
+    ) -> MomentoResult<DictionaryRemoveFieldResponse> {
+        DictionaryRemoveFieldRequest::new(self.cache.name(), self.name(), field)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Removes multiple fields from the dictionary.
+    /// See [CacheClient::dictionary_remove_fields](crate::CacheClient::dictionary_remove_fields).
+    pub async fn remove_fields<F: IntoBytesIterable>(
+        &self,
+        fields: F,
+    ) -> MomentoResult<DictionaryRemoveFieldsResponse> {
+        DictionaryRemoveFieldsRequest::new(self.cache.name(), self.name(), fields)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Sets a field of the dictionary.
+    /// See [CacheClient::dictionary_set_field](crate::CacheClient::dictionary_set_field).
+    pub async fn set_field(
+        &self,
+        field: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<DictionarySetFieldResponse> {
+        DictionarySetFieldRequest::new(self.cache.name(), self.name(), field, value)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Sets multiple fields of the dictionary.
+    /// See [CacheClient::dictionary_set_fields](crate::CacheClient::dictionary_set_fields).
+    pub async fn set_fields<F: IntoBytes, V: IntoBytes>(
+        &self,
+        elements: impl IntoDictionaryFieldValuePairs<F, V>,
+    ) -> MomentoResult<DictionarySetFieldsResponse> {
+        DictionarySetFieldsRequest::new(self.cache.name(), self.name(), elements)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(cache: Cache, dictionary_name: Vec<u8>) -> Self {
+        let collection_ttl = cache.collection_ttl();
+        Self {
+            cache,
+            dictionary_name,
+            collection_ttl,
+        }
+    }
+}
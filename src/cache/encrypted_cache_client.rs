@@ -0,0 +1,981 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cache::encryption_key_ring::{EncryptionContext, EncryptionMode};
+use crate::cache::messages::data::dictionary::dictionary_fetch::Value as DictionaryValue;
+use crate::cache::messages::data::dictionary::dictionary_get_field::Value as DictionaryFieldValue;
+use crate::cache::messages::data::list::list_fetch::Value as ListValue;
+use crate::cache::messages::data::list::list_pop_back::Value as ListPopBackValue;
+use crate::cache::messages::data::list::list_pop_front::Value as ListPopFrontValue;
+use crate::cache::messages::data::scalar::get::Value;
+use crate::cache::messages::data::set::set_fetch::Value as SetValue;
+use crate::cache::{
+    DictionaryFetchResponse, DictionaryGetFieldResponse, DictionaryGetFieldsResponse,
+    DictionarySetFieldRequest, DictionarySetFieldResponse, DictionarySetFieldsRequest,
+    DictionarySetFieldsResponse, EncryptionKeyRing, GetBatchResponse, GetResponse,
+    GetWithHashResponse, IntoDictionaryFieldValuePairs, IntoSortedSetElements,
+    ListConcatenateBackRequest, ListConcatenateBackResponse, ListConcatenateFrontRequest,
+    ListConcatenateFrontResponse, ListFetchResponse, ListPopBackResponse, ListPopFrontResponse,
+    ListPushBackRequest, ListPushBackResponse, ListPushFrontRequest, ListPushFrontResponse,
+    ListRemoveValueResponse, MomentoRequest, SetAddElementsRequest, SetAddElementsResponse,
+    SetBatchRequest, SetBatchResponse, SetFetchResponse, SetIfAbsentOrEqualRequest,
+    SetIfAbsentOrEqualResponse, SetIfAbsentOrHashEqualRequest, SetIfAbsentOrHashEqualResponse,
+    SetIfAbsentRequest, SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse,
+    SetIfNotEqualRequest, SetIfNotEqualResponse, SetIfPresentAndHashEqualRequest,
+    SetIfPresentAndHashEqualResponse, SetIfPresentAndNotEqualRequest,
+    SetIfPresentAndNotEqualResponse, SetIfPresentRequest, SetIfPresentResponse,
+    SetRemoveElementsResponse, SetRequest, SetResponse, SortedSetElements, SortedSetFetchResponse,
+    SortedSetGetRankResponse, SortedSetGetScoreResponse, SortedSetIncrementScoreRequest,
+    SortedSetIncrementScoreResponse, SortedSetOrder, SortedSetPutElementRequest,
+    SortedSetPutElementResponse, SortedSetPutElementsRequest, SortedSetPutElementsResponse,
+    SortedSetRemoveElementsResponse,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{CacheClient, IntoBytes, MomentoResult};
+
+/// A [CacheClient] which encrypts values before they leave the process and decrypts them when
+/// they are read back.
+///
+/// Values are encrypted with AES-256-GCM using the primary key of an [EncryptionKeyRing]. Each
+/// encrypted value starts with a small header containing the id of the key that encrypted it, so
+/// values written before a key rotation stay readable as long as the old key is in the ring.
+///
+/// Only values are encrypted; keys, collection names and dictionary fields are stored as-is. They
+/// are authenticated along with each value, so a value copied to a different cache, item or
+/// dictionary field fails to decrypt.
+///
+/// Scalar and dictionary values are encrypted with a random nonce, so equal values cannot be
+/// correlated. The `set_if_equal` family compares against the decrypted value and then uses a
+/// compare-and-swap on the stored ciphertext, so those methods read the item before writing it.
+///
+/// List values and set and sorted set elements are encrypted deterministically, so that
+/// [EncryptedCacheClient::list_remove_value], [EncryptedCacheClient::set_remove_elements] and the
+/// sorted set lookups can match them on the server. Equal elements encrypted with the same key
+/// produce equal ciphertexts. Removals match elements written with any key in the ring, but
+/// after a rotation re-adding an element written with an old key stores a second copy, and
+/// sorted set lookups only match elements written with the primary key.
+///
+/// Operations which do not carry values, such as `delete` or `list_length`, are available on the
+/// underlying [CacheClient] via [EncryptedCacheClient::client]. `increment` and
+/// `dictionary_increment` cannot operate on encrypted values and are not provided.
+///
+/// Use [send_request](EncryptedCacheClient::send_request) to encrypt and send a write request
+/// builder, for example to set a per-item TTL or a [CollectionTtl](crate::cache::CollectionTtl).
+///
+/// # Example
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::{EncryptionKeyRing, GetResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// // Load keys from your key management system rather than hard-coding them.
+/// let key_ring = EncryptionKeyRing::new("2024-01", [7u8; 32])?;
+/// let encrypted_client = cache_client.encrypted(key_ring);
+///
+/// encrypted_client.set(&cache_name, "ssn", "078-05-1120").await?;
+///
+/// let item: String = encrypted_client.get(&cache_name, "ssn").await?.try_into()?;
+/// assert_eq!(item, "078-05-1120");
+///
+/// // The value stored in the cache is encrypted.
+/// let stored: Vec<u8> = cache_client.get(&cache_name, "ssn").await?.try_into()?;
+/// assert_ne!(stored, b"078-05-1120");
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct EncryptedCacheClient {
+    client: CacheClient,
+    key_ring: Arc<EncryptionKeyRing>,
+}
+
+impl EncryptedCacheClient {
+    /// The underlying [CacheClient].
+    pub fn client(&self) -> &CacheClient {
+        &self.client
+    }
+
+    /// The key ring used to encrypt and decrypt values.
+    pub fn key_ring(&self) -> &EncryptionKeyRing {
+        &self.key_ring
+    }
+
+    /// Encrypts and sets an item. See [CacheClient::set].
+    pub async fn set(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetResponse> {
+        self.send_request(SetRequest::new(cache_name, key, value))
+            .await
+    }
+
+    /// Encrypts and sets a batch of items. See [CacheClient::set_batch].
+    pub async fn set_batch<K: IntoBytes, V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        items: impl IntoIterator<Item = (K, V)>,
+    ) -> MomentoResult<SetBatchResponse> {
+        self.send_request(SetBatchRequest::new(cache_name, items))
+            .await
+    }
+
+    /// Gets and decrypts an item. See [CacheClient::get].
+    pub async fn get(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        match self.client.get(cache_name.as_str(), key.clone()).await? {
+            GetResponse::Hit { value } => Ok(GetResponse::Hit {
+                value: Value::new(self.decrypt(&cache_name, &key, &value.raw_item)?),
+            }),
+            GetResponse::Miss => Ok(GetResponse::Miss),
+        }
+    }
+
+    /// Gets and decrypts an item along with the hash of its stored, encrypted value.
+    /// See [CacheClient::get_with_hash].
+    pub async fn get_with_hash(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetWithHashResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        match self
+            .client
+            .get_with_hash(cache_name.as_str(), key.clone())
+            .await?
+        {
+            GetWithHashResponse::Hit { value, hash } => Ok(GetWithHashResponse::Hit {
+                value: Value::new(self.decrypt(&cache_name, &key, &value.raw_item)?),
+                hash,
+            }),
+            GetWithHashResponse::Miss => Ok(GetWithHashResponse::Miss),
+        }
+    }
+
+    /// Gets and decrypts a batch of items. See [CacheClient::get_batch].
+    pub async fn get_batch(
+        &self,
+        cache_name: impl Into<String>,
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<GetBatchResponse> {
+        let cache_name = cache_name.into();
+        self.client
+            .get_batch(cache_name.as_str(), keys)
+            .await?
+            .try_map_values(|key, value| self.decrypt(&cache_name, key, &value))
+    }
+
+    /// Encrypts and sets an item if the key is absent. See [CacheClient::set_if_absent].
+    pub async fn set_if_absent(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentResponse> {
+        self.send_request(SetIfAbsentRequest::new(cache_name, key, value))
+            .await
+    }
+
+    /// Encrypts and sets an item if the key is present. See [CacheClient::set_if_present].
+    pub async fn set_if_present(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentResponse> {
+        self.send_request(SetIfPresentRequest::new(cache_name, key, value))
+            .await
+    }
+
+    /// Encrypts and sets an item if its decrypted value equals `equal`.
+    /// See [CacheClient::set_if_equal].
+    pub async fn set_if_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfEqualResponse> {
+        self.send_request(SetIfEqualRequest::new(cache_name, key, value, equal))
+            .await
+    }
+
+    /// Encrypts and sets an item if the key is absent or its decrypted value does not equal
+    /// `not_equal`. See [CacheClient::set_if_not_equal].
+    pub async fn set_if_not_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfNotEqualResponse> {
+        self.send_request(SetIfNotEqualRequest::new(cache_name, key, value, not_equal))
+            .await
+    }
+
+    /// Encrypts and sets an item if the key is present and its decrypted value does not equal
+    /// `not_equal`. See [CacheClient::set_if_present_and_not_equal].
+    pub async fn set_if_present_and_not_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndNotEqualResponse> {
+        self.send_request(SetIfPresentAndNotEqualRequest::new(
+            cache_name, key, value, not_equal,
+        ))
+        .await
+    }
+
+    /// Encrypts and sets an item if the key is absent or its decrypted value equals `equal`.
+    /// See [CacheClient::set_if_absent_or_equal].
+    pub async fn set_if_absent_or_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrEqualResponse> {
+        self.send_request(SetIfAbsentOrEqualRequest::new(
+            cache_name, key, value, equal,
+        ))
+        .await
+    }
+
+    /// Encrypts and sets an item if the key is present and the hash of its stored value equals
+    /// `hash_equal`. Use the hash returned by [EncryptedCacheClient::get_with_hash].
+    /// See [CacheClient::set_if_present_and_hash_equal].
+    pub async fn set_if_present_and_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndHashEqualResponse> {
+        self.send_request(SetIfPresentAndHashEqualRequest::new(
+            cache_name, key, value, hash_equal,
+        ))
+        .await
+    }
+
+    /// Encrypts and sets an item if the key is absent or the hash of its stored value equals
+    /// `hash_equal`. Use the hash returned by [EncryptedCacheClient::get_with_hash].
+    /// See [CacheClient::set_if_absent_or_hash_equal].
+    pub async fn set_if_absent_or_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrHashEqualResponse> {
+        self.send_request(SetIfAbsentOrHashEqualRequest::new(
+            cache_name, key, value, hash_equal,
+        ))
+        .await
+    }
+
+    /// Fetches and decrypts a dictionary. See [CacheClient::dictionary_fetch].
+    pub async fn dictionary_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+    ) -> MomentoResult<DictionaryFetchResponse> {
+        let (cache_name, dictionary_name) = (cache_name.into(), dictionary_name.into_bytes());
+        match self
+            .client
+            .dictionary_fetch(cache_name.as_str(), dictionary_name.clone())
+            .await?
+        {
+            DictionaryFetchResponse::Hit { value } => {
+                let raw_item = value
+                    .raw_item
+                    .into_iter()
+                    .map(|(field, value)| {
+                        let context =
+                            EncryptionContext::field(&cache_name, &dictionary_name, &field);
+                        let value = self.key_ring.decrypt(&context, &value)?;
+                        Ok((field, value))
+                    })
+                    .collect::<MomentoResult<_>>()?;
+                Ok(DictionaryFetchResponse::Hit {
+                    value: DictionaryValue::new(raw_item),
+                })
+            }
+            DictionaryFetchResponse::Miss => Ok(DictionaryFetchResponse::Miss),
+        }
+    }
+
+    /// Gets and decrypts a field from a dictionary. See [CacheClient::dictionary_get_field].
+    pub async fn dictionary_get_field(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+    ) -> MomentoResult<DictionaryGetFieldResponse> {
+        let (cache_name, dictionary_name) = (cache_name.into(), dictionary_name.into_bytes());
+        let field = field.into_bytes();
+        let response = self
+            .client
+            .dictionary_get_field(cache_name.as_str(), dictionary_name.clone(), field.clone())
+            .await?;
+        let context = EncryptionContext::field(&cache_name, &dictionary_name, &field);
+        self.decrypt_field(&context, response)
+    }
+
+    /// Gets and decrypts fields from a dictionary. See [CacheClient::dictionary_get_fields].
+    pub async fn dictionary_get_fields<F: IntoBytesIterable + Clone>(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        fields: F,
+    ) -> MomentoResult<DictionaryGetFieldsResponse<F>> {
+        let (cache_name, dictionary_name) = (cache_name.into(), dictionary_name.into_bytes());
+        match self
+            .client
+            .dictionary_get_fields(cache_name.as_str(), dictionary_name.clone(), fields)
+            .await?
+        {
+            DictionaryGetFieldsResponse::Hit { fields, responses } => {
+                let responses = fields
+                    .clone()
+                    .into_bytes()
+                    .iter()
+                    .zip(responses)
+                    .map(|(field, response)| {
+                        let context =
+                            EncryptionContext::field(&cache_name, &dictionary_name, field);
+                        self.decrypt_field(&context, response)
+                    })
+                    .collect::<MomentoResult<_>>()?;
+                Ok(DictionaryGetFieldsResponse::Hit { fields, responses })
+            }
+            DictionaryGetFieldsResponse::Miss => Ok(DictionaryGetFieldsResponse::Miss),
+        }
+    }
+
+    /// Encrypts and sets a field in a dictionary. See [CacheClient::dictionary_set_field].
+    pub async fn dictionary_set_field(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<DictionarySetFieldResponse> {
+        self.send_request(DictionarySetFieldRequest::new(
+            cache_name,
+            dictionary_name,
+            field,
+            value,
+        ))
+        .await
+    }
+
+    /// Encrypts and sets fields in a dictionary. See [CacheClient::dictionary_set_fields].
+    pub async fn dictionary_set_fields<F: IntoBytes, V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        elements: impl IntoDictionaryFieldValuePairs<F, V>,
+    ) -> MomentoResult<DictionarySetFieldsResponse> {
+        self.send_request(DictionarySetFieldsRequest::new(
+            cache_name,
+            dictionary_name,
+            elements,
+        ))
+        .await
+    }
+
+    /// Encrypts and adds elements to the front of a list.
+    /// See [CacheClient::list_concatenate_front].
+    pub async fn list_concatenate_front(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<ListConcatenateFrontResponse> {
+        self.send_request(ListConcatenateFrontRequest::new(
+            cache_name, list_name, values,
+        ))
+        .await
+    }
+
+    /// Encrypts and adds elements to the back of a list. See [CacheClient::list_concatenate_back].
+    pub async fn list_concatenate_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<ListConcatenateBackResponse> {
+        self.send_request(ListConcatenateBackRequest::new(
+            cache_name, list_name, values,
+        ))
+        .await
+    }
+
+    /// Fetches and decrypts a list. See [CacheClient::list_fetch].
+    pub async fn list_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListFetchResponse> {
+        let (cache_name, list_name) = (cache_name.into(), list_name.into_bytes());
+        match self
+            .client
+            .list_fetch(cache_name.as_str(), list_name.clone())
+            .await?
+        {
+            ListFetchResponse::Hit { values } => Ok(ListFetchResponse::Hit {
+                values: ListValue::new(self.decrypt_all(
+                    &cache_name,
+                    &list_name,
+                    values.raw_item,
+                )?),
+            }),
+            ListFetchResponse::Miss => Ok(ListFetchResponse::Miss),
+        }
+    }
+
+    /// Removes and decrypts the last element of a list. See [CacheClient::list_pop_back].
+    pub async fn list_pop_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListPopBackResponse> {
+        let (cache_name, list_name) = (cache_name.into(), list_name.into_bytes());
+        match self
+            .client
+            .list_pop_back(cache_name.as_str(), list_name.clone())
+            .await?
+        {
+            ListPopBackResponse::Hit { value } => Ok(ListPopBackResponse::Hit {
+                value: ListPopBackValue::new(self.decrypt(
+                    &cache_name,
+                    &list_name,
+                    &value.raw_item,
+                )?),
+            }),
+            ListPopBackResponse::Miss => Ok(ListPopBackResponse::Miss),
+        }
+    }
+
+    /// Removes and decrypts the first element of a list. See [CacheClient::list_pop_front].
+    pub async fn list_pop_front(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListPopFrontResponse> {
+        let (cache_name, list_name) = (cache_name.into(), list_name.into_bytes());
+        match self
+            .client
+            .list_pop_front(cache_name.as_str(), list_name.clone())
+            .await?
+        {
+            ListPopFrontResponse::Hit { value } => Ok(ListPopFrontResponse::Hit {
+                value: ListPopFrontValue::new(self.decrypt(
+                    &cache_name,
+                    &list_name,
+                    &value.raw_item,
+                )?),
+            }),
+            ListPopFrontResponse::Miss => Ok(ListPopFrontResponse::Miss),
+        }
+    }
+
+    /// Removes all elements equal to `value` from a list, whichever key in the ring encrypted
+    /// them. See [CacheClient::list_remove_value].
+    pub async fn list_remove_value(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListRemoveValueResponse> {
+        let (cache_name, list_name) = (cache_name.into(), list_name.into_bytes());
+        let context = EncryptionContext::item(&cache_name, &list_name);
+        for ciphertext in self
+            .key_ring
+            .encrypt_with_all_keys(&context, &value.into_bytes())?
+        {
+            self.client
+                .list_remove_value(cache_name.as_str(), list_name.clone(), ciphertext)
+                .await?;
+        }
+        Ok(ListRemoveValueResponse {})
+    }
+
+    /// Encrypts and adds an element to the back of a list. See [CacheClient::list_push_back].
+    pub async fn list_push_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListPushBackResponse> {
+        self.send_request(ListPushBackRequest::new(cache_name, list_name, value))
+            .await
+    }
+
+    /// Encrypts and adds an element to the front of a list. See [CacheClient::list_push_front].
+    pub async fn list_push_front(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListPushFrontResponse> {
+        self.send_request(ListPushFrontRequest::new(cache_name, list_name, value))
+            .await
+    }
+
+    /// Encrypts and adds elements to a set. See [CacheClient::set_add_elements].
+    pub async fn set_add_elements<E: IntoBytesIterable>(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+        elements: E,
+    ) -> MomentoResult<SetAddElementsResponse> {
+        self.send_request(SetAddElementsRequest::new(cache_name, set_name, elements))
+            .await
+    }
+
+    /// Fetches and decrypts a set. See [CacheClient::set_fetch].
+    pub async fn set_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+    ) -> MomentoResult<SetFetchResponse> {
+        let (cache_name, set_name) = (cache_name.into(), set_name.into_bytes());
+        match self
+            .client
+            .set_fetch(cache_name.as_str(), set_name.clone())
+            .await?
+        {
+            SetFetchResponse::Hit { values } => Ok(SetFetchResponse::Hit {
+                values: SetValue::new(self.decrypt_all(&cache_name, &set_name, values.raw_item)?),
+            }),
+            SetFetchResponse::Miss => Ok(SetFetchResponse::Miss),
+        }
+    }
+
+    /// Removes elements from a set, whichever key in the ring encrypted them.
+    /// See [CacheClient::set_remove_elements].
+    pub async fn set_remove_elements<E: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+        elements: Vec<E>,
+    ) -> MomentoResult<SetRemoveElementsResponse> {
+        let (cache_name, set_name) = (cache_name.into(), set_name.into_bytes());
+        let elements = self.encrypt_elements_with_all_keys(&cache_name, &set_name, elements)?;
+        self.client
+            .set_remove_elements(cache_name, set_name, elements)
+            .await
+    }
+
+    /// Encrypts and adds an element to a sorted set. See [CacheClient::sorted_set_put_element].
+    pub async fn sorted_set_put_element(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+        score: f64,
+    ) -> MomentoResult<SortedSetPutElementResponse> {
+        self.send_request(SortedSetPutElementRequest::new(
+            cache_name,
+            sorted_set_name,
+            value,
+            score,
+        ))
+        .await
+    }
+
+    /// Encrypts and adds elements to a sorted set. See [CacheClient::sorted_set_put_elements].
+    pub async fn sorted_set_put_elements<V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        elements: impl IntoSortedSetElements<V>,
+    ) -> MomentoResult<SortedSetPutElementsResponse> {
+        self.send_request(SortedSetPutElementsRequest::new(
+            cache_name,
+            sorted_set_name,
+            elements,
+        ))
+        .await
+    }
+
+    /// Fetches and decrypts elements of a sorted set by rank.
+    /// See [CacheClient::sorted_set_fetch_by_rank].
+    pub async fn sorted_set_fetch_by_rank(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        order: SortedSetOrder,
+        start_rank: Option<i32>,
+        end_rank: Option<i32>,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        let (cache_name, sorted_set_name) = (cache_name.into(), sorted_set_name.into_bytes());
+        let response = self
+            .client
+            .sorted_set_fetch_by_rank(
+                cache_name.as_str(),
+                sorted_set_name.clone(),
+                order,
+                start_rank,
+                end_rank,
+            )
+            .await?;
+        self.decrypt_sorted_set(&cache_name, &sorted_set_name, response)
+    }
+
+    /// Fetches and decrypts elements of a sorted set by score.
+    /// See [CacheClient::sorted_set_fetch_by_score].
+    pub async fn sorted_set_fetch_by_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        order: SortedSetOrder,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        let (cache_name, sorted_set_name) = (cache_name.into(), sorted_set_name.into_bytes());
+        let response = self
+            .client
+            .sorted_set_fetch_by_score(cache_name.as_str(), sorted_set_name.clone(), order)
+            .await?;
+        self.decrypt_sorted_set(&cache_name, &sorted_set_name, response)
+    }
+
+    /// Gets the rank of an element in a sorted set. See [CacheClient::sorted_set_get_rank].
+    pub async fn sorted_set_get_rank(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SortedSetGetRankResponse> {
+        let (cache_name, sorted_set_name) = (cache_name.into(), sorted_set_name.into_bytes());
+        let value = self.encrypt_element(&cache_name, &sorted_set_name, value)?;
+        self.client
+            .sorted_set_get_rank(cache_name, sorted_set_name, value)
+            .await
+    }
+
+    /// Gets the score of an element in a sorted set. See [CacheClient::sorted_set_get_score].
+    pub async fn sorted_set_get_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SortedSetGetScoreResponse> {
+        let (cache_name, sorted_set_name) = (cache_name.into(), sorted_set_name.into_bytes());
+        let value = self.encrypt_element(&cache_name, &sorted_set_name, value)?;
+        self.client
+            .sorted_set_get_score(cache_name, sorted_set_name, value)
+            .await
+    }
+
+    /// Increments the score of an element in a sorted set.
+    /// See [CacheClient::sorted_set_increment_score].
+    pub async fn sorted_set_increment_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+        score: f64,
+    ) -> MomentoResult<SortedSetIncrementScoreResponse> {
+        self.send_request(SortedSetIncrementScoreRequest::new(
+            cache_name,
+            sorted_set_name,
+            value,
+            score,
+        ))
+        .await
+    }
+
+    /// Removes elements from a sorted set, whichever key in the ring encrypted them.
+    /// See [CacheClient::sorted_set_remove_elements].
+    pub async fn sorted_set_remove_elements<V: IntoBytesIterable>(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        values: V,
+    ) -> MomentoResult<SortedSetRemoveElementsResponse> {
+        let (cache_name, sorted_set_name) = (cache_name.into(), sorted_set_name.into_bytes());
+        let values = self.encrypt_elements_with_all_keys(
+            &cache_name,
+            &sorted_set_name,
+            values.into_bytes(),
+        )?;
+        self.client
+            .sorted_set_remove_elements(cache_name, sorted_set_name, values)
+            .await
+    }
+
+    /// Lower-level API to encrypt and send a write request. This is used for cases when you want
+    /// to set optional fields on a request, such as a TTL or a
+    /// [CollectionTtl](crate::cache::CollectionTtl), that are not supported by the short-hand API
+    /// for that request type.
+    ///
+    /// # Example
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use momento::cache::{CollectionTtl, EncryptionKeyRing, ListPushBackRequest, SetRequest};
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let key_ring = EncryptionKeyRing::new("2024-01", [7u8; 32])?;
+    /// let encrypted_client = cache_client.encrypted(key_ring);
+    ///
+    /// encrypted_client
+    ///     .send_request(SetRequest::new(&cache_name, "ssn", "078-05-1120").ttl(Duration::from_secs(60)))
+    ///     .await?;
+    /// encrypted_client
+    ///     .send_request(
+    ///         ListPushBackRequest::new(&cache_name, "history", "078-05-1120")
+    ///             .ttl(CollectionTtl::refresh_on_update(Duration::from_secs(60))),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn send_request<R: EncryptedRequest>(
+        &self,
+        request: R,
+    ) -> MomentoResult<R::Response> {
+        request.send_encrypted(self).await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(client: CacheClient, key_ring: EncryptionKeyRing) -> Self {
+        Self {
+            client,
+            key_ring: Arc::new(key_ring),
+        }
+    }
+
+    /// Encrypt the value of a scalar item with a random nonce.
+    pub(crate) fn encrypt(
+        &self,
+        cache_name: &str,
+        key: &[u8],
+        value: impl IntoBytes,
+    ) -> MomentoResult<Vec<u8>> {
+        self.key_ring.encrypt(
+            &EncryptionContext::item(cache_name, key),
+            &value.into_bytes(),
+            EncryptionMode::Randomized,
+        )
+    }
+
+    /// Encrypt the value of a dictionary field with a random nonce.
+    pub(crate) fn encrypt_field(
+        &self,
+        cache_name: &str,
+        dictionary_name: &[u8],
+        field: &[u8],
+        value: impl IntoBytes,
+    ) -> MomentoResult<Vec<u8>> {
+        self.key_ring.encrypt(
+            &EncryptionContext::field(cache_name, dictionary_name, field),
+            &value.into_bytes(),
+            EncryptionMode::Randomized,
+        )
+    }
+
+    /// Deterministically encrypt an element of a list, set or sorted set.
+    pub(crate) fn encrypt_element(
+        &self,
+        cache_name: &str,
+        collection_name: &[u8],
+        value: impl IntoBytes,
+    ) -> MomentoResult<Vec<u8>> {
+        self.key_ring.encrypt(
+            &EncryptionContext::item(cache_name, collection_name),
+            &value.into_bytes(),
+            EncryptionMode::Deterministic,
+        )
+    }
+
+    /// Deterministically encrypt the elements of a list, set or sorted set.
+    pub(crate) fn encrypt_elements(
+        &self,
+        cache_name: &str,
+        collection_name: &[u8],
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<Vec<Vec<u8>>> {
+        values
+            .into_bytes()
+            .into_iter()
+            .map(|value| self.encrypt_element(cache_name, collection_name, value))
+            .collect()
+    }
+
+    /// Encrypt `value` and write it if the decrypted value of the item satisfies `condition`.
+    /// Returns whether the value was stored.
+    pub(crate) async fn conditional_set(
+        &self,
+        cache_name: &str,
+        key: Vec<u8>,
+        value: impl IntoBytes,
+        expected: impl IntoBytes,
+        condition: Condition,
+        ttl: Option<Duration>,
+    ) -> MomentoResult<bool> {
+        let value = self.encrypt(cache_name, &key, value)?;
+        match (self.current(cache_name, &key, expected).await?, condition) {
+            (Current::Equal(ciphertext), Condition::PresentAndEqual | Condition::AbsentOrEqual)
+            | (
+                Current::NotEqual(ciphertext),
+                Condition::PresentAndNotEqual | Condition::AbsentOrNotEqual,
+            ) => {
+                // Swap only if the item still holds the ciphertext that was compared.
+                let response = SetIfEqualRequest::new(cache_name, key, value, ciphertext)
+                    .ttl(ttl)
+                    .send(&self.client)
+                    .await?;
+                Ok(response == SetIfEqualResponse::Stored)
+            }
+            (Current::Absent, Condition::AbsentOrEqual | Condition::AbsentOrNotEqual) => {
+                let response = SetIfAbsentRequest::new(cache_name, key, value)
+                    .ttl(ttl)
+                    .send(&self.client)
+                    .await?;
+                Ok(response == SetIfAbsentResponse::Stored)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn decrypt(&self, cache_name: &str, key: &[u8], ciphertext: &[u8]) -> MomentoResult<Vec<u8>> {
+        self.key_ring
+            .decrypt(&EncryptionContext::item(cache_name, key), ciphertext)
+    }
+
+    fn encrypt_elements_with_all_keys<E: IntoBytes>(
+        &self,
+        cache_name: &str,
+        collection_name: &[u8],
+        values: Vec<E>,
+    ) -> MomentoResult<Vec<Vec<u8>>> {
+        let context = EncryptionContext::item(cache_name, collection_name);
+        let mut encrypted = Vec::new();
+        for value in values {
+            encrypted.extend(
+                self.key_ring
+                    .encrypt_with_all_keys(&context, &value.into_bytes())?,
+            );
+        }
+        Ok(encrypted)
+    }
+
+    fn decrypt_all(
+        &self,
+        cache_name: &str,
+        collection_name: &[u8],
+        values: Vec<Vec<u8>>,
+    ) -> MomentoResult<Vec<Vec<u8>>> {
+        values
+            .iter()
+            .map(|value| self.decrypt(cache_name, collection_name, value))
+            .collect()
+    }
+
+    fn decrypt_field(
+        &self,
+        context: &EncryptionContext<'_>,
+        response: DictionaryGetFieldResponse,
+    ) -> MomentoResult<DictionaryGetFieldResponse> {
+        match response {
+            DictionaryGetFieldResponse::Hit { value } => Ok(DictionaryGetFieldResponse::Hit {
+                value: DictionaryFieldValue::new(self.key_ring.decrypt(context, &value.raw_item)?),
+            }),
+            DictionaryGetFieldResponse::Miss => Ok(DictionaryGetFieldResponse::Miss),
+        }
+    }
+
+    fn decrypt_sorted_set(
+        &self,
+        cache_name: &str,
+        sorted_set_name: &[u8],
+        response: SortedSetFetchResponse,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        match response {
+            SortedSetFetchResponse::Hit { value } => {
+                let elements = value
+                    .elements
+                    .into_iter()
+                    .map(|(value, score)| {
+                        Ok((self.decrypt(cache_name, sorted_set_name, &value)?, score))
+                    })
+                    .collect::<MomentoResult<_>>()?;
+                Ok(SortedSetFetchResponse::Hit {
+                    value: SortedSetElements::new(elements),
+                })
+            }
+            SortedSetFetchResponse::Miss => Ok(SortedSetFetchResponse::Miss),
+        }
+    }
+
+    /// Read the stored ciphertext of an item and compare its decrypted value to `expected`.
+    async fn current(
+        &self,
+        cache_name: &str,
+        key: &[u8],
+        expected: impl IntoBytes,
+    ) -> MomentoResult<Current> {
+        match self.client.get(cache_name, key.to_vec()).await? {
+            GetResponse::Hit { value } => {
+                let ciphertext = value.raw_item;
+                if self.decrypt(cache_name, key, &ciphertext)? == expected.into_bytes() {
+                    Ok(Current::Equal(ciphertext))
+                } else {
+                    Ok(Current::NotEqual(ciphertext))
+                }
+            }
+            GetResponse::Miss => Ok(Current::Absent),
+        }
+    }
+}
+
+/// A write request that [EncryptedCacheClient::send_request] can encrypt and send.
+///
+/// This is implemented for every request type that writes values: the scalar `set` family, the
+/// dictionary setters, and the list, set and sorted set writes.
+pub trait EncryptedRequest {
+    #[allow(missing_docs)]
+    type Response;
+
+    /// Encrypts the values of the request and sends it with the underlying [CacheClient].
+    #[doc(hidden)]
+    fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> impl std::future::Future<Output = MomentoResult<Self::Response>> + Send;
+}
+
+/// The condition a conditional write checks against the decrypted value of an item.
+#[derive(Clone, Copy, Debug)]
+#[allow(clippy::enum_variant_names)]
+pub(crate) enum Condition {
+    PresentAndEqual,
+    PresentAndNotEqual,
+    AbsentOrEqual,
+    AbsentOrNotEqual,
+}
+
+/// The current state of an item, compared to an expected plaintext value.
+enum Current {
+    Absent,
+    Equal(Vec<u8>),
+    NotEqual(Vec<u8>),
+}
@@ -0,0 +1,443 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+
+use crate::{MomentoError, MomentoErrorCode, MomentoResult};
+
+const HEADER_VERSION: u8 = 1;
+const NONCE_LENGTH: usize = 12;
+const MAX_KEY_ID_LENGTH: usize = u8::MAX as usize;
+const NONCE_KEY_CONTEXT: &[u8] = b"momento deterministic nonce";
+
+/// A set of AES-256-GCM keys used by [EncryptedCacheClient](crate::cache::EncryptedCacheClient).
+///
+/// New values are always encrypted with the primary key. Every encrypted value starts with a
+/// small header containing the id of the key that encrypted it, so values written with any key in
+/// the ring can be decrypted. The cache name, item key or collection name, and dictionary field
+/// a value was written to are authenticated along with it, so a value copied to a different item
+/// fails to decrypt. To rotate keys, add the new key with [EncryptionKeyRing::rotate]
+/// and keep the old keys in the ring until all values encrypted with them have expired or been
+/// rewritten.
+///
+/// # Example
+/// ```
+/// use momento::cache::EncryptionKeyRing;
+/// # fn main() -> anyhow::Result<()> {
+/// // Load keys from your key management system rather than hard-coding them.
+/// let key_ring = EncryptionKeyRing::new("2024-01", [7u8; 32])?
+///     .rotate("2024-06", [9u8; 32])?;
+/// assert_eq!(key_ring.primary_key_id(), "2024-06");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct EncryptionKeyRing {
+    primary_key_id: String,
+    keys: BTreeMap<String, Arc<EncryptionKey>>,
+}
+
+struct EncryptionKey {
+    cipher: Aes256Gcm,
+    nonce_key: Vec<u8>,
+}
+
+/// Where an encrypted value is stored. The context is authenticated as additional data, so a
+/// ciphertext only decrypts in the context it was encrypted for.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EncryptionContext<'a> {
+    cache_name: &'a str,
+    key: &'a [u8],
+    field: Option<&'a [u8]>,
+}
+
+impl<'a> EncryptionContext<'a> {
+    /// The context of a scalar item, or of an element of a list, set or sorted set.
+    pub(crate) fn item(cache_name: &'a str, key: &'a [u8]) -> Self {
+        Self {
+            cache_name,
+            key,
+            field: None,
+        }
+    }
+
+    /// The context of the value of a dictionary field.
+    pub(crate) fn field(cache_name: &'a str, dictionary_name: &'a [u8], field: &'a [u8]) -> Self {
+        Self {
+            cache_name,
+            key: dictionary_name,
+            field: Some(field),
+        }
+    }
+
+    /// Append the context to `aad`, length-prefixing each part so that contexts cannot collide.
+    fn encode(&self, aad: &mut Vec<u8>) {
+        for part in [Some(self.cache_name.as_bytes()), Some(self.key), self.field] {
+            match part {
+                Some(part) => {
+                    aad.push(1);
+                    aad.extend_from_slice(&(part.len() as u64).to_be_bytes());
+                    aad.extend_from_slice(part);
+                }
+                None => aad.push(0),
+            }
+        }
+    }
+}
+
+/// Whether encrypting the same plaintext twice produces the same ciphertext.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum EncryptionMode {
+    /// A random nonce is used for each encryption. Equal plaintexts cannot be correlated.
+    Randomized,
+    /// The nonce is derived from the plaintext, so equal plaintexts encrypted with the same key
+    /// produce equal ciphertexts and can be matched by the server.
+    Deterministic,
+}
+
+impl EncryptionKeyRing {
+    /// Constructs a new EncryptionKeyRing with a single key, which becomes the primary key.
+    pub fn new(key_id: impl Into<String>, key: [u8; 32]) -> MomentoResult<Self> {
+        let key_id = validate_key_id(key_id.into())?;
+        let mut keys = BTreeMap::new();
+        keys.insert(key_id.clone(), Arc::new(EncryptionKey::new(&key)));
+        Ok(Self {
+            primary_key_id: key_id,
+            keys,
+        })
+    }
+
+    /// Adds a key which is only used to decrypt existing values.
+    pub fn with_key(mut self, key_id: impl Into<String>, key: [u8; 32]) -> MomentoResult<Self> {
+        let key_id = validate_key_id(key_id.into())?;
+        if self.keys.contains_key(&key_id) {
+            return Err(invalid_argument(format!(
+                "key id {key_id} is already in the key ring"
+            )));
+        }
+        self.keys.insert(key_id, Arc::new(EncryptionKey::new(&key)));
+        Ok(self)
+    }
+
+    /// Adds a key and makes it the primary key. Existing keys remain available for decryption.
+    pub fn rotate(self, key_id: impl Into<String>, key: [u8; 32]) -> MomentoResult<Self> {
+        let key_id = key_id.into();
+        let mut key_ring = self.with_key(key_id.clone(), key)?;
+        key_ring.primary_key_id = key_id;
+        Ok(key_ring)
+    }
+
+    /// The id of the key used to encrypt new values.
+    pub fn primary_key_id(&self) -> &str {
+        &self.primary_key_id
+    }
+
+    /// The ids of all keys in the ring.
+    pub fn key_ids(&self) -> impl Iterator<Item = &str> {
+        self.keys.keys().map(String::as_str)
+    }
+
+    /// Encrypt `plaintext` for `context` with the primary key.
+    pub(crate) fn encrypt(
+        &self,
+        context: &EncryptionContext<'_>,
+        plaintext: &[u8],
+        mode: EncryptionMode,
+    ) -> MomentoResult<Vec<u8>> {
+        self.encrypt_with(&self.primary_key_id, context, plaintext, mode)
+    }
+
+    /// Deterministically encrypt `plaintext` for `context` with every key in the ring, so that a
+    /// value written with any of them can be matched.
+    pub(crate) fn encrypt_with_all_keys(
+        &self,
+        context: &EncryptionContext<'_>,
+        plaintext: &[u8],
+    ) -> MomentoResult<Vec<Vec<u8>>> {
+        self.keys
+            .keys()
+            .map(|key_id| {
+                self.encrypt_with(key_id, context, plaintext, EncryptionMode::Deterministic)
+            })
+            .collect()
+    }
+
+    /// Decrypt a value encrypted for `context` with any key in the ring.
+    pub(crate) fn decrypt(
+        &self,
+        context: &EncryptionContext<'_>,
+        ciphertext: &[u8],
+    ) -> MomentoResult<Vec<u8>> {
+        let (version, rest) = ciphertext
+            .split_first()
+            .ok_or_else(|| decryption_error("value is empty"))?;
+        if *version != HEADER_VERSION {
+            return Err(decryption_error(
+                "value is not encrypted or has an unsupported header version",
+            ));
+        }
+        let (key_id_length, rest) = rest
+            .split_first()
+            .ok_or_else(|| decryption_error("value header is truncated"))?;
+        let key_id_length = *key_id_length as usize;
+        if rest.len() < key_id_length + NONCE_LENGTH {
+            return Err(decryption_error("value header is truncated"));
+        }
+        let (key_id, rest) = rest.split_at(key_id_length);
+        let (nonce, sealed) = rest.split_at(NONCE_LENGTH);
+        let key_id = std::str::from_utf8(key_id)
+            .map_err(|_| decryption_error("value header contains an invalid key id"))?;
+        let key = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| decryption_error(format!("key id {key_id} is not in the key ring")))?;
+        let mut aad = ciphertext[..2 + key_id_length].to_vec();
+        context.encode(&mut aad);
+        key.cipher
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: sealed,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| {
+                decryption_error(format!("value could not be decrypted with key {key_id}"))
+            })
+    }
+
+    /* helper fns */
+    fn encrypt_with(
+        &self,
+        key_id: &str,
+        context: &EncryptionContext<'_>,
+        plaintext: &[u8],
+        mode: EncryptionMode,
+    ) -> MomentoResult<Vec<u8>> {
+        let key = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| invalid_argument(format!("key id {key_id} is not in the key ring")))?;
+
+        let mut output = Vec::with_capacity(2 + key_id.len() + NONCE_LENGTH + plaintext.len() + 16);
+        output.push(HEADER_VERSION);
+        output.push(key_id.len() as u8);
+        output.extend_from_slice(key_id.as_bytes());
+        let mut aad = output.clone();
+        context.encode(&mut aad);
+
+        let mut nonce = [0u8; NONCE_LENGTH];
+        match mode {
+            EncryptionMode::Randomized => rand::rng().fill_bytes(&mut nonce),
+            EncryptionMode::Deterministic => {
+                nonce.copy_from_slice(&key.derive_nonce(&aad, plaintext)[..NONCE_LENGTH])
+            }
+        }
+
+        let sealed = key
+            .cipher
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext,
+                    aad: &aad,
+                },
+            )
+            .map_err(|_| invalid_argument("value could not be encrypted"))?;
+        output.extend_from_slice(&nonce);
+        output.extend_from_slice(&sealed);
+        Ok(output)
+    }
+}
+
+impl std::fmt::Debug for EncryptionKeyRing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionKeyRing")
+            .field("primary_key_id", &self.primary_key_id)
+            .field("key_ids", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl EncryptionKey {
+    fn new(key: &[u8; 32]) -> Self {
+        Self {
+            cipher: Aes256Gcm::new(key.into()),
+            nonce_key: hmac_sha256(key, &[NONCE_KEY_CONTEXT]),
+        }
+    }
+
+    fn derive_nonce(&self, aad: &[u8], plaintext: &[u8]) -> Vec<u8> {
+        hmac_sha256(&self.nonce_key, &[aad, plaintext])
+    }
+}
+
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> Vec<u8> {
+    let mut mac = match <Hmac<Sha256> as Mac>::new_from_slice(key) {
+        Ok(mac) => mac,
+        Err(_) => unreachable!("hmac accepts keys of any length"),
+    };
+    for part in parts {
+        mac.update(part);
+    }
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn validate_key_id(key_id: String) -> MomentoResult<String> {
+    if key_id.is_empty() || key_id.len() > MAX_KEY_ID_LENGTH {
+        return Err(invalid_argument(format!(
+            "key id must be between 1 and {MAX_KEY_ID_LENGTH} bytes long"
+        )));
+    }
+    Ok(key_id)
+}
+
+fn invalid_argument(message: impl Into<String>) -> MomentoError {
+    MomentoError {
+        message: message.into(),
+        error_code: MomentoErrorCode::InvalidArgumentError,
+        inner_error: None,
+    }
+}
+
+fn decryption_error(message: impl Into<String>) -> MomentoError {
+    MomentoError {
+        message: message.into(),
+        error_code: MomentoErrorCode::TypeError,
+        inner_error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTEXT: EncryptionContext<'static> = EncryptionContext {
+        cache_name: "cache",
+        key: b"key",
+        field: None,
+    };
+
+    fn key_ring() -> EncryptionKeyRing {
+        EncryptionKeyRing::new("k1", [1u8; 32]).expect("key ring should be valid")
+    }
+
+    #[test]
+    fn test_round_trip() -> MomentoResult<()> {
+        let key_ring = key_ring();
+        for mode in [EncryptionMode::Randomized, EncryptionMode::Deterministic] {
+            let ciphertext = key_ring.encrypt(&CONTEXT, b"taco", mode)?;
+            assert_ne!(ciphertext, b"taco");
+            assert_eq!(&ciphertext[..4], &[HEADER_VERSION, 2, b'k', b'1']);
+            assert_eq!(key_ring.decrypt(&CONTEXT, &ciphertext)?, b"taco");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_randomized_encryption_differs() -> MomentoResult<()> {
+        let key_ring = key_ring();
+        let first = key_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Randomized)?;
+        let second = key_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Randomized)?;
+        assert_ne!(first, second);
+        Ok(())
+    }
+
+    #[test]
+    fn test_deterministic_encryption_matches() -> MomentoResult<()> {
+        let key_ring = key_ring();
+        let first = key_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Deterministic)?;
+        let second = key_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Deterministic)?;
+        let other = key_ring.encrypt(&CONTEXT, b"burrito", EncryptionMode::Deterministic)?;
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotation_keeps_old_values_readable() -> MomentoResult<()> {
+        let old_ring = key_ring();
+        let old_value = old_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Randomized)?;
+
+        let new_ring = old_ring.clone().rotate("k2", [2u8; 32])?;
+        assert_eq!(new_ring.primary_key_id(), "k2");
+        assert_eq!(new_ring.key_ids().collect::<Vec<_>>(), vec!["k1", "k2"]);
+        assert_eq!(new_ring.decrypt(&CONTEXT, &old_value)?, b"taco");
+
+        let new_value = new_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Deterministic)?;
+        assert_eq!(&new_value[..4], &[HEADER_VERSION, 2, b'k', b'2']);
+
+        let all = new_ring.encrypt_with_all_keys(&CONTEXT, b"taco")?;
+        assert_eq!(all.len(), 2);
+        assert!(all.contains(&new_value));
+        assert!(all.contains(&old_ring.encrypt(
+            &CONTEXT,
+            b"taco",
+            EncryptionMode::Deterministic
+        )?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_key_id() -> MomentoResult<()> {
+        let other_ring = EncryptionKeyRing::new("other", [3u8; 32])?;
+        let value = other_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Randomized)?;
+        let error = key_ring()
+            .decrypt(&CONTEXT, &value)
+            .expect_err("key is not in the ring");
+        assert_eq!(error.error_code, MomentoErrorCode::TypeError);
+        assert_eq!(error.message, "key id other is not in the key ring");
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampered_value() -> MomentoResult<()> {
+        let key_ring = key_ring();
+        let mut value = key_ring.encrypt(&CONTEXT, b"taco", EncryptionMode::Randomized)?;
+        let last = value.len() - 1;
+        value[last] ^= 1;
+        assert!(key_ring.decrypt(&CONTEXT, &value).is_err());
+        assert!(key_ring.decrypt(&CONTEXT, b"plaintext").is_err());
+        assert!(key_ring.decrypt(&CONTEXT, &[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_is_bound_to_its_context() -> MomentoResult<()> {
+        let key_ring = key_ring();
+        let other_contexts = [
+            EncryptionContext::item("other-cache", b"key"),
+            EncryptionContext::item("cache", b"other-key"),
+            EncryptionContext::field("cache", b"key", b""),
+            EncryptionContext::item("cach", b"ekey"),
+        ];
+        for mode in [EncryptionMode::Randomized, EncryptionMode::Deterministic] {
+            let value = key_ring.encrypt(&CONTEXT, b"taco", mode)?;
+            for context in &other_contexts {
+                assert!(key_ring.decrypt(context, &value).is_err(), "{:?}", context);
+            }
+        }
+
+        let field = EncryptionContext::field("cache", b"key", b"field");
+        let other_field = EncryptionContext::field("cache", b"key", b"other-field");
+        let value = key_ring.encrypt(&field, b"taco", EncryptionMode::Deterministic)?;
+        assert_eq!(key_ring.decrypt(&field, &value)?, b"taco");
+        assert!(key_ring.decrypt(&other_field, &value).is_err());
+        assert_ne!(
+            value,
+            key_ring.encrypt(&other_field, b"taco", EncryptionMode::Deterministic)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_key_ids() {
+        assert!(EncryptionKeyRing::new("", [0u8; 32]).is_err());
+        assert!(EncryptionKeyRing::new("x".repeat(256), [0u8; 32]).is_err());
+        assert!(key_ring().with_key("k1", [0u8; 32]).is_err());
+    }
+}
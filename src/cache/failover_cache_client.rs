@@ -0,0 +1,797 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+
+use crate::cache::{
+    DecreaseTtlResponse, DeleteResponse, FailoverCacheClientBuilder, GetBatchResponse, GetResponse,
+    GetWithHashResponse, IncreaseTtlResponse, IncrementResponse, ItemGetTtlResponse,
+    ItemGetTypeResponse, KeyExistsResponse, KeysExistResponse, NeedsPrimaryClient,
+    SetBatchResponse, SetIfAbsentOrEqualResponse, SetIfAbsentOrHashEqualResponse,
+    SetIfAbsentResponse, SetIfEqualResponse, SetIfNotEqualResponse,
+    SetIfPresentAndHashEqualResponse, SetIfPresentAndNotEqualResponse, SetIfPresentResponse,
+    SetResponse, UpdateTtlResponse,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{CacheClient, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// How a [FailoverCacheClient] sends writes to its clients.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FailoverWriteMode {
+    /// Write to the first healthy client only, falling back to the secondaries like reads do.
+    #[default]
+    PrimaryOnly,
+    /// Write to every healthy client concurrently and wait for all of them. The response comes
+    /// from the first client in priority order. If any client fails, the first error in priority
+    /// order is returned, even though the write may have been applied in the other regions.
+    ///
+    /// Clients which are currently unhealthy are skipped, so once a region has failed
+    /// [failure_threshold](crate::cache::FailoverCacheClientBuilder::failure_threshold) times in
+    /// a row, writes succeed without it until the
+    /// [fail_back_interval](crate::cache::FailoverCacheClientBuilder::fail_back_interval) has
+    /// passed. When every client is unhealthy, they are all written to.
+    SynchronousDualWrite,
+    /// Write to the first healthy client, then write to the other clients in the background.
+    /// Failures of the background writes are logged and counted against the client's health.
+    AsynchronousDualWrite,
+}
+
+/// A client which spreads requests across caches in multiple regions so that a regional outage
+/// does not take the application down.
+///
+/// A `FailoverCacheClient` is built from a primary [CacheClient] and one or more secondaries,
+/// usually each configured with a [CredentialProvider](crate::CredentialProvider) for a different
+/// region. The same cache name is used with every client.
+///
+/// Reads go to the primary. If it times out or is unavailable, the read is retried against the
+/// secondaries in order. Other errors, such as a missing cache or invalid arguments, are returned
+/// without failing over.
+///
+/// Each client has a health tracker. After
+/// [failure_threshold](crate::cache::FailoverCacheClientBuilder::failure_threshold) consecutive
+/// timeouts or unavailable errors the client is skipped for the
+/// [fail_back_interval](crate::cache::FailoverCacheClientBuilder::fail_back_interval). After
+/// that the next request tries it again, and if it succeeds requests fail back to it. When every
+/// client is unhealthy, they are all tried in order anyway.
+///
+/// Writes follow the [FailoverWriteMode]. Dual writes are independent requests to each region,
+/// so non-idempotent operations like `increment` and the conditional sets may produce
+/// different results in each region.
+///
+/// The scalar operations are provided directly. Any other operation can be sent with
+/// [FailoverCacheClient::read] and [FailoverCacheClient::write].
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::{FailoverCacheClient, FailoverWriteMode};
+/// # let (us_west_client, cache_name) = create_doctest_cache_client();
+/// # let (us_east_client, _) = create_doctest_cache_client();
+///
+/// let cache_client = FailoverCacheClient::builder()
+///     .primary(us_west_client)
+///     .secondary(us_east_client)
+///     .write_mode(FailoverWriteMode::SynchronousDualWrite)
+///     .build();
+///
+/// cache_client.set(&cache_name, "key", "value").await?;
+/// let item: String = cache_client.get(&cache_name, "key").await?.try_into()?;
+/// assert_eq!(item, "value");
+///
+/// // Operations without a shorthand can be sent with `read` and `write`.
+/// let length = cache_client
+///     .read(|client| {
+///         let cache_name = cache_name.clone();
+///         async move { client.list_length(cache_name, "list").await }
+///     })
+///     .await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FailoverCacheClient {
+    endpoints: Arc<Vec<Endpoint>>,
+    write_mode: FailoverWriteMode,
+}
+
+#[derive(Debug)]
+struct Endpoint {
+    client: CacheClient,
+    health: HealthTracker,
+}
+
+impl FailoverCacheClient {
+    /// Constructs a new FailoverCacheClientBuilder.
+    pub fn builder() -> FailoverCacheClientBuilder<NeedsPrimaryClient> {
+        FailoverCacheClientBuilder(NeedsPrimaryClient(()))
+    }
+
+    /// The clients, in priority order. The first client is the primary.
+    pub fn clients(&self) -> impl Iterator<Item = &CacheClient> {
+        self.endpoints.iter().map(|endpoint| &endpoint.client)
+    }
+
+    /// Whether each client is currently considered healthy, in priority order.
+    pub fn health(&self) -> Vec<bool> {
+        self.endpoints
+            .iter()
+            .map(|endpoint| endpoint.health.is_healthy())
+            .collect()
+    }
+
+    /// The [FailoverWriteMode] used for writes.
+    pub fn write_mode(&self) -> FailoverWriteMode {
+        self.write_mode
+    }
+
+    /// Sends a read to the first healthy client, falling back to the other clients if it times
+    /// out or is unavailable.
+    ///
+    /// `operation` is called once per attempt with the client to use.
+    pub async fn read<T, F, Fut>(&self, operation: F) -> MomentoResult<T>
+    where
+        F: Fn(CacheClient) -> Fut,
+        Fut: Future<Output = MomentoResult<T>>,
+    {
+        self.send_with_failover(&operation)
+            .await
+            .map(|(_, response)| response)
+    }
+
+    /// Sends a write according to the [FailoverWriteMode].
+    ///
+    /// `operation` is called once per client written to. With
+    /// [FailoverWriteMode::AsynchronousDualWrite] it may be called after this method returns.
+    pub async fn write<T, F, Fut>(&self, operation: F) -> MomentoResult<T>
+    where
+        F: Fn(CacheClient) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = MomentoResult<T>> + Send + 'static,
+        T: Send + 'static,
+    {
+        match self.write_mode {
+            FailoverWriteMode::PrimaryOnly => self.read(operation).await,
+            FailoverWriteMode::SynchronousDualWrite => self.write_to_all(operation).await,
+            FailoverWriteMode::AsynchronousDualWrite => {
+                let (index, response) = self.send_with_failover(&operation).await?;
+                let operation = Arc::new(operation);
+                for (other, _) in self
+                    .endpoints
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                {
+                    let endpoints = self.endpoints.clone();
+                    let operation = operation.clone();
+                    tokio::spawn(async move {
+                        let endpoint = &endpoints[other];
+                        let result = operation(endpoint.client.clone()).await;
+                        endpoint.record(other, &result);
+                    });
+                }
+                Ok(response)
+            }
+        }
+    }
+
+    /// Calls [CacheClient::get] on the first healthy client.
+    pub async fn get(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.read(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.get(cache_name, key).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::get_with_hash] on the first healthy client.
+    pub async fn get_with_hash(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetWithHashResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.read(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.get_with_hash(cache_name, key).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::get_batch] on the first healthy client.
+    pub async fn get_batch(
+        &self,
+        cache_name: impl Into<String>,
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<GetBatchResponse> {
+        let (cache_name, keys) = (cache_name.into(), keys.into_bytes());
+        self.read(move |client| {
+            let (cache_name, keys) = (cache_name.clone(), keys.clone());
+            async move { client.get_batch(cache_name, keys).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::key_exists] on the first healthy client.
+    pub async fn key_exists(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<KeyExistsResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.read(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.key_exists(cache_name, key).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::keys_exist] on the first healthy client.
+    pub async fn keys_exist(
+        &self,
+        cache_name: impl Into<String>,
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<KeysExistResponse> {
+        let (cache_name, keys) = (cache_name.into(), keys.into_bytes());
+        self.read(move |client| {
+            let (cache_name, keys) = (cache_name.clone(), keys.clone());
+            async move { client.keys_exist(cache_name, keys).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::item_get_type] on the first healthy client.
+    pub async fn item_get_type(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<ItemGetTypeResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.read(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.item_get_type(cache_name, key).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::item_get_ttl] on the first healthy client.
+    pub async fn item_get_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<ItemGetTtlResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.read(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.item_get_ttl(cache_name, key).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set] according to the [FailoverWriteMode].
+    pub async fn set(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetResponse> {
+        let (cache_name, key, value) = (cache_name.into(), key.into_bytes(), value.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key, value) = (cache_name.clone(), key.clone(), value.clone());
+            async move { client.set(cache_name, key, value).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_batch] according to the [FailoverWriteMode].
+    pub async fn set_batch<K: IntoBytes, V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        items: impl IntoIterator<Item = (K, V)>,
+    ) -> MomentoResult<SetBatchResponse> {
+        let cache_name = cache_name.into();
+        let items: Vec<(Vec<u8>, Vec<u8>)> = items
+            .into_iter()
+            .map(|(key, value)| (key.into_bytes(), value.into_bytes()))
+            .collect();
+        self.write(move |client| {
+            let (cache_name, items) = (cache_name.clone(), items.clone());
+            async move { client.set_batch(cache_name, items).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::delete] according to the [FailoverWriteMode].
+    pub async fn delete(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<DeleteResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.delete(cache_name, key).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::increment] according to the [FailoverWriteMode].
+    pub async fn increment(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        amount: i64,
+    ) -> MomentoResult<IncrementResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.increment(cache_name, key, amount).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::update_ttl] according to the [FailoverWriteMode].
+    pub async fn update_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<UpdateTtlResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.update_ttl(cache_name, key, ttl).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::increase_ttl] according to the [FailoverWriteMode].
+    pub async fn increase_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<IncreaseTtlResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.increase_ttl(cache_name, key, ttl).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::decrease_ttl] according to the [FailoverWriteMode].
+    pub async fn decrease_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<DecreaseTtlResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            async move { client.decrease_ttl(cache_name, key, ttl).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_absent] according to the [FailoverWriteMode].
+    pub async fn set_if_absent(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentResponse> {
+        let (cache_name, key, value) = (cache_name.into(), key.into_bytes(), value.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key, value) = (cache_name.clone(), key.clone(), value.clone());
+            async move { client.set_if_absent(cache_name, key, value).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_present] according to the [FailoverWriteMode].
+    pub async fn set_if_present(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentResponse> {
+        let (cache_name, key, value) = (cache_name.into(), key.into_bytes(), value.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key, value) = (cache_name.clone(), key.clone(), value.clone());
+            async move { client.set_if_present(cache_name, key, value).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_equal] according to the [FailoverWriteMode].
+    pub async fn set_if_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfEqualResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        let (value, equal) = (value.into_bytes(), equal.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            let (value, equal) = (value.clone(), equal.clone());
+            async move { client.set_if_equal(cache_name, key, value, equal).await }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_not_equal] according to the [FailoverWriteMode].
+    pub async fn set_if_not_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfNotEqualResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        let (value, not_equal) = (value.into_bytes(), not_equal.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            let (value, not_equal) = (value.clone(), not_equal.clone());
+            async move {
+                client
+                    .set_if_not_equal(cache_name, key, value, not_equal)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_present_and_not_equal] according to the [FailoverWriteMode].
+    pub async fn set_if_present_and_not_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndNotEqualResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        let (value, not_equal) = (value.into_bytes(), not_equal.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            let (value, not_equal) = (value.clone(), not_equal.clone());
+            async move {
+                client
+                    .set_if_present_and_not_equal(cache_name, key, value, not_equal)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_absent_or_equal] according to the [FailoverWriteMode].
+    pub async fn set_if_absent_or_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrEqualResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        let (value, equal) = (value.into_bytes(), equal.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            let (value, equal) = (value.clone(), equal.clone());
+            async move {
+                client
+                    .set_if_absent_or_equal(cache_name, key, value, equal)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_present_and_hash_equal] according to the [FailoverWriteMode].
+    pub async fn set_if_present_and_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndHashEqualResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        let (value, hash_equal) = (value.into_bytes(), hash_equal.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            let (value, hash_equal) = (value.clone(), hash_equal.clone());
+            async move {
+                client
+                    .set_if_present_and_hash_equal(cache_name, key, value, hash_equal)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /// Calls [CacheClient::set_if_absent_or_hash_equal] according to the [FailoverWriteMode].
+    pub async fn set_if_absent_or_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrHashEqualResponse> {
+        let (cache_name, key) = (cache_name.into(), key.into_bytes());
+        let (value, hash_equal) = (value.into_bytes(), hash_equal.into_bytes());
+        self.write(move |client| {
+            let (cache_name, key) = (cache_name.clone(), key.clone());
+            let (value, hash_equal) = (value.clone(), hash_equal.clone());
+            async move {
+                client
+                    .set_if_absent_or_hash_equal(cache_name, key, value, hash_equal)
+                    .await
+            }
+        })
+        .await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(
+        clients: Vec<CacheClient>,
+        write_mode: FailoverWriteMode,
+        failure_threshold: u32,
+        fail_back_interval: Duration,
+    ) -> Self {
+        let endpoints = clients
+            .into_iter()
+            .map(|client| Endpoint {
+                client,
+                health: HealthTracker::new(failure_threshold, fail_back_interval),
+            })
+            .collect();
+        Self {
+            endpoints: Arc::new(endpoints),
+            write_mode,
+        }
+    }
+
+    /// The indexes of the clients to try, in order: healthy clients first, then unhealthy ones.
+    fn candidates(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let (mut healthy, unhealthy): (Vec<usize>, Vec<usize>) = (0..self.endpoints.len())
+            .partition(|&index| self.endpoints[index].health.is_healthy_at(now));
+        healthy.extend(unhealthy);
+        healthy
+    }
+
+    /// Try each candidate in turn until one succeeds or returns an error which should not fail
+    /// over. Returns the index of the client which produced the response.
+    async fn send_with_failover<T, F, Fut>(&self, operation: &F) -> MomentoResult<(usize, T)>
+    where
+        F: Fn(CacheClient) -> Fut,
+        Fut: Future<Output = MomentoResult<T>>,
+    {
+        let mut last_error = None;
+        for index in self.candidates() {
+            let endpoint = &self.endpoints[index];
+            let result = operation(endpoint.client.clone()).await;
+            endpoint.record(index, &result);
+            match result {
+                Ok(response) => return Ok((index, response)),
+                Err(error) if should_fail_over(&error) => last_error = Some(error),
+                Err(error) => return Err(error),
+            }
+        }
+        Err(last_error.unwrap_or_else(no_clients_error))
+    }
+
+    /// The indices of the healthy clients, or of every client if none are healthy.
+    fn healthy_candidates(&self) -> Vec<usize> {
+        let now = Instant::now();
+        let healthy: Vec<usize> = (0..self.endpoints.len())
+            .filter(|&index| self.endpoints[index].health.is_healthy_at(now))
+            .collect();
+        if healthy.is_empty() {
+            (0..self.endpoints.len()).collect()
+        } else {
+            healthy
+        }
+    }
+
+    /// Send the operation to every healthy client, failing unless all of them succeed.
+    async fn write_to_all<T, F, Fut>(&self, operation: F) -> MomentoResult<T>
+    where
+        F: Fn(CacheClient) -> Fut,
+        Fut: Future<Output = MomentoResult<T>>,
+    {
+        let candidates = self.healthy_candidates();
+        let results = join_all(
+            candidates
+                .iter()
+                .map(|&index| operation(self.endpoints[index].client.clone())),
+        )
+        .await;
+
+        let mut first_error = None;
+        let mut first_response = None;
+        for (index, result) in candidates.into_iter().zip(results) {
+            self.endpoints[index].record(index, &result);
+            match result {
+                Ok(response) => {
+                    if first_response.is_none() {
+                        first_response = Some(response);
+                    }
+                }
+                Err(error) => {
+                    if first_error.is_none() {
+                        first_error = Some(error);
+                    }
+                }
+            }
+        }
+        match first_error {
+            Some(error) => Err(error),
+            None => first_response.ok_or_else(no_clients_error),
+        }
+    }
+}
+
+impl Endpoint {
+    fn record<T>(&self, index: usize, result: &MomentoResult<T>) {
+        match result {
+            Ok(_) => self.health.record_success(),
+            Err(error) => {
+                log::warn!("failover cache client {index} returned an error: {error:?}");
+                if should_fail_over(error) {
+                    self.health.record_failure();
+                }
+            }
+        }
+    }
+}
+
+fn should_fail_over(error: &MomentoError) -> bool {
+    matches!(
+        error.error_code,
+        MomentoErrorCode::TimeoutError
+            | MomentoErrorCode::ServerUnavailable
+            | MomentoErrorCode::InternalServerError
+    )
+}
+
+fn no_clients_error() -> MomentoError {
+    MomentoError {
+        message: "failover cache client has no clients".into(),
+        error_code: MomentoErrorCode::InvalidArgumentError,
+        inner_error: None,
+    }
+}
+
+/// Tracks consecutive failures of a client and when it may be tried again.
+#[derive(Debug)]
+struct HealthTracker {
+    failure_threshold: u32,
+    fail_back_interval: Duration,
+    consecutive_failures: AtomicU32,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl HealthTracker {
+    fn new(failure_threshold: u32, fail_back_interval: Duration) -> Self {
+        Self {
+            failure_threshold,
+            fail_back_interval,
+            consecutive_failures: AtomicU32::new(0),
+            unhealthy_until: Mutex::new(None),
+        }
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.is_healthy_at(Instant::now())
+    }
+
+    fn is_healthy_at(&self, now: Instant) -> bool {
+        match *self.lock() {
+            Some(until) => now >= until,
+            None => true,
+        }
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+        *self.lock() = None;
+    }
+
+    fn record_failure(&self) {
+        self.record_failure_at(Instant::now())
+    }
+
+    fn record_failure_at(&self, now: Instant) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if failures >= self.failure_threshold {
+            *self.lock() = Some(now + self.fail_back_interval);
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<Instant>> {
+        // The guarded value is always valid, so a poisoned lock can be recovered.
+        self.unhealthy_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unhealthy_after_threshold() {
+        let tracker = HealthTracker::new(2, Duration::from_secs(30));
+        let now = Instant::now();
+
+        tracker.record_failure_at(now);
+        assert!(tracker.is_healthy_at(now));
+
+        tracker.record_failure_at(now);
+        assert!(!tracker.is_healthy_at(now));
+        assert!(!tracker.is_healthy_at(now + Duration::from_secs(29)));
+    }
+
+    #[test]
+    fn test_retried_after_fail_back_interval() {
+        let tracker = HealthTracker::new(1, Duration::from_secs(30));
+        let now = Instant::now();
+
+        tracker.record_failure_at(now);
+        assert!(!tracker.is_healthy_at(now));
+        assert!(tracker.is_healthy_at(now + Duration::from_secs(30)));
+
+        // A failed retry keeps the client unhealthy for another interval.
+        let retry = now + Duration::from_secs(30);
+        tracker.record_failure_at(retry);
+        assert!(!tracker.is_healthy_at(retry + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_success_resets_failures() {
+        let tracker = HealthTracker::new(2, Duration::from_secs(30));
+        let now = Instant::now();
+
+        tracker.record_failure_at(now);
+        tracker.record_success();
+        tracker.record_failure_at(now);
+        assert!(tracker.is_healthy_at(now));
+
+        tracker.record_failure_at(now);
+        assert!(!tracker.is_healthy_at(now));
+        tracker.record_success();
+        assert!(tracker.is_healthy_at(now));
+    }
+
+    #[test]
+    fn test_should_fail_over() {
+        let error = |error_code| MomentoError {
+            message: "error".into(),
+            error_code,
+            inner_error: None,
+        };
+        assert!(should_fail_over(&error(MomentoErrorCode::TimeoutError)));
+        assert!(should_fail_over(&error(
+            MomentoErrorCode::ServerUnavailable
+        )));
+        assert!(!should_fail_over(&error(
+            MomentoErrorCode::CacheNotFoundError
+        )));
+        assert!(!should_fail_over(&error(
+            MomentoErrorCode::InvalidArgumentError
+        )));
+    }
+}
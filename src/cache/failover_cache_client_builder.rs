@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use crate::cache::{FailoverCacheClient, FailoverWriteMode};
+use crate::CacheClient;
+
+/// The initial state of the FailoverCacheClientBuilder.
+#[derive(Clone, Debug)]
+pub struct FailoverCacheClientBuilder<State>(pub State);
+
+/// The state of the FailoverCacheClientBuilder when it is waiting for a primary client.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct NeedsPrimaryClient(pub ());
+
+/// The state of the FailoverCacheClientBuilder when it is waiting for a secondary client.
+#[derive(Clone, Debug)]
+pub struct NeedsSecondaryClient {
+    primary: CacheClient,
+}
+
+/// The state of the FailoverCacheClientBuilder when it is ready to build a FailoverCacheClient.
+#[derive(Clone, Debug)]
+pub struct ReadyToBuildFailover {
+    clients: Vec<CacheClient>,
+    write_mode: FailoverWriteMode,
+    failure_threshold: u32,
+    fail_back_interval: Duration,
+}
+
+impl FailoverCacheClientBuilder<NeedsPrimaryClient> {
+    /// Sets the client which receives requests while it is healthy.
+    pub fn primary(self, primary: CacheClient) -> FailoverCacheClientBuilder<NeedsSecondaryClient> {
+        FailoverCacheClientBuilder(NeedsSecondaryClient { primary })
+    }
+}
+
+impl FailoverCacheClientBuilder<NeedsSecondaryClient> {
+    /// Adds a client which receives requests when the primary is unavailable.
+    pub fn secondary(
+        self,
+        secondary: CacheClient,
+    ) -> FailoverCacheClientBuilder<ReadyToBuildFailover> {
+        FailoverCacheClientBuilder(ReadyToBuildFailover {
+            clients: vec![self.0.primary, secondary],
+            write_mode: FailoverWriteMode::default(),
+            failure_threshold: 3,
+            fail_back_interval: Duration::from_secs(30),
+        })
+    }
+}
+
+impl FailoverCacheClientBuilder<ReadyToBuildFailover> {
+    /// Adds another secondary client. Secondaries are tried in the order they were added.
+    pub fn secondary(mut self, secondary: CacheClient) -> Self {
+        self.0.clients.push(secondary);
+        self
+    }
+
+    /// Sets how writes are sent to the clients. Defaults to [FailoverWriteMode::PrimaryOnly].
+    pub fn write_mode(mut self, write_mode: FailoverWriteMode) -> Self {
+        self.0.write_mode = write_mode;
+        self
+    }
+
+    /// Sets the number of consecutive timeouts or unavailable errors after which a client is
+    /// considered unhealthy and skipped. Defaults to 3.
+    pub fn failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.0.failure_threshold = failure_threshold.max(1);
+        self
+    }
+
+    /// Sets how long an unhealthy client is skipped before it is tried again. If that request
+    /// succeeds the client is healthy again, so requests fail back to the primary. Defaults to 30
+    /// seconds.
+    pub fn fail_back_interval(mut self, fail_back_interval: Duration) -> Self {
+        self.0.fail_back_interval = fail_back_interval;
+        self
+    }
+
+    /// Constructs the FailoverCacheClient.
+    pub fn build(self) -> FailoverCacheClient {
+        FailoverCacheClient::new(
+            self.0.clients,
+            self.0.write_mode,
+            self.0.failure_threshold,
+            self.0.fail_back_interval,
+        )
+    }
+}
@@ -0,0 +1,823 @@
+use std::convert::{TryFrom, TryInto};
+use std::error::Error as StdError;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use bytes::Bytes;
+use http::header::{self, HeaderMap, HeaderName, HeaderValue};
+use http::{Method, Request, Response, StatusCode, Uri, Version};
+use http_body::Body;
+use http_body_util::{BodyExt, Either, Full};
+use tower::{Layer, Service, ServiceExt};
+
+use rand::Rng;
+
+use crate::cache::{
+    GetResponse, MomentoRequest, SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse,
+    SetIfEqualRequest, SetRequest,
+};
+use crate::CacheClient;
+
+type BoxError = Box<dyn StdError + Send + Sync>;
+
+/// The body of a response from [HttpCache]: the buffered body of a response which was read from
+/// or written to the cache, or the inner service's body otherwise.
+pub type HttpCacheBody<B> = Either<Full<Bytes>, B>;
+
+/// How often a request waiting for another request to fill the same entry checks the cache.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// The value a lock is overwritten with when its holder releases it. Momento has no conditional
+/// delete, so a released lock is marked rather than deleted and can be taken again.
+const RELEASED_LOCK: &str = "released";
+
+/// A [tower::Layer] which caches HTTP responses in a Momento cache.
+///
+/// Only `GET` and `HEAD` requests without an `Authorization` header are cached. Entries are keyed
+/// by method, URI and the values of the request headers selected with
+/// [HttpCacheLayer::vary_header]. The status, headers and body of a response are stored if:
+///
+/// * the status is cacheable by default, such as `200 OK` or `404 Not Found`,
+/// * its `Cache-Control` header does not contain `no-store`, `no-cache` or `private`,
+/// * it has no `Set-Cookie` header,
+/// * every header named by its `Vary` header was selected with [HttpCacheLayer::vary_header],
+/// * and it has a freshness lifetime: `s-maxage` or `max-age`, falling back to
+///   [HttpCacheLayer::ttl].
+///
+/// A response with `stale-while-revalidate` is served from the cache for that long after it
+/// becomes stale while a single background request refreshes it.
+///
+/// When a request misses, it takes a short-lived lock on the entry with a random token before
+/// calling the inner service. The lock is only released if it still holds that token, so a slow
+/// request whose lock expired cannot release a lock another request has since taken. Concurrent
+/// requests for the same entry wait for the lock holder to fill it instead of all calling the inner
+/// service, up to [HttpCacheLayer::lock_timeout].
+///
+/// Requests with `Cache-Control: no-store` bypass the cache and requests with
+/// `Cache-Control: no-cache` skip the lookup. Failing to read from or write to the cache never
+/// fails a request.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use bytes::Bytes;
+/// use http::{header, Request, Response};
+/// use http_body_util::Full;
+/// use momento::cache::HttpCacheLayer;
+/// use tower::{service_fn, ServiceBuilder, ServiceExt};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let service = ServiceBuilder::new()
+///     .layer(
+///         HttpCacheLayer::new(cache_client, &cache_name)
+///             .ttl(Duration::from_secs(60))
+///             .vary_header(header::ACCEPT_LANGUAGE),
+///     )
+///     .service(service_fn(|_request: Request<()>| async {
+///         Ok::<_, std::convert::Infallible>(Response::new(Full::new(Bytes::from("hello"))))
+///     }));
+///
+/// let response = service
+///     .oneshot(Request::get("/greeting").body(())?)
+///     .await
+///     .map_err(|err| anyhow::anyhow!(err))?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HttpCacheLayer {
+    client: CacheClient,
+    cache_name: String,
+    policy: CachePolicy,
+    max_body_size: usize,
+    lock_timeout: Duration,
+}
+
+impl HttpCacheLayer {
+    /// Constructs a layer which caches responses in the given cache.
+    pub fn new(client: CacheClient, cache_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            cache_name: cache_name.into(),
+            policy: CachePolicy::default(),
+            max_body_size: 1024 * 1024,
+            lock_timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// Sets the prefix of the keys responses are stored under. Defaults to `http:`.
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.policy.key_prefix = key_prefix.into();
+        self
+    }
+
+    /// Sets how long responses without `s-maxage` or `max-age` are cached. By default such
+    /// responses are not cached.
+    pub fn ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.policy.ttl = ttl.into();
+        self
+    }
+
+    /// Includes the value of a request header in the cache key, so responses which `Vary` on it
+    /// can be cached.
+    pub fn vary_header(mut self, name: HeaderName) -> Self {
+        self.policy.vary_headers.push(name);
+        self
+    }
+
+    /// Sets the largest body which is cached. Defaults to 1 MiB.
+    pub fn max_body_size(mut self, max_body_size: usize) -> Self {
+        self.max_body_size = max_body_size;
+        self
+    }
+
+    /// Sets how long a request waits for a concurrent request to fill the same entry before
+    /// calling the inner service itself. This is also the TTL of the lock. Defaults to 5 seconds.
+    pub fn lock_timeout(mut self, lock_timeout: Duration) -> Self {
+        self.lock_timeout = lock_timeout;
+        self
+    }
+
+    async fn lookup(&self, key: &str) -> Option<CachedResponse> {
+        match self.client.get(&self.cache_name, key).await {
+            Ok(GetResponse::Hit { value }) => {
+                let cached = CachedResponse::decode(&value.raw_item);
+                if cached.is_none() {
+                    log::debug!("ignoring cached response for {key} which failed to decode");
+                }
+                cached
+            }
+            Ok(GetResponse::Miss) => None,
+            Err(error) => {
+                log::debug!("failed to read cached response for {key}: {error}");
+                None
+            }
+        }
+    }
+
+    async fn store(&self, key: &str, cached: &CachedResponse) {
+        let result = SetRequest::new(&self.cache_name, key, cached.encode())
+            .ttl(cached.fresh_for + cached.stale_for)
+            .send(&self.client)
+            .await;
+        if let Err(error) = result {
+            log::debug!("failed to cache response for {key}: {error}");
+        }
+    }
+
+    /// Returns the lock's token if this request should fill the entry, or `None` if another
+    /// request holds the lock. If the cache cannot be reached the request fills it without
+    /// coordinating with others.
+    async fn try_lock(&self, lock_key: &str) -> Option<String> {
+        let token = format!("{:032x}", rand::rng().random::<u128>());
+        let result = SetIfAbsentOrEqualRequest::new(
+            &self.cache_name,
+            lock_key,
+            token.as_str(),
+            RELEASED_LOCK,
+        )
+        .ttl(self.lock_timeout)
+        .send(&self.client)
+        .await;
+        match result {
+            Ok(SetIfAbsentOrEqualResponse::Stored) => Some(token),
+            Ok(SetIfAbsentOrEqualResponse::NotStored) => None,
+            Err(error) => {
+                log::debug!("failed to take lock {lock_key}: {error}");
+                Some(token)
+            }
+        }
+    }
+
+    /// Releases the lock if it is still held with `token`. A lock which expired and was taken by
+    /// another request is left alone.
+    async fn unlock(&self, lock_key: &str, token: &str) {
+        let result = SetIfEqualRequest::new(&self.cache_name, lock_key, RELEASED_LOCK, token)
+            .ttl(self.lock_timeout)
+            .send(&self.client)
+            .await;
+        if let Err(error) = result {
+            log::debug!("failed to release lock {lock_key}: {error}");
+        }
+    }
+
+    async fn is_locked(&self, lock_key: &str) -> bool {
+        match self.client.get(&self.cache_name, lock_key).await {
+            Ok(GetResponse::Hit { value }) => value.raw_item != RELEASED_LOCK.as_bytes(),
+            _ => false,
+        }
+    }
+
+    async fn handle<S, ReqBody, ResBody>(
+        self: Arc<Self>,
+        inner: S,
+        request: Request<ReqBody>,
+    ) -> Result<Response<HttpCacheBody<ResBody>>, BoxError>
+    where
+        S: Service<Request<ReqBody>, Response = Response<ResBody>> + Send + 'static,
+        S::Error: Into<BoxError>,
+        S::Future: Send,
+        ReqBody: Default + Send + 'static,
+        ResBody: Body<Data = Bytes> + Send + 'static,
+        ResBody::Error: Into<BoxError>,
+    {
+        let cache_control = CacheControl::from_headers(request.headers());
+        if !is_cacheable_request(&request) || cache_control.no_store {
+            return pass_through(inner, request).await;
+        }
+
+        let key = self.policy.key(&request);
+        if !cache_control.no_cache {
+            if let Some(cached) = self.lookup(&key).await {
+                let age = cached.age();
+                if age < cached.fresh_for {
+                    return Ok(cached.into_response(age));
+                }
+                if age < cached.fresh_for + cached.stale_for {
+                    let head = RequestHead::from(&request);
+                    tokio::spawn(self.clone().revalidate(inner, key, head));
+                    return Ok(cached.into_response(age));
+                }
+            }
+        }
+        self.fill(inner, key, request).await
+    }
+
+    async fn fill<S, ReqBody, ResBody>(
+        &self,
+        inner: S,
+        key: String,
+        request: Request<ReqBody>,
+    ) -> Result<Response<HttpCacheBody<ResBody>>, BoxError>
+    where
+        S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+        S::Error: Into<BoxError>,
+        ResBody: Body<Data = Bytes>,
+        ResBody::Error: Into<BoxError>,
+    {
+        let lock_key = format!("{key}:lock");
+        if let Some(token) = self.try_lock(&lock_key).await {
+            let result = self.fetch_and_store(inner, &key, request).await;
+            self.unlock(&lock_key, &token).await;
+            return result;
+        }
+
+        // Another request is filling this entry; wait for it rather than calling the inner
+        // service as well. Stop waiting if it releases the lock without storing a response.
+        let deadline = Instant::now() + self.lock_timeout;
+        while Instant::now() < deadline {
+            tokio::time::sleep(LOCK_POLL_INTERVAL).await;
+            // Check the lock first: the lock holder stores the response before releasing it.
+            let locked = self.is_locked(&lock_key).await;
+            if let Some(cached) = self.lookup(&key).await {
+                let age = cached.age();
+                if age < cached.fresh_for {
+                    return Ok(cached.into_response(age));
+                }
+            }
+            if !locked {
+                break;
+            }
+        }
+        pass_through(inner, request).await
+    }
+
+    async fn revalidate<S, ReqBody, ResBody>(
+        self: Arc<Self>,
+        inner: S,
+        key: String,
+        head: RequestHead,
+    ) where
+        S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+        S::Error: Into<BoxError>,
+        ReqBody: Default,
+        ResBody: Body<Data = Bytes>,
+        ResBody::Error: Into<BoxError>,
+    {
+        let lock_key = format!("{key}:lock");
+        let token = match self.try_lock(&lock_key).await {
+            Some(token) => token,
+            None => return,
+        };
+        if let Err(error) = self.fetch_and_store(inner, &key, head.into_request()).await {
+            log::debug!("failed to revalidate cached response for {key}: {error}");
+        }
+        self.unlock(&lock_key, &token).await;
+    }
+
+    async fn fetch_and_store<S, ReqBody, ResBody>(
+        &self,
+        inner: S,
+        key: &str,
+        request: Request<ReqBody>,
+    ) -> Result<Response<HttpCacheBody<ResBody>>, BoxError>
+    where
+        S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+        S::Error: Into<BoxError>,
+        ResBody: Body<Data = Bytes>,
+        ResBody::Error: Into<BoxError>,
+    {
+        let response = inner.oneshot(request).await.map_err(Into::into)?;
+        let (fresh_for, stale_for) = match self.policy.storable(&response) {
+            Some(lifetime) => lifetime,
+            None => return Ok(response.map(Either::Right)),
+        };
+        let too_large = response
+            .body()
+            .size_hint()
+            .exact()
+            .and_then(|size| usize::try_from(size).ok())
+            .is_some_and(|size| size > self.max_body_size);
+        if too_large {
+            return Ok(response.map(Either::Right));
+        }
+
+        let (parts, body) = response.into_parts();
+        let body = body.collect().await.map_err(Into::into)?.to_bytes();
+        if body.len() <= self.max_body_size {
+            let mut headers = parts.headers.clone();
+            headers.remove(header::AGE);
+            let cached = CachedResponse {
+                status: parts.status,
+                headers,
+                body: body.clone(),
+                stored_at: unix_time(),
+                fresh_for,
+                stale_for,
+            };
+            self.store(key, &cached).await;
+        }
+        Ok(Response::from_parts(parts, Either::Left(Full::new(body))))
+    }
+}
+
+impl<S> Layer<S> for HttpCacheLayer {
+    type Service = HttpCache<S>;
+
+    fn layer(&self, inner: S) -> HttpCache<S> {
+        HttpCache {
+            inner,
+            config: Arc::new(self.clone()),
+        }
+    }
+}
+
+/// A [tower::Service] which caches HTTP responses from the inner service in Momento. See
+/// [HttpCacheLayer].
+#[derive(Clone, Debug)]
+pub struct HttpCache<S> {
+    inner: S,
+    config: Arc<HttpCacheLayer>,
+}
+
+impl<S> HttpCache<S> {
+    /// Wraps `inner` with the cache configured by `layer`.
+    pub fn new(inner: S, layer: HttpCacheLayer) -> Self {
+        layer.layer(inner)
+    }
+
+    /// The wrapped service.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for HttpCache<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Error: Into<BoxError>,
+    S::Future: Send,
+    ReqBody: Default + Send + 'static,
+    ResBody: Body<Data = Bytes> + Send + 'static,
+    ResBody::Error: Into<BoxError>,
+{
+    type Response = Response<HttpCacheBody<ResBody>>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+        self.inner.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        // Take the instance which was polled ready and leave a clone in its place.
+        let clone = self.inner.clone();
+        let inner = std::mem::replace(&mut self.inner, clone);
+        Box::pin(self.config.clone().handle(inner, request))
+    }
+}
+
+async fn pass_through<S, ReqBody, ResBody>(
+    inner: S,
+    request: Request<ReqBody>,
+) -> Result<Response<HttpCacheBody<ResBody>>, BoxError>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Error: Into<BoxError>,
+{
+    let response = inner.oneshot(request).await.map_err(Into::into)?;
+    Ok(response.map(Either::Right))
+}
+
+fn is_cacheable_request<B>(request: &Request<B>) -> bool {
+    (request.method() == Method::GET || request.method() == Method::HEAD)
+        && !request.headers().contains_key(header::AUTHORIZATION)
+}
+
+/// Statuses which may be cached without explicit freshness information, per RFC 9110.
+fn is_cacheable_status(status: StatusCode) -> bool {
+    matches!(
+        status.as_u16(),
+        200 | 203 | 204 | 206 | 300 | 301 | 308 | 404 | 405 | 410 | 414 | 501
+    )
+}
+
+fn unix_time() -> Duration {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+}
+
+/// The parts of a request needed to repeat it for revalidation.
+struct RequestHead {
+    method: Method,
+    uri: Uri,
+    version: Version,
+    headers: HeaderMap,
+}
+
+impl RequestHead {
+    fn into_request<B: Default>(self) -> Request<B> {
+        let mut request = Request::new(B::default());
+        *request.method_mut() = self.method;
+        *request.uri_mut() = self.uri;
+        *request.version_mut() = self.version;
+        *request.headers_mut() = self.headers;
+        request
+    }
+}
+
+impl<B> From<&Request<B>> for RequestHead {
+    fn from(request: &Request<B>) -> Self {
+        Self {
+            method: request.method().clone(),
+            uri: request.uri().clone(),
+            version: request.version(),
+            headers: request.headers().clone(),
+        }
+    }
+}
+
+/// Decides which responses are cached, for how long and under which key.
+#[derive(Clone, Debug)]
+struct CachePolicy {
+    key_prefix: String,
+    ttl: Option<Duration>,
+    vary_headers: Vec<HeaderName>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            key_prefix: "http:".to_string(),
+            ttl: None,
+            vary_headers: Vec::new(),
+        }
+    }
+}
+
+impl CachePolicy {
+    fn key<B>(&self, request: &Request<B>) -> String {
+        let mut key = format!("{}{} {}", self.key_prefix, request.method(), request.uri());
+        for name in &self.vary_headers {
+            key.push('\n');
+            key.push_str(name.as_str());
+            key.push(':');
+            let values: Vec<_> = request
+                .headers()
+                .get_all(name)
+                .iter()
+                .map(|value| String::from_utf8_lossy(value.as_bytes()))
+                .collect();
+            key.push_str(&values.join(","));
+        }
+        key
+    }
+
+    /// How long a response is fresh and then stale-but-usable for, or `None` if it must not be
+    /// stored.
+    fn storable<B>(&self, response: &Response<B>) -> Option<(Duration, Duration)> {
+        if !is_cacheable_status(response.status())
+            || response.headers().contains_key(header::SET_COOKIE)
+        {
+            return None;
+        }
+        let cache_control = CacheControl::from_headers(response.headers());
+        if cache_control.no_store || cache_control.no_cache || cache_control.private {
+            return None;
+        }
+        for value in response.headers().get_all(header::VARY) {
+            for name in value.to_str().ok()?.split(',').map(str::trim) {
+                if name == "*"
+                    || !self
+                        .vary_headers
+                        .iter()
+                        .any(|selected| selected.as_str().eq_ignore_ascii_case(name))
+                {
+                    return None;
+                }
+            }
+        }
+        let fresh_for = cache_control
+            .s_maxage
+            .or(cache_control.max_age)
+            .map(Duration::from_secs)
+            .or(self.ttl)
+            .filter(|fresh_for| !fresh_for.is_zero())?;
+        let stale_for = Duration::from_secs(cache_control.stale_while_revalidate.unwrap_or(0));
+        Some((fresh_for, stale_for))
+    }
+}
+
+/// The `Cache-Control` directives the cache acts on.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    private: bool,
+    max_age: Option<u64>,
+    s_maxage: Option<u64>,
+    stale_while_revalidate: Option<u64>,
+}
+
+impl CacheControl {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let mut cache_control = Self::default();
+        for value in headers.get_all(header::CACHE_CONTROL) {
+            let value = match value.to_str() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+            for directive in value.split(',') {
+                let mut parts = directive.splitn(2, '=');
+                let name = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+                let seconds = parts
+                    .next()
+                    .and_then(|argument| argument.trim().trim_matches('"').parse().ok());
+                match name.as_str() {
+                    "no-store" => cache_control.no_store = true,
+                    "no-cache" => cache_control.no_cache = true,
+                    "private" => cache_control.private = true,
+                    "max-age" => cache_control.max_age = seconds,
+                    "s-maxage" => cache_control.s_maxage = seconds,
+                    "stale-while-revalidate" => cache_control.stale_while_revalidate = seconds,
+                    _ => {}
+                }
+            }
+        }
+        cache_control
+    }
+}
+
+/// A response as stored in the cache.
+#[derive(Debug, PartialEq)]
+struct CachedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    body: Bytes,
+    /// Time since the unix epoch at which the response was stored.
+    stored_at: Duration,
+    fresh_for: Duration,
+    stale_for: Duration,
+}
+
+impl CachedResponse {
+    const FORMAT_VERSION: u8 = 1;
+
+    fn age(&self) -> Duration {
+        unix_time().saturating_sub(self.stored_at)
+    }
+
+    fn into_response<B>(self, age: Duration) -> Response<HttpCacheBody<B>> {
+        let mut response = Response::new(Either::Left(Full::new(self.body)));
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response
+            .headers_mut()
+            .insert(header::AGE, HeaderValue::from(age.as_secs()));
+        response
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(self.body.len() + 64);
+        encoded.push(Self::FORMAT_VERSION);
+        encoded.extend_from_slice(&self.status.as_u16().to_be_bytes());
+        for duration in [self.stored_at, self.fresh_for, self.stale_for] {
+            encoded.extend_from_slice(&(duration.as_millis() as u64).to_be_bytes());
+        }
+        encoded.extend_from_slice(&(self.headers.len() as u32).to_be_bytes());
+        for (name, value) in &self.headers {
+            for bytes in [name.as_str().as_bytes(), value.as_bytes()] {
+                encoded.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+                encoded.extend_from_slice(bytes);
+            }
+        }
+        encoded.extend_from_slice(&self.body);
+        encoded
+    }
+
+    fn decode(mut encoded: &[u8]) -> Option<Self> {
+        fn take<'a>(encoded: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+            if encoded.len() < len {
+                return None;
+            }
+            let (taken, rest) = encoded.split_at(len);
+            *encoded = rest;
+            Some(taken)
+        }
+        fn take_u32(encoded: &mut &[u8]) -> Option<u32> {
+            Some(u32::from_be_bytes(take(encoded, 4)?.try_into().ok()?))
+        }
+        fn take_millis(encoded: &mut &[u8]) -> Option<Duration> {
+            let millis = u64::from_be_bytes(take(encoded, 8)?.try_into().ok()?);
+            Some(Duration::from_millis(millis))
+        }
+
+        if take(&mut encoded, 1)? != [Self::FORMAT_VERSION] {
+            return None;
+        }
+        let status = u16::from_be_bytes(take(&mut encoded, 2)?.try_into().ok()?);
+        let status = StatusCode::from_u16(status).ok()?;
+        let stored_at = take_millis(&mut encoded)?;
+        let fresh_for = take_millis(&mut encoded)?;
+        let stale_for = take_millis(&mut encoded)?;
+        let header_count = take_u32(&mut encoded)?;
+        let mut headers = HeaderMap::new();
+        for _ in 0..header_count {
+            let len = take_u32(&mut encoded)? as usize;
+            let name = HeaderName::from_bytes(take(&mut encoded, len)?).ok()?;
+            let len = take_u32(&mut encoded)? as usize;
+            let value = HeaderValue::from_bytes(take(&mut encoded, len)?).ok()?;
+            headers.append(name, value);
+        }
+        Some(Self {
+            status,
+            headers,
+            body: Bytes::copy_from_slice(encoded),
+            stored_at,
+            fresh_for,
+            stale_for,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(pairs: &[(HeaderName, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.append(name.clone(), HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    fn response(pairs: &[(HeaderName, &'static str)]) -> Response<()> {
+        let mut response = Response::new(());
+        *response.headers_mut() = headers(pairs);
+        response
+    }
+
+    #[test]
+    fn parses_cache_control() {
+        let cache_control = CacheControl::from_headers(&headers(&[
+            (header::CACHE_CONTROL, "public, max-age=60"),
+            (
+                header::CACHE_CONTROL,
+                "S-MaxAge=\"120\", stale-while-revalidate=30",
+            ),
+        ]));
+        assert_eq!(
+            cache_control,
+            CacheControl {
+                max_age: Some(60),
+                s_maxage: Some(120),
+                stale_while_revalidate: Some(30),
+                ..Default::default()
+            }
+        );
+
+        let cache_control =
+            CacheControl::from_headers(&headers(&[(header::CACHE_CONTROL, "no-store, private")]));
+        assert!(cache_control.no_store && cache_control.private && !cache_control.no_cache);
+    }
+
+    #[test]
+    fn storable_uses_shared_max_age_then_max_age_then_ttl() {
+        let policy = CachePolicy {
+            ttl: Some(Duration::from_secs(5)),
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.storable(&response(&[(
+                header::CACHE_CONTROL,
+                "max-age=10, s-maxage=20, stale-while-revalidate=3"
+            )])),
+            Some((Duration::from_secs(20), Duration::from_secs(3)))
+        );
+        assert_eq!(
+            policy.storable(&response(&[(header::CACHE_CONTROL, "max-age=10")])),
+            Some((Duration::from_secs(10), Duration::ZERO))
+        );
+        assert_eq!(
+            policy.storable(&response(&[])),
+            Some((Duration::from_secs(5), Duration::ZERO))
+        );
+        assert_eq!(CachePolicy::default().storable(&response(&[])), None);
+    }
+
+    #[test]
+    fn storable_rejects_uncacheable_responses() {
+        let policy = CachePolicy {
+            ttl: Some(Duration::from_secs(5)),
+            vary_headers: vec![header::ACCEPT_ENCODING],
+            ..Default::default()
+        };
+        for pairs in [
+            &[(header::CACHE_CONTROL, "no-store")][..],
+            &[(header::CACHE_CONTROL, "no-cache")],
+            &[(header::CACHE_CONTROL, "private, max-age=60")],
+            &[(header::CACHE_CONTROL, "max-age=0")],
+            &[(header::SET_COOKIE, "session=1")],
+            &[(header::VARY, "*")],
+            &[(header::VARY, "accept-encoding, cookie")],
+        ] {
+            assert_eq!(policy.storable(&response(pairs)), None, "{pairs:?}");
+        }
+        assert!(policy
+            .storable(&response(&[(header::VARY, "Accept-Encoding")]))
+            .is_some());
+
+        let mut error = response(&[]);
+        *error.status_mut() = StatusCode::INTERNAL_SERVER_ERROR;
+        assert_eq!(policy.storable(&error), None);
+    }
+
+    #[test]
+    fn key_includes_method_uri_and_selected_headers() {
+        let policy = CachePolicy {
+            vary_headers: vec![header::ACCEPT_LANGUAGE],
+            ..Default::default()
+        };
+        let request = |method: Method, language: &'static str| {
+            let mut request = Request::new(());
+            *request.method_mut() = method;
+            *request.uri_mut() = Uri::from_static("/path?query=1");
+            *request.headers_mut() = headers(&[
+                (header::ACCEPT_LANGUAGE, language),
+                (header::USER_AGENT, "test"),
+            ]);
+            request
+        };
+        assert_eq!(
+            policy.key(&request(Method::GET, "en")),
+            "http:GET /path?query=1\naccept-language:en"
+        );
+        assert_ne!(
+            policy.key(&request(Method::GET, "en")),
+            policy.key(&request(Method::GET, "fr"))
+        );
+        assert_ne!(
+            policy.key(&request(Method::GET, "en")),
+            policy.key(&request(Method::HEAD, "en"))
+        );
+    }
+
+    #[test]
+    fn cached_response_round_trips() {
+        let cached = CachedResponse {
+            status: StatusCode::NOT_FOUND,
+            headers: headers(&[
+                (header::CONTENT_TYPE, "text/plain"),
+                (header::LINK, "</a>"),
+                (header::LINK, "</b>"),
+            ]),
+            body: Bytes::from_static(b"not found"),
+            stored_at: Duration::from_millis(1_700_000_000_123),
+            fresh_for: Duration::from_secs(60),
+            stale_for: Duration::from_secs(30),
+        };
+        let encoded = cached.encode();
+        assert_eq!(CachedResponse::decode(&encoded), Some(cached));
+        assert_eq!(CachedResponse::decode(&encoded[..20]), None);
+        assert_eq!(CachedResponse::decode(b"\x02"), None);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::cache::{
+    Cache, CollectionTtl, DeleteRequest, DeleteResponse, ListConcatenateBackRequest,
+    ListConcatenateBackResponse, ListConcatenateFrontRequest, ListConcatenateFrontResponse,
+    ListFetchRequest, ListFetchResponse, ListLengthRequest, ListLengthResponse, ListPopBackRequest,
+    ListPopBackResponse, ListPopFrontRequest, ListPopFrontResponse, ListPushBackRequest,
+    ListPushBackResponse, ListPushFrontRequest, ListPushFrontResponse, ListRemoveValueRequest,
+    ListRemoveValueResponse, MomentoRequest,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{IntoBytes, MomentoResult};
+
+/// Represents a list in a cache.
+///
+/// Obtain a `List` with [Cache::list]. Every write uses the handle's [CollectionTtl], which
+/// defaults to the TTL of the [Cache] it was created from.
+#[derive(Clone, Debug)]
+pub struct List {
+    cache: Cache,
+    list_name: Vec<u8>,
+    collection_ttl: CollectionTtl,
+}
+
+impl List {
+    /// The name of the list.
+    pub fn name(&self) -> &[u8] {
+        &self.list_name
+    }
+
+    /// The cache containing the list.
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// The [CollectionTtl] used when writing to the list.
+    pub fn collection_ttl(&self) -> CollectionTtl {
+        self.collection_ttl
+    }
+
+    /// Return a new handle which writes to the list with the provided [CollectionTtl].
+    pub fn with_ttl(mut self, collection_ttl: CollectionTtl) -> Self {
+        self.collection_ttl = collection_ttl;
+        self
+    }
+
+    /// Deletes the list.
+    pub async fn delete(&self) -> MomentoResult<DeleteResponse> {
+        DeleteRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets the number of elements in the list.
+    /// See [CacheClient::list_length](crate::CacheClient::list_length).
+    pub async fn length(&self) -> MomentoResult<ListLengthResponse> {
+        ListLengthRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Fetches all elements of the list.
+    /// See [CacheClient::list_fetch](crate::CacheClient::list_fetch).
+    pub async fn fetch(&self) -> MomentoResult<ListFetchResponse> {
+        ListFetchRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds multiple elements to the front of the list.
+    /// See [CacheClient::list_concatenate_front](crate::CacheClient::list_concatenate_front).
+    pub async fn concatenate_front(
+        &self,
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<ListConcatenateFrontResponse> {
+        ListConcatenateFrontRequest::new(self.cache.name(), self.name(), values)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds multiple elements to the back of the list.
+    /// See [CacheClient::list_concatenate_back](crate::CacheClient::list_concatenate_back).
+    pub async fn concatenate_back(
+        &self,
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<ListConcatenateBackResponse> {
+        ListConcatenateBackRequest::new(self.cache.name(), self.name(), values)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds an element to the front of the list.
+    /// See [CacheClient::list_push_front](crate::CacheClient::list_push_front).
+    pub async fn push_front(&self, value: impl IntoBytes) -> MomentoResult<ListPushFrontResponse> {
+        ListPushFrontRequest::new(self.cache.name(), self.name(), value)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds an element to the back of the list.
+    /// See [CacheClient::list_push_back](crate::CacheClient::list_push_back).
+    pub async fn push_back(&self, value: impl IntoBytes) -> MomentoResult<ListPushBackResponse> {
+        ListPushBackRequest::new(self.cache.name(), self.name(), value)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Removes and returns the first element of the list.
+    /// See [CacheClient::list_pop_front](crate::CacheClient::list_pop_front).
+    pub async fn pop_front(&self) -> MomentoResult<ListPopFrontResponse> {
+        ListPopFrontRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Removes and returns the last element of the list.
+    /// See [CacheClient::list_pop_back](crate::CacheClient::list_pop_back).
+    pub async fn pop_back(&self) -> MomentoResult<ListPopBackResponse> {
+        ListPopBackRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Removes all elements equal to `value` from the list.
+    /// See [CacheClient::list_remove_value](crate::CacheClient::list_remove_value).
+    pub async fn remove_value(
+        &self,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListRemoveValueResponse> {
+        ListRemoveValueRequest::new(self.cache.name(), self.name(), value)
+            .send(self.cache.client())
+            .await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(cache: Cache, list_name: Vec<u8>) -> Self {
+        let collection_ttl = cache.collection_ttl();
+        Self {
+            cache,
+            list_name,
+            collection_ttl,
+        }
+    }
+}
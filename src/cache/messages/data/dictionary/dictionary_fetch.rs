@@ -1,4 +1,6 @@
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::utils::fmt::{AsDebuggableValue, DebuggableValue};
 use crate::utils::{parse_string, prep_request_with_timeout};
 use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
@@ -101,6 +103,17 @@ impl<D: IntoBytes> MomentoRequest for DictionaryFetchRequest<D> {
     }
 }
 
+impl<D: IntoBytes> NamespacedRequest for DictionaryFetchRequest<D> {
+    type Prefixed = DictionaryFetchRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionaryFetchRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+        }
+    }
+}
+
 /// Response to a dictionary fetch request.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
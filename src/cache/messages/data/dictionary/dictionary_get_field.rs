@@ -1,4 +1,6 @@
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::utils::{parse_string, prep_request_with_timeout};
 use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
 use momento_protos::cache_client::dictionary_get_response::DictionaryGetResponsePart;
@@ -118,6 +120,18 @@ impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryGetFieldRequest<D,
     }
 }
 
+impl<D: IntoBytes, F: IntoBytes> NamespacedRequest for DictionaryGetFieldRequest<D, F> {
+    type Prefixed = DictionaryGetFieldRequest<Vec<u8>, F>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionaryGetFieldRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+            field: self.field,
+        }
+    }
+}
+
 /// Response object for a [DictionaryGetFieldRequest].
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
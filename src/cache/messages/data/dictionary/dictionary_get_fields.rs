@@ -1,5 +1,7 @@
 use super::dictionary_get_field::{DictionaryGetFieldResponse, Value};
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::utils::{parse_string, prep_request_with_timeout};
 use crate::{
     CacheClient, IntoBytes, IntoBytesIterable, MomentoError, MomentoErrorCode, MomentoResult,
@@ -126,6 +128,20 @@ impl<D: IntoBytes, F: IntoBytesIterable + Clone> MomentoRequest
     }
 }
 
+impl<D: IntoBytes, F: IntoBytesIterable + Clone> NamespacedRequest
+    for DictionaryGetFieldsRequest<D, F>
+{
+    type Prefixed = DictionaryGetFieldsRequest<Vec<u8>, F>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionaryGetFieldsRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+            fields: self.fields,
+        }
+    }
+}
+
 /// Response object for a [DictionaryGetFieldsRequest].
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
@@ -1,3 +1,5 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
@@ -102,6 +104,20 @@ impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryIncrementRequest<D
     }
 }
 
+impl<D: IntoBytes, F: IntoBytes> NamespacedRequest for DictionaryIncrementRequest<D, F> {
+    type Prefixed = DictionaryIncrementRequest<Vec<u8>, F>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionaryIncrementRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+            field: self.field,
+            amount: self.amount,
+            collection_ttl: self.collection_ttl,
+        }
+    }
+}
+
 /// The response type for a successful dictionary increment request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct DictionaryIncrementResponse {
@@ -4,6 +4,8 @@ use momento_protos::cache_client::{
     dictionary_length_response, DictionaryLengthRequest as DictionaryLengthRequestProto,
 };
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
     MomentoResult,
@@ -84,6 +86,17 @@ impl<D: IntoBytes> MomentoRequest for DictionaryLengthRequest<D> {
     }
 }
 
+impl<D: IntoBytes> NamespacedRequest for DictionaryLengthRequest<D> {
+    type Prefixed = DictionaryLengthRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionaryLengthRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+        }
+    }
+}
+
 /// Response for a dictionary length operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
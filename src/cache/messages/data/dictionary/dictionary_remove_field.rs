@@ -1,3 +1,5 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
 };
@@ -81,6 +83,18 @@ impl<D: IntoBytes, F: IntoBytes> MomentoRequest for DictionaryRemoveFieldRequest
     }
 }
 
+impl<D: IntoBytes, F: IntoBytes> NamespacedRequest for DictionaryRemoveFieldRequest<D, F> {
+    type Prefixed = DictionaryRemoveFieldRequest<Vec<u8>, F>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionaryRemoveFieldRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+            field: self.field,
+        }
+    }
+}
+
 /// The response type for a successful dictionary remove field request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct DictionaryRemoveFieldResponse {}
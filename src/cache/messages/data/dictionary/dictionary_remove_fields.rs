@@ -1,3 +1,5 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes,
     IntoBytesIterable, MomentoError,
@@ -82,6 +84,18 @@ impl<D: IntoBytes, F: IntoBytesIterable> MomentoRequest for DictionaryRemoveFiel
     }
 }
 
+impl<D: IntoBytes, F: IntoBytesIterable> NamespacedRequest for DictionaryRemoveFieldsRequest<D, F> {
+    type Prefixed = DictionaryRemoveFieldsRequest<Vec<u8>, F>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionaryRemoveFieldsRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+            fields: self.fields,
+        }
+    }
+}
+
 /// The response type for a successful dictionary remove fields request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct DictionaryRemoveFieldsResponse {}
@@ -1,5 +1,9 @@
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
 use crate::cache::CollectionTtl;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::IntoBytes;
 use crate::{CacheClient, MomentoResult};
@@ -116,6 +120,53 @@ where
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<D, F, V> EncryptedRequest for DictionarySetFieldRequest<D, F, V>
+where
+    D: IntoBytes,
+    F: IntoBytes,
+    V: IntoBytes,
+{
+    type Response = DictionarySetFieldResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<DictionarySetFieldResponse> {
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let field = self.field.into_bytes();
+        let value = client.encrypt_field(&self.cache_name, &dictionary_name, &field, self.value)?;
+        DictionarySetFieldRequest {
+            cache_name: self.cache_name,
+            dictionary_name,
+            field,
+            value,
+            collection_ttl: self.collection_ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<D, F, V> NamespacedRequest for DictionarySetFieldRequest<D, F, V>
+where
+    D: IntoBytes,
+    F: IntoBytes,
+    V: IntoBytes,
+{
+    type Prefixed = DictionarySetFieldRequest<Vec<u8>, F, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionarySetFieldRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+            field: self.field,
+            value: self.value,
+            collection_ttl: self.collection_ttl,
+        }
+    }
+}
+
 /// The response type for a successful dictionary set field request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct DictionarySetFieldResponse {}
@@ -2,7 +2,11 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
 use crate::cache::CollectionTtl;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::IntoBytes;
 use crate::{CacheClient, MomentoResult};
@@ -170,6 +174,67 @@ where
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<D, F, V, E> EncryptedRequest for DictionarySetFieldsRequest<D, F, V, E>
+where
+    D: IntoBytes,
+    F: IntoBytes,
+    V: IntoBytes,
+    E: IntoDictionaryFieldValuePairs<F, V>,
+{
+    type Response = DictionarySetFieldsResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<DictionarySetFieldsResponse> {
+        let cache_name = self.cache_name.as_str();
+        let dictionary_name = self.dictionary_name.into_bytes();
+        let elements = self
+            .elements
+            .into_dictionary_field_value_pairs()
+            .into_iter()
+            .map(|pair| {
+                let field = pair.field.into_bytes();
+                let value =
+                    client.encrypt_field(cache_name, &dictionary_name, &field, pair.value)?;
+                Ok((field, value))
+            })
+            .collect::<MomentoResult<Vec<_>>>()?;
+        DictionarySetFieldsRequest {
+            cache_name: self.cache_name,
+            dictionary_name,
+            elements,
+            collection_ttl: self.collection_ttl,
+            _field_marker: PhantomData,
+            _value_marker: PhantomData,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<D, F, V, E> NamespacedRequest for DictionarySetFieldsRequest<D, F, V, E>
+where
+    D: IntoBytes,
+    F: IntoBytes,
+    V: IntoBytes,
+    E: IntoDictionaryFieldValuePairs<F, V>,
+{
+    type Prefixed = DictionarySetFieldsRequest<Vec<u8>, F, V, E>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DictionarySetFieldsRequest {
+            cache_name: self.cache_name,
+            dictionary_name: prefixed_key(prefix, self.dictionary_name),
+            elements: self.elements,
+            collection_ttl: self.collection_ttl,
+            _field_marker: PhantomData,
+            _value_marker: PhantomData,
+        }
+    }
+}
+
 /// The response type for a successful dictionary set fields request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct DictionarySetFieldsResponse {}
@@ -1,3 +1,7 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
@@ -104,6 +108,42 @@ impl<L: IntoBytes, V: IntoBytesIterable> MomentoRequest for ListConcatenateBackR
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<L: IntoBytes, V: IntoBytesIterable> EncryptedRequest for ListConcatenateBackRequest<L, V> {
+    type Response = ListConcatenateBackResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<ListConcatenateBackResponse> {
+        let list_name = self.list_name.into_bytes();
+        let values = client.encrypt_elements(&self.cache_name, &list_name, self.values)?;
+        ListConcatenateBackRequest {
+            cache_name: self.cache_name,
+            list_name,
+            values,
+            collection_ttl: self.collection_ttl,
+            truncate_front_to_size: self.truncate_front_to_size,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<L: IntoBytes, V: IntoBytesIterable> NamespacedRequest for ListConcatenateBackRequest<L, V> {
+    type Prefixed = ListConcatenateBackRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListConcatenateBackRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+            values: self.values,
+            collection_ttl: self.collection_ttl,
+            truncate_front_to_size: self.truncate_front_to_size,
+        }
+    }
+}
+
 /// The response type for a successful list concatenate back request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListConcatenateBackResponse {}
@@ -1,3 +1,7 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
@@ -101,6 +105,42 @@ impl<L: IntoBytes, V: IntoBytesIterable> MomentoRequest for ListConcatenateFront
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<L: IntoBytes, V: IntoBytesIterable> EncryptedRequest for ListConcatenateFrontRequest<L, V> {
+    type Response = ListConcatenateFrontResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<ListConcatenateFrontResponse> {
+        let list_name = self.list_name.into_bytes();
+        let values = client.encrypt_elements(&self.cache_name, &list_name, self.values)?;
+        ListConcatenateFrontRequest {
+            cache_name: self.cache_name,
+            list_name,
+            values,
+            collection_ttl: self.collection_ttl,
+            truncate_back_to_size: self.truncate_back_to_size,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<L: IntoBytes, V: IntoBytesIterable> NamespacedRequest for ListConcatenateFrontRequest<L, V> {
+    type Prefixed = ListConcatenateFrontRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListConcatenateFrontRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+            values: self.values,
+            collection_ttl: self.collection_ttl,
+            truncate_back_to_size: self.truncate_back_to_size,
+        }
+    }
+}
+
 /// The response type for a successful list concatenate front request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListConcatenateFrontResponse {}
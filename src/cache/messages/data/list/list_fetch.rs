@@ -8,6 +8,8 @@ use momento_protos::{
     common::Unbounded,
 };
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest,
     utils::{parse_string, prep_request_with_timeout},
@@ -118,6 +120,19 @@ impl<L: IntoBytes> MomentoRequest for ListFetchRequest<L> {
     }
 }
 
+impl<L: IntoBytes> NamespacedRequest for ListFetchRequest<L> {
+    type Prefixed = ListFetchRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListFetchRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+            start_index: self.start_index,
+            end_index: self.end_index,
+        }
+    }
+}
+
 /// Response for a list fetch operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 
 use momento_protos::cache_client::list_length_response;
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
     MomentoResult,
@@ -78,6 +80,17 @@ impl<L: IntoBytes> MomentoRequest for ListLengthRequest<L> {
     }
 }
 
+impl<L: IntoBytes> NamespacedRequest for ListLengthRequest<L> {
+    type Prefixed = ListLengthRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListLengthRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+        }
+    }
+}
+
 /// Response for a list length operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
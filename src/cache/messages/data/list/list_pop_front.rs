@@ -2,6 +2,8 @@ use std::convert::{TryFrom, TryInto};
 
 use momento_protos::cache_client::list_pop_front_response;
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest,
     utils::{parse_string, prep_request_with_timeout},
@@ -79,6 +81,17 @@ impl<L: IntoBytes> MomentoRequest for ListPopFrontRequest<L> {
     }
 }
 
+impl<L: IntoBytes> NamespacedRequest for ListPopFrontRequest<L> {
+    type Prefixed = ListPopFrontRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListPopFrontRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+        }
+    }
+}
+
 /// Response for a list pop front operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
@@ -1,3 +1,7 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
@@ -101,6 +105,42 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListPushBackRequest<L, V> {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<L: IntoBytes, V: IntoBytes> EncryptedRequest for ListPushBackRequest<L, V> {
+    type Response = ListPushBackResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<ListPushBackResponse> {
+        let list_name = self.list_name.into_bytes();
+        let value = client.encrypt_element(&self.cache_name, &list_name, self.value)?;
+        ListPushBackRequest {
+            cache_name: self.cache_name,
+            list_name,
+            value,
+            collection_ttl: self.collection_ttl,
+            truncate_front_to_size: self.truncate_front_to_size,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<L: IntoBytes, V: IntoBytes> NamespacedRequest for ListPushBackRequest<L, V> {
+    type Prefixed = ListPushBackRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListPushBackRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+            value: self.value,
+            collection_ttl: self.collection_ttl,
+            truncate_front_to_size: self.truncate_front_to_size,
+        }
+    }
+}
+
 /// The response type for a successful list push back request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListPushBackResponse {}
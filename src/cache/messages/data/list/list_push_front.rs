@@ -1,3 +1,7 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::{
     cache::{CollectionTtl, MomentoRequest},
     utils::prep_request_with_timeout,
@@ -98,6 +102,42 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListPushFrontRequest<L, V> {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<L: IntoBytes, V: IntoBytes> EncryptedRequest for ListPushFrontRequest<L, V> {
+    type Response = ListPushFrontResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<ListPushFrontResponse> {
+        let list_name = self.list_name.into_bytes();
+        let value = client.encrypt_element(&self.cache_name, &list_name, self.value)?;
+        ListPushFrontRequest {
+            cache_name: self.cache_name,
+            list_name,
+            value,
+            collection_ttl: self.collection_ttl,
+            truncate_back_to_size: self.truncate_back_to_size,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<L: IntoBytes, V: IntoBytes> NamespacedRequest for ListPushFrontRequest<L, V> {
+    type Prefixed = ListPushFrontRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListPushFrontRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+            value: self.value,
+            collection_ttl: self.collection_ttl,
+            truncate_back_to_size: self.truncate_back_to_size,
+        }
+    }
+}
+
 /// The response type for a successful list push front request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListPushFrontResponse {}
@@ -1,5 +1,7 @@
 use momento_protos::cache_client::list_remove_request::Remove;
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
 };
@@ -71,6 +73,18 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for ListRemoveValueRequest<L, V>
     }
 }
 
+impl<L: IntoBytes, V: IntoBytes> NamespacedRequest for ListRemoveValueRequest<L, V> {
+    type Prefixed = ListRemoveValueRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ListRemoveValueRequest {
+            cache_name: self.cache_name,
+            list_name: prefixed_key(prefix, self.list_name),
+            value: self.value,
+        }
+    }
+}
+
 /// The response type for a successful list remove value request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListRemoveValueResponse {}
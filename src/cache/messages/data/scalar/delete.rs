@@ -1,3 +1,5 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
 };
@@ -68,6 +70,17 @@ impl<K: IntoBytes> MomentoRequest for DeleteRequest<K> {
     }
 }
 
+impl<K: IntoBytes> NamespacedRequest for DeleteRequest<K> {
+    type Prefixed = DeleteRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        DeleteRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+        }
+    }
+}
+
 /// The response type for a successful delete request
 #[derive(Debug, PartialEq, Eq)]
 pub struct DeleteResponse {}
@@ -1,4 +1,6 @@
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::utils;
 use crate::utils::fmt::AsDebuggableValue;
 use crate::CacheClient;
@@ -86,6 +88,17 @@ impl<K: IntoBytes> MomentoRequest for GetRequest<K> {
     }
 }
 
+impl<K: IntoBytes> NamespacedRequest for GetRequest<K> {
+    type Prefixed = GetRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        GetRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+        }
+    }
+}
+
 /// Response for a cache get operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
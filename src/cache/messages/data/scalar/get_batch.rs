@@ -11,6 +11,8 @@ use crate::{
 };
 
 use crate::cache::messages::data::scalar::get::{GetResponse, Value};
+use crate::cache::namespaced_cache_client::prefixed_keys;
+use crate::cache::NamespacedRequest;
 
 /// Request to get a batch of items from a Momento Cache
 ///
@@ -130,6 +132,21 @@ impl<K: IntoBytesIterable> MomentoRequest for GetBatchRequest<K> {
     }
 }
 
+impl<K: IntoBytesIterable> NamespacedRequest for GetBatchRequest<K> {
+    type Prefixed = GetBatchRequest<Vec<Vec<u8>>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        GetBatchRequest {
+            cache_name: self.cache_name,
+            keys: prefixed_keys(prefix, self.keys),
+        }
+    }
+
+    fn strip_namespace(response: GetBatchResponse, prefix: &str) -> GetBatchResponse {
+        response.strip_key_prefix(prefix.as_bytes())
+    }
+}
+
 /// Response for a cache get batch operation.
 ///
 /// You can use `into()` to convert a `GetBatchResponse` into one of the following:
@@ -180,6 +197,52 @@ pub struct GetBatchResponse {
     results_dictionary: HashMap<Vec<u8>, GetResponse>,
 }
 
+impl GetBatchResponse {
+    /// Combine the responses to batches with disjoint keys into one response.
+    pub(crate) fn merge(responses: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            results_dictionary: responses
+                .into_iter()
+                .flat_map(|response| response.results_dictionary)
+                .collect(),
+        }
+    }
+
+    /// Remove `prefix` from the start of every key in the response.
+    pub(crate) fn strip_key_prefix(self, prefix: &[u8]) -> Self {
+        Self {
+            results_dictionary: self
+                .results_dictionary
+                .into_iter()
+                .map(|(key, response)| match key.strip_prefix(prefix) {
+                    Some(stripped) => (stripped.to_vec(), response),
+                    None => (key, response),
+                })
+                .collect(),
+        }
+    }
+
+    /// Apply `f` to the key and raw value of every hit in the response.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn try_map_values(
+        self,
+        f: impl Fn(&[u8], Vec<u8>) -> MomentoResult<Vec<u8>>,
+    ) -> MomentoResult<Self> {
+        let results_dictionary = self
+            .results_dictionary
+            .into_iter()
+            .map(|(key, response)| match response {
+                GetResponse::Hit { value } => {
+                    let value = Value::new(f(&key, value.raw_item)?);
+                    Ok((key, GetResponse::Hit { value }))
+                }
+                GetResponse::Miss => Ok((key, GetResponse::Miss)),
+            })
+            .collect::<MomentoResult<_>>()?;
+        Ok(Self { results_dictionary })
+    }
+}
+
 // (Bytes key, GetResponse) pairs -- does NOT filter out Miss responses
 impl From<GetBatchResponse> for HashMap<Vec<u8>, GetResponse> {
     fn from(response: GetBatchResponse) -> Self {
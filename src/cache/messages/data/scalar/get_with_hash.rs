@@ -0,0 +1,128 @@
+use momento_protos::cache_client::get_with_hash_response;
+
+use crate::cache::messages::data::scalar::get::Value;
+use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+use crate::utils;
+use crate::CacheClient;
+use crate::{IntoBytes, MomentoError, MomentoResult};
+
+/// Request to get an item from a cache along with the server-side hash of its value.
+///
+/// The returned hash can be passed to [SetIfPresentAndHashEqualRequest](crate::cache::SetIfPresentAndHashEqualRequest)
+/// or [SetIfAbsentOrHashEqualRequest](crate::cache::SetIfAbsentOrHashEqualRequest) to perform a
+/// compare-and-swap without resending the previous value.
+///
+/// # Arguments
+///
+/// * `cache_name` - name of cache
+/// * `key` - key of entry within the cache.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::{GetWithHashResponse, GetWithHashRequest};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # cache_client.set(&cache_name, "key", "value").await?;
+///
+/// let get_request = GetWithHashRequest::new(
+///     cache_name,
+///     "key"
+/// );
+///
+/// match cache_client.send_request(get_request).await? {
+///     GetWithHashResponse::Hit { value, hash } => {
+///         let item: String = value.try_into().expect("I stored a string!");
+///         println!("Fetched value {} with hash {:?}", item, hash);
+///     }
+///     GetWithHashResponse::Miss => println!("Cache miss"),
+/// };
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct GetWithHashRequest<K: IntoBytes> {
+    cache_name: String,
+    key: K,
+}
+
+impl<K: IntoBytes> GetWithHashRequest<K> {
+    /// Constructs a new GetWithHashRequest.
+    pub fn new(cache_name: impl Into<String>, key: K) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            key,
+        }
+    }
+}
+
+impl<K: IntoBytes> MomentoRequest for GetWithHashRequest<K> {
+    type Response = GetWithHashResponse;
+
+    async fn send(self, cache_client: &CacheClient) -> MomentoResult<GetWithHashResponse> {
+        let request = utils::prep_request_with_timeout(
+            &self.cache_name,
+            cache_client.deadline_millis(),
+            momento_protos::cache_client::GetWithHashRequest {
+                cache_key: self.key.into_bytes(),
+            },
+        )?;
+
+        let response = cache_client
+            .next_data_client()
+            .get_with_hash(request)
+            .await?
+            .into_inner();
+        match response.result {
+            Some(get_with_hash_response::Result::Found(found)) => Ok(GetWithHashResponse::Hit {
+                value: Value::new(found.value),
+                hash: found.hash,
+            }),
+            Some(get_with_hash_response::Result::Missing(_)) => Ok(GetWithHashResponse::Miss),
+            _ => Err(MomentoError::unknown_error(
+                "GetWithHash",
+                Some(format!("{response:#?}")),
+            )),
+        }
+    }
+}
+
+impl<K: IntoBytes> NamespacedRequest for GetWithHashRequest<K> {
+    type Prefixed = GetWithHashRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        GetWithHashRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+        }
+    }
+}
+
+/// Response for a cache get with hash operation.
+///
+/// ```
+/// # use momento::cache::{GetWithHashResponse, messages::data::scalar::get::Value};
+/// # let get_response = GetWithHashResponse::Hit { value: Value::default(), hash: vec![] };
+/// use std::convert::TryInto;
+/// let (item, hash): (String, Vec<u8>) = match get_response {
+///     GetWithHashResponse::Hit { value, hash } => (value.try_into().expect("I stored a string!"), hash),
+///     GetWithHashResponse::Miss => return // probably you'll do something else here
+/// };
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GetWithHashResponse {
+    /// The item was found in the cache.
+    Hit {
+        /// The value of the item.
+        value: Value,
+        /// The hash the server computed on the value of the item.
+        hash: Vec<u8>,
+    },
+    /// The item was not found in the cache.
+    Miss,
+}
@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
 };
@@ -97,6 +99,19 @@ impl<K: IntoBytes> MomentoRequest for IncrementRequest<K> {
     }
 }
 
+impl<K: IntoBytes> NamespacedRequest for IncrementRequest<K> {
+    type Prefixed = IncrementRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        IncrementRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            amount: self.amount,
+            ttl: self.ttl,
+        }
+    }
+}
+
 /// The response type for a successful increment request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct IncrementResponse {
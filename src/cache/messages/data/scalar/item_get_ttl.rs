@@ -3,6 +3,8 @@ use std::time::Duration;
 
 use momento_protos::cache_client::item_get_ttl_response::{self};
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
     MomentoResult,
@@ -80,6 +82,17 @@ impl<K: IntoBytes> MomentoRequest for ItemGetTtlRequest<K> {
     }
 }
 
+impl<K: IntoBytes> NamespacedRequest for ItemGetTtlRequest<K> {
+    type Prefixed = ItemGetTtlRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ItemGetTtlRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+        }
+    }
+}
+
 /// Response for a get item ttl operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
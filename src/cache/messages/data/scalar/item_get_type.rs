@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 
 use momento_protos::cache_client::item_get_type_response::{self};
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
     MomentoResult,
@@ -97,6 +99,17 @@ impl<K: IntoBytes> MomentoRequest for ItemGetTypeRequest<K> {
     }
 }
 
+impl<K: IntoBytes> NamespacedRequest for ItemGetTypeRequest<K> {
+    type Prefixed = ItemGetTypeRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        ItemGetTypeRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+        }
+    }
+}
+
 /// The type of an item in the cache.
 #[derive(Debug, PartialEq, Eq)]
 pub enum ItemType {
@@ -1,4 +1,6 @@
+use crate::cache::namespaced_cache_client::prefixed_key;
 use crate::cache::MomentoRequest;
+use crate::cache::NamespacedRequest;
 use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
 
@@ -75,6 +77,17 @@ impl<K: IntoBytes> MomentoRequest for KeyExistsRequest<K> {
     }
 }
 
+impl<K: IntoBytes> NamespacedRequest for KeyExistsRequest<K> {
+    type Prefixed = KeyExistsRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        KeyExistsRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+        }
+    }
+}
+
 /// Response for a key exists operation.
 #[derive(Debug, PartialEq, Eq)]
 pub struct KeyExistsResponse {
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
+use crate::cache::namespaced_cache_client::prefixed_keys;
 use crate::cache::MomentoRequest;
+use crate::cache::NamespacedRequest;
 use crate::utils::parse_string;
 use crate::utils::prep_request_with_timeout;
 use crate::IntoBytesIterable;
@@ -109,6 +111,21 @@ impl<K: IntoBytesIterable> MomentoRequest for KeysExistRequest<K> {
     }
 }
 
+impl<K: IntoBytesIterable> NamespacedRequest for KeysExistRequest<K> {
+    type Prefixed = KeysExistRequest<Vec<Vec<u8>>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        KeysExistRequest {
+            cache_name: self.cache_name,
+            keys: prefixed_keys(prefix, self.keys),
+        }
+    }
+
+    fn strip_namespace(response: KeysExistResponse, prefix: &str) -> KeysExistResponse {
+        response.strip_key_prefix(prefix)
+    }
+}
+
 /// Response for a keys exist operation.
 ///
 /// You can use `into()` to convert a `KeysExist` response into a `Vec<bool>` or a `HashMap<String, bool>`.
@@ -133,6 +150,23 @@ pub struct KeysExistResponse {
     exists_dictionary: HashMap<String, bool>,
 }
 
+impl KeysExistResponse {
+    /// Remove `prefix` from the start of every key in the response.
+    pub(crate) fn strip_key_prefix(self, prefix: &str) -> Self {
+        Self {
+            exists: self.exists,
+            exists_dictionary: self
+                .exists_dictionary
+                .into_iter()
+                .map(|(key, exists)| match key.strip_prefix(prefix) {
+                    Some(stripped) => (stripped.to_string(), exists),
+                    None => (key, exists),
+                })
+                .collect(),
+        }
+    }
+}
+
 impl From<KeysExistResponse> for Vec<bool> {
     fn from(response: KeysExistResponse) -> Self {
         response.exists
@@ -6,6 +6,8 @@ pub mod delete;
 pub mod get;
 /// Contains the request and response types for getting a batch of items from a cache.
 pub mod get_batch;
+/// Contains the request and response types for getting an item and the hash of its value from a cache.
+pub mod get_with_hash;
 /// Contains the request and response types for increasing the time-to-live of an item in a cache.
 pub mod increase_ttl;
 /// Contains the request and response types for incrementing the value of an item in a cache.
@@ -26,13 +28,19 @@ pub mod set_batch;
 pub mod set_if_absent;
 /// Contains the request and response types for setting an item in a cache if it is absent or equal to a given value.
 pub mod set_if_absent_or_equal;
+/// Contains the request and response types for setting an item in a cache if it is absent or the hash of its value is equal to a given hash.
+pub mod set_if_absent_or_hash_equal;
 /// Contains the request and response types for setting an item in a cache if it is equal to a given value.
 pub mod set_if_equal;
 /// Contains the request and response types for setting an item in a cache if it is not equal to a given value.
 pub mod set_if_not_equal;
 /// Contains the request and response types for setting an item in a cache if it is present in the cache.
 pub mod set_if_present;
+/// Contains the request and response types for setting an item in a cache if it is present and the hash of its value is equal to a given hash.
+pub mod set_if_present_and_hash_equal;
 /// Contains the request and response types for setting an item in a cache if it is present and not equal to a given value.
 pub mod set_if_present_and_not_equal;
 /// Contains the request and response types for overwriting the time-to-live of an item in a cache.
 pub mod update_ttl;
+/// Contains the request and response types for atomically updating an item in a cache with a read-modify-write loop.
+pub mod update_with;
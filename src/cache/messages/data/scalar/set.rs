@@ -1,6 +1,10 @@
 use momento_protos::cache_client::ECacheResult;
 
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, MomentoError};
 use crate::{IntoBytes, MomentoResult};
@@ -102,6 +106,37 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetRequest<K, V> {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<K: IntoBytes, V: IntoBytes> EncryptedRequest for SetRequest<K, V> {
+    type Response = SetResponse;
+
+    async fn send_encrypted(self, client: &EncryptedCacheClient) -> MomentoResult<SetResponse> {
+        let key = self.key.into_bytes();
+        let value = client.encrypt(&self.cache_name, &key, self.value)?;
+        SetRequest {
+            cache_name: self.cache_name,
+            key,
+            value,
+            ttl: self.ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes> NamespacedRequest for SetRequest<K, V> {
+    type Prefixed = SetRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            value: self.value,
+            ttl: self.ttl,
+        }
+    }
+}
+
 /// The response type for a successful set request.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct SetResponse {}
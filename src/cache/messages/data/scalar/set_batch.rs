@@ -9,6 +9,10 @@ use crate::utils::{parse_string, prep_request_with_timeout};
 use crate::{CacheClient, IntoBytes, MomentoError, MomentoResult};
 
 use crate::cache::messages::data::scalar::set::SetResponse;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 
 /// Request to set a batch of items in a cache.
 ///
@@ -138,6 +142,54 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetBatchRequest<K, V> {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<K: IntoBytes, V: IntoBytes> EncryptedRequest for SetBatchRequest<K, V> {
+    type Response = SetBatchResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SetBatchResponse> {
+        let cache_name = self.cache_name.as_str();
+        let items = self
+            .items
+            .into_iter()
+            .map(|(key, value)| {
+                let key = key.into_bytes();
+                let value = client.encrypt(cache_name, &key, value)?;
+                Ok((key, value))
+            })
+            .collect::<MomentoResult<Vec<_>>>()?;
+        SetBatchRequest {
+            cache_name: self.cache_name,
+            items,
+            ttl: self.ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes> NamespacedRequest for SetBatchRequest<K, V> {
+    type Prefixed = SetBatchRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetBatchRequest {
+            cache_name: self.cache_name,
+            items: self
+                .items
+                .into_iter()
+                .map(|(key, value)| (prefixed_key(prefix, key), value))
+                .collect(),
+            ttl: self.ttl,
+        }
+    }
+
+    fn strip_namespace(response: SetBatchResponse, prefix: &str) -> SetBatchResponse {
+        response.strip_key_prefix(prefix.as_bytes())
+    }
+}
+
 /// The response type for a successful set request.
 ///
 /// You can use `into()` to convert a `SetBatchResponse` into a `HashMap<Vec<u8>, SetResponse>`.
@@ -167,6 +219,32 @@ pub struct SetBatchResponse {
     results_dictionary: HashMap<Vec<u8>, SetResponse>,
 }
 
+impl SetBatchResponse {
+    /// Combine the responses to batches with disjoint keys into one response.
+    pub(crate) fn merge(responses: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            results_dictionary: responses
+                .into_iter()
+                .flat_map(|response| response.results_dictionary)
+                .collect(),
+        }
+    }
+
+    /// Remove `prefix` from the start of every key in the response.
+    pub(crate) fn strip_key_prefix(self, prefix: &[u8]) -> Self {
+        Self {
+            results_dictionary: self
+                .results_dictionary
+                .into_iter()
+                .map(|(key, response)| match key.strip_prefix(prefix) {
+                    Some(stripped) => (stripped.to_vec(), response),
+                    None => (key, response),
+                })
+                .collect(),
+        }
+    }
+}
+
 impl From<SetBatchResponse> for HashMap<Vec<u8>, SetResponse> {
     fn from(response: SetBatchResponse) -> Self {
         response.results_dictionary
@@ -0,0 +1,194 @@
+use momento_protos::cache_client::set_if_hash_request::Condition::AbsentOrHashEqual;
+use momento_protos::cache_client::set_if_hash_response;
+
+use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
+use crate::utils::prep_request_with_timeout;
+use crate::CacheClient;
+use crate::{IntoBytes, MomentoError, MomentoResult};
+use std::time::Duration;
+
+/// Request to associate the given key with the given value if the key does not already
+/// exist in the cache or the hash of the value in the cache is equal to the supplied
+/// `hash_equal` value.
+///
+/// The hash of an item can be retrieved with a [GetWithHashRequest](crate::cache::GetWithHashRequest).
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache to create.
+/// * `key` - key of the item whose value we are setting
+/// * `value` - data to store
+/// * `hash_equal` - hash to compare to the hash of the cached value
+///
+/// # Optional Arguments
+///
+/// * `ttl` - The time-to-live for the item. If not provided, the client's default time-to-live is used.
+///
+/// # Example
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::cache::{GetWithHashResponse, SetIfAbsentOrHashEqualResponse, SetIfAbsentOrHashEqualRequest};
+/// use momento::MomentoErrorCode;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let hash = match cache_client.get_with_hash(&cache_name, "key").await? {
+///     GetWithHashResponse::Hit { hash, .. } => hash,
+///     GetWithHashResponse::Miss => vec![],
+/// };
+///
+/// let set_request = SetIfAbsentOrHashEqualRequest::new(
+///     &cache_name,
+///     "key",
+///     "new-value",
+///     hash
+/// ).ttl(Duration::from_secs(60));
+///
+/// match cache_client.send_request(set_request).await {
+///     Ok(response) => match response {
+///         SetIfAbsentOrHashEqualResponse::Stored { .. } => println!("Value stored"),
+///         SetIfAbsentOrHashEqualResponse::NotStored => println!("Value not stored"),
+///     }
+///     Err(e) => if let MomentoErrorCode::CacheNotFoundError = e.error_code {
+///         println!("Cache not found: {}", &cache_name);
+///     } else {
+///         eprintln!("Error setting value in cache {}: {}", &cache_name, e);
+///     }
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SetIfAbsentOrHashEqualRequest<K: IntoBytes, V: IntoBytes, H: IntoBytes> {
+    cache_name: String,
+    key: K,
+    value: V,
+    hash_equal: H,
+    ttl: Option<Duration>,
+}
+
+impl<K: IntoBytes, V: IntoBytes, H: IntoBytes> SetIfAbsentOrHashEqualRequest<K, V, H> {
+    /// Constructs a new SetIfAbsentOrHashEqualRequest.
+    pub fn new(cache_name: impl Into<String>, key: K, value: V, hash_equal: H) -> Self {
+        let ttl = None;
+        Self {
+            cache_name: cache_name.into(),
+            key,
+            value,
+            hash_equal,
+            ttl,
+        }
+    }
+
+    /// Set the time-to-live for the item.
+    pub fn ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes, H: IntoBytes> MomentoRequest
+    for SetIfAbsentOrHashEqualRequest<K, V, H>
+{
+    type Response = SetIfAbsentOrHashEqualResponse;
+
+    async fn send(
+        self,
+        cache_client: &CacheClient,
+    ) -> MomentoResult<SetIfAbsentOrHashEqualResponse> {
+        let request = prep_request_with_timeout(
+            &self.cache_name,
+            cache_client.deadline_millis(),
+            momento_protos::cache_client::SetIfHashRequest {
+                cache_key: self.key.into_bytes(),
+                cache_body: self.value.into_bytes(),
+                ttl_milliseconds: cache_client.expand_ttl_ms(self.ttl)?,
+                condition: Some(AbsentOrHashEqual(
+                    momento_protos::common::AbsentOrHashEqual {
+                        hash_to_check: self.hash_equal.into_bytes(),
+                    },
+                )),
+            },
+        )?;
+
+        let response = cache_client
+            .next_data_client()
+            .set_if_hash(request)
+            .await?
+            .into_inner();
+        match response.result {
+            Some(set_if_hash_response::Result::Stored(stored)) => {
+                Ok(SetIfAbsentOrHashEqualResponse::Stored {
+                    new_hash: stored.new_hash,
+                })
+            }
+            Some(set_if_hash_response::Result::NotStored(_)) => {
+                Ok(SetIfAbsentOrHashEqualResponse::NotStored)
+            }
+            _ => Err(MomentoError::unknown_error(
+                "SetIfAbsentOrHashEqual",
+                Some(format!("{response:#?}")),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "encryption")]
+impl<K: IntoBytes, V: IntoBytes, H: IntoBytes> EncryptedRequest
+    for SetIfAbsentOrHashEqualRequest<K, V, H>
+{
+    type Response = SetIfAbsentOrHashEqualResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SetIfAbsentOrHashEqualResponse> {
+        let key = self.key.into_bytes();
+        let value = client.encrypt(&self.cache_name, &key, self.value)?;
+        SetIfAbsentOrHashEqualRequest {
+            cache_name: self.cache_name,
+            key,
+            value,
+            hash_equal: self.hash_equal,
+            ttl: self.ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes, H: IntoBytes> NamespacedRequest
+    for SetIfAbsentOrHashEqualRequest<K, V, H>
+{
+    type Prefixed = SetIfAbsentOrHashEqualRequest<Vec<u8>, V, H>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetIfAbsentOrHashEqualRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            value: self.value,
+            hash_equal: self.hash_equal,
+            ttl: self.ttl,
+        }
+    }
+}
+
+/// Response for a set if absent or hash equal request.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SetIfAbsentOrHashEqualResponse {
+    /// The value was successfully stored.
+    Stored {
+        /// The hash the server computed on the newly stored value.
+        new_hash: Vec<u8>,
+    },
+    /// The value was not stored because the key already exists in the cache and the hash
+    /// of its value is not equal to the supplied hash.
+    NotStored,
+}
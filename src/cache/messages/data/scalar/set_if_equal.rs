@@ -1,7 +1,13 @@
 use momento_protos::cache_client::set_if_request::Condition::Equal;
 use momento_protos::cache_client::set_if_response;
 
+#[cfg(feature = "encryption")]
+use crate::cache::encrypted_cache_client::Condition;
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::CacheClient;
 use crate::{IntoBytes, MomentoError, MomentoResult};
@@ -115,6 +121,46 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest for SetIfEqualRequ
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> EncryptedRequest for SetIfEqualRequest<K, V, E> {
+    type Response = SetIfEqualResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SetIfEqualResponse> {
+        let stored = client
+            .conditional_set(
+                &self.cache_name,
+                self.key.into_bytes(),
+                self.value,
+                self.equal,
+                Condition::PresentAndEqual,
+                self.ttl,
+            )
+            .await?;
+        Ok(if stored {
+            SetIfEqualResponse::Stored
+        } else {
+            SetIfEqualResponse::NotStored
+        })
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> NamespacedRequest for SetIfEqualRequest<K, V, E> {
+    type Prefixed = SetIfEqualRequest<Vec<u8>, V, E>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetIfEqualRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            value: self.value,
+            equal: self.equal,
+            ttl: self.ttl,
+        }
+    }
+}
+
 /// Response for a set if equal request.
 #[derive(Debug, PartialEq, Eq)]
 pub enum SetIfEqualResponse {
@@ -2,6 +2,10 @@ use momento_protos::cache_client::set_if_request::Condition::Present;
 use momento_protos::cache_client::set_if_response;
 
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::CacheClient;
 use crate::{IntoBytes, MomentoError, MomentoResult};
@@ -108,6 +112,40 @@ impl<K: IntoBytes, V: IntoBytes> MomentoRequest for SetIfPresentRequest<K, V> {
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<K: IntoBytes, V: IntoBytes> EncryptedRequest for SetIfPresentRequest<K, V> {
+    type Response = SetIfPresentResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SetIfPresentResponse> {
+        let key = self.key.into_bytes();
+        let value = client.encrypt(&self.cache_name, &key, self.value)?;
+        SetIfPresentRequest {
+            cache_name: self.cache_name,
+            key,
+            value,
+            ttl: self.ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes> NamespacedRequest for SetIfPresentRequest<K, V> {
+    type Prefixed = SetIfPresentRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetIfPresentRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            value: self.value,
+            ttl: self.ttl,
+        }
+    }
+}
+
 /// Response for a set if present operation.
 #[derive(Debug, PartialEq, Eq)]
 pub enum SetIfPresentResponse {
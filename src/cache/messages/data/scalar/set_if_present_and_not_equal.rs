@@ -1,7 +1,13 @@
 use momento_protos::cache_client::set_if_request::Condition::PresentAndNotEqual;
 use momento_protos::cache_client::set_if_response;
 
+#[cfg(feature = "encryption")]
+use crate::cache::encrypted_cache_client::Condition;
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::CacheClient;
 use crate::{IntoBytes, MomentoError, MomentoResult};
@@ -124,6 +130,50 @@ impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> MomentoRequest
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> EncryptedRequest
+    for SetIfPresentAndNotEqualRequest<K, V, E>
+{
+    type Response = SetIfPresentAndNotEqualResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SetIfPresentAndNotEqualResponse> {
+        let stored = client
+            .conditional_set(
+                &self.cache_name,
+                self.key.into_bytes(),
+                self.value,
+                self.not_equal,
+                Condition::PresentAndNotEqual,
+                self.ttl,
+            )
+            .await?;
+        Ok(if stored {
+            SetIfPresentAndNotEqualResponse::Stored
+        } else {
+            SetIfPresentAndNotEqualResponse::NotStored
+        })
+    }
+}
+
+impl<K: IntoBytes, V: IntoBytes, E: IntoBytes> NamespacedRequest
+    for SetIfPresentAndNotEqualRequest<K, V, E>
+{
+    type Prefixed = SetIfPresentAndNotEqualRequest<Vec<u8>, V, E>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetIfPresentAndNotEqualRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            value: self.value,
+            not_equal: self.not_equal,
+            ttl: self.ttl,
+        }
+    }
+}
+
 /// Response for a set if present and not equal operation.
 #[derive(Debug, PartialEq, Eq)]
 pub enum SetIfPresentAndNotEqualResponse {
@@ -3,6 +3,8 @@ use std::time::Duration;
 use momento_protos::cache_client::update_ttl_request::UpdateTtl::OverwriteToMilliseconds;
 use momento_protos::cache_client::update_ttl_response::{self};
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::MomentoError;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
@@ -85,6 +87,18 @@ impl<K: IntoBytes> MomentoRequest for UpdateTtlRequest<K> {
     }
 }
 
+impl<K: IntoBytes> NamespacedRequest for UpdateTtlRequest<K> {
+    type Prefixed = UpdateTtlRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        UpdateTtlRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            ttl: self.ttl,
+        }
+    }
+}
+
 /// Response for an update ttl operation.
 #[derive(Debug, PartialEq, Eq)]
 pub enum UpdateTtlResponse {
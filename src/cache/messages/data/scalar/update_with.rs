@@ -0,0 +1,264 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::cache::messages::data::scalar::get::Value;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::{
+    DeleteRequest, GetRequest, GetResponse, MomentoRequest, NamespacedRequest, SetIfAbsentRequest,
+    SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse,
+};
+use crate::{CacheClient, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult};
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 10;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(10);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Request to atomically update an item in a cache with a read-modify-write loop.
+///
+/// The current value of the item is read and passed to `update_fn`, which returns the new value
+/// to store, or `None` to delete the item. If the item is absent and `update_fn` returns `None`,
+/// nothing is written. The new value is written with
+/// [SetIfEqualRequest] if the item was present, or [SetIfAbsentRequest] if it was not, so the
+/// write only succeeds if no other client modified the item in the meantime. If the write loses
+/// that race, the request backs off and retries with the latest value, up to `max_attempts` times.
+///
+/// Because `update_fn` may be invoked once per attempt, it should not have side effects.
+///
+/// Deleting an item is not conditional: if `update_fn` returns `None`, the item is deleted
+/// regardless of concurrent modifications.
+///
+/// # Arguments
+///
+/// * `cache_name` - name of cache
+/// * `key` - key of the item to update
+/// * `update_fn` - function computing the new value from the current value
+///
+/// # Optional Arguments
+///
+/// * `ttl` - The time-to-live for the item. If not provided, the client's default time-to-live is used.
+/// * `max_attempts` - The maximum number of read-modify-write attempts before giving up with a
+///   [FailedPreconditionError](MomentoErrorCode::FailedPreconditionError). Defaults to 10.
+/// * `backoff` - The initial and maximum delay between attempts. The delay doubles after each
+///   conflict and is jittered. Defaults to 10 milliseconds and 500 milliseconds.
+///
+/// # Examples
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryFrom;
+/// use std::time::Duration;
+/// use momento::cache::{UpdateWithRequest, UpdateWithResponse};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let update_request = UpdateWithRequest::new(&cache_name, "counter", |current| {
+///     let count: u64 = current
+///         .and_then(|value| String::try_from(value).ok())
+///         .and_then(|value| value.parse().ok())
+///         .unwrap_or(0);
+///     Some((count + 1).to_string().into_bytes())
+/// })
+/// .ttl(Duration::from_secs(60))
+/// .max_attempts(5);
+///
+/// match cache_client.send_request(update_request).await? {
+///     UpdateWithResponse::Stored => println!("Counter updated"),
+///     UpdateWithResponse::Deleted => println!("Counter deleted"),
+///     UpdateWithResponse::NotStored => println!("Counter was absent and left unset"),
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct UpdateWithRequest<K, F>
+where
+    K: IntoBytes,
+    F: FnMut(Option<Value>) -> Option<Vec<u8>> + Send,
+{
+    cache_name: String,
+    key: K,
+    update_fn: F,
+    ttl: Option<Duration>,
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl<K, F> UpdateWithRequest<K, F>
+where
+    K: IntoBytes,
+    F: FnMut(Option<Value>) -> Option<Vec<u8>> + Send,
+{
+    /// Constructs a new UpdateWithRequest.
+    pub fn new(cache_name: impl Into<String>, key: K, update_fn: F) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            key,
+            update_fn,
+            ttl: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+
+    /// Set the time-to-live for the item.
+    pub fn ttl(mut self, ttl: impl Into<Option<Duration>>) -> Self {
+        self.ttl = ttl.into();
+        self
+    }
+
+    /// Set the maximum number of read-modify-write attempts. Values below 1 are treated as 1.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the initial and maximum delay between attempts.
+    pub fn backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff.max(initial_backoff);
+        self
+    }
+}
+
+impl<K, F> MomentoRequest for UpdateWithRequest<K, F>
+where
+    K: IntoBytes,
+    F: FnMut(Option<Value>) -> Option<Vec<u8>> + Send,
+{
+    type Response = UpdateWithResponse;
+
+    async fn send(mut self, cache_client: &CacheClient) -> MomentoResult<UpdateWithResponse> {
+        let key = self.key.into_bytes();
+        for attempt in 1..=self.max_attempts {
+            let current = match GetRequest::new(self.cache_name.as_str(), key.clone())
+                .send(cache_client)
+                .await?
+            {
+                GetResponse::Hit { value } => Some(value),
+                GetResponse::Miss => None,
+            };
+            let previous = current.as_ref().map(|value| value.raw_item.clone());
+
+            let stored = match ((self.update_fn)(current), previous) {
+                (Some(new_value), Some(previous)) => {
+                    let request = SetIfEqualRequest::new(
+                        self.cache_name.as_str(),
+                        key.clone(),
+                        new_value,
+                        previous,
+                    )
+                    .ttl(self.ttl);
+                    request.send(cache_client).await? == SetIfEqualResponse::Stored
+                }
+                (Some(new_value), None) => {
+                    let request =
+                        SetIfAbsentRequest::new(self.cache_name.as_str(), key.clone(), new_value)
+                            .ttl(self.ttl);
+                    request.send(cache_client).await? == SetIfAbsentResponse::Stored
+                }
+                (None, Some(_)) => {
+                    DeleteRequest::new(self.cache_name.as_str(), key.clone())
+                        .send(cache_client)
+                        .await?;
+                    return Ok(UpdateWithResponse::Deleted);
+                }
+                (None, None) => return Ok(UpdateWithResponse::NotStored),
+            };
+            if stored {
+                return Ok(UpdateWithResponse::Stored);
+            }
+
+            if attempt < self.max_attempts {
+                let delay = backoff_delay(attempt, self.initial_backoff, self.max_backoff);
+                log::debug!("update conflict on attempt {attempt}, retrying in {delay:?}");
+                tokio::time::sleep(delay).await;
+            }
+        }
+        Err(MomentoError {
+            message: format!(
+                "Item was modified concurrently and could not be updated after {} attempts",
+                self.max_attempts
+            ),
+            error_code: MomentoErrorCode::FailedPreconditionError,
+            inner_error: None,
+        })
+    }
+}
+
+impl<K, F> NamespacedRequest for UpdateWithRequest<K, F>
+where
+    K: IntoBytes,
+    F: FnMut(Option<Value>) -> Option<Vec<u8>> + Send,
+{
+    type Prefixed = UpdateWithRequest<Vec<u8>, F>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        UpdateWithRequest {
+            cache_name: self.cache_name,
+            key: prefixed_key(prefix, self.key),
+            update_fn: self.update_fn,
+            ttl: self.ttl,
+            max_attempts: self.max_attempts,
+            initial_backoff: self.initial_backoff,
+            max_backoff: self.max_backoff,
+        }
+    }
+}
+
+/// Exponential backoff capped at `max_backoff`, jittered between half and all of the delay.
+fn backoff_delay(attempt: u32, initial_backoff: Duration, max_backoff: Duration) -> Duration {
+    let exponent = attempt.saturating_sub(1).min(16);
+    let ceiling = initial_backoff
+        .saturating_mul(1 << exponent)
+        .min(max_backoff);
+    let ceiling_millis = ceiling.as_millis() as u64;
+    if ceiling_millis == 0 {
+        return ceiling;
+    }
+    Duration::from_millis(rand::rng().random_range(ceiling_millis / 2..=ceiling_millis))
+}
+
+/// Response for an update with request.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UpdateWithResponse {
+    /// The value returned by the update function was stored.
+    Stored,
+    /// The update function returned `None` and the item was deleted.
+    Deleted,
+    /// The item was absent and the update function returned `None`, so nothing was written.
+    NotStored,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay_grows_and_is_capped() {
+        let initial = Duration::from_millis(10);
+        let max = Duration::from_millis(100);
+
+        let first = backoff_delay(1, initial, max);
+        assert!(first >= Duration::from_millis(5) && first <= initial);
+
+        let third = backoff_delay(3, initial, max);
+        assert!(third >= Duration::from_millis(20) && third <= Duration::from_millis(40));
+
+        for attempt in 5..40 {
+            let delay = backoff_delay(attempt, initial, max);
+            assert!(delay >= max / 2 && delay <= max, "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn test_backoff_delay_zero() {
+        assert_eq!(
+            backoff_delay(3, Duration::ZERO, Duration::ZERO),
+            Duration::ZERO
+        );
+    }
+}
@@ -1,7 +1,11 @@
 use momento_protos::cache_client::SetUnionRequest;
 
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
 use crate::cache::CollectionTtl;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::CacheClient;
 use crate::{IntoBytes, IntoBytesIterable, MomentoResult};
@@ -92,6 +96,40 @@ impl<S: IntoBytes, E: IntoBytesIterable> MomentoRequest for SetAddElementsReques
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<S: IntoBytes, E: IntoBytesIterable> EncryptedRequest for SetAddElementsRequest<S, E> {
+    type Response = SetAddElementsResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SetAddElementsResponse> {
+        let set_name = self.set_name.into_bytes();
+        let elements = client.encrypt_elements(&self.cache_name, &set_name, self.elements)?;
+        SetAddElementsRequest {
+            cache_name: self.cache_name,
+            set_name,
+            elements,
+            collection_ttl: self.collection_ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<S: IntoBytes, E: IntoBytesIterable> NamespacedRequest for SetAddElementsRequest<S, E> {
+    type Prefixed = SetAddElementsRequest<Vec<u8>, E>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetAddElementsRequest {
+            cache_name: self.cache_name,
+            set_name: prefixed_key(prefix, self.set_name),
+            elements: self.elements,
+            collection_ttl: self.collection_ttl,
+        }
+    }
+}
+
 /// The response type for a successful set add elements request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SetAddElementsResponse {}
@@ -5,6 +5,8 @@ use std::{
 
 use momento_protos::cache_client::set_fetch_response;
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest,
     utils::{parse_string, prep_request_with_timeout},
@@ -87,6 +89,17 @@ impl<S: IntoBytes> MomentoRequest for SetFetchRequest<S> {
     }
 }
 
+impl<S: IntoBytes> NamespacedRequest for SetFetchRequest<S> {
+    type Prefixed = SetFetchRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetFetchRequest {
+            cache_name: self.cache_name,
+            set_name: prefixed_key(prefix, self.set_name),
+        }
+    }
+}
+
 /// Response for a set fetch operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
@@ -6,6 +6,8 @@ use momento_protos::cache_client::{
     SetDifferenceRequest,
 };
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
 };
@@ -82,6 +84,18 @@ impl<S: IntoBytes, E: IntoBytes> MomentoRequest for SetRemoveElementsRequest<S,
     }
 }
 
+impl<S: IntoBytes, E: IntoBytes> NamespacedRequest for SetRemoveElementsRequest<S, E> {
+    type Prefixed = SetRemoveElementsRequest<Vec<u8>, E>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SetRemoveElementsRequest {
+            cache_name: self.cache_name,
+            set_name: prefixed_key(prefix, self.set_name),
+            elements: self.elements,
+        }
+    }
+}
+
 /// The response type for a successful set remove elements request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SetRemoveElementsResponse {}
@@ -4,6 +4,8 @@ use momento_protos::common::Unbounded;
 
 use crate::cache::messages::data::sorted_set::sorted_set_fetch_response::SortedSetFetchResponse;
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::cache::SortedSetOrder;
 use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, IntoBytes, MomentoResult};
@@ -136,6 +138,20 @@ impl<S: IntoBytes> MomentoRequest for SortedSetFetchByRankRequest<S> {
     }
 }
 
+impl<S: IntoBytes> NamespacedRequest for SortedSetFetchByRankRequest<S> {
+    type Prefixed = SortedSetFetchByRankRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetFetchByRankRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            start_rank: self.start_rank,
+            end_rank: self.end_rank,
+            order: self.order,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
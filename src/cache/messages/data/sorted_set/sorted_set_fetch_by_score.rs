@@ -9,6 +9,8 @@ use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, IntoBytes, MomentoResult};
 
 use super::sorted_set_common::{ScoreBound, SortedSetOrder};
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 
 /// Fetch the elements in the given sorted set by their score.
 ///
@@ -181,6 +183,22 @@ impl<S: IntoBytes> MomentoRequest for SortedSetFetchByScoreRequest<S> {
     }
 }
 
+impl<S: IntoBytes> NamespacedRequest for SortedSetFetchByScoreRequest<S> {
+    type Prefixed = SortedSetFetchByScoreRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetFetchByScoreRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            min_score: self.min_score,
+            max_score: self.max_score,
+            order: self.order,
+            offset: self.offset,
+            count: self.count,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::{ScoreBound, SortedSetFetchByScoreRequest};
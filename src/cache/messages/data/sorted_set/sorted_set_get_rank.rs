@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 
 use momento_protos::cache_client::{sorted_set_get_rank_response::Rank, ECacheResult};
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::{MomentoRequest, SortedSetOrder},
     utils::prep_request_with_timeout,
@@ -104,6 +106,19 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetGetRankRequest<L, V
     }
 }
 
+impl<L: IntoBytes, V: IntoBytes> NamespacedRequest for SortedSetGetRankRequest<L, V> {
+    type Prefixed = SortedSetGetRankRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetGetRankRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            value: self.value,
+            order: self.order,
+        }
+    }
+}
+
 /// Response for a sorted set get rank operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
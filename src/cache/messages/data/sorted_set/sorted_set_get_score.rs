@@ -5,6 +5,8 @@ use momento_protos::cache_client::{
     ECacheResult,
 };
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
     MomentoResult,
@@ -110,6 +112,18 @@ impl<L: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetGetScoreRequest<L,
     }
 }
 
+impl<L: IntoBytes, V: IntoBytes> NamespacedRequest for SortedSetGetScoreRequest<L, V> {
+    type Prefixed = SortedSetGetScoreRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetGetScoreRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            value: self.value,
+        }
+    }
+}
+
 /// Response for a sorted set get score operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
@@ -5,6 +5,8 @@ use momento_protos::cache_client::{
     ECacheResult,
 };
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{cache::SortedSetGetScoreResponse, MomentoErrorCode};
 use crate::{
     cache::{MomentoRequest, SortedSetElement},
@@ -119,6 +121,20 @@ impl<L: IntoBytes, V: IntoBytesIterable + Clone> MomentoRequest
     }
 }
 
+impl<L: IntoBytes, V: IntoBytesIterable + Clone> NamespacedRequest
+    for SortedSetGetScoresRequest<L, V>
+{
+    type Prefixed = SortedSetGetScoresRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetGetScoresRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            values: self.values,
+        }
+    }
+}
+
 impl<F: IntoBytesIterable + Clone> TryFrom<SortedSetGetScoresResponse<F>>
     for Vec<SortedSetElement<Vec<u8>>>
 {
@@ -1,6 +1,10 @@
 use momento_protos::cache_client::{SortedSetIncrementRequest, SortedSetIncrementResponse};
 
+use crate::cache::namespaced_cache_client::prefixed_key;
 use crate::cache::CollectionTtl;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoResult,
 };
@@ -104,6 +108,42 @@ impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetIncrementScoreReque
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<S: IntoBytes, V: IntoBytes> EncryptedRequest for SortedSetIncrementScoreRequest<S, V> {
+    type Response = SortedSetIncrementScoreResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SortedSetIncrementScoreResponse> {
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let value = client.encrypt_element(&self.cache_name, &sorted_set_name, self.value)?;
+        SortedSetIncrementScoreRequest {
+            cache_name: self.cache_name,
+            sorted_set_name,
+            value,
+            amount: self.amount,
+            collection_ttl: self.collection_ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<S: IntoBytes, V: IntoBytes> NamespacedRequest for SortedSetIncrementScoreRequest<S, V> {
+    type Prefixed = SortedSetIncrementScoreRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetIncrementScoreRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            value: self.value,
+            amount: self.amount,
+            collection_ttl: self.collection_ttl,
+        }
+    }
+}
+
 /// The incremented score of the item in the sorted set
 #[derive(Debug, PartialEq, PartialOrd)]
 pub struct SortedSetIncrementScoreResponse {
@@ -2,6 +2,8 @@ use std::convert::TryFrom;
 
 use momento_protos::cache_client::sorted_set_length_response;
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::MomentoRequest, utils::prep_request_with_timeout, CacheClient, IntoBytes, MomentoError,
     MomentoResult,
@@ -83,6 +85,17 @@ impl<L: IntoBytes> MomentoRequest for SortedSetLengthRequest<L> {
     }
 }
 
+impl<L: IntoBytes> NamespacedRequest for SortedSetLengthRequest<L> {
+    type Prefixed = SortedSetLengthRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetLengthRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+        }
+    }
+}
+
 /// Response for a sorted set length operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
@@ -5,6 +5,8 @@ use momento_protos::{
     common::Unbounded,
 };
 
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::{
     cache::{MomentoRequest, ScoreBound},
     utils::prep_request_with_timeout,
@@ -147,6 +149,19 @@ impl<L: IntoBytes> MomentoRequest for SortedSetLengthByScoreRequest<L> {
     }
 }
 
+impl<L: IntoBytes> NamespacedRequest for SortedSetLengthByScoreRequest<L> {
+    type Prefixed = SortedSetLengthByScoreRequest<Vec<u8>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetLengthByScoreRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            min_score: self.min_score,
+            max_score: self.max_score,
+        }
+    }
+}
+
 /// Response for a sorted set length by score operation.
 ///
 /// If you'd like to handle misses you can simply match and handle your response:
@@ -1,7 +1,11 @@
 use momento_protos::cache_client::{SortedSetElement, SortedSetPutRequest};
 
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
 use crate::cache::CollectionTtl;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, IntoBytes, MomentoResult};
 
@@ -101,6 +105,42 @@ impl<S: IntoBytes, V: IntoBytes> MomentoRequest for SortedSetPutElementRequest<S
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<S: IntoBytes, V: IntoBytes> EncryptedRequest for SortedSetPutElementRequest<S, V> {
+    type Response = SortedSetPutElementResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SortedSetPutElementResponse> {
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let value = client.encrypt_element(&self.cache_name, &sorted_set_name, self.value)?;
+        SortedSetPutElementRequest {
+            cache_name: self.cache_name,
+            sorted_set_name,
+            value,
+            score: self.score,
+            collection_ttl: self.collection_ttl,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<S: IntoBytes, V: IntoBytes> NamespacedRequest for SortedSetPutElementRequest<S, V> {
+    type Prefixed = SortedSetPutElementRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetPutElementRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            value: self.value,
+            score: self.score,
+            collection_ttl: self.collection_ttl,
+        }
+    }
+}
+
 /// The response type for a successful sorted set put element request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SortedSetPutElementResponse {}
@@ -5,7 +5,11 @@ use momento_protos::cache_client::SortedSetElement as ProtoSortedSetElement;
 use momento_protos::cache_client::SortedSetPutRequest;
 
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
 use crate::cache::CollectionTtl;
+use crate::cache::NamespacedRequest;
+#[cfg(feature = "encryption")]
+use crate::cache::{EncryptedCacheClient, EncryptedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, IntoBytes, MomentoResult};
 
@@ -207,6 +211,55 @@ impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> MomentoRequest
     }
 }
 
+#[cfg(feature = "encryption")]
+impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> EncryptedRequest
+    for SortedSetPutElementsRequest<S, V, E>
+{
+    type Response = SortedSetPutElementsResponse;
+
+    async fn send_encrypted(
+        self,
+        client: &EncryptedCacheClient,
+    ) -> MomentoResult<SortedSetPutElementsResponse> {
+        let cache_name = self.cache_name.as_str();
+        let sorted_set_name = self.sorted_set_name.into_bytes();
+        let elements = self
+            .elements
+            .into_sorted_set_elements()
+            .into_iter()
+            .map(|element| {
+                let value = client.encrypt_element(cache_name, &sorted_set_name, element.value)?;
+                Ok((value, element.score))
+            })
+            .collect::<MomentoResult<Vec<_>>>()?;
+        SortedSetPutElementsRequest {
+            cache_name: self.cache_name,
+            sorted_set_name,
+            elements,
+            collection_ttl: self.collection_ttl,
+            _marker: PhantomData,
+        }
+        .send(client.client())
+        .await
+    }
+}
+
+impl<S: IntoBytes, V: IntoBytes, E: IntoSortedSetElements<V>> NamespacedRequest
+    for SortedSetPutElementsRequest<S, V, E>
+{
+    type Prefixed = SortedSetPutElementsRequest<Vec<u8>, V, E>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetPutElementsRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            elements: self.elements,
+            collection_ttl: self.collection_ttl,
+            _marker: PhantomData,
+        }
+    }
+}
+
 /// The response type for a successful sorted set put elements request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SortedSetPutElementsResponse {}
@@ -2,6 +2,8 @@ use momento_protos::cache_client::sorted_set_remove_request::{RemoveElements, So
 use momento_protos::cache_client::SortedSetRemoveRequest;
 
 use crate::cache::messages::MomentoRequest;
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::NamespacedRequest;
 use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, IntoBytes, IntoBytesIterable, MomentoResult};
 
@@ -80,6 +82,20 @@ impl<S: IntoBytes, V: IntoBytesIterable> MomentoRequest for SortedSetRemoveEleme
     }
 }
 
+impl<S: IntoBytes, V: IntoBytesIterable> NamespacedRequest
+    for SortedSetRemoveElementsRequest<S, V>
+{
+    type Prefixed = SortedSetRemoveElementsRequest<Vec<u8>, V>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetRemoveElementsRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            values: self.values,
+        }
+    }
+}
+
 /// The response type for a successful sorted set remove elements request.
 #[derive(Debug, PartialEq, Eq)]
 pub struct SortedSetRemoveElementsResponse {}
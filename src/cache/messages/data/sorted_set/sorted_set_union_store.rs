@@ -5,7 +5,8 @@ use derive_more::Display;
 
 use momento_protos::cache_client::sorted_set_union_store_request;
 
-use crate::cache::{CollectionTtl, MomentoRequest};
+use crate::cache::namespaced_cache_client::prefixed_key;
+use crate::cache::{CollectionTtl, MomentoRequest, NamespacedRequest};
 use crate::utils::prep_request_with_timeout;
 use crate::{CacheClient, IntoBytes, MomentoResult};
 
@@ -201,6 +202,34 @@ impl<S: IntoBytes, Z: IntoBytes, U: IntoSortedSetUnionStoreSources<Z>> MomentoRe
     }
 }
 
+impl<S: IntoBytes, Z: IntoBytes, U: IntoSortedSetUnionStoreSources<Z>> NamespacedRequest
+    for SortedSetUnionStoreRequest<S, Z, U>
+{
+    type Prefixed =
+        SortedSetUnionStoreRequest<Vec<u8>, Vec<u8>, Vec<SortedSetUnionStoreSource<Vec<u8>>>>;
+
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed {
+        SortedSetUnionStoreRequest {
+            cache_name: self.cache_name,
+            sorted_set_name: prefixed_key(prefix, self.sorted_set_name),
+            sources: self
+                .sources
+                .into_sorted_set_union_store_sources()
+                .into_iter()
+                .map(|source| {
+                    SortedSetUnionStoreSource::new(
+                        prefixed_key(prefix, source.sorted_set_name),
+                        source.weight,
+                    )
+                })
+                .collect(),
+            aggregate: self.aggregate,
+            collection_ttl: self.collection_ttl,
+            _source_marker: PhantomData,
+        }
+    }
+}
+
 /// Response for a successful sorted set union store request.
 ///
 /// You can cast the result into a u32 value or access the length field directly.
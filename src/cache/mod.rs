@@ -43,6 +43,7 @@ pub use messages::data::scalar::decrease_ttl::{DecreaseTtlRequest, DecreaseTtlRe
 pub use messages::data::scalar::delete::{DeleteRequest, DeleteResponse};
 pub use messages::data::scalar::get::{GetRequest, GetResponse};
 pub use messages::data::scalar::get_batch::{GetBatchRequest, GetBatchResponse};
+pub use messages::data::scalar::get_with_hash::{GetWithHashRequest, GetWithHashResponse};
 pub use messages::data::scalar::increase_ttl::{IncreaseTtlRequest, IncreaseTtlResponse};
 pub use messages::data::scalar::increment::{IncrementRequest, IncrementResponse};
 pub use messages::data::scalar::item_get_ttl::{ItemGetTtlRequest, ItemGetTtlResponse};
@@ -57,13 +58,20 @@ pub use messages::data::scalar::set_if_absent::{SetIfAbsentRequest, SetIfAbsentR
 pub use messages::data::scalar::set_if_absent_or_equal::{
     SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse,
 };
+pub use messages::data::scalar::set_if_absent_or_hash_equal::{
+    SetIfAbsentOrHashEqualRequest, SetIfAbsentOrHashEqualResponse,
+};
 pub use messages::data::scalar::set_if_equal::{SetIfEqualRequest, SetIfEqualResponse};
 pub use messages::data::scalar::set_if_not_equal::{SetIfNotEqualRequest, SetIfNotEqualResponse};
 pub use messages::data::scalar::set_if_present::{SetIfPresentRequest, SetIfPresentResponse};
+pub use messages::data::scalar::set_if_present_and_hash_equal::{
+    SetIfPresentAndHashEqualRequest, SetIfPresentAndHashEqualResponse,
+};
 pub use messages::data::scalar::set_if_present_and_not_equal::{
     SetIfPresentAndNotEqualRequest, SetIfPresentAndNotEqualResponse,
 };
 pub use messages::data::scalar::update_ttl::{UpdateTtlRequest, UpdateTtlResponse};
+pub use messages::data::scalar::update_with::{UpdateWithRequest, UpdateWithResponse};
 
 pub use messages::data::set::set_add_elements::{SetAddElementsRequest, SetAddElementsResponse};
 pub use messages::data::set::set_fetch::{SetFetchRequest, SetFetchResponse};
@@ -141,3 +149,48 @@ mod cache_client;
 pub use cache_client::CacheClient;
 mod cache_client_builder;
 pub use cache_client_builder::{CacheClientBuilder, ReadyToBuild};
+
+mod cache_resource;
+pub use cache_resource::Cache;
+#[cfg(feature = "macros")]
+pub(crate) mod cached;
+mod dictionary_resource;
+pub use dictionary_resource::Dictionary;
+#[cfg(feature = "encryption")]
+mod encrypted_cache_client;
+#[cfg(feature = "encryption")]
+pub use encrypted_cache_client::{EncryptedCacheClient, EncryptedRequest};
+#[cfg(feature = "encryption")]
+mod encryption_key_ring;
+#[cfg(feature = "encryption")]
+pub use encryption_key_ring::EncryptionKeyRing;
+mod failover_cache_client;
+pub use failover_cache_client::{FailoverCacheClient, FailoverWriteMode};
+mod failover_cache_client_builder;
+pub use failover_cache_client_builder::{
+    FailoverCacheClientBuilder, NeedsPrimaryClient, ReadyToBuildFailover,
+};
+#[cfg(feature = "http-cache")]
+mod http_cache;
+#[cfg(feature = "http-cache")]
+pub use http_cache::{HttpCache, HttpCacheBody, HttpCacheLayer};
+mod list_resource;
+pub use list_resource::List;
+mod namespaced_cache_client;
+pub use namespaced_cache_client::{NamespacedCacheClient, NamespacedRequest};
+#[cfg(feature = "tower-sessions")]
+mod session_store;
+#[cfg(feature = "tower-sessions")]
+pub use session_store::CacheSessionStore;
+mod set_resource;
+pub use set_resource::Set;
+mod sharded_cache_client;
+pub use sharded_cache_client::{CacheShard, MovedKey, RebalanceReport, ShardedCacheClient};
+mod sorted_set_resource;
+pub use sorted_set_resource::SortedSet;
+mod typed_dictionary;
+pub use typed_dictionary::TypedDictionary;
+mod typed_list;
+pub use typed_list::TypedList;
+mod typed_sorted_set;
+pub use typed_sorted_set::TypedSortedSet;
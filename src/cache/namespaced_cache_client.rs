@@ -0,0 +1,880 @@
+use std::time::Duration;
+
+use crate::auth::{CacheSelector, DisposableTokenScope, DisposableTokenScopes};
+use crate::cache::messages::data::scalar::get::Value;
+use crate::cache::MomentoRequest;
+use crate::cache::{
+    DecreaseTtlResponse, DeleteResponse, DictionaryFetchResponse, DictionaryGetFieldResponse,
+    DictionaryGetFieldsResponse, DictionaryIncrementResponse, DictionaryLengthResponse,
+    DictionaryRemoveFieldResponse, DictionaryRemoveFieldsResponse, DictionarySetFieldResponse,
+    DictionarySetFieldsResponse, GetBatchResponse, GetResponse, GetWithHashResponse,
+    IncreaseTtlResponse, IncrementResponse, IntoDictionaryFieldValuePairs, IntoSortedSetElements,
+    IntoSortedSetUnionStoreSources, ItemGetTtlResponse, ItemGetTypeResponse, KeyExistsResponse,
+    KeysExistResponse, ListConcatenateBackResponse, ListConcatenateFrontResponse,
+    ListFetchResponse, ListLengthResponse, ListPopBackResponse, ListPopFrontResponse,
+    ListPushBackResponse, ListPushFrontResponse, ListRemoveValueResponse, SetAddElementsResponse,
+    SetBatchResponse, SetFetchResponse, SetIfAbsentOrEqualResponse, SetIfAbsentOrHashEqualResponse,
+    SetIfAbsentResponse, SetIfEqualResponse, SetIfNotEqualResponse,
+    SetIfPresentAndHashEqualResponse, SetIfPresentAndNotEqualResponse, SetIfPresentResponse,
+    SetRemoveElementsResponse, SetResponse, SortedSetFetchResponse, SortedSetGetRankResponse,
+    SortedSetGetScoreResponse, SortedSetGetScoresResponse, SortedSetIncrementScoreResponse,
+    SortedSetLengthByScoreResponse, SortedSetLengthResponse, SortedSetOrder,
+    SortedSetPutElementResponse, SortedSetPutElementsResponse, SortedSetRemoveElementsResponse,
+    SortedSetUnionStoreResponse, SortedSetUnionStoreSource, UpdateTtlResponse, UpdateWithResponse,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{CacheClient, IntoBytes, MomentoResult};
+
+/// A [CacheClient] which transparently prefixes every key with a namespace.
+///
+/// This allows many tenants to share a single cache without their keys colliding. Scalar keys,
+/// collection names, and the keys of `get_batch`, `set_batch` and `keys_exist` requests are all
+/// prefixed, and the keys in the responses to those batch requests are returned with the prefix
+/// removed. Collection fields and values are not prefixed.
+///
+/// The namespace is a plain key prefix, so tenant tokens created with
+/// [DisposableTokenScopes::cache_key_prefix_read_write] and related scopes grant access to
+/// exactly the keys in a namespace. Use [NamespacedCacheClient::read_write_token_scope] and
+/// related methods to build those scopes.
+///
+/// Use [send_request](NamespacedCacheClient::send_request) to send a request builder with the
+/// namespace applied, for example to set a per-item TTL or a [CollectionTtl](crate::cache::CollectionTtl).
+///
+/// Control plane operations such as `create_cache` and `flush_cache` are not namespaced; use the
+/// underlying [CacheClient] for those.
+///
+/// # Example
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let tenant = cache_client.namespaced("tenant-a:");
+/// tenant.set(&cache_name, "key", "value").await?;
+///
+/// // The item is stored under the prefixed key.
+/// let item: String = cache_client.get(&cache_name, "tenant-a:key").await?.try_into()?;
+/// assert_eq!(item, "value");
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct NamespacedCacheClient {
+    client: CacheClient,
+    prefix: String,
+}
+
+impl NamespacedCacheClient {
+    /// The namespace prefix added to every key.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The underlying [CacheClient].
+    ///
+    /// Requests sent through the underlying client are not namespaced. Use
+    /// [send_request](NamespacedCacheClient::send_request) to send a request builder with the
+    /// namespace applied.
+    pub fn client(&self) -> &CacheClient {
+        &self.client
+    }
+
+    /// A read-write disposable token scope for all keys in this namespace.
+    pub fn read_write_token_scope(
+        &self,
+        cache_selector: impl Into<CacheSelector>,
+    ) -> DisposableTokenScope {
+        DisposableTokenScopes::cache_key_prefix_read_write(cache_selector, self.prefix.as_str())
+    }
+
+    /// A read-only disposable token scope for all keys in this namespace.
+    pub fn read_only_token_scope(
+        &self,
+        cache_selector: impl Into<CacheSelector>,
+    ) -> DisposableTokenScope {
+        DisposableTokenScopes::cache_key_prefix_read_only(cache_selector, self.prefix.as_str())
+    }
+
+    /// A write-only disposable token scope for all keys in this namespace.
+    pub fn write_only_token_scope(
+        &self,
+        cache_selector: impl Into<CacheSelector>,
+    ) -> DisposableTokenScope {
+        DisposableTokenScopes::cache_key_prefix_write_only(cache_selector, self.prefix.as_str())
+    }
+
+    /// Calls [CacheClient::set] with the namespace applied.
+    pub async fn set(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetResponse> {
+        self.client.set(cache_name, self.key(key), value).await
+    }
+
+    /// Calls [CacheClient::set_batch] with the namespace applied.
+    pub async fn set_batch<K: IntoBytes, V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        items: impl IntoIterator<Item = (K, V)>,
+    ) -> MomentoResult<SetBatchResponse> {
+        self.client
+            .set_batch(
+                cache_name,
+                items.into_iter().map(|(key, value)| (self.key(key), value)),
+            )
+            .await
+            .map(|response| response.strip_key_prefix(self.prefix.as_bytes()))
+    }
+
+    /// Calls [CacheClient::get] with the namespace applied.
+    pub async fn get(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetResponse> {
+        self.client.get(cache_name, self.key(key)).await
+    }
+
+    /// Calls [CacheClient::get_with_hash] with the namespace applied.
+    pub async fn get_with_hash(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<GetWithHashResponse> {
+        self.client.get_with_hash(cache_name, self.key(key)).await
+    }
+
+    /// Calls [CacheClient::get_batch] with the namespace applied.
+    pub async fn get_batch(
+        &self,
+        cache_name: impl Into<String>,
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<GetBatchResponse> {
+        self.client
+            .get_batch(cache_name, self.keys(keys))
+            .await
+            .map(|response| response.strip_key_prefix(self.prefix.as_bytes()))
+    }
+
+    /// Calls [CacheClient::delete] with the namespace applied.
+    pub async fn delete(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<DeleteResponse> {
+        self.client.delete(cache_name, self.key(key)).await
+    }
+
+    /// Calls [CacheClient::dictionary_fetch] with the namespace applied.
+    pub async fn dictionary_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+    ) -> MomentoResult<DictionaryFetchResponse> {
+        self.client
+            .dictionary_fetch(cache_name, self.key(dictionary_name))
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_get_field] with the namespace applied.
+    pub async fn dictionary_get_field(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+    ) -> MomentoResult<DictionaryGetFieldResponse> {
+        self.client
+            .dictionary_get_field(cache_name, self.key(dictionary_name), field)
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_get_fields] with the namespace applied.
+    pub async fn dictionary_get_fields<F: IntoBytesIterable + Clone>(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        fields: F,
+    ) -> MomentoResult<DictionaryGetFieldsResponse<F>> {
+        self.client
+            .dictionary_get_fields(cache_name, self.key(dictionary_name), fields)
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_increment] with the namespace applied.
+    pub async fn dictionary_increment(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+        amount: i64,
+    ) -> MomentoResult<DictionaryIncrementResponse> {
+        self.client
+            .dictionary_increment(cache_name, self.key(dictionary_name), field, amount)
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_length] with the namespace applied.
+    pub async fn dictionary_length(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+    ) -> MomentoResult<DictionaryLengthResponse> {
+        self.client
+            .dictionary_length(cache_name, self.key(dictionary_name))
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_remove_field] with the namespace applied.
+    pub async fn dictionary_remove_field(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+    ) -> MomentoResult<DictionaryRemoveFieldResponse> {
+        self.client
+            .dictionary_remove_field(cache_name, self.key(dictionary_name), field)
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_remove_fields] with the namespace applied.
+    pub async fn dictionary_remove_fields<F: IntoBytesIterable>(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        fields: F,
+    ) -> MomentoResult<DictionaryRemoveFieldsResponse> {
+        self.client
+            .dictionary_remove_fields(cache_name, self.key(dictionary_name), fields)
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_set_field] with the namespace applied.
+    pub async fn dictionary_set_field(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        field: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<DictionarySetFieldResponse> {
+        self.client
+            .dictionary_set_field(cache_name, self.key(dictionary_name), field, value)
+            .await
+    }
+
+    /// Calls [CacheClient::dictionary_set_fields] with the namespace applied.
+    pub async fn dictionary_set_fields<F: IntoBytes, V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        dictionary_name: impl IntoBytes,
+        elements: impl IntoDictionaryFieldValuePairs<F, V>,
+    ) -> MomentoResult<DictionarySetFieldsResponse> {
+        self.client
+            .dictionary_set_fields(cache_name, self.key(dictionary_name), elements)
+            .await
+    }
+
+    /// Calls [CacheClient::set_add_elements] with the namespace applied.
+    pub async fn set_add_elements<E: IntoBytesIterable>(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+        elements: E,
+    ) -> MomentoResult<SetAddElementsResponse> {
+        self.client
+            .set_add_elements(cache_name, self.key(set_name), elements)
+            .await
+    }
+
+    /// Calls [CacheClient::set_fetch] with the namespace applied.
+    pub async fn set_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+    ) -> MomentoResult<SetFetchResponse> {
+        self.client.set_fetch(cache_name, self.key(set_name)).await
+    }
+
+    /// Calls [CacheClient::set_remove_elements] with the namespace applied.
+    pub async fn set_remove_elements<E: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        set_name: impl IntoBytes,
+        elements: Vec<E>,
+    ) -> MomentoResult<SetRemoveElementsResponse> {
+        self.client
+            .set_remove_elements(cache_name, self.key(set_name), elements)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_put_element] with the namespace applied.
+    pub async fn sorted_set_put_element(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+        score: f64,
+    ) -> MomentoResult<SortedSetPutElementResponse> {
+        self.client
+            .sorted_set_put_element(cache_name, self.key(sorted_set_name), value, score)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_put_elements] with the namespace applied.
+    pub async fn sorted_set_put_elements<V: IntoBytes>(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        elements: impl IntoSortedSetElements<V>,
+    ) -> MomentoResult<SortedSetPutElementsResponse> {
+        self.client
+            .sorted_set_put_elements(cache_name, self.key(sorted_set_name), elements)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_fetch_by_rank] with the namespace applied.
+    pub async fn sorted_set_fetch_by_rank(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        order: SortedSetOrder,
+        start_rank: Option<i32>,
+        end_rank: Option<i32>,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        self.client
+            .sorted_set_fetch_by_rank(
+                cache_name,
+                self.key(sorted_set_name),
+                order,
+                start_rank,
+                end_rank,
+            )
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_fetch_by_score] with the namespace applied.
+    pub async fn sorted_set_fetch_by_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        order: SortedSetOrder,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        self.client
+            .sorted_set_fetch_by_score(cache_name, self.key(sorted_set_name), order)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_remove_elements] with the namespace applied.
+    pub async fn sorted_set_remove_elements<V: IntoBytesIterable>(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        values: V,
+    ) -> MomentoResult<SortedSetRemoveElementsResponse> {
+        self.client
+            .sorted_set_remove_elements(cache_name, self.key(sorted_set_name), values)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_length] with the namespace applied.
+    pub async fn sorted_set_length(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+    ) -> MomentoResult<SortedSetLengthResponse> {
+        self.client
+            .sorted_set_length(cache_name, self.key(sorted_set_name))
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_get_rank] with the namespace applied.
+    pub async fn sorted_set_get_rank(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SortedSetGetRankResponse> {
+        self.client
+            .sorted_set_get_rank(cache_name, self.key(sorted_set_name), value)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_get_score] with the namespace applied.
+    pub async fn sorted_set_get_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SortedSetGetScoreResponse> {
+        self.client
+            .sorted_set_get_score(cache_name, self.key(sorted_set_name), value)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_get_scores] with the namespace applied.
+    pub async fn sorted_set_get_scores<F: IntoBytesIterable + Clone>(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        values: F,
+    ) -> MomentoResult<SortedSetGetScoresResponse<F>> {
+        self.client
+            .sorted_set_get_scores(cache_name, self.key(sorted_set_name), values)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_increment_score] with the namespace applied.
+    pub async fn sorted_set_increment_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+        value: impl IntoBytes,
+        score: f64,
+    ) -> MomentoResult<SortedSetIncrementScoreResponse> {
+        self.client
+            .sorted_set_increment_score(cache_name, self.key(sorted_set_name), value, score)
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_length_by_score] with the namespace applied.
+    pub async fn sorted_set_length_by_score(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: impl IntoBytes,
+    ) -> MomentoResult<SortedSetLengthByScoreResponse> {
+        self.client
+            .sorted_set_length_by_score(cache_name, self.key(sorted_set_name))
+            .await
+    }
+
+    /// Calls [CacheClient::sorted_set_union_store] with the namespace applied.
+    pub async fn sorted_set_union_store<
+        S: IntoBytes,
+        Z: IntoBytes,
+        U: IntoSortedSetUnionStoreSources<Z>,
+    >(
+        &self,
+        cache_name: impl Into<String>,
+        sorted_set_name: S,
+        sources: U,
+    ) -> MomentoResult<SortedSetUnionStoreResponse> {
+        self.client
+            .sorted_set_union_store(cache_name, self.key(sorted_set_name), self.sources(sources))
+            .await
+    }
+
+    /// Calls [CacheClient::key_exists] with the namespace applied.
+    pub async fn key_exists(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<KeyExistsResponse> {
+        self.client.key_exists(cache_name, self.key(key)).await
+    }
+
+    /// Calls [CacheClient::keys_exist] with the namespace applied.
+    pub async fn keys_exist(
+        &self,
+        cache_name: impl Into<String>,
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<KeysExistResponse> {
+        self.client
+            .keys_exist(cache_name, self.keys(keys))
+            .await
+            .map(|response| response.strip_key_prefix(&self.prefix))
+    }
+
+    /// Calls [CacheClient::increment] with the namespace applied.
+    pub async fn increment(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        amount: i64,
+    ) -> MomentoResult<IncrementResponse> {
+        self.client
+            .increment(cache_name, self.key(key), amount)
+            .await
+    }
+
+    /// Calls [CacheClient::item_get_type] with the namespace applied.
+    pub async fn item_get_type(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<ItemGetTypeResponse> {
+        self.client.item_get_type(cache_name, self.key(key)).await
+    }
+
+    /// Calls [CacheClient::item_get_ttl] with the namespace applied.
+    pub async fn item_get_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+    ) -> MomentoResult<ItemGetTtlResponse> {
+        self.client.item_get_ttl(cache_name, self.key(key)).await
+    }
+
+    /// Calls [CacheClient::update_ttl] with the namespace applied.
+    pub async fn update_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<UpdateTtlResponse> {
+        self.client.update_ttl(cache_name, self.key(key), ttl).await
+    }
+
+    /// Calls [CacheClient::increase_ttl] with the namespace applied.
+    pub async fn increase_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<IncreaseTtlResponse> {
+        self.client
+            .increase_ttl(cache_name, self.key(key), ttl)
+            .await
+    }
+
+    /// Calls [CacheClient::decrease_ttl] with the namespace applied.
+    pub async fn decrease_ttl(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: Duration,
+    ) -> MomentoResult<DecreaseTtlResponse> {
+        self.client
+            .decrease_ttl(cache_name, self.key(key), ttl)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_absent] with the namespace applied.
+    pub async fn set_if_absent(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentResponse> {
+        self.client
+            .set_if_absent(cache_name, self.key(key), value)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_present] with the namespace applied.
+    pub async fn set_if_present(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentResponse> {
+        self.client
+            .set_if_present(cache_name, self.key(key), value)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_equal] with the namespace applied.
+    pub async fn set_if_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfEqualResponse> {
+        self.client
+            .set_if_equal(cache_name, self.key(key), value, equal)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_not_equal] with the namespace applied.
+    pub async fn set_if_not_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfNotEqualResponse> {
+        self.client
+            .set_if_not_equal(cache_name, self.key(key), value, not_equal)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_present_and_not_equal] with the namespace applied.
+    pub async fn set_if_present_and_not_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        not_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndNotEqualResponse> {
+        self.client
+            .set_if_present_and_not_equal(cache_name, self.key(key), value, not_equal)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_absent_or_equal] with the namespace applied.
+    pub async fn set_if_absent_or_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrEqualResponse> {
+        self.client
+            .set_if_absent_or_equal(cache_name, self.key(key), value, equal)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_present_and_hash_equal] with the namespace applied.
+    pub async fn set_if_present_and_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfPresentAndHashEqualResponse> {
+        self.client
+            .set_if_present_and_hash_equal(cache_name, self.key(key), value, hash_equal)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_absent_or_hash_equal] with the namespace applied.
+    pub async fn set_if_absent_or_hash_equal(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+        hash_equal: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentOrHashEqualResponse> {
+        self.client
+            .set_if_absent_or_hash_equal(cache_name, self.key(key), value, hash_equal)
+            .await
+    }
+
+    /// Calls [CacheClient::update_with] with the namespace applied.
+    pub async fn update_with(
+        &self,
+        cache_name: impl Into<String>,
+        key: impl IntoBytes,
+        ttl: impl Into<Option<Duration>>,
+        update_fn: impl FnMut(Option<Value>) -> Option<Vec<u8>> + Send,
+    ) -> MomentoResult<UpdateWithResponse> {
+        self.client
+            .update_with(cache_name, self.key(key), ttl, update_fn)
+            .await
+    }
+
+    /// Calls [CacheClient::list_length] with the namespace applied.
+    pub async fn list_length(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListLengthResponse> {
+        self.client
+            .list_length(cache_name, self.key(list_name))
+            .await
+    }
+
+    /// Calls [CacheClient::list_concatenate_front] with the namespace applied.
+    pub async fn list_concatenate_front(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<ListConcatenateFrontResponse> {
+        self.client
+            .list_concatenate_front(cache_name, self.key(list_name), values)
+            .await
+    }
+
+    /// Calls [CacheClient::list_concatenate_back] with the namespace applied.
+    pub async fn list_concatenate_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        values: impl IntoBytesIterable,
+    ) -> MomentoResult<ListConcatenateBackResponse> {
+        self.client
+            .list_concatenate_back(cache_name, self.key(list_name), values)
+            .await
+    }
+
+    /// Calls [CacheClient::list_fetch] with the namespace applied.
+    pub async fn list_fetch(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListFetchResponse> {
+        self.client
+            .list_fetch(cache_name, self.key(list_name))
+            .await
+    }
+
+    /// Calls [CacheClient::list_pop_back] with the namespace applied.
+    pub async fn list_pop_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListPopBackResponse> {
+        self.client
+            .list_pop_back(cache_name, self.key(list_name))
+            .await
+    }
+
+    /// Calls [CacheClient::list_pop_front] with the namespace applied.
+    pub async fn list_pop_front(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+    ) -> MomentoResult<ListPopFrontResponse> {
+        self.client
+            .list_pop_front(cache_name, self.key(list_name))
+            .await
+    }
+
+    /// Calls [CacheClient::list_remove_value] with the namespace applied.
+    pub async fn list_remove_value(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListRemoveValueResponse> {
+        self.client
+            .list_remove_value(cache_name, self.key(list_name), value)
+            .await
+    }
+
+    /// Calls [CacheClient::list_push_back] with the namespace applied.
+    pub async fn list_push_back(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListPushBackResponse> {
+        self.client
+            .list_push_back(cache_name, self.key(list_name), value)
+            .await
+    }
+
+    /// Calls [CacheClient::list_push_front] with the namespace applied.
+    pub async fn list_push_front(
+        &self,
+        cache_name: impl Into<String>,
+        list_name: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<ListPushFrontResponse> {
+        self.client
+            .list_push_front(cache_name, self.key(list_name), value)
+            .await
+    }
+
+    /// Lower-level API to send a request with the namespace applied. This is used for cases when
+    /// you want to set optional fields on a request, such as a TTL, that are not supported by the
+    /// short-hand API for that request type.
+    ///
+    /// # Example
+    /// Assumes that a CacheClient named `cache_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_cache_client;
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use momento::cache::{CollectionTtl, ListPushBackRequest, SetRequest};
+    /// # let (cache_client, cache_name) = create_doctest_cache_client();
+    ///
+    /// let tenant = cache_client.namespaced("tenant-a:");
+    /// tenant
+    ///     .send_request(SetRequest::new(&cache_name, "key", "value").ttl(Duration::from_secs(60)))
+    ///     .await?;
+    /// tenant
+    ///     .send_request(
+    ///         ListPushBackRequest::new(&cache_name, "list", "value")
+    ///             .ttl(CollectionTtl::refresh_on_update(Duration::from_secs(60))),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn send_request<R: NamespacedRequest>(
+        &self,
+        request: R,
+    ) -> MomentoResult<<R::Prefixed as MomentoRequest>::Response> {
+        let response = request
+            .apply_namespace(&self.prefix)
+            .send(&self.client)
+            .await?;
+        Ok(R::strip_namespace(response, &self.prefix))
+    }
+
+    /* helper fns */
+    pub(crate) fn new(client: CacheClient, prefix: impl Into<String>) -> Self {
+        Self {
+            client,
+            prefix: prefix.into(),
+        }
+    }
+
+    fn key(&self, key: impl IntoBytes) -> Vec<u8> {
+        prefixed_key(&self.prefix, key)
+    }
+
+    fn keys(&self, keys: impl IntoBytesIterable) -> Vec<Vec<u8>> {
+        prefixed_keys(&self.prefix, keys)
+    }
+
+    fn sources<Z: IntoBytes>(
+        &self,
+        sources: impl IntoSortedSetUnionStoreSources<Z>,
+    ) -> Vec<SortedSetUnionStoreSource<Vec<u8>>> {
+        sources
+            .into_sorted_set_union_store_sources()
+            .into_iter()
+            .map(|source| {
+                SortedSetUnionStoreSource::new(self.key(source.sorted_set_name), source.weight)
+            })
+            .collect()
+    }
+}
+
+/// A request that [NamespacedCacheClient::send_request] can send with the namespace applied.
+///
+/// This is implemented for every data plane request type. Scalar keys, collection names and the
+/// keys of batch requests are prefixed, and the keys in batch responses are returned with the
+/// prefix removed. Collection fields and values are not prefixed.
+pub trait NamespacedRequest {
+    /// The request with the namespace applied.
+    type Prefixed: MomentoRequest;
+
+    /// Prefixes the keys of the request with the namespace.
+    #[doc(hidden)]
+    fn apply_namespace(self, prefix: &str) -> Self::Prefixed;
+
+    /// Removes the namespace from any keys in the response.
+    #[doc(hidden)]
+    fn strip_namespace(
+        response: <Self::Prefixed as MomentoRequest>::Response,
+        _prefix: &str,
+    ) -> <Self::Prefixed as MomentoRequest>::Response {
+        response
+    }
+}
+
+pub(crate) fn prefixed_key(prefix: &str, key: impl IntoBytes) -> Vec<u8> {
+    let key = key.into_bytes();
+    let mut prefixed = Vec::with_capacity(prefix.len() + key.len());
+    prefixed.extend_from_slice(prefix.as_bytes());
+    prefixed.extend_from_slice(&key);
+    prefixed
+}
+
+pub(crate) fn prefixed_keys(prefix: &str, keys: impl IntoBytesIterable) -> Vec<Vec<u8>> {
+    keys.into_bytes()
+        .into_iter()
+        .map(|key| prefixed_key(prefix, key))
+        .collect()
+}
@@ -0,0 +1,208 @@
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use time::OffsetDateTime;
+use tower_sessions_core::session::{Id, Record};
+use tower_sessions_core::session_store::{Error, Result};
+use tower_sessions_core::SessionStore;
+
+use crate::cache::{GetResponse, MomentoRequest, SetIfAbsentRequest, SetIfAbsentResponse};
+use crate::cache::{SetRequest, UpdateTtlResponse};
+use crate::{CacheClient, MomentoError};
+
+/// A [tower-sessions](https://docs.rs/tower-sessions) `SessionStore` which keeps sessions in a
+/// Momento cache, so web applications built on axum or tower can use Momento as their session
+/// backend.
+///
+/// Each session record is serialized as JSON and stored under `<key prefix><session id>` with a
+/// TTL matching the record's expiry date, so expired sessions are evicted by the server. Deleting
+/// a session, e.g. when the user logs out, deletes the item.
+///
+/// By default sessions are only written when tower-sessions saves them. With
+/// [CacheSessionStore::idle_timeout], loading a session also extends its TTL with `update_ttl`,
+/// so active sessions stay alive without saving the whole record on every request. The TTL
+/// never extends past the record's expiry date.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::cache::CacheSessionStore;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let session_store = CacheSessionStore::new(cache_client, &cache_name)
+///     .key_prefix("my-app:session:")
+///     .idle_timeout(Duration::from_secs(30 * 60));
+/// let session_layer = tower_sessions::SessionManagerLayer::new(session_store);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CacheSessionStore {
+    client: CacheClient,
+    cache_name: String,
+    key_prefix: String,
+    idle_timeout: Option<Duration>,
+}
+
+impl CacheSessionStore {
+    /// Constructs a session store which keeps sessions in the given cache.
+    pub fn new(client: CacheClient, cache_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            cache_name: cache_name.into(),
+            key_prefix: "session:".to_string(),
+            idle_timeout: None,
+        }
+    }
+
+    /// Sets the prefix of the keys sessions are stored under. Defaults to `session:`.
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+        self
+    }
+
+    /// Expire sessions which have not been loaded for the given duration. Each load resets the
+    /// session's TTL to this duration, or to the time left until the record's expiry date if
+    /// that is sooner.
+    ///
+    /// Use this instead of `SessionManagerLayer::with_always_save` to keep sessions alive
+    /// without rewriting them on every request.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// The underlying [CacheClient].
+    pub fn client(&self) -> &CacheClient {
+        &self.client
+    }
+
+    fn key(&self, session_id: &Id) -> String {
+        format!("{}{}", self.key_prefix, session_id)
+    }
+
+    /// The TTL to store a record with, or `None` if it has already expired.
+    fn ttl(&self, record: &Record) -> Option<Duration> {
+        let remaining = remaining(record.expiry_date)?;
+        Some(match self.idle_timeout {
+            Some(idle_timeout) => idle_timeout.min(remaining),
+            None => remaining,
+        })
+    }
+}
+
+#[async_trait]
+impl SessionStore for CacheSessionStore {
+    async fn create(&self, record: &mut Record) -> Result<()> {
+        loop {
+            let ttl = match self.ttl(record) {
+                Some(ttl) => ttl,
+                None => return Ok(()),
+            };
+            let value = encode(record)?;
+            let response = SetIfAbsentRequest::new(&self.cache_name, self.key(&record.id), value)
+                .ttl(ttl)
+                .send(&self.client)
+                .await
+                .map_err(backend)?;
+            match response {
+                SetIfAbsentResponse::Stored => return Ok(()),
+                SetIfAbsentResponse::NotStored => record.id = Id::default(),
+            }
+        }
+    }
+
+    async fn save(&self, record: &Record) -> Result<()> {
+        let key = self.key(&record.id);
+        match self.ttl(record) {
+            Some(ttl) => {
+                SetRequest::new(&self.cache_name, key, encode(record)?)
+                    .ttl(ttl)
+                    .send(&self.client)
+                    .await
+                    .map_err(backend)?;
+            }
+            None => {
+                self.client
+                    .delete(&self.cache_name, key)
+                    .await
+                    .map_err(backend)?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn load(&self, session_id: &Id) -> Result<Option<Record>> {
+        let key = self.key(session_id);
+        let value = match self
+            .client
+            .get(&self.cache_name, key.as_str())
+            .await
+            .map_err(backend)?
+        {
+            GetResponse::Hit { value } => value,
+            GetResponse::Miss => return Ok(None),
+        };
+        let record: Record = serde_json::from_slice(&value.raw_item)
+            .map_err(|error| Error::Decode(error.to_string()))?;
+
+        let ttl = match self.ttl(&record) {
+            Some(ttl) => ttl,
+            None => return Ok(None),
+        };
+        if self.idle_timeout.is_some() {
+            match self
+                .client
+                .update_ttl(&self.cache_name, key, ttl)
+                .await
+                .map_err(backend)?
+            {
+                UpdateTtlResponse::Set => {}
+                UpdateTtlResponse::Miss => return Ok(None),
+            }
+        }
+        Ok(Some(record))
+    }
+
+    async fn delete(&self, session_id: &Id) -> Result<()> {
+        self.client
+            .delete(&self.cache_name, self.key(session_id))
+            .await
+            .map_err(backend)?;
+        Ok(())
+    }
+}
+
+/// The time left until `expiry_date`, or `None` if it has passed.
+fn remaining(expiry_date: OffsetDateTime) -> Option<Duration> {
+    Duration::try_from(expiry_date - OffsetDateTime::now_utc())
+        .ok()
+        .filter(|remaining| !remaining.is_zero())
+}
+
+fn encode(record: &Record) -> Result<Vec<u8>> {
+    serde_json::to_vec(record).map_err(|error| Error::Encode(error.to_string()))
+}
+
+fn backend(error: MomentoError) -> Error {
+    Error::Backend(error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remaining_is_none_once_expired() {
+        let now = OffsetDateTime::now_utc();
+        assert_eq!(remaining(now - time::Duration::seconds(1)), None);
+
+        let ttl = remaining(now + time::Duration::minutes(5)).expect("should not be expired");
+        assert!(ttl > Duration::from_secs(4 * 60) && ttl <= Duration::from_secs(5 * 60));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::cache::{
+    Cache, CollectionTtl, DeleteRequest, DeleteResponse, MomentoRequest, SetAddElementsRequest,
+    SetAddElementsResponse, SetFetchRequest, SetFetchResponse, SetRemoveElementsRequest,
+    SetRemoveElementsResponse,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{IntoBytes, MomentoResult};
+
+/// Represents a set in a cache.
+///
+/// Obtain a `Set` with [Cache::set_collection]. Every write uses the handle's [CollectionTtl],
+/// which defaults to the TTL of the [Cache] it was created from.
+#[derive(Clone, Debug)]
+pub struct Set {
+    cache: Cache,
+    set_name: Vec<u8>,
+    collection_ttl: CollectionTtl,
+}
+
+impl Set {
+    /// The name of the set.
+    pub fn name(&self) -> &[u8] {
+        &self.set_name
+    }
+
+    /// The cache containing the set.
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// The [CollectionTtl] used when writing to the set.
+    pub fn collection_ttl(&self) -> CollectionTtl {
+        self.collection_ttl
+    }
+
+    /// Return a new handle which writes to the set with the provided [CollectionTtl].
+    pub fn with_ttl(mut self, collection_ttl: CollectionTtl) -> Self {
+        self.collection_ttl = collection_ttl;
+        self
+    }
+
+    /// Deletes the set.
+    pub async fn delete(&self) -> MomentoResult<DeleteResponse> {
+        DeleteRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds elements to the set.
+    /// See [CacheClient::set_add_elements](crate::CacheClient::set_add_elements).
+    pub async fn add_elements<E: IntoBytesIterable>(
+        &self,
+        elements: E,
+    ) -> MomentoResult<SetAddElementsResponse> {
+        SetAddElementsRequest::new(self.cache.name(), self.name(), elements)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Fetches all elements of the set.
+    /// See [CacheClient::set_fetch](crate::CacheClient::set_fetch).
+    pub async fn fetch(&self) -> MomentoResult<SetFetchResponse> {
+        SetFetchRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Removes elements from the set.
+    /// See [CacheClient::set_remove_elements](crate::CacheClient::set_remove_elements).
+    pub async fn remove_elements<E: IntoBytes>(
+        &self,
+        elements: Vec<E>,
+    ) -> MomentoResult<SetRemoveElementsResponse> {
+        SetRemoveElementsRequest::new(self.cache.name(), self.name(), elements)
+            .send(self.cache.client())
+            .await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(cache: Cache, set_name: Vec<u8>) -> Self {
+        let collection_ttl = cache.collection_ttl();
+        Self {
+            cache,
+            set_name,
+            collection_ttl,
+        }
+    }
+}
@@ -0,0 +1,450 @@
+use std::collections::{BTreeMap, HashMap};
+
+use futures::future::try_join_all;
+
+use crate::cache::{
+    DeleteResponse, GetBatchResponse, GetResponse, IncrementResponse, KeyExistsResponse,
+    SetBatchResponse, SetIfAbsentResponse, SetResponse,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{CacheClient, IntoBytes, MomentoError, MomentoErrorCode, MomentoResult};
+
+const DEFAULT_VIRTUAL_NODES: u32 = 160;
+
+/// One of the caches a [ShardedCacheClient] spreads keys across.
+///
+/// Each shard has a client, which may belong to a different account, and the name of the cache
+/// to use with it. The `id` identifies the shard on the hash ring, so it must be unique and
+/// should stay the same across deployments; changing it moves the shard's keys.
+#[derive(Clone, Debug)]
+pub struct CacheShard {
+    id: String,
+    client: CacheClient,
+    cache_name: String,
+}
+
+impl CacheShard {
+    /// Constructs a new CacheShard.
+    pub fn new(id: impl Into<String>, client: CacheClient, cache_name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            client,
+            cache_name: cache_name.into(),
+        }
+    }
+
+    /// The id of the shard on the hash ring.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// The client used to reach the shard's cache.
+    pub fn client(&self) -> &CacheClient {
+        &self.client
+    }
+
+    /// The name of the shard's cache.
+    pub fn cache_name(&self) -> &str {
+        &self.cache_name
+    }
+}
+
+/// A key which maps to a different shard after a shard was added.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MovedKey {
+    /// The key.
+    pub key: Vec<u8>,
+    /// The id of the shard the key used to map to.
+    pub from: String,
+    /// The id of the shard the key maps to now.
+    pub to: String,
+}
+
+/// Lists the keys which moved to a different shard when a shard was added to a
+/// [ShardedCacheClient].
+///
+/// Items are not copied between shards, so moved keys read as misses until they are written
+/// again. Use the report to warm the new shard or to delete stale copies from the old ones.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RebalanceReport {
+    moved: Vec<MovedKey>,
+}
+
+impl RebalanceReport {
+    /// The keys which moved, in the order they were passed to
+    /// [ShardedCacheClient::add_shard].
+    pub fn moved_keys(&self) -> &[MovedKey] {
+        &self.moved
+    }
+
+    /// Returns true if no keys moved.
+    pub fn is_empty(&self) -> bool {
+        self.moved.is_empty()
+    }
+}
+
+impl From<RebalanceReport> for Vec<MovedKey> {
+    fn from(report: RebalanceReport) -> Self {
+        report.moved
+    }
+}
+
+/// A client which spreads keys across several caches, possibly in several accounts, to get past
+/// per-cache throughput limits.
+///
+/// Keys are assigned to shards with a consistent-hash ring. Each shard is placed on the ring many
+/// times ("virtual nodes") so keys are spread evenly, and adding a shard only moves the keys
+/// which now belong to it.
+///
+/// The scalar operations are provided directly and take no cache name, since each shard has its
+/// own. `get_batch` and `set_batch` are split into one request per shard, sent concurrently, and
+/// their results merged. For any other operation use [ShardedCacheClient::shard_for] to find the
+/// shard which owns a key or collection.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::cache::{CacheShard, ShardedCacheClient};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+/// # let (cache_name_1, cache_name_2) = (cache_name.clone(), cache_name.clone());
+///
+/// let sharded_client = ShardedCacheClient::new(vec![
+///     CacheShard::new("shard-1", cache_client.clone(), cache_name_1),
+///     CacheShard::new("shard-2", cache_client.clone(), cache_name_2),
+/// ])?;
+///
+/// sharded_client.set("key", "value").await?;
+/// let item: String = sharded_client.get("key").await?.try_into()?;
+/// assert_eq!(item, "value");
+///
+/// let shard = sharded_client.shard_for("my-list");
+/// shard.client().list_push_back(shard.cache_name(), "my-list", "element").await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShardedCacheClient {
+    shards: Vec<CacheShard>,
+    ring: HashRing,
+}
+
+impl ShardedCacheClient {
+    /// Constructs a ShardedCacheClient with 160 virtual nodes per shard.
+    ///
+    /// Returns an error if `shards` is empty or contains duplicate ids.
+    pub fn new(shards: Vec<CacheShard>) -> MomentoResult<Self> {
+        if shards.is_empty() {
+            return Err(invalid_argument(
+                "a sharded cache client needs at least one shard",
+            ));
+        }
+        let mut client = Self {
+            shards: Vec::with_capacity(shards.len()),
+            ring: HashRing::new(DEFAULT_VIRTUAL_NODES),
+        };
+        for shard in shards {
+            client.insert(shard)?;
+        }
+        Ok(client)
+    }
+
+    /// Return a new client which places each shard on the ring `virtual_nodes` times. More
+    /// virtual nodes spread keys more evenly at the cost of a larger ring.
+    ///
+    /// Changing the number of virtual nodes moves keys between shards.
+    pub fn with_virtual_nodes(mut self, virtual_nodes: u32) -> Self {
+        self.ring = HashRing::new(virtual_nodes);
+        for shard in &self.shards {
+            self.ring.add(&shard.id);
+        }
+        self
+    }
+
+    /// The shards, in the order they were added.
+    pub fn shards(&self) -> &[CacheShard] {
+        &self.shards
+    }
+
+    /// The number of virtual nodes per shard.
+    pub fn virtual_nodes(&self) -> u32 {
+        self.ring.virtual_nodes
+    }
+
+    /// The shard which owns `key`.
+    pub fn shard_for(&self, key: impl IntoBytes) -> &CacheShard {
+        &self.shards[self.ring.shard_index(&key.into_bytes())]
+    }
+
+    /// Adds a shard to the ring and reports which of `keys` now map to it.
+    ///
+    /// The client cannot list the keys in a cache, so pass the keys you want checked, such as
+    /// your hot keys or the keys of a known data set.
+    ///
+    /// Returns an error if a shard with the same id is already on the ring.
+    pub fn add_shard(
+        &mut self,
+        shard: CacheShard,
+        keys: impl IntoBytesIterable,
+    ) -> MomentoResult<RebalanceReport> {
+        let keys = keys.into_bytes();
+        let before: Vec<usize> = keys.iter().map(|key| self.ring.shard_index(key)).collect();
+        self.insert(shard)?;
+
+        let moved = keys
+            .into_iter()
+            .zip(before)
+            .filter_map(|(key, from)| {
+                let to = self.ring.shard_index(&key);
+                (to != from).then(|| MovedKey {
+                    key,
+                    from: self.shards[from].id.clone(),
+                    to: self.shards[to].id.clone(),
+                })
+            })
+            .collect();
+        Ok(RebalanceReport { moved })
+    }
+
+    /// Calls [CacheClient::get] on the shard which owns `key`.
+    pub async fn get(&self, key: impl IntoBytes) -> MomentoResult<GetResponse> {
+        let key = key.into_bytes();
+        let shard = self.shard_for(key.as_slice());
+        shard.client.get(shard.cache_name.as_str(), key).await
+    }
+
+    /// Calls [CacheClient::set] on the shard which owns `key`.
+    pub async fn set(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetResponse> {
+        let key = key.into_bytes();
+        let shard = self.shard_for(key.as_slice());
+        shard
+            .client
+            .set(shard.cache_name.as_str(), key, value)
+            .await
+    }
+
+    /// Calls [CacheClient::delete] on the shard which owns `key`.
+    pub async fn delete(&self, key: impl IntoBytes) -> MomentoResult<DeleteResponse> {
+        let key = key.into_bytes();
+        let shard = self.shard_for(key.as_slice());
+        shard.client.delete(shard.cache_name.as_str(), key).await
+    }
+
+    /// Calls [CacheClient::increment] on the shard which owns `key`.
+    pub async fn increment(
+        &self,
+        key: impl IntoBytes,
+        amount: i64,
+    ) -> MomentoResult<IncrementResponse> {
+        let key = key.into_bytes();
+        let shard = self.shard_for(key.as_slice());
+        shard
+            .client
+            .increment(shard.cache_name.as_str(), key, amount)
+            .await
+    }
+
+    /// Calls [CacheClient::key_exists] on the shard which owns `key`.
+    pub async fn key_exists(&self, key: impl IntoBytes) -> MomentoResult<KeyExistsResponse> {
+        let key = key.into_bytes();
+        let shard = self.shard_for(key.as_slice());
+        shard
+            .client
+            .key_exists(shard.cache_name.as_str(), key)
+            .await
+    }
+
+    /// Calls [CacheClient::set_if_absent] on the shard which owns `key`.
+    pub async fn set_if_absent(
+        &self,
+        key: impl IntoBytes,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SetIfAbsentResponse> {
+        let key = key.into_bytes();
+        let shard = self.shard_for(key.as_slice());
+        shard
+            .client
+            .set_if_absent(shard.cache_name.as_str(), key, value)
+            .await
+    }
+
+    /// Gets a batch of items, sending one [CacheClient::get_batch] request to each shard which
+    /// owns some of the keys and merging the results.
+    pub async fn get_batch(&self, keys: impl IntoBytesIterable) -> MomentoResult<GetBatchResponse> {
+        let mut keys_by_shard: HashMap<usize, Vec<Vec<u8>>> = HashMap::new();
+        for key in keys.into_bytes() {
+            keys_by_shard
+                .entry(self.ring.shard_index(&key))
+                .or_default()
+                .push(key);
+        }
+        let responses = try_join_all(keys_by_shard.into_iter().map(|(index, keys)| {
+            let shard = &self.shards[index];
+            shard.client.get_batch(shard.cache_name.as_str(), keys)
+        }))
+        .await?;
+        Ok(GetBatchResponse::merge(responses))
+    }
+
+    /// Sets a batch of items, sending one [CacheClient::set_batch] request to each shard which
+    /// owns some of the keys and merging the results.
+    pub async fn set_batch<K: IntoBytes, V: IntoBytes>(
+        &self,
+        items: impl IntoIterator<Item = (K, V)>,
+    ) -> MomentoResult<SetBatchResponse> {
+        let mut items_by_shard: HashMap<usize, Vec<(Vec<u8>, V)>> = HashMap::new();
+        for (key, value) in items {
+            let key = key.into_bytes();
+            items_by_shard
+                .entry(self.ring.shard_index(&key))
+                .or_default()
+                .push((key, value));
+        }
+        let responses = try_join_all(items_by_shard.into_iter().map(|(index, items)| {
+            let shard = &self.shards[index];
+            shard.client.set_batch(shard.cache_name.as_str(), items)
+        }))
+        .await?;
+        Ok(SetBatchResponse::merge(responses))
+    }
+
+    /* helper fns */
+    fn insert(&mut self, shard: CacheShard) -> MomentoResult<()> {
+        if self.shards.iter().any(|existing| existing.id == shard.id) {
+            return Err(invalid_argument(format!(
+                "shard id {} is already on the hash ring",
+                shard.id
+            )));
+        }
+        self.ring.add(&shard.id);
+        self.shards.push(shard);
+        Ok(())
+    }
+}
+
+/// A consistent-hash ring mapping keys to shard indexes.
+#[derive(Clone, Debug)]
+struct HashRing {
+    virtual_nodes: u32,
+    shard_count: usize,
+    points: BTreeMap<u64, usize>,
+}
+
+impl HashRing {
+    fn new(virtual_nodes: u32) -> Self {
+        Self {
+            virtual_nodes: virtual_nodes.max(1),
+            shard_count: 0,
+            points: BTreeMap::new(),
+        }
+    }
+
+    /// Place the next shard on the ring. Its index is the number of shards added before it.
+    fn add(&mut self, id: &str) {
+        for node in 0..self.virtual_nodes {
+            let point = hash(format!("{}#{}", id, node).as_bytes());
+            self.points.insert(point, self.shard_count);
+        }
+        self.shard_count += 1;
+    }
+
+    /// The index of the shard owning the first point at or after the key's hash.
+    fn shard_index(&self, key: &[u8]) -> usize {
+        let point = hash(key);
+        self.points
+            .range(point..)
+            .next()
+            .or_else(|| self.points.iter().next())
+            .map(|(_, index)| *index)
+            .unwrap_or_default()
+    }
+}
+
+/// A stable 64-bit hash: FNV-1a, followed by the MurmurHash3 finalizer so that similar inputs
+/// such as consecutive virtual node names land far apart on the ring.
+fn hash(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in bytes {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    hash ^ (hash >> 33)
+}
+
+fn invalid_argument(message: impl Into<String>) -> MomentoError {
+    MomentoError {
+        message: message.into(),
+        error_code: MomentoErrorCode::InvalidArgumentError,
+        inner_error: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_is_stable() {
+        assert_eq!(hash(b"taco"), hash(b"taco"));
+        assert_ne!(hash(b"taco"), hash(b"burrito"));
+    }
+
+    fn ring(shards: usize) -> HashRing {
+        let mut ring = HashRing::new(DEFAULT_VIRTUAL_NODES);
+        for shard in 0..shards {
+            ring.add(&format!("shard-{}", shard));
+        }
+        ring
+    }
+
+    fn keys() -> Vec<Vec<u8>> {
+        (0..10_000)
+            .map(|key| format!("key-{}", key).into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn test_keys_are_spread_evenly() {
+        let ring = ring(4);
+        let mut counts = [0; 4];
+        for key in keys() {
+            counts[ring.shard_index(&key)] += 1;
+        }
+        for count in counts {
+            assert!((1_800..3_200).contains(&count), "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn test_adding_a_shard_only_moves_keys_to_it() {
+        let before = ring(4);
+        let after = ring(5);
+
+        let mut moved = 0;
+        for key in keys() {
+            let (from, to) = (before.shard_index(&key), after.shard_index(&key));
+            if from != to {
+                assert_eq!(to, 4);
+                moved += 1;
+            }
+        }
+        assert!((1_400..2_600).contains(&moved), "{}", moved);
+    }
+
+    #[test]
+    fn test_single_shard_owns_everything() {
+        let ring = ring(1);
+        assert!(keys().iter().all(|key| ring.shard_index(key) == 0));
+    }
+}
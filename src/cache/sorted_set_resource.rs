@@ -0,0 +1,200 @@
+use crate::cache::{
+    Cache, CollectionTtl, DeleteRequest, DeleteResponse, IntoSortedSetElements,
+    IntoSortedSetUnionStoreSources, MomentoRequest, SortedSetFetchByRankRequest,
+    SortedSetFetchByScoreRequest, SortedSetFetchResponse, SortedSetGetRankRequest,
+    SortedSetGetRankResponse, SortedSetGetScoreRequest, SortedSetGetScoreResponse,
+    SortedSetGetScoresRequest, SortedSetGetScoresResponse, SortedSetIncrementScoreRequest,
+    SortedSetIncrementScoreResponse, SortedSetLengthByScoreRequest, SortedSetLengthByScoreResponse,
+    SortedSetLengthRequest, SortedSetLengthResponse, SortedSetOrder, SortedSetPutElementRequest,
+    SortedSetPutElementResponse, SortedSetPutElementsRequest, SortedSetPutElementsResponse,
+    SortedSetRemoveElementsRequest, SortedSetRemoveElementsResponse, SortedSetUnionStoreRequest,
+    SortedSetUnionStoreResponse,
+};
+use crate::utils::IntoBytesIterable;
+use crate::{IntoBytes, MomentoResult};
+
+/// Represents a sorted set in a cache.
+///
+/// Obtain a `SortedSet` with [Cache::sorted_set]. Every write uses the handle's
+/// [CollectionTtl], which defaults to the TTL of the [Cache] it was created from.
+#[derive(Clone, Debug)]
+pub struct SortedSet {
+    cache: Cache,
+    sorted_set_name: Vec<u8>,
+    collection_ttl: CollectionTtl,
+}
+
+impl SortedSet {
+    /// The name of the sorted set.
+    pub fn name(&self) -> &[u8] {
+        &self.sorted_set_name
+    }
+
+    /// The cache containing the sorted set.
+    pub fn cache(&self) -> &Cache {
+        &self.cache
+    }
+
+    /// The [CollectionTtl] used when writing to the sorted set.
+    pub fn collection_ttl(&self) -> CollectionTtl {
+        self.collection_ttl
+    }
+
+    /// Return a new handle which writes to the sorted set with the provided [CollectionTtl].
+    pub fn with_ttl(mut self, collection_ttl: CollectionTtl) -> Self {
+        self.collection_ttl = collection_ttl;
+        self
+    }
+
+    /// Deletes the sorted set.
+    pub async fn delete(&self) -> MomentoResult<DeleteResponse> {
+        DeleteRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds an element to the sorted set, or updates its score.
+    /// See [CacheClient::sorted_set_put_element](crate::CacheClient::sorted_set_put_element).
+    pub async fn put_element(
+        &self,
+        value: impl IntoBytes,
+        score: f64,
+    ) -> MomentoResult<SortedSetPutElementResponse> {
+        SortedSetPutElementRequest::new(self.cache.name(), self.name(), value, score)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Adds elements to the sorted set, or updates their scores.
+    /// See [CacheClient::sorted_set_put_elements](crate::CacheClient::sorted_set_put_elements).
+    pub async fn put_elements<V: IntoBytes>(
+        &self,
+        elements: impl IntoSortedSetElements<V>,
+    ) -> MomentoResult<SortedSetPutElementsResponse> {
+        SortedSetPutElementsRequest::new(self.cache.name(), self.name(), elements)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Fetches elements of the sorted set by rank.
+    /// See [CacheClient::sorted_set_fetch_by_rank](crate::CacheClient::sorted_set_fetch_by_rank).
+    pub async fn fetch_by_rank(
+        &self,
+        order: SortedSetOrder,
+        start_rank: Option<i32>,
+        end_rank: Option<i32>,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        SortedSetFetchByRankRequest::new(self.cache.name(), self.name())
+            .order(order)
+            .start_rank(start_rank)
+            .end_rank(end_rank)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Fetches elements of the sorted set by score.
+    /// See [CacheClient::sorted_set_fetch_by_score](crate::CacheClient::sorted_set_fetch_by_score).
+    pub async fn fetch_by_score(
+        &self,
+        order: SortedSetOrder,
+    ) -> MomentoResult<SortedSetFetchResponse> {
+        SortedSetFetchByScoreRequest::new(self.cache.name(), self.name())
+            .order(order)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Removes elements from the sorted set.
+    /// See [CacheClient::sorted_set_remove_elements](crate::CacheClient::sorted_set_remove_elements).
+    pub async fn remove_elements<V: IntoBytesIterable>(
+        &self,
+        values: V,
+    ) -> MomentoResult<SortedSetRemoveElementsResponse> {
+        SortedSetRemoveElementsRequest::new(self.cache.name(), self.name(), values)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets the number of elements in the sorted set.
+    /// See [CacheClient::sorted_set_length](crate::CacheClient::sorted_set_length).
+    pub async fn length(&self) -> MomentoResult<SortedSetLengthResponse> {
+        SortedSetLengthRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets the number of elements in the sorted set, optionally within a score range.
+    /// See [CacheClient::sorted_set_length_by_score](crate::CacheClient::sorted_set_length_by_score).
+    pub async fn length_by_score(&self) -> MomentoResult<SortedSetLengthByScoreResponse> {
+        SortedSetLengthByScoreRequest::new(self.cache.name(), self.name())
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets the rank of an element in the sorted set.
+    /// See [CacheClient::sorted_set_get_rank](crate::CacheClient::sorted_set_get_rank).
+    pub async fn get_rank(&self, value: impl IntoBytes) -> MomentoResult<SortedSetGetRankResponse> {
+        SortedSetGetRankRequest::new(self.cache.name(), self.name(), value)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets the score of an element in the sorted set.
+    /// See [CacheClient::sorted_set_get_score](crate::CacheClient::sorted_set_get_score).
+    pub async fn get_score(
+        &self,
+        value: impl IntoBytes,
+    ) -> MomentoResult<SortedSetGetScoreResponse> {
+        SortedSetGetScoreRequest::new(self.cache.name(), self.name(), value)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Gets the scores of multiple elements in the sorted set.
+    /// See [CacheClient::sorted_set_get_scores](crate::CacheClient::sorted_set_get_scores).
+    pub async fn get_scores<F: IntoBytesIterable + Clone>(
+        &self,
+        values: F,
+    ) -> MomentoResult<SortedSetGetScoresResponse<F>> {
+        SortedSetGetScoresRequest::new(self.cache.name(), self.name(), values)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Increments the score of an element in the sorted set.
+    /// See [CacheClient::sorted_set_increment_score](crate::CacheClient::sorted_set_increment_score).
+    pub async fn increment_score(
+        &self,
+        value: impl IntoBytes,
+        amount: f64,
+    ) -> MomentoResult<SortedSetIncrementScoreResponse> {
+        SortedSetIncrementScoreRequest::new(self.cache.name(), self.name(), value, amount)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /// Stores the union of the given sorted sets in this sorted set.
+    /// See [CacheClient::sorted_set_union_store](crate::CacheClient::sorted_set_union_store).
+    pub async fn union_store<Z: IntoBytes>(
+        &self,
+        sources: impl IntoSortedSetUnionStoreSources<Z>,
+    ) -> MomentoResult<SortedSetUnionStoreResponse> {
+        SortedSetUnionStoreRequest::new(self.cache.name(), self.name(), sources)
+            .ttl(self.collection_ttl)
+            .send(self.cache.client())
+            .await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(cache: Cache, sorted_set_name: Vec<u8>) -> Self {
+        let collection_ttl = cache.collection_ttl();
+        Self {
+            cache,
+            sorted_set_name,
+            collection_ttl,
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::cache::{Dictionary, DictionaryFetchResponse, DictionaryGetFieldResponse};
+use crate::codec::{codec_error, with_context};
+use crate::utils::parse_string;
+use crate::{MomentoError, MomentoErrorCode, MomentoResult};
+
+/// A [Dictionary] which stores a serde struct of type `T`, one dictionary field per struct field.
+///
+/// Each field of `T` is stored in its own dictionary field, with the field value encoded as
+/// JSON. Individual fields can be read and written with [TypedDictionary::get_field] and
+/// [TypedDictionary::set_field] without fetching the whole struct. Decoding errors report the
+/// name of the field that failed.
+///
+/// `T` must serialize to a map, such as a struct with named fields.
+///
+/// # Example
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use momento::cache::TypedDictionary;
+/// use serde::{Deserialize, Serialize};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Profile {
+///     name: String,
+///     visits: u32,
+/// }
+///
+/// let profile: TypedDictionary<Profile> =
+///     TypedDictionary::new(cache_client.cache(&cache_name).dictionary("profile"));
+/// profile.set(&Profile { name: "alice".to_string(), visits: 1 }).await?;
+/// profile.set_field("visits", &2).await?;
+///
+/// let fetched = profile.fetch().await?;
+/// assert_eq!(fetched, Some(Profile { name: "alice".to_string(), visits: 2 }));
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct TypedDictionary<T> {
+    dictionary: Dictionary,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T: Serialize + DeserializeOwned> TypedDictionary<T> {
+    /// Constructs a new TypedDictionary.
+    pub fn new(dictionary: Dictionary) -> Self {
+        Self {
+            dictionary,
+            _value: PhantomData,
+        }
+    }
+
+    /// The underlying [Dictionary].
+    pub fn dictionary(&self) -> &Dictionary {
+        &self.dictionary
+    }
+
+    /// Stores every field of `value` in the dictionary.
+    ///
+    /// Fields already in the dictionary which are not fields of `value` are left unchanged.
+    pub async fn set(&self, value: &T) -> MomentoResult<()> {
+        let fields = encode_fields(value)?;
+        self.dictionary.set_fields(fields).await?;
+        Ok(())
+    }
+
+    /// Fetches and decodes the dictionary. Returns `None` if the dictionary does not exist.
+    pub async fn fetch(&self) -> MomentoResult<Option<T>> {
+        let raw_fields = match self.dictionary.fetch().await? {
+            DictionaryFetchResponse::Hit { value } => value.raw_item,
+            DictionaryFetchResponse::Miss => return Ok(None),
+        };
+        decode_fields(raw_fields).map(Some)
+    }
+
+    /// Stores a single field of the dictionary.
+    pub async fn set_field<V: Serialize>(
+        &self,
+        field: impl Into<String>,
+        value: &V,
+    ) -> MomentoResult<()> {
+        let field = field.into();
+        let value = serde_json::to_vec(value)
+            .map_err(|e| codec_error(format!("failed to encode dictionary field `{field}`"), e))?;
+        self.dictionary.set_field(field, value).await?;
+        Ok(())
+    }
+
+    /// Fetches and decodes a single field of the dictionary. Returns `None` if the field or
+    /// dictionary does not exist.
+    pub async fn get_field<V: DeserializeOwned>(
+        &self,
+        field: impl Into<String>,
+    ) -> MomentoResult<Option<V>> {
+        let field = field.into();
+        match self.dictionary.get_field(field.as_str()).await? {
+            DictionaryGetFieldResponse::Hit { value } => {
+                let value: Vec<u8> = value.into();
+                serde_json::from_slice(&value).map(Some).map_err(|e| {
+                    codec_error(format!("failed to decode dictionary field `{field}`"), e)
+                })
+            }
+            DictionaryGetFieldResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Deletes the dictionary.
+    pub async fn delete(&self) -> MomentoResult<()> {
+        self.dictionary.delete().await?;
+        Ok(())
+    }
+}
+
+fn encode_fields<T: Serialize>(value: &T) -> MomentoResult<Vec<(String, Vec<u8>)>> {
+    let fields = match serde_json::to_value(value)
+        .map_err(|e| codec_error("failed to encode dictionary value", e))?
+    {
+        serde_json::Value::Object(fields) => fields,
+        other => {
+            return Err(MomentoError {
+                message: format!(
+                    "dictionary value must serialize to a map, but serialized to {other}"
+                ),
+                error_code: MomentoErrorCode::InvalidArgumentError,
+                inner_error: None,
+            })
+        }
+    };
+    fields
+        .into_iter()
+        .map(|(field, value)| {
+            let value = serde_json::to_vec(&value).map_err(|e| {
+                codec_error(format!("failed to encode dictionary field `{field}`"), e)
+            })?;
+            Ok((field, value))
+        })
+        .collect()
+}
+
+fn decode_fields<T: DeserializeOwned>(raw_fields: HashMap<Vec<u8>, Vec<u8>>) -> MomentoResult<T> {
+    let mut fields = serde_json::Map::with_capacity(raw_fields.len());
+    for (field, value) in raw_fields {
+        let field = parse_string(field).map_err(|e| with_context(e, "dictionary field name"))?;
+        let value = serde_json::from_slice(&value)
+            .map_err(|e| codec_error(format!("failed to decode dictionary field `{field}`"), e))?;
+        fields.insert(field, value);
+    }
+    serde_path_to_error::deserialize(serde_json::Value::Object(fields)).map_err(|e| {
+        let path = e.path().to_string();
+        if path == "." {
+            codec_error("failed to decode dictionary", e.into_inner())
+        } else {
+            codec_error(
+                format!("failed to decode dictionary field `{path}`"),
+                e.into_inner(),
+            )
+        }
+    })
+}
+
+impl<T> Clone for TypedDictionary<T> {
+    fn clone(&self) -> Self {
+        Self {
+            dictionary: self.dictionary.clone(),
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for TypedDictionary<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedDictionary")
+            .field("dictionary", &self.dictionary)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Profile {
+        name: String,
+        visits: u32,
+    }
+
+    fn raw(fields: &[(&str, &str)]) -> HashMap<Vec<u8>, Vec<u8>> {
+        fields
+            .iter()
+            .map(|(field, value)| (field.as_bytes().to_vec(), value.as_bytes().to_vec()))
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip() -> MomentoResult<()> {
+        let profile = Profile {
+            name: "alice".to_string(),
+            visits: 3,
+        };
+        let fields = encode_fields(&profile)?;
+        assert_eq!(
+            fields,
+            vec![
+                ("name".to_string(), br#""alice""#.to_vec()),
+                ("visits".to_string(), b"3".to_vec()),
+            ]
+        );
+        let decoded: Profile = decode_fields(
+            fields
+                .into_iter()
+                .map(|(f, v)| (f.into_bytes(), v))
+                .collect(),
+        )?;
+        assert_eq!(decoded, profile);
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_requires_map() {
+        let error = encode_fields(&vec![1, 2, 3]).expect_err("a list is not a map");
+        assert_eq!(error.error_code, MomentoErrorCode::InvalidArgumentError);
+    }
+
+    #[test]
+    fn test_decode_reports_invalid_json_field() {
+        let error = decode_fields::<Profile>(raw(&[("name", "alice"), ("visits", "3")]))
+            .expect_err("name is not json");
+        assert_eq!(error.error_code, MomentoErrorCode::TypeError);
+        assert!(
+            error
+                .message
+                .starts_with("failed to decode dictionary field `name`"),
+            "{}",
+            error.message
+        );
+    }
+
+    #[test]
+    fn test_decode_reports_mismatched_field() {
+        let error =
+            decode_fields::<Profile>(raw(&[("name", r#""alice""#), ("visits", r#""many""#)]))
+                .expect_err("visits is not a number");
+        assert!(
+            error
+                .message
+                .starts_with("failed to decode dictionary field `visits`"),
+            "{}",
+            error.message
+        );
+    }
+
+    #[test]
+    fn test_decode_reports_missing_field() {
+        let error = decode_fields::<Profile>(raw(&[("name", r#""alice""#)]))
+            .expect_err("visits is missing");
+        assert!(
+            error.message.contains("missing field `visits`"),
+            "{}",
+            error.message
+        );
+    }
+}
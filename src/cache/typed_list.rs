@@ -0,0 +1,180 @@
+use std::marker::PhantomData;
+
+use crate::cache::{List, ListFetchResponse, ListPopBackResponse, ListPopFrontResponse};
+use crate::codec::{with_context, Codec, JsonCodec};
+use crate::MomentoResult;
+
+/// A [List] whose elements are values of type `T`, encoded with a [Codec].
+///
+/// Elements are encoded as JSON with [JsonCodec] unless another codec is provided with
+/// [TypedList::with_codec]. Decoding errors report the index of the element that failed.
+///
+/// # Example
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use momento::cache::TypedList;
+/// use serde::{Deserialize, Serialize};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Order {
+///     id: u32,
+///     item: String,
+/// }
+///
+/// let orders: TypedList<Order> = TypedList::new(cache_client.cache(&cache_name).list("orders"));
+/// orders.push_back(&Order { id: 1, item: "taco".to_string() }).await?;
+///
+/// let fetched = orders.fetch().await?;
+/// assert_eq!(fetched, Some(vec![Order { id: 1, item: "taco".to_string() }]));
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct TypedList<T, C = JsonCodec> {
+    list: List,
+    codec: C,
+    _element: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedList<T, JsonCodec>
+where
+    JsonCodec: Codec<T>,
+{
+    /// Constructs a new TypedList which encodes its elements as JSON.
+    pub fn new(list: List) -> Self {
+        Self::with_codec(list, JsonCodec)
+    }
+}
+
+impl<T, C: Codec<T>> TypedList<T, C> {
+    /// Constructs a new TypedList which encodes its elements with the provided codec.
+    pub fn with_codec(list: List, codec: C) -> Self {
+        Self {
+            list,
+            codec,
+            _element: PhantomData,
+        }
+    }
+
+    /// The underlying [List].
+    pub fn list(&self) -> &List {
+        &self.list
+    }
+
+    /// Adds an element to the front of the list.
+    pub async fn push_front(&self, value: &T) -> MomentoResult<()> {
+        self.list.push_front(self.codec.encode(value)?).await?;
+        Ok(())
+    }
+
+    /// Adds an element to the back of the list.
+    pub async fn push_back(&self, value: &T) -> MomentoResult<()> {
+        self.list.push_back(self.codec.encode(value)?).await?;
+        Ok(())
+    }
+
+    /// Adds multiple elements to the front of the list.
+    pub async fn concatenate_front(&self, values: &[T]) -> MomentoResult<()> {
+        self.list
+            .concatenate_front(self.encode_all(values)?)
+            .await?;
+        Ok(())
+    }
+
+    /// Adds multiple elements to the back of the list.
+    pub async fn concatenate_back(&self, values: &[T]) -> MomentoResult<()> {
+        self.list.concatenate_back(self.encode_all(values)?).await?;
+        Ok(())
+    }
+
+    /// Fetches and decodes all elements of the list. Returns `None` if the list does not exist.
+    pub async fn fetch(&self) -> MomentoResult<Option<Vec<T>>> {
+        match self.list.fetch().await? {
+            ListFetchResponse::Hit { values } => {
+                let values: Vec<Vec<u8>> = values.into();
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(index, value)| {
+                        self.codec
+                            .decode(value)
+                            .map_err(|e| with_context(e, format!("list element {index}")))
+                    })
+                    .collect::<MomentoResult<Vec<T>>>()
+                    .map(Some)
+            }
+            ListFetchResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Removes and decodes the first element of the list. Returns `None` if the list does not exist.
+    pub async fn pop_front(&self) -> MomentoResult<Option<T>> {
+        match self.list.pop_front().await? {
+            ListPopFrontResponse::Hit { value } => {
+                let value: Vec<u8> = value.into();
+                self.codec
+                    .decode(&value)
+                    .map(Some)
+                    .map_err(|e| with_context(e, "popped list element"))
+            }
+            ListPopFrontResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Removes and decodes the last element of the list. Returns `None` if the list does not exist.
+    pub async fn pop_back(&self) -> MomentoResult<Option<T>> {
+        match self.list.pop_back().await? {
+            ListPopBackResponse::Hit { value } => {
+                let value: Vec<u8> = value.into();
+                self.codec
+                    .decode(&value)
+                    .map(Some)
+                    .map_err(|e| with_context(e, "popped list element"))
+            }
+            ListPopBackResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Removes all elements equal to `value` from the list.
+    pub async fn remove_value(&self, value: &T) -> MomentoResult<()> {
+        self.list.remove_value(self.codec.encode(value)?).await?;
+        Ok(())
+    }
+
+    /// Deletes the list.
+    pub async fn delete(&self) -> MomentoResult<()> {
+        self.list.delete().await?;
+        Ok(())
+    }
+
+    /* helper fns */
+    fn encode_all(&self, values: &[T]) -> MomentoResult<Vec<Vec<u8>>> {
+        values
+            .iter()
+            .map(|value| self.codec.encode(value))
+            .collect()
+    }
+}
+
+impl<T, C: Clone> Clone for TypedList<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            list: self.list.clone(),
+            codec: self.codec.clone(),
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T, C: std::fmt::Debug> std::fmt::Debug for TypedList<T, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedList")
+            .field("list", &self.list)
+            .field("codec", &self.codec)
+            .finish()
+    }
+}
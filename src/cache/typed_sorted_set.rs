@@ -0,0 +1,197 @@
+use std::marker::PhantomData;
+
+use crate::cache::{
+    SortedSet, SortedSetFetchResponse, SortedSetGetRankResponse, SortedSetGetScoreResponse,
+    SortedSetOrder,
+};
+use crate::codec::{with_context, Codec, JsonCodec};
+use crate::MomentoResult;
+
+/// A [SortedSet] whose elements are values of type `T`, encoded with a [Codec].
+///
+/// Elements are encoded as JSON with [JsonCodec] unless another codec is provided with
+/// [TypedSortedSet::with_codec]. Because elements are compared by their encoded bytes, the codec
+/// must encode equal values to identical bytes. Decoding errors report the position of the element
+/// that failed within the fetched elements.
+///
+/// # Example
+/// Assumes that a CacheClient named `cache_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use momento::cache::{SortedSetOrder, TypedSortedSet};
+/// use serde::{Deserialize, Serialize};
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Player {
+///     name: String,
+/// }
+///
+/// let scores: TypedSortedSet<Player> =
+///     TypedSortedSet::new(cache_client.cache(&cache_name).sorted_set("scores"));
+/// scores.put_element(&Player { name: "alice".to_string() }, 10.0).await?;
+///
+/// let top = scores.fetch_by_rank(SortedSetOrder::Descending, None, Some(10)).await?;
+/// assert_eq!(top, Some(vec![(Player { name: "alice".to_string() }, 10.0)]));
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct TypedSortedSet<T, C = JsonCodec> {
+    sorted_set: SortedSet,
+    codec: C,
+    _element: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedSortedSet<T, JsonCodec>
+where
+    JsonCodec: Codec<T>,
+{
+    /// Constructs a new TypedSortedSet which encodes its elements as JSON.
+    pub fn new(sorted_set: SortedSet) -> Self {
+        Self::with_codec(sorted_set, JsonCodec)
+    }
+}
+
+impl<T, C: Codec<T>> TypedSortedSet<T, C> {
+    /// Constructs a new TypedSortedSet which encodes its elements with the provided codec.
+    pub fn with_codec(sorted_set: SortedSet, codec: C) -> Self {
+        Self {
+            sorted_set,
+            codec,
+            _element: PhantomData,
+        }
+    }
+
+    /// The underlying [SortedSet].
+    pub fn sorted_set(&self) -> &SortedSet {
+        &self.sorted_set
+    }
+
+    /// Adds an element to the sorted set, or updates its score.
+    pub async fn put_element(&self, value: &T, score: f64) -> MomentoResult<()> {
+        self.sorted_set
+            .put_element(self.codec.encode(value)?, score)
+            .await?;
+        Ok(())
+    }
+
+    /// Adds elements to the sorted set, or updates their scores.
+    pub async fn put_elements(&self, elements: &[(T, f64)]) -> MomentoResult<()> {
+        let elements = elements
+            .iter()
+            .map(|(value, score)| Ok((self.codec.encode(value)?, *score)))
+            .collect::<MomentoResult<Vec<(Vec<u8>, f64)>>>()?;
+        self.sorted_set.put_elements(elements).await?;
+        Ok(())
+    }
+
+    /// Fetches and decodes elements of the sorted set by rank. Returns `None` if the sorted set
+    /// does not exist.
+    pub async fn fetch_by_rank(
+        &self,
+        order: SortedSetOrder,
+        start_rank: Option<i32>,
+        end_rank: Option<i32>,
+    ) -> MomentoResult<Option<Vec<(T, f64)>>> {
+        let response = self
+            .sorted_set
+            .fetch_by_rank(order, start_rank, end_rank)
+            .await?;
+        self.decode_elements(response)
+    }
+
+    /// Fetches and decodes all elements of the sorted set ordered by score. Returns `None` if the
+    /// sorted set does not exist.
+    pub async fn fetch_by_score(
+        &self,
+        order: SortedSetOrder,
+    ) -> MomentoResult<Option<Vec<(T, f64)>>> {
+        let response = self.sorted_set.fetch_by_score(order).await?;
+        self.decode_elements(response)
+    }
+
+    /// Gets the score of an element. Returns `None` if the element or sorted set does not exist.
+    pub async fn get_score(&self, value: &T) -> MomentoResult<Option<f64>> {
+        match self.sorted_set.get_score(self.codec.encode(value)?).await? {
+            SortedSetGetScoreResponse::Hit { score } => Ok(Some(score)),
+            SortedSetGetScoreResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Gets the rank of an element. Returns `None` if the element or sorted set does not exist.
+    pub async fn get_rank(&self, value: &T) -> MomentoResult<Option<u64>> {
+        match self.sorted_set.get_rank(self.codec.encode(value)?).await? {
+            SortedSetGetRankResponse::Hit { rank } => Ok(Some(rank)),
+            SortedSetGetRankResponse::Miss => Ok(None),
+        }
+    }
+
+    /// Increments the score of an element and returns the new score.
+    pub async fn increment_score(&self, value: &T, amount: f64) -> MomentoResult<f64> {
+        let response = self
+            .sorted_set
+            .increment_score(self.codec.encode(value)?, amount)
+            .await?;
+        Ok(response.score)
+    }
+
+    /// Removes elements from the sorted set.
+    pub async fn remove_elements(&self, values: &[T]) -> MomentoResult<()> {
+        let values = values
+            .iter()
+            .map(|value| self.codec.encode(value))
+            .collect::<MomentoResult<Vec<Vec<u8>>>>()?;
+        self.sorted_set.remove_elements(values).await?;
+        Ok(())
+    }
+
+    /// Deletes the sorted set.
+    pub async fn delete(&self) -> MomentoResult<()> {
+        self.sorted_set.delete().await?;
+        Ok(())
+    }
+
+    /* helper fns */
+    fn decode_elements(
+        &self,
+        response: SortedSetFetchResponse,
+    ) -> MomentoResult<Option<Vec<(T, f64)>>> {
+        match response {
+            SortedSetFetchResponse::Hit { value } => value
+                .elements
+                .iter()
+                .enumerate()
+                .map(|(index, (value, score))| {
+                    self.codec
+                        .decode(value)
+                        .map(|value| (value, *score))
+                        .map_err(|e| with_context(e, format!("sorted set element {index}")))
+                })
+                .collect::<MomentoResult<Vec<(T, f64)>>>()
+                .map(Some),
+            SortedSetFetchResponse::Miss => Ok(None),
+        }
+    }
+}
+
+impl<T, C: Clone> Clone for TypedSortedSet<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            sorted_set: self.sorted_set.clone(),
+            codec: self.codec.clone(),
+            _element: PhantomData,
+        }
+    }
+}
+
+impl<T, C: std::fmt::Debug> std::fmt::Debug for TypedSortedSet<T, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedSortedSet")
+            .field("sorted_set", &self.sorted_set)
+            .field("codec", &self.codec)
+            .finish()
+    }
+}
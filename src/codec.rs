@@ -0,0 +1,167 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{ErrorSource, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// Converts values of type `T` to and from bytes.
+///
+/// Typed wrappers such as [TypedList](crate::cache::TypedList) and
+/// [TypedTopic](crate::topics::TypedTopic) use a codec to encode the values they write and decode
+/// the values they read. Besides [JsonCodec], the `bincode` and `messagepack` features provide
+/// `BincodeCodec` and `MessagePackCodec`. Implement this trait to store values in another
+/// format.
+pub trait Codec<T>: Send + Sync {
+    /// Encode a value into bytes.
+    fn encode(&self, value: &T) -> MomentoResult<Vec<u8>>;
+
+    /// Decode a value from bytes.
+    fn decode(&self, bytes: &[u8]) -> MomentoResult<T>;
+}
+
+/// A [Codec] which stores values as JSON using serde.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JsonCodec;
+
+impl<T: Serialize + DeserializeOwned> Codec<T> for JsonCodec {
+    fn encode(&self, value: &T) -> MomentoResult<Vec<u8>> {
+        serde_json::to_vec(value).map_err(|e| codec_error("failed to encode value as json", e))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> MomentoResult<T> {
+        serde_json::from_slice(bytes).map_err(|e| codec_error("failed to decode json value", e))
+    }
+}
+
+/// A [Codec] which stores values in serde's compact [bincode](https://docs.rs/bincode/1) binary
+/// format. Smaller and faster than JSON, but only readable by Rust clients using bincode.
+///
+/// Requires the `bincode` feature.
+#[cfg(feature = "bincode")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BincodeCodec;
+
+#[cfg(feature = "bincode")]
+impl<T: Serialize + DeserializeOwned> Codec<T> for BincodeCodec {
+    fn encode(&self, value: &T) -> MomentoResult<Vec<u8>> {
+        bincode::serialize(value).map_err(|e| codec_error("failed to encode value as bincode", e))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> MomentoResult<T> {
+        bincode::deserialize(bytes).map_err(|e| codec_error("failed to decode bincode value", e))
+    }
+}
+
+/// A [Codec] which stores values as [MessagePack](https://msgpack.org) using serde. Structs are
+/// encoded as maps keyed by field name, so other MessagePack clients can read them.
+///
+/// Requires the `messagepack` feature.
+#[cfg(feature = "messagepack")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MessagePackCodec;
+
+#[cfg(feature = "messagepack")]
+impl<T: Serialize + DeserializeOwned> Codec<T> for MessagePackCodec {
+    fn encode(&self, value: &T) -> MomentoResult<Vec<u8>> {
+        rmp_serde::to_vec_named(value)
+            .map_err(|e| codec_error("failed to encode value as messagepack", e))
+    }
+
+    fn decode(&self, bytes: &[u8]) -> MomentoResult<T> {
+        rmp_serde::from_slice(bytes)
+            .map_err(|e| codec_error("failed to decode messagepack value", e))
+    }
+}
+
+pub(crate) fn codec_error(
+    message: impl Into<String>,
+    error: impl std::error::Error + Send + Sync + 'static,
+) -> MomentoError {
+    let message = message.into();
+    MomentoError {
+        message: format!("{message}: {error}"),
+        error_code: MomentoErrorCode::TypeError,
+        inner_error: Some(ErrorSource::Unknown(Box::new(error))),
+    }
+}
+
+/// Prefix the message of a decoding error with the element or field that failed.
+pub(crate) fn with_context(
+    mut error: MomentoError,
+    context: impl std::fmt::Display,
+) -> MomentoError {
+    error.message = format!("{context}: {}", error.message);
+    error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u32,
+        item: String,
+    }
+
+    #[test]
+    fn test_json_codec_round_trip() {
+        let order = Order {
+            id: 7,
+            item: "taco".to_string(),
+        };
+        let bytes = JsonCodec.encode(&order).expect("order should encode");
+        assert_eq!(bytes, br#"{"id":7,"item":"taco"}"#);
+        let decoded: Order = JsonCodec.decode(&bytes).expect("order should decode");
+        assert_eq!(decoded, order);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_bincode_codec_round_trip() {
+        let order = Order {
+            id: 7,
+            item: "taco".to_string(),
+        };
+        let bytes = BincodeCodec.encode(&order).expect("order should encode");
+        let decoded: Order = BincodeCodec.decode(&bytes).expect("order should decode");
+        assert_eq!(decoded, order);
+
+        let result: MomentoResult<Order> = BincodeCodec.decode(&bytes[..3]);
+        let error = result.expect_err("decoding should fail");
+        assert_eq!(error.error_code, MomentoErrorCode::TypeError);
+    }
+
+    #[cfg(feature = "messagepack")]
+    #[test]
+    fn test_messagepack_codec_round_trip() {
+        let order = Order {
+            id: 7,
+            item: "taco".to_string(),
+        };
+        let bytes = MessagePackCodec
+            .encode(&order)
+            .expect("order should encode");
+        assert_eq!(bytes, b"\x82\xa2id\x07\xa4item\xa4taco");
+        let decoded: Order = MessagePackCodec
+            .decode(&bytes)
+            .expect("order should decode");
+        assert_eq!(decoded, order);
+
+        let result: MomentoResult<Order> = MessagePackCodec.decode(b"not messagepack");
+        let error = result.expect_err("decoding should fail");
+        assert_eq!(error.error_code, MomentoErrorCode::TypeError);
+    }
+
+    #[test]
+    fn test_json_codec_decode_error() {
+        let result: MomentoResult<Order> = JsonCodec.decode(b"not json");
+        let error = result.expect_err("decoding should fail");
+        assert_eq!(error.error_code, MomentoErrorCode::TypeError);
+        assert!(
+            error.message.starts_with("failed to decode json value"),
+            "{}",
+            error.message
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use std::task::{Context, Poll};
+
+use tonic::body::Body;
+use tonic::codegen::http::Request;
+use tonic::transport::Channel;
+use tower::Service;
+
+use crate::{ErrorSource, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// Reports whether a client's gRPC channels can accept another request, for
+/// [tower::Service::poll_ready].
+///
+/// Each channel queues requests in a bounded buffer. Polling a channel reserves a slot in that
+/// buffer, so once a channel reports ready it is replaced with a fresh clone to give the slot
+/// back; the request itself is sent through the client's own handle to the channel.
+///
+/// Cloning a `ChannelReadiness` clones the channels, so each clone of a client polls
+/// independently, as tower expects.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ChannelReadiness {
+    channels: Vec<Channel>,
+}
+
+impl ChannelReadiness {
+    pub(crate) fn new(channels: Vec<Channel>) -> Self {
+        Self { channels }
+    }
+
+    /// Ready once every channel is ready, since requests are spread across all of them.
+    pub(crate) fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<MomentoResult<()>> {
+        for channel in &mut self.channels {
+            match Service::<Request<Body>>::poll_ready(channel, cx) {
+                Poll::Ready(Ok(())) => *channel = channel.clone(),
+                Poll::Ready(Err(error)) => {
+                    return Poll::Ready(Err(MomentoError {
+                        message: "the connection to the server is unavailable".into(),
+                        error_code: MomentoErrorCode::ServerUnavailable,
+                        inner_error: Some(ErrorSource::Unknown(error.into())),
+                    }))
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1 +1,4 @@
+#[cfg(feature = "tower")]
+pub(crate) mod channel_readiness;
 pub mod header_interceptor;
+pub(crate) mod unary;
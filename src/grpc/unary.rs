@@ -0,0 +1,39 @@
+//! Unary calls for services whose protos are not in `momento-protos`, made the way the clients
+//! `tonic` generates would make them.
+
+use tonic::codec::ProstCodec;
+use tonic::codegen::http::uri::PathAndQuery;
+use tonic::codegen::InterceptedService;
+use tonic::transport::Channel;
+use tonic::GrpcMethod;
+
+use crate::grpc::header_interceptor::HeaderInterceptor;
+
+pub(crate) type AuthorizedGrpc =
+    tonic::client::Grpc<InterceptedService<Channel, HeaderInterceptor>>;
+
+/// Call `method` of `service`, whose full path is `path`.
+pub(crate) async fn unary<Req, Resp>(
+    grpc: &mut AuthorizedGrpc,
+    mut request: tonic::Request<Req>,
+    service: &'static str,
+    method: &'static str,
+    path: &'static str,
+) -> Result<tonic::Response<Resp>, tonic::Status>
+where
+    Req: prost::Message + 'static,
+    Resp: prost::Message + Default + 'static,
+{
+    grpc.ready()
+        .await
+        .map_err(|e| tonic::Status::unknown(format!("Service was not ready: {e}")))?;
+    request
+        .extensions_mut()
+        .insert(GrpcMethod::new(service, method));
+    grpc.unary(
+        request,
+        PathAndQuery::from_static(path),
+        ProstCodec::default(),
+    )
+    .await
+}
@@ -9,12 +9,17 @@ use momento_protos::leaderboard::leaderboard_client as leaderboard_proto;
 use tonic::codegen::InterceptedService;
 use tonic::transport::Channel;
 
+#[cfg(feature = "tower")]
+use crate::grpc::channel_readiness::ChannelReadiness;
+
 /// Client to work with Momento Leaderboards.
 #[derive(Clone, Debug)]
 pub struct LeaderboardClient {
     data_clients:
         Vec<leaderboard_proto::LeaderboardClient<InterceptedService<Channel, HeaderInterceptor>>>,
     configuration: Configuration,
+    #[cfg(feature = "tower")]
+    readiness: ChannelReadiness,
 }
 
 impl LeaderboardClient {
@@ -32,20 +37,31 @@ impl LeaderboardClient {
         Self {
             data_clients,
             configuration,
+            #[cfg(feature = "tower")]
+            readiness: ChannelReadiness::default(),
         }
     }
 
+    #[cfg(feature = "tower")]
+    pub(crate) fn with_channel_readiness(mut self, readiness: ChannelReadiness) -> Self {
+        self.readiness = readiness;
+        self
+    }
+
     /// Returns a `Leaderboard` client to work with a specific leaderboard.
     pub fn leaderboard(
         &self,
         cache_name: impl Into<String>,
         leaderboard_name: impl Into<String>,
     ) -> Leaderboard {
-        Leaderboard::new(
+        let leaderboard = Leaderboard::new(
             self.data_clients.clone(),
             self.configuration.client_timeout(),
             cache_name,
             leaderboard_name,
-        )
+        );
+        #[cfg(feature = "tower")]
+        let leaderboard = leaderboard.with_channel_readiness(self.readiness.clone());
+        leaderboard
     }
 }
@@ -1,5 +1,7 @@
 use crate::config::grpc_configuration::GrpcConfiguration;
 use crate::config::transport_strategy::TransportStrategy;
+#[cfg(feature = "tower")]
+use crate::grpc::channel_readiness::ChannelReadiness;
 use crate::grpc::header_interceptor::HeaderInterceptor;
 use crate::leaderboard::{Configuration, LeaderboardClient};
 use crate::utils::ChannelConnectError;
@@ -86,6 +88,8 @@ impl LeaderboardClientBuilder<ReadyToBuild> {
             .collect();
 
         let data_channels = data_channels_result?;
+        #[cfg(feature = "tower")]
+        let readiness = ChannelReadiness::new(data_channels.clone());
 
         let data_clients: Vec<
             leaderboard_proto::LeaderboardClient<InterceptedService<Channel, HeaderInterceptor>>,
@@ -100,6 +104,9 @@ impl LeaderboardClientBuilder<ReadyToBuild> {
             })
             .collect();
 
-        Ok(LeaderboardClient::new(data_clients, self.0.configuration))
+        let client = LeaderboardClient::new(data_clients, self.0.configuration);
+        #[cfg(feature = "tower")]
+        let client = client.with_channel_readiness(readiness);
+        Ok(client)
     }
 }
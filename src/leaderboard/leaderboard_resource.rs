@@ -21,15 +21,29 @@ use tonic::transport::Channel;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::Duration;
 
+#[cfg(feature = "tower")]
+use crate::grpc::channel_readiness::ChannelReadiness;
+#[cfg(feature = "tower")]
+use crate::MomentoError;
+#[cfg(feature = "tower")]
+use std::future::Future;
+#[cfg(feature = "tower")]
+use std::pin::Pin;
+#[cfg(feature = "tower")]
+use std::task::{Context, Poll};
+
 static NEXT_DATA_CLIENT_INDEX: AtomicUsize = AtomicUsize::new(0);
 
 /// Represents a remote leaderboard resource.
+#[derive(Clone, Debug)]
 pub struct Leaderboard {
     data_clients:
         Vec<leaderboard_proto::LeaderboardClient<InterceptedService<Channel, HeaderInterceptor>>>,
     client_timeout: Duration,
     cache_name: String,
     leaderboard_name: String,
+    #[cfg(feature = "tower")]
+    readiness: ChannelReadiness,
 }
 
 impl Leaderboard {
@@ -130,9 +144,17 @@ impl Leaderboard {
             client_timeout,
             cache_name: cache_name.into(),
             leaderboard_name: leaderboard_name.into(),
+            #[cfg(feature = "tower")]
+            readiness: ChannelReadiness::default(),
         }
     }
 
+    #[cfg(feature = "tower")]
+    pub(crate) fn with_channel_readiness(mut self, readiness: ChannelReadiness) -> Self {
+        self.readiness = readiness;
+        self
+    }
+
     pub(crate) fn next_data_client(
         &self,
     ) -> leaderboard_proto::LeaderboardClient<InterceptedService<Channel, HeaderInterceptor>> {
@@ -163,3 +185,47 @@ impl Leaderboard {
         request.send(self).await
     }
 }
+
+/// Sends any [LeaderboardRequest] as a [tower::Service], so tower middleware such as timeouts,
+/// retries, load shedding and concurrency limits can wrap leaderboard calls.
+///
+/// `poll_ready` reports whether the client's data channels can accept another request.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_leaderboard_client;
+/// # tokio_test::block_on(async {
+/// use momento::leaderboard::{LengthRequest, LengthResponse};
+/// use tower::{ServiceBuilder, ServiceExt};
+/// # let (leaderboard_client, cache_name) = create_doctest_leaderboard_client();
+/// let leaderboard = leaderboard_client.leaderboard(cache_name, "leaderboard");
+///
+/// let service = ServiceBuilder::new()
+///     .concurrency_limit(16)
+///     .service(leaderboard);
+///
+/// let response: LengthResponse = service.oneshot(LengthRequest::new()).await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[cfg(feature = "tower")]
+impl<R> tower::Service<R> for Leaderboard
+where
+    R: LeaderboardRequest + Send + 'static,
+    R::Response: Send + 'static,
+{
+    type Response = R::Response;
+    type Error = MomentoError;
+    type Future = Pin<Box<dyn Future<Output = MomentoResult<R::Response>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<MomentoResult<()>> {
+        self.readiness.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        let leaderboard = self.clone();
+        Box::pin(async move { request.send(&leaderboard).await })
+    }
+}
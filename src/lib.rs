@@ -117,6 +117,8 @@
 pub mod cache;
 pub use cache::{CacheClient, CacheClientBuilder, ReadyToBuild};
 
+/// Contains the [Codec](codec::Codec) trait used by typed wrappers to convert values to and from bytes.
+pub mod codec;
 /// Contains configuration settings for the Momento SDK that are shared between the Cache and Topics clients.
 pub mod config;
 mod credential_provider;
@@ -132,6 +134,10 @@ mod grpc;
 pub mod leaderboard;
 pub use leaderboard::{Leaderboard, LeaderboardClient};
 
+/// Contains the [StorageClient] for interacting with Momento Storage (preview).
+pub mod storage;
+pub use storage::StorageClient;
+
 /// Contains the [TopicClient] for interacting with Momento Topics.
 pub mod topics;
 pub use topics::TopicClient;
@@ -149,3 +155,15 @@ pub use auth::AuthClient;
 
 pub mod functions;
 pub use functions::FunctionClient;
+
+/// Memoizes an async function in a Momento cache. Requires the `macros` feature.
+///
+/// See the [macro documentation](momento_macros::cached) for the supported arguments.
+#[cfg(feature = "macros")]
+pub use momento_macros::cached;
+
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::cache::cached::{cache_key, cached_get, cached_set, returning};
+}
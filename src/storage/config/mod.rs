@@ -0,0 +1,4 @@
+/// Configuration for the Momento storage client.
+pub mod configuration;
+/// Pre-built configurations for the Momento storage client.
+pub mod configurations;
@@ -0,0 +1,79 @@
+use tonic::Request;
+
+use crate::storage::messages::StorageRequest;
+use crate::storage::protos::control;
+use crate::storage::StorageClient;
+use crate::MomentoResult;
+
+/// Request to list all stores in your account.
+///
+/// # Example
+/// Assumes that a StorageClient named `storage_client` has been created and is available.
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// use momento::storage::ListStoresRequest;
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+///
+/// match storage_client.send_request(ListStoresRequest {}).await {
+///     Ok(response) => println!("Stores: {:#?}", response.stores),
+///     Err(e) => eprintln!("Error listing stores: {}", e),
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct ListStoresRequest {}
+
+impl StorageRequest for ListStoresRequest {
+    type Response = ListStoresResponse;
+
+    async fn send(self, storage_client: &StorageClient) -> MomentoResult<ListStoresResponse> {
+        let mut stores = Vec::new();
+        let mut next_token = String::new();
+        loop {
+            let request = Request::new(control::ListStoresRequest { next_token });
+            let response = storage_client
+                .control_client()
+                .list_stores(request)
+                .await?
+                .into_inner();
+            stores.extend(response.store.into_iter().map(|store| StoreInfo {
+                name: store.store_name,
+            }));
+            if response.next_token.is_empty() {
+                break;
+            }
+            next_token = response.next_token;
+        }
+        Ok(ListStoresResponse { stores })
+    }
+}
+
+/// Information about a store.
+#[derive(Debug, PartialEq, Eq)]
+pub struct StoreInfo {
+    /// The name of the store.
+    pub name: String,
+}
+
+/// Response for a list stores operation.
+///
+/// You can cast your result directly into a `Vec<StoreInfo>` if that is all you need.
+/// ```
+/// # use momento::storage::{ListStoresResponse, StoreInfo};
+/// # let list_stores_response = ListStoresResponse { stores: vec![] };
+/// let stores: Vec<StoreInfo> = list_stores_response.into();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ListStoresResponse {
+    /// The stores in your account.
+    pub stores: Vec<StoreInfo>,
+}
+
+impl From<ListStoresResponse> for Vec<StoreInfo> {
+    fn from(response: ListStoresResponse) -> Self {
+        response.stores
+    }
+}
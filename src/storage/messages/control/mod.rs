@@ -0,0 +1,7 @@
+/// Contains the request and response types for creating a store.
+pub mod create_store;
+/// Contains the request and response types for deleting a store.
+pub mod delete_store;
+/// Contains the request and response types for listing the stores in your
+/// account.
+pub mod list_stores;
@@ -0,0 +1,61 @@
+use crate::storage::messages::StorageRequest;
+use crate::storage::protos::store;
+use crate::storage::StorageClient;
+use crate::utils::prep_storage_request_with_timeout;
+use crate::MomentoResult;
+
+/// Request to delete a value from a store. Deleting a key that has no value succeeds.
+///
+/// # Arguments
+///
+/// * `store_name` - name of the store
+/// * `key` - key of the value to delete
+///
+/// # Example
+/// Assumes that a StorageClient named `storage_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// use momento::storage::DeleteRequest;
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+///
+/// let delete_request = DeleteRequest::new(&store_name, "visits");
+/// storage_client.send_request(delete_request).await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct DeleteRequest {
+    store_name: String,
+    key: String,
+}
+
+impl DeleteRequest {
+    /// Constructs a new DeleteRequest.
+    pub fn new(store_name: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            store_name: store_name.into(),
+            key: key.into(),
+        }
+    }
+}
+
+impl StorageRequest for DeleteRequest {
+    type Response = DeleteResponse;
+
+    async fn send(self, storage_client: &StorageClient) -> MomentoResult<DeleteResponse> {
+        let request = prep_storage_request_with_timeout(
+            &self.store_name,
+            storage_client.client_timeout(),
+            store::StoreDeleteRequest { key: self.key },
+        )?;
+
+        storage_client.next_data_client().delete(request).await?;
+        Ok(DeleteResponse {})
+    }
+}
+
+/// The response type for a successful delete request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DeleteResponse {}
@@ -0,0 +1,145 @@
+use std::convert::{TryFrom, TryInto};
+
+use crate::storage::messages::StorageRequest;
+use crate::storage::protos::store;
+use crate::storage::{StorageClient, StorageValue};
+use crate::utils::prep_storage_request_with_timeout;
+use crate::{status_to_error, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// Request to get a value from a store.
+///
+/// # Arguments
+///
+/// * `store_name` - name of the store
+/// * `key` - key of the value
+///
+/// # Example
+/// Assumes that a StorageClient named `storage_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::storage::{GetRequest, GetResponse};
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+/// # storage_client.put(&store_name, "visits", 1).await?;
+///
+/// let get_request = GetRequest::new(&store_name, "visits");
+///
+/// let visits: i64 = match storage_client.send_request(get_request).await? {
+///     GetResponse::Found { value } => value.try_into().expect("I stored an integer!"),
+///     GetResponse::NotFound => 0,
+/// };
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct GetRequest {
+    store_name: String,
+    key: String,
+}
+
+impl GetRequest {
+    /// Constructs a new GetRequest.
+    pub fn new(store_name: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            store_name: store_name.into(),
+            key: key.into(),
+        }
+    }
+}
+
+impl StorageRequest for GetRequest {
+    type Response = GetResponse;
+
+    async fn send(self, storage_client: &StorageClient) -> MomentoResult<GetResponse> {
+        let request = prep_storage_request_with_timeout(
+            &self.store_name,
+            storage_client.client_timeout(),
+            store::StoreGetRequest { key: self.key },
+        )?;
+
+        match storage_client.next_data_client().get(request).await {
+            Ok(response) => Ok(response
+                .into_inner()
+                .value
+                .and_then(StorageValue::from_proto)
+                .map_or(GetResponse::NotFound, |value| GetResponse::Found { value })),
+            Err(status) => {
+                let error = status_to_error(status);
+                if error.error_code == MomentoErrorCode::ItemNotFoundError {
+                    return Ok(GetResponse::NotFound);
+                }
+                Err(error)
+            }
+        }
+    }
+}
+
+/// Response for a get operation.
+///
+/// If the value was found, you can cast it directly into the type it was stored as, suitable for
+/// ?-propagation:
+/// ```
+/// # use momento::storage::{GetResponse, StorageValue};
+/// # use momento::MomentoResult;
+/// use std::convert::TryInto;
+/// # let get_response = GetResponse::Found { value: StorageValue::from("hello") };
+/// let value: MomentoResult<String> = get_response.try_into();
+/// # assert_eq!(value.unwrap(), "hello");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum GetResponse {
+    /// The value stored under the key.
+    Found {
+        /// The value, with the type it was stored as.
+        value: StorageValue,
+    },
+    /// The store has no value for the key.
+    NotFound,
+}
+
+impl GetResponse {
+    fn into_value(self) -> MomentoResult<StorageValue> {
+        match self {
+            GetResponse::Found { value } => Ok(value),
+            GetResponse::NotFound => Err(MomentoError {
+                message: "Received a NotFound for Get".to_string(),
+                error_code: MomentoErrorCode::ItemNotFoundError,
+                inner_error: None,
+            }),
+        }
+    }
+}
+
+impl TryFrom<GetResponse> for String {
+    type Error = MomentoError;
+
+    fn try_from(value: GetResponse) -> Result<Self, Self::Error> {
+        value.into_value()?.try_into()
+    }
+}
+
+impl TryFrom<GetResponse> for Vec<u8> {
+    type Error = MomentoError;
+
+    fn try_from(value: GetResponse) -> Result<Self, Self::Error> {
+        value.into_value()?.try_into()
+    }
+}
+
+impl TryFrom<GetResponse> for i64 {
+    type Error = MomentoError;
+
+    fn try_from(value: GetResponse) -> Result<Self, Self::Error> {
+        value.into_value()?.try_into()
+    }
+}
+
+impl TryFrom<GetResponse> for f64 {
+    type Error = MomentoError;
+
+    fn try_from(value: GetResponse) -> Result<Self, Self::Error> {
+        value.into_value()?.try_into()
+    }
+}
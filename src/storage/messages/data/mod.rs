@@ -0,0 +1,10 @@
+/// Contains the request and response types for deleting a value from a
+/// store.
+pub mod delete;
+/// Contains the request and response types for getting a value from a store.
+pub mod get;
+/// Contains the request and response types for putting a value in a store.
+pub mod put;
+/// Contains the [StorageValue](storage_value::StorageValue) type, which
+/// holds a value of any of the types a store can keep.
+pub mod storage_value;
@@ -0,0 +1,71 @@
+use crate::storage::messages::StorageRequest;
+use crate::storage::protos::store;
+use crate::storage::{StorageClient, StorageValue};
+use crate::utils::prep_storage_request_with_timeout;
+use crate::MomentoResult;
+
+/// Request to put a value in a store, replacing any value already stored under the key.
+///
+/// # Arguments
+///
+/// * `store_name` - name of the store
+/// * `key` - key of the value
+/// * `value` - the string, bytes, integer or double to store
+///
+/// # Example
+/// Assumes that a StorageClient named `storage_client` has been created and is available.
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_storage_client;
+/// # tokio_test::block_on(async {
+/// use momento::storage::PutRequest;
+/// # let (storage_client, store_name) = create_doctest_storage_client();
+///
+/// let put_request = PutRequest::new(&store_name, "visits", 1);
+/// storage_client.send_request(put_request).await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct PutRequest {
+    store_name: String,
+    key: String,
+    value: StorageValue,
+}
+
+impl PutRequest {
+    /// Constructs a new PutRequest.
+    pub fn new(
+        store_name: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<StorageValue>,
+    ) -> Self {
+        Self {
+            store_name: store_name.into(),
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl StorageRequest for PutRequest {
+    type Response = PutResponse;
+
+    async fn send(self, storage_client: &StorageClient) -> MomentoResult<PutResponse> {
+        let request = prep_storage_request_with_timeout(
+            &self.store_name,
+            storage_client.client_timeout(),
+            store::StorePutRequest {
+                key: self.key,
+                value: Some(self.value.into_proto()),
+            },
+        )?;
+
+        storage_client.next_data_client().put(request).await?;
+        Ok(PutResponse {})
+    }
+}
+
+/// The response type for a successful put request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PutResponse {}
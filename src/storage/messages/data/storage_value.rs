@@ -0,0 +1,197 @@
+use std::convert::TryFrom;
+
+use crate::storage::protos::store::{store_value, StoreValue};
+use crate::{MomentoError, MomentoErrorCode};
+
+/// A value in a store. Unlike cache values, store values keep their type: a value put as an
+/// integer is read back as an integer.
+///
+/// Values can be converted into the type they were stored as with `try_into`:
+/// ```
+/// # use momento::storage::StorageValue;
+/// use std::convert::TryInto;
+///
+/// let count: i64 = StorageValue::from(42).try_into().expect("I stored an integer!");
+/// # assert_eq!(count, 42);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageValue {
+    /// A UTF-8 string.
+    String(String),
+    /// Raw bytes.
+    Bytes(Vec<u8>),
+    /// A signed 64-bit integer.
+    Integer(i64),
+    /// A 64-bit floating point number.
+    Double(f64),
+}
+
+impl StorageValue {
+    fn type_name(&self) -> &'static str {
+        match self {
+            StorageValue::String(_) => "string",
+            StorageValue::Bytes(_) => "bytes",
+            StorageValue::Integer(_) => "integer",
+            StorageValue::Double(_) => "double",
+        }
+    }
+
+    fn type_error(&self, expected: &str) -> MomentoError {
+        MomentoError {
+            message: format!("store value has type {}, not {expected}", self.type_name()),
+            error_code: MomentoErrorCode::TypeError,
+            inner_error: None,
+        }
+    }
+
+    pub(crate) fn into_proto(self) -> StoreValue {
+        let value = match self {
+            StorageValue::String(value) => store_value::Value::StringValue(value),
+            StorageValue::Bytes(value) => store_value::Value::BytesValue(value),
+            StorageValue::Integer(value) => store_value::Value::IntegerValue(value),
+            StorageValue::Double(value) => store_value::Value::DoubleValue(value),
+        };
+        StoreValue { value: Some(value) }
+    }
+
+    pub(crate) fn from_proto(value: StoreValue) -> Option<Self> {
+        Some(match value.value? {
+            store_value::Value::StringValue(value) => StorageValue::String(value),
+            store_value::Value::BytesValue(value) => StorageValue::Bytes(value),
+            store_value::Value::IntegerValue(value) => StorageValue::Integer(value),
+            store_value::Value::DoubleValue(value) => StorageValue::Double(value),
+        })
+    }
+}
+
+impl From<String> for StorageValue {
+    fn from(value: String) -> Self {
+        StorageValue::String(value)
+    }
+}
+
+impl From<&str> for StorageValue {
+    fn from(value: &str) -> Self {
+        StorageValue::String(value.to_string())
+    }
+}
+
+impl From<Vec<u8>> for StorageValue {
+    fn from(value: Vec<u8>) -> Self {
+        StorageValue::Bytes(value)
+    }
+}
+
+impl From<&[u8]> for StorageValue {
+    fn from(value: &[u8]) -> Self {
+        StorageValue::Bytes(value.to_vec())
+    }
+}
+
+impl From<i64> for StorageValue {
+    fn from(value: i64) -> Self {
+        StorageValue::Integer(value)
+    }
+}
+
+impl From<i32> for StorageValue {
+    fn from(value: i32) -> Self {
+        StorageValue::Integer(value.into())
+    }
+}
+
+impl From<f64> for StorageValue {
+    fn from(value: f64) -> Self {
+        StorageValue::Double(value)
+    }
+}
+
+impl TryFrom<StorageValue> for String {
+    type Error = MomentoError;
+
+    fn try_from(value: StorageValue) -> Result<Self, Self::Error> {
+        match value {
+            StorageValue::String(value) => Ok(value),
+            other => Err(other.type_error("string")),
+        }
+    }
+}
+
+impl TryFrom<StorageValue> for Vec<u8> {
+    type Error = MomentoError;
+
+    fn try_from(value: StorageValue) -> Result<Self, Self::Error> {
+        match value {
+            StorageValue::Bytes(value) => Ok(value),
+            other => Err(other.type_error("bytes")),
+        }
+    }
+}
+
+impl TryFrom<StorageValue> for i64 {
+    type Error = MomentoError;
+
+    fn try_from(value: StorageValue) -> Result<Self, Self::Error> {
+        match value {
+            StorageValue::Integer(value) => Ok(value),
+            other => Err(other.type_error("integer")),
+        }
+    }
+}
+
+impl TryFrom<StorageValue> for f64 {
+    type Error = MomentoError;
+
+    fn try_from(value: StorageValue) -> Result<Self, Self::Error> {
+        match value {
+            StorageValue::Double(value) => Ok(value),
+            other => Err(other.type_error("double")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    #[test]
+    fn converts_values_of_the_stored_type() -> Result<(), MomentoError> {
+        let string: String = StorageValue::from("hello").try_into()?;
+        assert_eq!(string, "hello");
+        let bytes: Vec<u8> = StorageValue::from(&b"hello"[..]).try_into()?;
+        assert_eq!(bytes, b"hello");
+        let integer: i64 = StorageValue::from(42).try_into()?;
+        assert_eq!(integer, 42);
+        let double: f64 = StorageValue::from(4.2).try_into()?;
+        assert_eq!(double, 4.2);
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_values_of_another_type() {
+        let result: Result<String, _> = StorageValue::from(42).try_into();
+        let error = result.expect_err("an integer is not a string");
+        assert_eq!(error.error_code, MomentoErrorCode::TypeError);
+        assert_eq!(error.message, "store value has type integer, not string");
+
+        let result: Result<i64, _> = StorageValue::from(4.2).try_into();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn round_trips_through_the_proto() {
+        for value in [
+            StorageValue::from("hello"),
+            StorageValue::from(vec![0, 1, 2]),
+            StorageValue::from(-7),
+            StorageValue::from(0.5),
+        ] {
+            assert_eq!(
+                StorageValue::from_proto(value.clone().into_proto()),
+                Some(value)
+            );
+        }
+        assert_eq!(StorageValue::from_proto(StoreValue { value: None }), None);
+    }
+}
@@ -0,0 +1,8 @@
+/// Control plane messages for stores
+pub mod control;
+/// Data plane messages for stores
+pub mod data;
+
+mod storage_request;
+
+pub use storage_request::StorageRequest;
@@ -0,0 +1,16 @@
+use crate::storage::StorageClient;
+use crate::MomentoResult;
+
+/// A trait that allows Momento request types to define their interaction with the gRPC client.
+pub trait StorageRequest {
+    /// The response type for this request.
+    type Response;
+
+    /// An internal fn that allows Momento request types to define their interaction with
+    /// the gRPC client.
+    #[doc(hidden)]
+    fn send(
+        self,
+        storage_client: &StorageClient,
+    ) -> impl std::future::Future<Output = MomentoResult<Self::Response>> + Send;
+}
@@ -0,0 +1,22 @@
+/// Contains the request and response types for storage operations.
+pub mod messages;
+
+pub use messages::StorageRequest;
+
+pub use messages::control::create_store::{CreateStoreRequest, CreateStoreResponse};
+pub use messages::control::delete_store::{DeleteStoreRequest, DeleteStoreResponse};
+pub use messages::control::list_stores::{ListStoresRequest, ListStoresResponse, StoreInfo};
+pub use messages::data::delete::{DeleteRequest, DeleteResponse};
+pub use messages::data::get::{GetRequest, GetResponse};
+pub use messages::data::put::{PutRequest, PutResponse};
+pub use messages::data::storage_value::StorageValue;
+
+mod config;
+mod protos;
+mod storage_client;
+mod storage_client_builder;
+
+pub use config::configuration::Configuration;
+pub use config::configurations;
+
+pub use storage_client::StorageClient;
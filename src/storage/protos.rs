@@ -0,0 +1,217 @@
+//! Wire types and gRPC clients for the store services.
+//!
+//! The published `momento-protos` crate does not include the store protos yet, so the messages
+//! the client needs are defined here the way `prost` would generate them.
+
+use tonic::codegen::InterceptedService;
+use tonic::transport::Channel;
+
+use crate::grpc::header_interceptor::HeaderInterceptor;
+use crate::grpc::unary::{unary, AuthorizedGrpc};
+
+pub(crate) mod store {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoreValue {
+        #[prost(oneof = "store_value::Value", tags = "1, 2, 3, 4")]
+        pub value: ::core::option::Option<store_value::Value>,
+    }
+
+    pub mod store_value {
+        #[allow(clippy::enum_variant_names)]
+        #[derive(Clone, PartialEq, ::prost::Oneof)]
+        pub enum Value {
+            #[prost(bytes, tag = "1")]
+            BytesValue(::prost::alloc::vec::Vec<u8>),
+            #[prost(string, tag = "2")]
+            StringValue(::prost::alloc::string::String),
+            #[prost(int64, tag = "3")]
+            IntegerValue(i64),
+            #[prost(double, tag = "4")]
+            DoubleValue(f64),
+        }
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoreGetRequest {
+        #[prost(string, tag = "1")]
+        pub key: ::prost::alloc::string::String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoreGetResponse {
+        #[prost(message, optional, tag = "1")]
+        pub value: ::core::option::Option<StoreValue>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StorePutRequest {
+        #[prost(string, tag = "1")]
+        pub key: ::prost::alloc::string::String,
+        #[prost(message, optional, tag = "2")]
+        pub value: ::core::option::Option<StoreValue>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StorePutResponse {}
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoreDeleteRequest {
+        #[prost(string, tag = "1")]
+        pub key: ::prost::alloc::string::String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct StoreDeleteResponse {}
+}
+
+pub(crate) mod control {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CreateStoreRequest {
+        #[prost(string, tag = "1")]
+        pub store_name: ::prost::alloc::string::String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CreateStoreResponse {}
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DeleteStoreRequest {
+        #[prost(string, tag = "1")]
+        pub store_name: ::prost::alloc::string::String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DeleteStoreResponse {}
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ListStoresRequest {
+        #[prost(string, tag = "1")]
+        pub next_token: ::prost::alloc::string::String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Store {
+        #[prost(string, tag = "1")]
+        pub store_name: ::prost::alloc::string::String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ListStoresResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub store: ::prost::alloc::vec::Vec<Store>,
+        #[prost(string, tag = "2")]
+        pub next_token: ::prost::alloc::string::String,
+    }
+}
+
+/// Client for the `store.Store` data service.
+#[derive(Clone, Debug)]
+pub(crate) struct StoreClient {
+    inner: AuthorizedGrpc,
+}
+
+impl StoreClient {
+    pub(crate) fn new(service: InterceptedService<Channel, HeaderInterceptor>) -> Self {
+        Self {
+            inner: tonic::client::Grpc::new(service),
+        }
+    }
+
+    pub(crate) async fn get(
+        &mut self,
+        request: tonic::Request<store::StoreGetRequest>,
+    ) -> Result<tonic::Response<store::StoreGetResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            "store.Store",
+            "Get",
+            "/store.Store/Get",
+        )
+        .await
+    }
+
+    pub(crate) async fn put(
+        &mut self,
+        request: tonic::Request<store::StorePutRequest>,
+    ) -> Result<tonic::Response<store::StorePutResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            "store.Store",
+            "Put",
+            "/store.Store/Put",
+        )
+        .await
+    }
+
+    pub(crate) async fn delete(
+        &mut self,
+        request: tonic::Request<store::StoreDeleteRequest>,
+    ) -> Result<tonic::Response<store::StoreDeleteResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            "store.Store",
+            "Delete",
+            "/store.Store/Delete",
+        )
+        .await
+    }
+}
+
+/// Client for the store operations of the `control_client.ScsControl` service.
+#[derive(Clone, Debug)]
+pub(crate) struct StoreControlClient {
+    inner: AuthorizedGrpc,
+}
+
+impl StoreControlClient {
+    pub(crate) fn new(service: InterceptedService<Channel, HeaderInterceptor>) -> Self {
+        Self {
+            inner: tonic::client::Grpc::new(service),
+        }
+    }
+
+    pub(crate) async fn create_store(
+        &mut self,
+        request: tonic::Request<control::CreateStoreRequest>,
+    ) -> Result<tonic::Response<control::CreateStoreResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            "control_client.ScsControl",
+            "CreateStore",
+            "/control_client.ScsControl/CreateStore",
+        )
+        .await
+    }
+
+    pub(crate) async fn delete_store(
+        &mut self,
+        request: tonic::Request<control::DeleteStoreRequest>,
+    ) -> Result<tonic::Response<control::DeleteStoreResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            "control_client.ScsControl",
+            "DeleteStore",
+            "/control_client.ScsControl/DeleteStore",
+        )
+        .await
+    }
+
+    pub(crate) async fn list_stores(
+        &mut self,
+        request: tonic::Request<control::ListStoresRequest>,
+    ) -> Result<tonic::Response<control::ListStoresResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            "control_client.ScsControl",
+            "ListStores",
+            "/control_client.ScsControl/ListStores",
+        )
+        .await
+    }
+}
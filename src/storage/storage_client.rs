@@ -0,0 +1,269 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use crate::storage::protos::{StoreClient, StoreControlClient};
+use crate::storage::storage_client_builder::{NeedsConfiguration, StorageClientBuilder};
+use crate::storage::{
+    Configuration, CreateStoreRequest, CreateStoreResponse, DeleteRequest, DeleteResponse,
+    DeleteStoreRequest, DeleteStoreResponse, GetRequest, GetResponse, ListStoresRequest,
+    ListStoresResponse, PutRequest, PutResponse, StorageRequest, StorageValue,
+};
+use crate::MomentoResult;
+
+static NEXT_DATA_CLIENT_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+/// Client to work with Momento Storage, which keeps values durably in stores until they are
+/// deleted, rather than caching them for a TTL.
+///
+/// Storage is in preview, and its API may change in future releases.
+///
+/// # Example
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use std::convert::TryInto;
+/// use momento::{CredentialProvider, StorageClient};
+/// use momento::storage::configurations;
+///
+/// let storage_client = StorageClient::builder()
+///     .configuration(configurations::Laptop::latest())
+///     .credential_provider(CredentialProvider::from_env_var("MOMENTO_API_KEY")?)
+///     .build()?;
+///
+/// storage_client.create_store("my-store").await?;
+/// storage_client.put("my-store", "greeting", "hello").await?;
+/// let greeting: String = storage_client.get("my-store", "greeting").await?.try_into()?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct StorageClient {
+    data_clients: Vec<StoreClient>,
+    control_client: StoreControlClient,
+    configuration: Configuration,
+}
+
+impl StorageClient {
+    /// Returns a builder to construct a `StorageClient`.
+    pub fn builder() -> StorageClientBuilder<NeedsConfiguration> {
+        StorageClientBuilder(NeedsConfiguration {})
+    }
+
+    /* public API */
+
+    /// Creates a store with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - The name of the store to create.
+    ///
+    /// # Example
+    /// Assumes that a StorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// use momento::storage::CreateStoreResponse;
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    ///
+    /// match storage_client.create_store(&store_name).await? {
+    ///     CreateStoreResponse::Created => println!("Store {} created", &store_name),
+    ///     CreateStoreResponse::AlreadyExists => println!("Store {} already exists", &store_name),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](StorageClient::send_request) method to create a store using a [CreateStoreRequest].
+    pub async fn create_store(
+        &self,
+        store_name: impl Into<String>,
+    ) -> MomentoResult<CreateStoreResponse> {
+        let request = CreateStoreRequest::new(store_name);
+        request.send(self).await
+    }
+
+    /// Deletes a store and all of the values in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - The name of the store to delete.
+    ///
+    /// # Example
+    /// Assumes that a StorageClient named `storage_client` has been created and is available.
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// storage_client.delete_store(&store_name).await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](StorageClient::send_request) method to delete a store using a [DeleteStoreRequest].
+    pub async fn delete_store(
+        &self,
+        store_name: impl Into<String>,
+    ) -> MomentoResult<DeleteStoreResponse> {
+        let request = DeleteStoreRequest::new(store_name);
+        request.send(self).await
+    }
+
+    /// Lists all stores in your account.
+    ///
+    /// # Example
+    /// Assumes that a StorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// let response = storage_client.list_stores().await?;
+    /// println!("Stores: {:#?}", response.stores);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](StorageClient::send_request) method to list stores using a [ListStoresRequest].
+    pub async fn list_stores(&self) -> MomentoResult<ListStoresResponse> {
+        let request = ListStoresRequest {};
+        request.send(self).await
+    }
+
+    /// Puts a value in a store, replacing any value already stored under the key.
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - name of the store
+    /// * `key` - key of the value
+    /// * `value` - the string, bytes, integer or double to store
+    ///
+    /// # Example
+    /// Assumes that a StorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// storage_client.put(&store_name, "greeting", "hello").await?;
+    /// storage_client.put(&store_name, "visits", 1).await?;
+    /// storage_client.put(&store_name, "ratio", 0.5).await?;
+    /// storage_client.put(&store_name, "raw", vec![0xde, 0xad]).await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](StorageClient::send_request) method to put a value using a [PutRequest].
+    pub async fn put(
+        &self,
+        store_name: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<StorageValue>,
+    ) -> MomentoResult<PutResponse> {
+        let request = PutRequest::new(store_name, key, value);
+        request.send(self).await
+    }
+
+    /// Gets a value from a store.
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - name of the store
+    /// * `key` - key of the value
+    ///
+    /// # Example
+    /// Assumes that a StorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// use std::convert::TryInto;
+    /// use momento::storage::GetResponse;
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// # storage_client.put(&store_name, "greeting", "hello").await?;
+    ///
+    /// match storage_client.get(&store_name, "greeting").await? {
+    ///     GetResponse::Found { value } => {
+    ///         let greeting: String = value.try_into()?;
+    ///         println!("Found {greeting}");
+    ///     }
+    ///     GetResponse::NotFound => println!("Not found"),
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](StorageClient::send_request) method to get a value using a [GetRequest].
+    pub async fn get(
+        &self,
+        store_name: impl Into<String>,
+        key: impl Into<String>,
+    ) -> MomentoResult<GetResponse> {
+        let request = GetRequest::new(store_name, key);
+        request.send(self).await
+    }
+
+    /// Deletes a value from a store. Deleting a key that has no value succeeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `store_name` - name of the store
+    /// * `key` - key of the value to delete
+    ///
+    /// # Example
+    /// Assumes that a StorageClient named `storage_client` has been created and is available.
+    /// ```
+    /// # fn main() -> anyhow::Result<()> {
+    /// # use momento_test_util::create_doctest_storage_client;
+    /// # tokio_test::block_on(async {
+    /// # let (storage_client, store_name) = create_doctest_storage_client();
+    /// storage_client.delete(&store_name, "greeting").await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    /// You can also use the [send_request](StorageClient::send_request) method to delete a value using a [DeleteRequest].
+    pub async fn delete(
+        &self,
+        store_name: impl Into<String>,
+        key: impl Into<String>,
+    ) -> MomentoResult<DeleteResponse> {
+        let request = DeleteRequest::new(store_name, key);
+        request.send(self).await
+    }
+
+    /// Lower-level API to send any type of [StorageRequest] to the server. This is used for cases when
+    /// you want to build a request yourself rather than use the short-hand API for that request type.
+    pub async fn send_request<R: StorageRequest>(&self, request: R) -> MomentoResult<R::Response> {
+        request.send(self).await
+    }
+
+    /* helper fns */
+    pub(crate) fn new(
+        data_clients: Vec<StoreClient>,
+        control_client: StoreControlClient,
+        configuration: Configuration,
+    ) -> Self {
+        Self {
+            data_clients,
+            control_client,
+            configuration,
+        }
+    }
+
+    pub(crate) fn client_timeout(&self) -> Duration {
+        self.configuration.client_timeout()
+    }
+
+    pub(crate) fn control_client(&self) -> StoreControlClient {
+        self.control_client.clone()
+    }
+
+    pub(crate) fn next_data_client(&self) -> StoreClient {
+        let next_index =
+            NEXT_DATA_CLIENT_INDEX.fetch_add(1, Ordering::Relaxed) % self.data_clients.len();
+        self.data_clients[next_index].clone()
+    }
+}
@@ -0,0 +1,119 @@
+use crate::config::grpc_configuration::GrpcConfiguration;
+use crate::config::transport_strategy::TransportStrategy;
+use crate::grpc::header_interceptor::HeaderInterceptor;
+use crate::storage::protos::{StoreClient, StoreControlClient};
+use crate::storage::{Configuration, StorageClient};
+use crate::utils::ChannelConnectError;
+use crate::{utils, CredentialProvider, MomentoResult};
+
+use tonic::codegen::InterceptedService;
+use tonic::transport::Channel;
+
+/// A builder for creating a [StorageClient].
+pub struct StorageClientBuilder<State>(pub State);
+
+/// Internal state marker for the builder.
+#[doc(hidden)]
+pub struct NeedsConfiguration {}
+
+/// Internal state marker for the builder.
+#[doc(hidden)]
+pub struct NeedsCredentialProvider {
+    configuration: Configuration,
+}
+
+/// Internal state marker for the builder.
+#[doc(hidden)]
+pub struct ReadyToBuild {
+    configuration: Configuration,
+    credential_provider: CredentialProvider,
+}
+
+impl StorageClientBuilder<NeedsConfiguration> {
+    /// Set the configuration for the client.
+    pub fn configuration(
+        self,
+        configuration: impl Into<Configuration>,
+    ) -> StorageClientBuilder<NeedsCredentialProvider> {
+        StorageClientBuilder(NeedsCredentialProvider {
+            configuration: configuration.into(),
+        })
+    }
+}
+
+impl StorageClientBuilder<NeedsCredentialProvider> {
+    /// Set the credential provider for the client.
+    pub fn credential_provider(
+        self,
+        credential_provider: CredentialProvider,
+    ) -> StorageClientBuilder<ReadyToBuild> {
+        StorageClientBuilder(ReadyToBuild {
+            configuration: self.0.configuration,
+            credential_provider,
+        })
+    }
+}
+
+impl StorageClientBuilder<ReadyToBuild> {
+    /// Set the number of connections the client opens for data operations.
+    pub fn with_num_connections(
+        self,
+        num_connections: usize,
+    ) -> StorageClientBuilder<ReadyToBuild> {
+        let grpc_configuration = self.0.configuration.transport_strategy.grpc_configuration;
+        let transport_strategy = TransportStrategy {
+            grpc_configuration: GrpcConfiguration {
+                num_channels: num_connections,
+                ..grpc_configuration
+            },
+        };
+
+        StorageClientBuilder(ReadyToBuild {
+            configuration: Configuration { transport_strategy },
+            ..self.0
+        })
+    }
+
+    /// Build the configured client.
+    pub fn build(self) -> MomentoResult<StorageClient> {
+        let agent_value = &utils::user_agent("store");
+        let grpc_configuration = &self.0.configuration.transport_strategy.grpc_configuration;
+
+        let data_channels_result: Result<Vec<Channel>, ChannelConnectError> = (0
+            ..grpc_configuration.num_channels)
+            .map(|_| {
+                utils::connect_channel_lazily_configurable(
+                    &self.0.credential_provider.cache_endpoint,
+                    grpc_configuration.clone(),
+                )
+            })
+            .collect();
+        let data_channels = data_channels_result?;
+
+        let control_channel = utils::connect_channel_lazily_configurable(
+            &self.0.credential_provider.control_endpoint,
+            grpc_configuration.clone(),
+        )?;
+        let control_client = StoreControlClient::new(InterceptedService::new(
+            control_channel,
+            HeaderInterceptor::new(&self.0.credential_provider.auth_token, agent_value),
+        ));
+
+        let data_clients: Vec<StoreClient> = data_channels
+            .into_iter()
+            .map(|c| {
+                let data_interceptor = InterceptedService::new(
+                    c,
+                    HeaderInterceptor::new(&self.0.credential_provider.auth_token, agent_value),
+                );
+                StoreClient::new(data_interceptor)
+            })
+            .collect();
+
+        Ok(StorageClient::new(
+            data_clients,
+            control_client,
+            self.0.configuration,
+        ))
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{GetResponse, MomentoRequest, SetRequest};
+use crate::codec::{Codec, JsonCodec};
+use crate::topics::SubscriptionValue;
+use crate::{CacheClient, MomentoResult};
+
+/// A position in a topic: the sequence number and page of the last item a subscriber
+/// processed. Subscribing from a checkpoint resumes with the items published after it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// The sequence number of the last processed item.
+    pub topic_sequence_number: u64,
+    /// The sequence page of the last processed item.
+    pub topic_sequence_page: u64,
+}
+
+impl From<&SubscriptionValue> for Checkpoint {
+    fn from(value: &SubscriptionValue) -> Self {
+        Self {
+            topic_sequence_number: value.topic_sequence_number,
+            topic_sequence_page: value.topic_sequence_page,
+        }
+    }
+}
+
+/// Persists the [Checkpoint]s of checkpointed subscriptions, so a subscriber can resume where
+/// it left off after its process restarts.
+///
+/// Checkpoints are stored under a name chosen by the subscriber. Give each subscriber that
+/// consumes a topic independently its own name.
+///
+/// [InMemoryCheckpointStore] keeps checkpoints for the life of the process and
+/// [CacheCheckpointStore] keeps them in a Momento cache. Implement this trait to keep them
+/// somewhere else, such as your own database.
+pub trait CheckpointStore: Send + Sync {
+    /// Load the checkpoint saved under `checkpoint_name`, if there is one.
+    fn load(
+        &self,
+        checkpoint_name: &str,
+    ) -> impl Future<Output = MomentoResult<Option<Checkpoint>>> + Send;
+
+    /// Save `checkpoint` under `checkpoint_name`, replacing any checkpoint saved before.
+    fn save(
+        &self,
+        checkpoint_name: &str,
+        checkpoint: Checkpoint,
+    ) -> impl Future<Output = MomentoResult<()>> + Send;
+}
+
+/// A [CheckpointStore] which keeps checkpoints in memory. Clones share the same checkpoints.
+///
+/// Checkpoints do not survive a restart, so this is mostly useful for tests, or for resuming
+/// subscriptions that are torn down and recreated within a process.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCheckpointStore {
+    checkpoints: Arc<Mutex<HashMap<String, Checkpoint>>>,
+}
+
+impl InMemoryCheckpointStore {
+    /// Constructs an empty checkpoint store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Checkpoint>> {
+        // The checkpoints are always valid, so a poisoned lock can be recovered.
+        self.checkpoints
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CheckpointStore for InMemoryCheckpointStore {
+    async fn load(&self, checkpoint_name: &str) -> MomentoResult<Option<Checkpoint>> {
+        Ok(self.lock().get(checkpoint_name).copied())
+    }
+
+    async fn save(&self, checkpoint_name: &str, checkpoint: Checkpoint) -> MomentoResult<()> {
+        self.lock().insert(checkpoint_name.to_string(), checkpoint);
+        Ok(())
+    }
+}
+
+/// A [CheckpointStore] which keeps checkpoints in a Momento cache, serialized as JSON under
+/// `<key prefix><checkpoint name>`.
+///
+/// Items in a cache expire, so set a [ttl](CacheCheckpointStore::ttl) longer than a subscriber
+/// may be down for. Otherwise checkpoints are stored with the client's default TTL.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_cache_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::topics::CacheCheckpointStore;
+/// # let (cache_client, cache_name) = create_doctest_cache_client();
+///
+/// let checkpoint_store = CacheCheckpointStore::new(cache_client, &cache_name)
+///     .key_prefix("my-app:checkpoint:")
+///     .ttl(Duration::from_secs(7 * 24 * 60 * 60));
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CacheCheckpointStore {
+    client: CacheClient,
+    cache_name: String,
+    key_prefix: String,
+    ttl: Option<Duration>,
+}
+
+impl CacheCheckpointStore {
+    /// Constructs a checkpoint store which keeps checkpoints in the given cache.
+    pub fn new(client: CacheClient, cache_name: impl Into<String>) -> Self {
+        Self {
+            client,
+            cache_name: cache_name.into(),
+            key_prefix: "topic-checkpoint:".to_string(),
+            ttl: None,
+        }
+    }
+
+    /// Sets the prefix of the keys checkpoints are stored under. Defaults to `topic-checkpoint:`.
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.key_prefix = key_prefix.into();
+        self
+    }
+
+    /// Sets how long a checkpoint is kept after it was last saved.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    fn key(&self, checkpoint_name: &str) -> String {
+        format!("{}{}", self.key_prefix, checkpoint_name)
+    }
+}
+
+impl CheckpointStore for CacheCheckpointStore {
+    async fn load(&self, checkpoint_name: &str) -> MomentoResult<Option<Checkpoint>> {
+        match self
+            .client
+            .get(&self.cache_name, self.key(checkpoint_name))
+            .await?
+        {
+            GetResponse::Hit { value } => JsonCodec.decode(&value.raw_item).map(Some),
+            GetResponse::Miss => Ok(None),
+        }
+    }
+
+    async fn save(&self, checkpoint_name: &str, checkpoint: Checkpoint) -> MomentoResult<()> {
+        SetRequest::new(
+            &self.cache_name,
+            self.key(checkpoint_name),
+            JsonCodec.encode(&checkpoint)?,
+        )
+        .ttl(self.ttl)
+        .send(&self.client)
+        .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn in_memory_store_keeps_the_latest_checkpoint_per_name() -> MomentoResult<()> {
+        let store = InMemoryCheckpointStore::new();
+        assert_eq!(store.load("reader").await?, None);
+
+        let first = Checkpoint {
+            topic_sequence_number: 3,
+            topic_sequence_page: 1,
+        };
+        let second = Checkpoint {
+            topic_sequence_number: 4,
+            topic_sequence_page: 1,
+        };
+        store.save("reader", first).await?;
+        store.clone().save("reader", second).await?;
+        assert_eq!(store.load("reader").await?, Some(second));
+        assert_eq!(store.load("other-reader").await?, None);
+        Ok(())
+    }
+}
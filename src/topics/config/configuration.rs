@@ -1,6 +1,7 @@
 use std::time::Duration;
 
 use crate::config::transport_strategy::TransportStrategy;
+use crate::topics::ReconnectPolicy;
 
 /// Configuration for a Momento Topics client.
 ///
@@ -29,13 +30,45 @@ use crate::config::transport_strategy::TransportStrategy;
 ///                     .deadline(Duration::from_millis(1000))
 ///             )
 ///     );
+/// ```
+/// Subscriptions reconnect with the default [ReconnectPolicy] unless you provide another, and
+/// can reconnect when heartbeats stop arriving:
+/// ```
+/// # use std::time::Duration;
+/// # use momento::topics::{Configuration, ReconnectPolicy};
+/// # use momento::config::grpc_configuration::GrpcConfiguration;
+/// # use momento::config::transport_strategy::TransportStrategy;
+/// let config = Configuration::builder()
+///     .transport_strategy(
+///         TransportStrategy::builder()
+///             .grpc_configuration(
+///                 GrpcConfiguration::builder()
+///                     .deadline(Duration::from_millis(1000))
+///             )
+///     )
+///     .reconnect_policy(
+///         ReconnectPolicy::new(Duration::from_millis(50), Duration::from_secs(2)).max_attempts(10)
+///     )
+///     .heartbeat_timeout(Duration::from_secs(30))
+///     .build();
+/// ```
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Configuration {
     /// Low-level options for network interactions with Momento.
     pub(crate) transport_strategy: TransportStrategy,
+    /// How subscriptions reconnect after their streams break.
+    pub(crate) reconnect_policy: ReconnectPolicy,
+    /// How long a subscription waits for a heartbeat or item before it reconnects.
+    pub(crate) heartbeat_timeout: Option<Duration>,
+    /// The most gRPC channels the client opens for subscriptions.
+    pub(crate) max_streaming_channels: usize,
 }
 
+/// How many gRPC channels a client opens for subscriptions unless configured otherwise. Each
+/// channel carries up to 100 subscriptions.
+const DEFAULT_MAX_STREAMING_CHANNELS: usize = 16;
+
 impl Configuration {
     /// First level of constructing a TopicClient configuration. Must provide a [TransportStrategy] to continue.
     pub fn builder() -> ConfigurationBuilder<NeedsTransportStrategy> {
@@ -46,6 +79,22 @@ impl Configuration {
     pub fn deadline_millis(&self) -> Duration {
         self.transport_strategy.grpc_configuration.deadline
     }
+
+    /// Returns how subscriptions reconnect after their streams break.
+    pub fn reconnect_policy(&self) -> &ReconnectPolicy {
+        &self.reconnect_policy
+    }
+
+    /// Returns how long a subscription waits for a heartbeat or item before it assumes the
+    /// stream was dropped and reconnects, if it watches for that at all.
+    pub fn heartbeat_timeout(&self) -> Option<Duration> {
+        self.heartbeat_timeout
+    }
+
+    /// Returns the most gRPC channels the client opens for subscriptions.
+    pub fn max_streaming_channels(&self) -> usize {
+        self.max_streaming_channels
+    }
 }
 
 /// The initial state of the ConfigurationBuilder.
@@ -57,6 +106,9 @@ pub struct NeedsTransportStrategy(());
 /// The state of the ConfigurationBuilder when it is ready to build a Configuration.
 pub struct ReadyToBuild {
     transport_strategy: TransportStrategy,
+    reconnect_policy: ReconnectPolicy,
+    heartbeat_timeout: Option<Duration>,
+    max_streaming_channels: usize,
 }
 
 impl ConfigurationBuilder<NeedsTransportStrategy> {
@@ -67,15 +119,45 @@ impl ConfigurationBuilder<NeedsTransportStrategy> {
     ) -> ConfigurationBuilder<ReadyToBuild> {
         ConfigurationBuilder(ReadyToBuild {
             transport_strategy: transport_strategy.into(),
+            reconnect_policy: ReconnectPolicy::default(),
+            heartbeat_timeout: None,
+            max_streaming_channels: DEFAULT_MAX_STREAMING_CHANNELS,
         })
     }
 }
 
 impl ConfigurationBuilder<ReadyToBuild> {
+    /// Sets how subscriptions reconnect after their streams break.
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.0.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// Sets how long a subscription waits for a heartbeat or item before it assumes the stream
+    /// was silently dropped, and resubscribes from the last item it received. The server sends
+    /// heartbeats while nothing is published, so this should be comfortably longer than the
+    /// heartbeat interval. Subscriptions do not watch for heartbeats unless this is set.
+    pub fn heartbeat_timeout(mut self, heartbeat_timeout: Duration) -> Self {
+        self.0.heartbeat_timeout = Some(heartbeat_timeout);
+        self
+    }
+
+    /// Sets the most gRPC channels the client opens for subscriptions. Each channel carries up
+    /// to 100 subscriptions; the client opens more channels as it needs them, up to this many,
+    /// and closes them again once they are idle. Subscribing fails once all of them are full.
+    /// Defaults to 16.
+    pub fn max_streaming_channels(mut self, max_streaming_channels: usize) -> Self {
+        self.0.max_streaming_channels = max_streaming_channels;
+        self
+    }
+
     /// Constructs the Configuration with the given transport strategy.
     pub fn build(self) -> Configuration {
         Configuration {
             transport_strategy: self.0.transport_strategy,
+            reconnect_policy: self.0.reconnect_policy,
+            heartbeat_timeout: self.0.heartbeat_timeout,
+            max_streaming_channels: self.0.max_streaming_channels,
         }
     }
 }
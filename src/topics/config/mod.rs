@@ -2,3 +2,5 @@
 pub mod configuration;
 /// Pre-built configurations for the Momento Topics client.
 pub mod configurations;
+/// How subscriptions reconnect after their streams break.
+pub mod reconnect_policy;
@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+const DEFAULT_INITIAL_DELAY: Duration = Duration::from_millis(100);
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// How a [Subscription](crate::topics::Subscription) reconnects after its stream breaks.
+///
+/// Before each reconnect attempt the subscription waits for an exponentially growing delay,
+/// starting at `initial_delay` and capped at `max_delay`, jittered between half and all of it.
+/// The count of attempts resets once the subscription receives something from the new stream.
+/// If `max_attempts` reconnects in a row fail, the subscription ends.
+///
+/// Errors that reconnecting cannot fix, such as a deleted cache or a revoked API key, end the
+/// subscription straight away, whatever the policy.
+///
+/// ```
+/// use std::time::Duration;
+/// use momento::topics::ReconnectPolicy;
+///
+/// // Back off from 50 milliseconds up to 2 seconds, and give up after 10 failed reconnects.
+/// let policy = ReconnectPolicy::new(Duration::from_millis(50), Duration::from_secs(2)).max_attempts(10);
+/// # assert_eq!(policy.attempt_limit(), Some(10));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ReconnectPolicy {
+    initial_delay: Duration,
+    max_delay: Duration,
+    max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy {
+    /// A policy that backs off from `initial_delay` up to `max_delay` and never gives up.
+    pub fn new(initial_delay: Duration, max_delay: Duration) -> Self {
+        Self {
+            initial_delay,
+            max_delay: max_delay.max(initial_delay),
+            max_attempts: None,
+        }
+    }
+
+    /// End the subscription after this many reconnect attempts in a row fail.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// The delay before the first reconnect attempt, before jitter.
+    pub fn initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    /// The longest delay between reconnect attempts, before jitter.
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// How many reconnect attempts in a row may fail before the subscription ends, if limited.
+    pub fn attempt_limit(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    /// Whether `attempt`, counting from 1, may be made.
+    pub(crate) fn allows(&self, attempt: u32) -> bool {
+        self.max_attempts
+            .is_none_or(|max_attempts| attempt <= max_attempts)
+    }
+
+    /// The jittered delay before reconnect `attempt`, counting from 1.
+    pub(crate) fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let ceiling = self
+            .initial_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        let ceiling_millis = ceiling.as_millis() as u64;
+        if ceiling_millis == 0 {
+            return ceiling;
+        }
+        Duration::from_millis(rand::rng().random_range(ceiling_millis / 2..=ceiling_millis))
+    }
+}
+
+impl Default for ReconnectPolicy {
+    /// Backs off from 100 milliseconds up to 5 seconds, and never gives up.
+    fn default() -> Self {
+        Self::new(DEFAULT_INITIAL_DELAY, DEFAULT_MAX_DELAY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_grow_exponentially_up_to_the_maximum() {
+        let policy = ReconnectPolicy::new(Duration::from_millis(100), Duration::from_secs(1));
+        for (attempt, ceiling) in [
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (40, 1000),
+        ] {
+            let delay = policy.delay(attempt);
+            assert!(
+                delay >= Duration::from_millis(ceiling / 2)
+                    && delay <= Duration::from_millis(ceiling),
+                "attempt {} waited {:?}",
+                attempt,
+                delay
+            );
+        }
+    }
+
+    #[test]
+    fn limits_attempts_only_when_asked() {
+        let policy = ReconnectPolicy::default();
+        assert!(policy.allows(u32::MAX));
+
+        let policy = policy.max_attempts(3);
+        assert!(policy.allows(3));
+        assert!(!policy.allows(4));
+        assert_eq!(policy.attempt_limit(), Some(3));
+    }
+}
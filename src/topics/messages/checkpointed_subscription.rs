@@ -0,0 +1,306 @@
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+
+use crate::topics::{
+    Checkpoint, CheckpointStore, MomentoRequest, SubscribeRequest, Subscription, SubscriptionValue,
+};
+use crate::{MomentoError, MomentoResult, TopicClient};
+
+/// When a [CheckpointedSubscription] saves its checkpoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitPolicy {
+    /// Save a checkpoint every time an item is [acknowledged](CheckpointedSubscription::ack).
+    OnAck,
+    /// Treat each item as processed once the next one is requested, or once it is
+    /// acknowledged, and save a checkpoint at most once per interval.
+    Periodically(Duration),
+}
+
+/// Subscribe to a topic, resuming from the checkpoint saved under `checkpoint_name` and saving
+/// new checkpoints as items are processed.
+///
+/// If there is no checkpoint yet, the subscription starts from the latest message, like an
+/// ordinary subscription. Commits default to [CommitPolicy::OnAck].
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::topics::{CheckpointedSubscribeRequest, CommitPolicy, InMemoryCheckpointStore};
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let request = CheckpointedSubscribeRequest::new(
+///     cache_name,
+///     "topic",
+///     "order-processor",
+///     InMemoryCheckpointStore::new(),
+/// )
+/// .commit_policy(CommitPolicy::Periodically(Duration::from_secs(5)));
+///
+/// let mut subscription = topic_client.send_request(request).await?;
+/// while let Some(message) = subscription.next().await? {
+///     println!("Received message: {:?}", message.kind);
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct CheckpointedSubscribeRequest<S> {
+    cache_name: String,
+    topic: String,
+    checkpoint_name: String,
+    checkpoint_store: S,
+    commit_policy: CommitPolicy,
+}
+
+impl<S: CheckpointStore> CheckpointedSubscribeRequest<S> {
+    /// Create a new CheckpointedSubscribeRequest.
+    pub fn new(
+        cache_name: impl Into<String>,
+        topic: impl Into<String>,
+        checkpoint_name: impl Into<String>,
+        checkpoint_store: S,
+    ) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            topic: topic.into(),
+            checkpoint_name: checkpoint_name.into(),
+            checkpoint_store,
+            commit_policy: CommitPolicy::OnAck,
+        }
+    }
+
+    /// Set when the subscription saves its checkpoint.
+    pub fn commit_policy(mut self, commit_policy: CommitPolicy) -> Self {
+        self.commit_policy = commit_policy;
+        self
+    }
+}
+
+impl<S: CheckpointStore> MomentoRequest for CheckpointedSubscribeRequest<S> {
+    type Response = CheckpointedSubscription<S>;
+
+    async fn send(self, topic_client: &TopicClient) -> MomentoResult<CheckpointedSubscription<S>> {
+        let checkpoint = self.checkpoint_store.load(&self.checkpoint_name).await?;
+        if let Some(checkpoint) = checkpoint {
+            log::debug!(
+                "resuming subscription to {} in {} from {checkpoint:?}",
+                self.topic,
+                self.cache_name
+            );
+        }
+        let subscription = SubscribeRequest::new(
+            self.cache_name,
+            self.topic,
+            checkpoint.map(|checkpoint| checkpoint.topic_sequence_number),
+            checkpoint.map(|checkpoint| checkpoint.topic_sequence_page),
+        )
+        .send(topic_client)
+        .await?;
+        Ok(CheckpointedSubscription {
+            subscription,
+            checkpoint_store: self.checkpoint_store,
+            checkpoint_name: self.checkpoint_name,
+            commit_policy: self.commit_policy,
+            delivered: None,
+            processed: None,
+            committed: checkpoint,
+            last_commit: Instant::now(),
+        })
+    }
+}
+
+/// A [Subscription] which saves how far it has got in a [CheckpointStore], so a subscriber can
+/// resume after a restart without missing items.
+///
+/// Items are received with [CheckpointedSubscription::next]. An item counts as processed once
+/// it is [acknowledged](CheckpointedSubscription::ack), or with
+/// [CommitPolicy::Periodically], once the next item is requested. Call
+/// [CheckpointedSubscription::commit] before shutting down to save the latest position.
+///
+/// Items processed after the last saved checkpoint are delivered again after a restart, so
+/// processing should tolerate seeing an item twice.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use momento::topics::InMemoryCheckpointStore;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let mut subscription = topic_client
+///     .subscribe_with_checkpoints(cache_name, "topic", "order-processor", InMemoryCheckpointStore::new())
+///     .await?;
+/// while let Some(message) = subscription.next().await? {
+///     println!("Received message: {:?}", message.kind);
+///     subscription.ack(&message).await?;
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct CheckpointedSubscription<S> {
+    subscription: Subscription,
+    checkpoint_store: S,
+    checkpoint_name: String,
+    commit_policy: CommitPolicy,
+    /// The last item handed to the caller.
+    delivered: Option<Checkpoint>,
+    /// The last item the caller is done with.
+    processed: Option<Checkpoint>,
+    /// The last checkpoint in the store.
+    committed: Option<Checkpoint>,
+    last_commit: Instant,
+}
+
+impl<S: CheckpointStore> CheckpointedSubscription<S> {
+    /// Receive the next item from the topic.
+    ///
+    /// Returns `Ok(None)` once the subscription has ended, or the error that ended it. With
+    /// [CommitPolicy::Periodically], this also marks the previous item as processed and saves a
+    /// checkpoint if one is due, returning an error if that fails.
+    pub async fn next(&mut self) -> MomentoResult<Option<SubscriptionValue>> {
+        if let CommitPolicy::Periodically(_) = self.commit_policy {
+            if self.delivered.is_some() {
+                self.processed = self.delivered;
+            }
+            self.commit_if_due().await?;
+        }
+        match self.subscription.next().await {
+            Some(value) => {
+                self.delivered = Some(Checkpoint::from(&value));
+                Ok(Some(value))
+            }
+            None => match self.subscription.error() {
+                Some(error) => Err(MomentoError {
+                    message: error.message.clone(),
+                    error_code: error.error_code.clone(),
+                    inner_error: None,
+                }),
+                None => Ok(None),
+            },
+        }
+    }
+
+    /// Mark `value` as processed. With [CommitPolicy::OnAck], this saves it as the checkpoint.
+    pub async fn ack(&mut self, value: &SubscriptionValue) -> MomentoResult<()> {
+        self.processed = Some(Checkpoint::from(value));
+        match self.commit_policy {
+            CommitPolicy::OnAck => self.commit().await,
+            CommitPolicy::Periodically(_) => self.commit_if_due().await,
+        }
+    }
+
+    /// Save the last processed item as the checkpoint now, if it has not been saved already.
+    pub async fn commit(&mut self) -> MomentoResult<()> {
+        let processed = match self.processed {
+            Some(processed) if self.committed != Some(processed) => processed,
+            _ => return Ok(()),
+        };
+        self.checkpoint_store
+            .save(&self.checkpoint_name, processed)
+            .await?;
+        log::trace!("saved checkpoint {} at {processed:?}", self.checkpoint_name);
+        self.committed = Some(processed);
+        self.last_commit = Instant::now();
+        Ok(())
+    }
+
+    /// The last checkpoint saved, or the one the subscription resumed from.
+    pub fn checkpoint(&self) -> Option<Checkpoint> {
+        self.committed
+    }
+
+    /// Stop receiving from the topic. See [Subscription::unsubscribe]. This does not save a
+    /// checkpoint; call [CheckpointedSubscription::commit] first to keep your progress.
+    pub fn unsubscribe(&mut self) {
+        self.subscription.unsubscribe()
+    }
+
+    async fn commit_if_due(&mut self) -> MomentoResult<()> {
+        if let CommitPolicy::Periodically(interval) = self.commit_policy {
+            if self.last_commit.elapsed() >= interval {
+                self.commit().await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topics::messages::subscription::test_support::test_subscription;
+    use crate::topics::{InMemoryCheckpointStore, ValueKind};
+
+    fn checkpointed(
+        store: InMemoryCheckpointStore,
+        commit_policy: CommitPolicy,
+    ) -> CheckpointedSubscription<InMemoryCheckpointStore> {
+        CheckpointedSubscription {
+            subscription: test_subscription(None),
+            checkpoint_store: store,
+            checkpoint_name: "reader".to_string(),
+            commit_policy,
+            delivered: None,
+            processed: None,
+            committed: None,
+            last_commit: Instant::now(),
+        }
+    }
+
+    fn value(topic_sequence_number: u64) -> SubscriptionValue {
+        SubscriptionValue {
+            kind: ValueKind::Text("value".to_string()),
+            topic_sequence_number,
+            topic_sequence_page: 2,
+            publisher_id: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn saves_a_checkpoint_on_each_ack() -> MomentoResult<()> {
+        let store = InMemoryCheckpointStore::new();
+        let mut subscription = checkpointed(store.clone(), CommitPolicy::OnAck);
+
+        subscription.ack(&value(7)).await?;
+        let expected = Checkpoint {
+            topic_sequence_number: 7,
+            topic_sequence_page: 2,
+        };
+        assert_eq!(store.load("reader").await?, Some(expected));
+        assert_eq!(subscription.checkpoint(), Some(expected));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn saves_periodically_and_on_commit() -> MomentoResult<()> {
+        let store = InMemoryCheckpointStore::new();
+        let mut subscription = checkpointed(
+            store.clone(),
+            CommitPolicy::Periodically(Duration::from_secs(60)),
+        );
+
+        subscription.ack(&value(7)).await?;
+        assert_eq!(store.load("reader").await?, None);
+
+        subscription.commit().await?;
+        assert_eq!(
+            store.load("reader").await?.map(|c| c.topic_sequence_number),
+            Some(7)
+        );
+
+        // Once the interval has passed, the next ack saves a checkpoint.
+        subscription.commit_policy = CommitPolicy::Periodically(Duration::ZERO);
+        subscription.ack(&value(8)).await?;
+        assert_eq!(
+            store.load("reader").await?.map(|c| c.topic_sequence_number),
+            Some(8)
+        );
+        Ok(())
+    }
+}
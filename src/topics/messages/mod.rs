@@ -1,6 +1,9 @@
+pub mod checkpointed_subscription;
+pub mod multi_topic_subscription;
 pub mod publish;
 pub mod subscribe;
 pub mod subscription;
+pub mod webhooks;
 
 mod momento_request;
 
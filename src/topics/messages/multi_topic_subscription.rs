@@ -0,0 +1,449 @@
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll};
+
+use futures::channel::mpsc;
+use futures::stream::{AbortHandle, Abortable, SelectAll};
+use futures::{Stream, StreamExt};
+
+use crate::topics::{Subscription, SubscriptionValue};
+use crate::{MomentoError, MomentoResult, TopicClient};
+
+/// One stream of the values published to many topics in a cache, each paired with the name of
+/// the topic it was published to.
+///
+/// Every topic has its own [Subscription] underneath, which reconnects independently of the
+/// others. Topics can be added and removed while the stream is consumed, either directly or
+/// from other tasks through a [MultiTopicSubscriptionHandle].
+///
+/// The stream stays open while it has no topics, so more can be added. If a topic's
+/// subscription ends, for instance because the API key may no longer subscribe to it, the topic
+/// is removed and the others carry on. Such topics and the errors that ended them are listed
+/// by [MultiTopicSubscription::ended_topics].
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use futures::StreamExt;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let mut notifications = topic_client
+///     .subscribe_many(&cache_name, ["user-1", "user-2"])
+///     .await?;
+///
+/// // Another task can follow new users as they sign in.
+/// let handle = notifications.handle();
+/// tokio::spawn(async move {
+///     handle.add_topic("user-3").await?;
+///     handle.remove_topic("user-1");
+///     Ok::<_, momento::MomentoError>(())
+/// });
+///
+/// while let Some((topic, message)) = notifications.next().await {
+///     println!("{}: {:?}", topic, message.kind);
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct MultiTopicSubscription {
+    subscriptions: SelectAll<Abortable<TopicStream>>,
+    topics: HashMap<String, TopicEntry>,
+    ended_topics: HashMap<String, MomentoError>,
+    /// Topics a handle is subscribing to, by the id of the add. Removing the topic cancels it.
+    pending_adds: HashMap<String, u64>,
+    next_id: u64,
+    commands: mpsc::UnboundedReceiver<Command>,
+    handle: MultiTopicSubscriptionHandle,
+}
+
+struct TopicEntry {
+    id: u64,
+    abort_handle: AbortHandle,
+}
+
+enum Command {
+    /// A handle started subscribing to a topic.
+    Subscribing(String, u64),
+    /// A handle finished subscribing to a topic, or failed to.
+    Add(String, u64, Option<Box<Subscription>>),
+    Remove(String),
+}
+
+impl MultiTopicSubscription {
+    pub(crate) fn new(topic_client: TopicClient, cache_name: String) -> Self {
+        let (sender, commands) = mpsc::unbounded();
+        Self {
+            subscriptions: SelectAll::new(),
+            topics: HashMap::new(),
+            ended_topics: HashMap::new(),
+            pending_adds: HashMap::new(),
+            next_id: 0,
+            commands,
+            handle: MultiTopicSubscriptionHandle {
+                topic_client,
+                cache_name,
+                commands: sender,
+                next_add_id: Arc::new(AtomicU64::new(0)),
+                topic_names: Arc::default(),
+            },
+        }
+    }
+
+    /// A handle for adding and removing topics from other tasks.
+    pub fn handle(&self) -> MultiTopicSubscriptionHandle {
+        self.handle.clone()
+    }
+
+    /// The topics currently subscribed to.
+    pub fn topics(&self) -> impl Iterator<Item = &str> {
+        self.topics.keys().map(String::as_str)
+    }
+
+    /// The topics whose subscriptions ended by themselves, with the error that ended each. A
+    /// topic is no longer listed once it is added or removed again.
+    ///
+    /// The stream does not yield anything when a topic ends, so check this after receiving
+    /// values, or periodically.
+    pub fn ended_topics(&self) -> impl Iterator<Item = (&str, &MomentoError)> {
+        self.ended_topics
+            .iter()
+            .map(|(topic, error)| (topic.as_str(), error))
+    }
+
+    /// Subscribe to another topic. Does nothing if the topic is already subscribed to.
+    pub async fn add_topic(&mut self, topic: impl Into<String>) -> MomentoResult<()> {
+        let topic = topic.into();
+        if self.handle.topic_names().contains(&topic) {
+            return Ok(());
+        }
+        let subscription = self
+            .handle
+            .topic_client
+            .subscribe(self.handle.cache_name.as_str(), topic.as_str())
+            .await?;
+        self.insert(topic, subscription);
+        Ok(())
+    }
+
+    /// Unsubscribe from a topic, or cancel a handle adding it. Returns whether it was
+    /// subscribed to.
+    pub fn remove_topic(&mut self, topic: &str) -> bool {
+        self.handle.topic_names().remove(topic);
+        self.pending_adds.remove(topic);
+        self.abort_topic(topic)
+    }
+
+    fn abort_topic(&mut self, topic: &str) -> bool {
+        self.ended_topics.remove(topic);
+        match self.topics.remove(topic) {
+            Some(entry) => {
+                entry.abort_handle.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub(crate) fn insert(&mut self, topic: String, subscription: Subscription) {
+        if self.topics.contains_key(&topic) {
+            return;
+        }
+        self.handle.topic_names().insert(topic.clone());
+        self.ended_topics.remove(&topic);
+        let id = self.next_id;
+        self.next_id += 1;
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        self.subscriptions.push(Abortable::new(
+            TopicStream {
+                topic: topic.clone(),
+                id,
+                subscription: Some(subscription),
+            },
+            abort_registration,
+        ));
+        self.topics.insert(topic, TopicEntry { id, abort_handle });
+    }
+
+    fn apply_commands(&mut self, context: &mut Context<'_>) {
+        // The receiver never ends: this subscription holds a sender for new handles.
+        while let Poll::Ready(Some(command)) = self.commands.poll_next_unpin(context) {
+            match command {
+                Command::Subscribing(topic, add_id) => {
+                    self.pending_adds.insert(topic, add_id);
+                }
+                Command::Add(topic, add_id, subscription) => {
+                    // Only add the topic if it was not removed while subscribing.
+                    if self.pending_adds.get(&topic) == Some(&add_id) {
+                        self.pending_adds.remove(&topic);
+                        match subscription {
+                            Some(subscription) => self.insert(topic, *subscription),
+                            None if !self.topics.contains_key(&topic) => {
+                                self.handle.topic_names().remove(&topic);
+                            }
+                            None => {}
+                        }
+                    }
+                }
+                Command::Remove(topic) => {
+                    // The handle already forgot the topic's name, and may have added it again.
+                    self.pending_adds.remove(&topic);
+                    self.abort_topic(&topic);
+                }
+            }
+        }
+    }
+}
+
+impl Stream for MultiTopicSubscription {
+    type Item = (String, SubscriptionValue);
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.apply_commands(context);
+        loop {
+            match self.subscriptions.poll_next_unpin(context) {
+                Poll::Ready(Some(TopicItem::Value(topic, value))) => {
+                    break Poll::Ready(Some((topic, value)))
+                }
+                Poll::Ready(Some(TopicItem::Ended(topic, id, error))) => {
+                    if self.topics.get(&topic).is_some_and(|entry| entry.id == id) {
+                        self.topics.remove(&topic);
+                        self.handle.topic_names().remove(&topic);
+                        if let Some(error) = error {
+                            self.ended_topics.insert(topic, error);
+                        }
+                    }
+                }
+                // No topics right now, but more may be added.
+                Poll::Ready(None) | Poll::Pending => break Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Adds and removes the topics of a [MultiTopicSubscription] from another task. Changes take
+/// effect the next time the subscription is polled.
+#[derive(Clone, Debug)]
+pub struct MultiTopicSubscriptionHandle {
+    topic_client: TopicClient,
+    cache_name: String,
+    commands: mpsc::UnboundedSender<Command>,
+    next_add_id: Arc<AtomicU64>,
+    /// The topics subscribed to or being added, so handles can skip topics that already are
+    /// without opening another stream.
+    topic_names: Arc<Mutex<HashSet<String>>>,
+}
+
+impl MultiTopicSubscriptionHandle {
+    /// Subscribe to another topic. Does nothing if the topic is already subscribed to or being
+    /// added.
+    ///
+    /// Removing the topic while this is subscribing cancels it: the topic is not added.
+    pub async fn add_topic(&self, topic: impl Into<String>) -> MomentoResult<()> {
+        let topic = topic.into();
+        let subscribe = self
+            .topic_client
+            .subscribe(self.cache_name.clone(), topic.clone());
+        self.add_topic_with(topic, subscribe).await
+    }
+
+    async fn add_topic_with(
+        &self,
+        topic: String,
+        subscribe: impl Future<Output = MomentoResult<Subscription>>,
+    ) -> MomentoResult<()> {
+        // Mark the add as pending first, so a removal sent while subscribing cancels it. If the
+        // subscription has been dropped there is nothing to add the topic to, and dropping the
+        // new subscription releases it.
+        if !self.topic_names().insert(topic.clone()) {
+            return Ok(());
+        }
+        let add_id = self.next_add_id.fetch_add(1, Ordering::Relaxed);
+        let _ = self
+            .commands
+            .unbounded_send(Command::Subscribing(topic.clone(), add_id));
+        match subscribe.await {
+            Ok(subscription) => {
+                let _ = self.commands.unbounded_send(Command::Add(
+                    topic,
+                    add_id,
+                    Some(Box::new(subscription)),
+                ));
+                Ok(())
+            }
+            Err(error) => {
+                let _ = self
+                    .commands
+                    .unbounded_send(Command::Add(topic, add_id, None));
+                Err(error)
+            }
+        }
+    }
+
+    /// Unsubscribe from a topic, or cancel adding it.
+    pub fn remove_topic(&self, topic: impl Into<String>) {
+        let topic = topic.into();
+        self.topic_names().remove(&topic);
+        let _ = self.commands.unbounded_send(Command::Remove(topic));
+    }
+
+    fn topic_names(&self) -> MutexGuard<'_, HashSet<String>> {
+        // The set only holds names, so a poisoned lock can be recovered.
+        self.topic_names
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+enum TopicItem {
+    Value(String, SubscriptionValue),
+    /// The topic's subscription ended by itself, with the error that ended it.
+    Ended(String, u64, Option<MomentoError>),
+}
+
+/// A topic's subscription, tagging its values with the topic.
+struct TopicStream {
+    topic: String,
+    id: u64,
+    subscription: Option<Subscription>,
+}
+
+impl Stream for TopicStream {
+    type Item = TopicItem;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let subscription = match this.subscription.as_mut() {
+            Some(subscription) => subscription,
+            None => return Poll::Ready(None),
+        };
+        match subscription.poll_next_unpin(context) {
+            Poll::Ready(Some(value)) => {
+                Poll::Ready(Some(TopicItem::Value(this.topic.clone(), value)))
+            }
+            Poll::Ready(None) => {
+                log::warn!(
+                    "subscription to {} ended: {:?}",
+                    this.topic,
+                    subscription.error()
+                );
+                let error = subscription.take_error();
+                this.subscription = None;
+                Poll::Ready(Some(TopicItem::Ended(this.topic.clone(), this.id, error)))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::topics::messages::subscription::test_support::test_subscription as subscription;
+    use crate::topics::topic_client::test_support::test_topic_client;
+    use crate::MomentoErrorCode;
+
+    fn multi_topic_subscription() -> MomentoResult<MultiTopicSubscription> {
+        Ok(MultiTopicSubscription::new(
+            test_topic_client()?,
+            "cache".to_string(),
+        ))
+    }
+
+    fn sorted_topics(subscription: &MultiTopicSubscription) -> Vec<&str> {
+        let mut topics: Vec<&str> = subscription.topics().collect();
+        topics.sort_unstable();
+        topics
+    }
+
+    #[tokio::test]
+    async fn adds_and_removes_topics() -> MomentoResult<()> {
+        let mut multi = multi_topic_subscription()?;
+        multi.insert("a".to_string(), subscription(None));
+        multi.insert("b".to_string(), subscription(None));
+        multi.insert("b".to_string(), subscription(None));
+        assert_eq!(sorted_topics(&multi), vec!["a", "b"]);
+
+        assert!(multi.remove_topic("a"));
+        assert!(!multi.remove_topic("a"));
+        multi.handle().remove_topic("b");
+        assert!(multi.next().now_or_never().is_none());
+        assert!(sorted_topics(&multi).is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn drops_topics_whose_subscriptions_end() -> MomentoResult<()> {
+        let mut multi = multi_topic_subscription()?;
+        multi.insert("open".to_string(), subscription(None));
+        multi.insert(
+            "denied".to_string(),
+            subscription(Some(tonic::Status::permission_denied("no"))),
+        );
+
+        // The stream carries on with the remaining topic rather than ending.
+        assert!(multi.next().now_or_never().is_none());
+        assert_eq!(sorted_topics(&multi), vec!["open"]);
+        let ended: Vec<_> = multi
+            .ended_topics()
+            .map(|(topic, error)| (topic, error.error_code.clone()))
+            .collect();
+        assert_eq!(ended, vec![("denied", MomentoErrorCode::PermissionError)]);
+
+        multi.insert("denied".to_string(), subscription(None));
+        assert_eq!(multi.ended_topics().count(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn removing_a_topic_cancels_adding_it() -> MomentoResult<()> {
+        let mut multi = multi_topic_subscription()?;
+        let handle = multi.handle();
+
+        let (subscribed, subscribe) = futures::channel::oneshot::channel();
+        let subscribe = async { Ok(subscribe.await.expect("subscription is sent")) };
+        let mut add = Box::pin(handle.add_topic_with("a".to_string(), subscribe));
+        assert!(futures::poll!(&mut add).is_pending());
+        handle.remove_topic("a");
+        let _ = subscribed.send(subscription(None));
+        add.await?;
+
+        assert!(multi.next().now_or_never().is_none());
+        assert!(sorted_topics(&multi).is_empty());
+
+        handle
+            .add_topic_with("a".to_string(), async { Ok(subscription(None)) })
+            .await?;
+        assert!(multi.next().now_or_never().is_none());
+        assert_eq!(sorted_topics(&multi), vec!["a"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn adding_a_subscribed_topic_does_not_subscribe_again() -> MomentoResult<()> {
+        let mut multi = multi_topic_subscription()?;
+        multi.insert("a".to_string(), subscription(None));
+        let handle = multi.handle();
+
+        let add = handle.add_topic_with("a".to_string(), futures::future::pending());
+        assert!(matches!(add.now_or_never(), Some(Ok(()))));
+
+        handle.remove_topic("a");
+        let (subscribed, subscribe) = futures::channel::oneshot::channel();
+        let subscribe = async { Ok(subscribe.await.expect("subscription is sent")) };
+        let mut add = Box::pin(handle.add_topic_with("a".to_string(), subscribe));
+        assert!(futures::poll!(&mut add).is_pending());
+        let _ = subscribed.send(subscription(None));
+        add.await?;
+        assert!(multi.next().now_or_never().is_none());
+        assert_eq!(sorted_topics(&multi), vec!["a"]);
+        Ok(())
+    }
+}
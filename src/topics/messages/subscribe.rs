@@ -16,6 +16,9 @@ use crate::{
 /// # Optional Arguments
 ///
 /// * `resume_at_topic_sequence_number` - The sequence number to resume from. If not provided, the subscription will start from the latest message or from zero if starting a new subscription.
+/// * `resume_at_sequence_page` - The sequence page to resume from, as received with the sequence number.
+///
+/// To have the SDK keep track of where to resume from, see [CheckpointedSubscribeRequest](crate::topics::CheckpointedSubscribeRequest).
 ///
 /// # Example
 ///
@@ -83,11 +86,11 @@ impl MomentoRequest for SubscribeRequest {
                 resume_at_topic_sequence_number: self
                     .resume_at_topic_sequence_number
                     .unwrap_or_default(),
-                sequence_page: self.resume_at_topic_sequence_number.unwrap_or_default(),
+                sequence_page: self.resume_at_sequence_page.unwrap_or_default(),
             },
         )?;
 
-        let next_stream_client = topic_client.get_next_streaming_client()?;
+        let (next_stream_client, slot) = topic_client.get_next_streaming_client()?;
         let stream = next_stream_client
             .clone()
             .subscribe(request)
@@ -100,6 +103,9 @@ impl MomentoRequest for SubscribeRequest {
             self.resume_at_topic_sequence_number.unwrap_or_default(),
             self.resume_at_sequence_page.unwrap_or_default(),
             SubscriptionState::Subscribed(stream),
-        ))
+        )
+        .with_reconnect_policy(topic_client.configuration.reconnect_policy().clone())
+        .with_heartbeat_timeout(topic_client.configuration.heartbeat_timeout())
+        .with_slot(slot))
     }
 }
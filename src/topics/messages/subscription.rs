@@ -2,14 +2,17 @@ use core::str;
 use std::convert::TryFrom;
 
 use futures::future::BoxFuture;
-use futures::{Future, FutureExt};
+use futures::{Future, FutureExt, Stream};
 use momento_protos::cache_client::pubsub::SubscriptionRequest;
 use momento_protos::cache_client::pubsub::{self, pubsub_client::PubsubClient};
 use tonic::{codegen::InterceptedService, transport::Channel};
 
 use crate::grpc::header_interceptor::HeaderInterceptor;
+use crate::status_to_error;
+use crate::topics::topic_subscription_manager::SubscriptionSlot;
+use crate::topics::ReconnectPolicy;
 use crate::utils::parse_string;
-use crate::MomentoError;
+use crate::{MomentoError, MomentoErrorCode};
 
 type ChannelType = InterceptedService<Channel, HeaderInterceptor>;
 
@@ -18,7 +21,13 @@ type ChannelType = InterceptedService<Channel, HeaderInterceptor>;
 /// subscribed and someone is publishing.
 ///
 /// A Subscription is a `futures::Stream<SubscriptionValue>`. It will try to
-/// stay connected for as long as you try to consume it.
+/// stay connected for as long as you try to consume it, reconnecting as the
+/// [ReconnectPolicy] in the client's [Configuration](crate::topics::Configuration)
+/// allows.
+///
+/// The stream ends if the subscription cannot go on, for instance because the
+/// cache was deleted, the API key is no longer allowed to subscribe, or the
+/// reconnect policy ran out of attempts. [Subscription::error] then tells you why.
 ///
 /// # Examples
 ///
@@ -82,6 +91,17 @@ pub struct Subscription {
     current_sequence_number: u64,
     current_sequence_page: u64,
     current_subscription: SubscriptionState,
+    reconnect_policy: ReconnectPolicy,
+    reconnect_attempts: u32,
+    terminal_error: Option<MomentoError>,
+    heartbeat_timeout: Option<std::time::Duration>,
+    /// Armed while the stream is quiet; fires if nothing arrives within the heartbeat timeout.
+    watchdog: Option<std::pin::Pin<Box<tokio::time::Sleep>>>,
+    /// An event to report on the next poll, when one poll produced two.
+    queued_event: Option<SubscriptionEvent>,
+    /// Counts this subscription against its channel's stream limit until it is dropped.
+    slot: Option<SubscriptionSlot>,
+    unsubscribed: bool,
 }
 
 type SubscriptionFuture = BoxFuture<
@@ -125,9 +145,79 @@ impl Subscription {
             current_sequence_number,
             current_sequence_page,
             current_subscription,
+            reconnect_policy: ReconnectPolicy::default(),
+            reconnect_attempts: 0,
+            terminal_error: None,
+            heartbeat_timeout: None,
+            watchdog: None,
+            queued_event: None,
+            slot: None,
+            unsubscribed: false,
         }
     }
 
+    pub(crate) fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    pub(crate) fn with_heartbeat_timeout(
+        mut self,
+        heartbeat_timeout: Option<std::time::Duration>,
+    ) -> Self {
+        self.heartbeat_timeout = heartbeat_timeout;
+        self
+    }
+
+    pub(crate) fn with_slot(mut self, slot: SubscriptionSlot) -> Self {
+        self.slot = Some(slot);
+        self
+    }
+
+    /// Stop receiving from the topic. The stream to the server is closed and the subscription
+    /// no longer counts against the client's limit of concurrent subscriptions. The stream
+    /// ends, without an [error](Subscription::error).
+    ///
+    /// Dropping a subscription does the same; this is for when you still hold the subscription
+    /// but are done with the topic, such as when breaking out of a loop over a borrowed stream.
+    pub fn unsubscribe(&mut self) {
+        self.unsubscribed = true;
+        self.slot = None;
+        self.watchdog = None;
+        self.queued_event = None;
+        // Replacing the state drops the stream, which tells the server we are done with it.
+        self.current_subscription = SubscriptionState::Resubscribing {
+            subscription_future: futures::future::pending().boxed(),
+        };
+    }
+
+    /// The error that ended the subscription, if it has ended.
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// use futures::StreamExt;
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    ///
+    /// let mut subscription = topic_client.subscribe(cache_name, "my-topic").await?;
+    /// while let Some(message) = subscription.next().await {
+    ///     println!("Received message: {:?}", message.kind);
+    /// }
+    /// if let Some(error) = subscription.error() {
+    ///     eprintln!("Subscription ended: {error}");
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn error(&self) -> Option<&MomentoError> {
+        self.terminal_error.as_ref()
+    }
+
+    pub(crate) fn take_error(&mut self) -> Option<MomentoError> {
+        self.terminal_error.take()
+    }
+
     /// Yeah this is a pain, but doing it here lets us yield a simpler-typed subscription stream.
     /// Also, we don't want to expose protocol buffers types outside of the sdk, so some type map
     /// had to happen. It's all one-off at the moment though so might as well leave it as one
@@ -182,13 +272,69 @@ impl Subscription {
         }
     }
 
-    fn resubscribe(&self) -> SubscriptionFuture {
+    /// Starts reconnecting after the stream broke with `status`, or closed if there is none.
+    /// Returns the event announcing the reconnect, or nothing if the subscription has to end.
+    fn reconnect(&mut self, status: Option<tonic::Status>) -> Option<SubscriptionEvent> {
+        let status = match status {
+            Some(status)
+                if matches!(
+                    status.code(),
+                    tonic::Code::NotFound
+                        | tonic::Code::PermissionDenied
+                        | tonic::Code::Unauthenticated
+                ) =>
+            {
+                log::warn!(
+                    "subscription to {} in {} cannot continue: {status:?}",
+                    self.topic,
+                    self.cache_name
+                );
+                self.terminal_error = Some(status_to_error(status));
+                self.slot = None;
+                return None;
+            }
+            status => status,
+        };
+
+        self.reconnect_attempts = self.reconnect_attempts.saturating_add(1);
+        if !self.reconnect_policy.allows(self.reconnect_attempts) {
+            log::warn!(
+                "subscription to {} in {} gave up after {} reconnect attempts",
+                self.topic,
+                self.cache_name,
+                self.reconnect_attempts - 1
+            );
+            self.terminal_error = Some(match status {
+                Some(status) => status_to_error(status),
+                None => MomentoError {
+                    message: "the subscription stream kept closing".to_string(),
+                    error_code: MomentoErrorCode::ServerUnavailable,
+                    inner_error: None,
+                },
+            });
+            self.slot = None;
+            return None;
+        }
+
+        let delay = self.reconnect_policy.delay(self.reconnect_attempts);
+        log::debug!("reconnect attempt {} in {delay:?}", self.reconnect_attempts);
+        self.current_subscription = SubscriptionState::Resubscribing {
+            subscription_future: self.resubscribe(delay),
+        };
+        Some(SubscriptionEvent::Reconnecting {
+            attempt: self.reconnect_attempts,
+            error: status.map(status_to_error),
+        })
+    }
+
+    fn resubscribe(&self, delay: std::time::Duration) -> SubscriptionFuture {
         let mut client = self.client.clone();
         let cache_name = self.cache_name.clone();
         let topic = self.topic.clone();
         let resume_at_topic_sequence_number = self.current_sequence_number;
         let resume_at_topic_sequence_page = self.current_sequence_page;
         async move {
+            tokio::time::sleep(delay).await;
             client
                 .subscribe(SubscriptionRequest {
                     cache_name,
@@ -202,64 +348,82 @@ impl Subscription {
     }
 }
 
-impl futures::Stream for Subscription {
-    type Item = SubscriptionValue;
-
-    fn poll_next(
-        mut self: std::pin::Pin<&mut Self>,
+impl Subscription {
+    fn poll_event(
+        &mut self,
         context: &mut std::task::Context<'_>,
-    ) -> std::task::Poll<Option<Self::Item>> {
+    ) -> std::task::Poll<Option<SubscriptionEvent>> {
+        if let Some(event) = self.queued_event.take() {
+            return std::task::Poll::Ready(Some(event));
+        }
+        if self.unsubscribed || self.terminal_error.is_some() {
+            return std::task::Poll::Ready(None);
+        }
         loop {
-            match &mut self.as_mut().current_subscription {
+            match &mut self.current_subscription {
                 SubscriptionState::Subscribed(subscription) => {
                     match std::pin::pin!(subscription).poll_next(context) {
                         std::task::Poll::Ready(possible_result) => match possible_result {
                             Some(result) => match result {
-                                Ok(item) => match Self::map_into(item) {
-                                    MapKind::RealItem(item) => {
-                                        log::trace!("received an item: {item:?}");
-                                        match &item {
-                                            SubscriptionItem::Value(v) => {
-                                                self.current_sequence_number =
-                                                    v.topic_sequence_number;
-                                                self.current_sequence_page = v.topic_sequence_page;
-                                                // We return only SubscriptionValues here
-                                                break std::task::Poll::Ready(Some(v.clone()));
-                                            }
-                                            SubscriptionItem::Discontinuity(d) => {
-                                                log::debug!("discontinuity! Updating sequence number and continuing...");
-                                                self.current_sequence_number =
-                                                    d.new_sequence_number;
-                                                self.current_sequence_page = d.new_sequence_page;
+                                Ok(item) => {
+                                    // Anything arriving on the stream, even a heartbeat, means
+                                    // the connection is healthy again.
+                                    self.reconnect_attempts = 0;
+                                    self.watchdog = None;
+                                    match Self::map_into(item) {
+                                        MapKind::RealItem(item) => {
+                                            log::trace!("received an item: {item:?}");
+                                            match item {
+                                                SubscriptionItem::Value(v) => {
+                                                    self.current_sequence_number =
+                                                        v.topic_sequence_number;
+                                                    self.current_sequence_page =
+                                                        v.topic_sequence_page;
+                                                    break std::task::Poll::Ready(Some(
+                                                        SubscriptionEvent::Item(v),
+                                                    ));
+                                                }
+                                                SubscriptionItem::Discontinuity(d) => {
+                                                    log::debug!("discontinuity! Updating sequence number and continuing...");
+                                                    self.current_sequence_number =
+                                                        d.new_sequence_number;
+                                                    self.current_sequence_page =
+                                                        d.new_sequence_page;
+                                                    break std::task::Poll::Ready(Some(
+                                                        SubscriptionEvent::Discontinuity {
+                                                            last: d.last_sequence_number,
+                                                            new: d.new_sequence_number,
+                                                        },
+                                                    ));
+                                                }
                                             }
                                         }
+                                        MapKind::Heartbeat => {
+                                            log::trace!("received a heartbeat");
+                                            break std::task::Poll::Ready(Some(
+                                                SubscriptionEvent::Heartbeat,
+                                            ));
+                                        }
+                                        MapKind::BrokenProtocolMissingAttribute(e) => {
+                                            log::debug!("bad item! Missing {e} - skipping...");
+                                        }
                                     }
-                                    MapKind::Heartbeat => {
-                                        log::trace!("received a heartbeat - skipping...");
-                                    }
-                                    MapKind::BrokenProtocolMissingAttribute(e) => {
-                                        log::debug!("bad item! Missing {e} - skipping...");
-                                    }
-                                },
+                                }
                                 Err(e) => {
                                     log::debug!(
                                         "error talking to momento! {e:?} - Reconnecting..."
                                     );
-                                    self.current_subscription = SubscriptionState::Resubscribing {
-                                        subscription_future: self.resubscribe(),
-                                    };
+                                    break std::task::Poll::Ready(self.reconnect(Some(e)));
                                 }
                             },
                             None => {
                                 log::debug!("stream closed - reconnecting...");
-                                self.current_subscription = SubscriptionState::Resubscribing {
-                                    subscription_future: self.resubscribe(),
-                                };
+                                break std::task::Poll::Ready(self.reconnect(None));
                             }
                         },
                         std::task::Poll::Pending => {
                             // Nobody has published anything just yet.
-                            break std::task::Poll::Pending;
+                            break self.poll_watchdog(context);
                         }
                     }
                 }
@@ -272,14 +436,14 @@ impl futures::Stream for Subscription {
                                 log::trace!("state transitioned back to subscribed");
                                 self.current_subscription =
                                     SubscriptionState::Subscribed(new_subscription.into_inner());
+                                self.watchdog = None;
+                                break std::task::Poll::Ready(Some(SubscriptionEvent::Reconnected));
                             }
                             Err(e) => {
                                 log::debug!(
                                     "error while trying to resubscribe. {e:?} - trying again..."
                                 );
-                                self.current_subscription = SubscriptionState::Resubscribing {
-                                    subscription_future: self.resubscribe(),
-                                };
+                                break std::task::Poll::Ready(self.reconnect(Some(e)));
                             }
                         },
                         std::task::Poll::Pending => {
@@ -293,6 +457,162 @@ impl futures::Stream for Subscription {
     }
 }
 
+impl Subscription {
+    /// Called while the stream has nothing to read. If it has been quiet for longer than the
+    /// heartbeat timeout, drops it and starts resubscribing.
+    fn poll_watchdog(
+        &mut self,
+        context: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<SubscriptionEvent>> {
+        let heartbeat_timeout = match self.heartbeat_timeout {
+            Some(heartbeat_timeout) => heartbeat_timeout,
+            None => return std::task::Poll::Pending,
+        };
+        let watchdog = self
+            .watchdog
+            .get_or_insert_with(|| Box::pin(tokio::time::sleep(heartbeat_timeout)));
+        match watchdog.as_mut().poll(context) {
+            std::task::Poll::Ready(()) => {
+                log::warn!(
+                    "no heartbeat on the subscription to {} in {} for {heartbeat_timeout:?} - reconnecting...",
+                    self.topic,
+                    self.cache_name
+                );
+                self.watchdog = None;
+                self.queued_event = self.reconnect(Some(tonic::Status::deadline_exceeded(
+                    format!("no heartbeat for {heartbeat_timeout:?}"),
+                )));
+                std::task::Poll::Ready(Some(SubscriptionEvent::HeartbeatTimeout))
+            }
+            std::task::Poll::Pending => std::task::Poll::Pending,
+        }
+    }
+}
+
+impl futures::Stream for Subscription {
+    type Item = SubscriptionValue;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        context: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        loop {
+            match self.poll_event(context) {
+                std::task::Poll::Ready(Some(SubscriptionEvent::Item(value))) => {
+                    // We return only SubscriptionValues here
+                    break std::task::Poll::Ready(Some(value));
+                }
+                std::task::Poll::Ready(Some(_)) => {}
+                std::task::Poll::Ready(None) => break std::task::Poll::Ready(None),
+                std::task::Poll::Pending => break std::task::Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Something that happened on a subscription: a published value, or a change in the state of
+/// the stream.
+///
+/// Get these by subscribing with
+/// [TopicClient::subscribe_with_events](crate::TopicClient::subscribe_with_events).
+#[derive(Debug)]
+pub enum SubscriptionEvent {
+    /// A value published to the topic.
+    Item(SubscriptionValue),
+    /// Some messages were missed. The next item follows sequence number `new`.
+    Discontinuity {
+        /// The last sequence number processed for this stream, if known.
+        last: Option<u64>,
+        /// The sequence number the stream continues from.
+        new: u64,
+    },
+    /// The server confirmed the stream is alive while nothing is being published.
+    Heartbeat,
+    /// Nothing arrived for longer than the configured
+    /// [heartbeat timeout](crate::topics::Configuration::heartbeat_timeout), so the stream is
+    /// assumed dropped. The subscription resubscribes from the last item it received.
+    HeartbeatTimeout,
+    /// The stream broke and the subscription is about to reconnect.
+    Reconnecting {
+        /// How many reconnects in a row this is, counting from 1.
+        attempt: u32,
+        /// What broke the stream, or nothing if the server closed it.
+        error: Option<MomentoError>,
+    },
+    /// The subscription reconnected. Items continue after the last one received.
+    Reconnected,
+}
+
+/// A stream of every [SubscriptionEvent] on a topic, for consumers that track gaps in the
+/// sequence or watch heartbeats to notice a stalled stream.
+///
+/// Like a [Subscription], it ends if the subscription cannot go on, and
+/// [SubscriptionEvents::error] then tells you why.
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use futures::StreamExt;
+/// use momento::topics::SubscriptionEvent;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let mut events = topic_client.subscribe_with_events(cache_name, "my-topic").await?;
+/// while let Some(event) = events.next().await {
+///     match event {
+///         SubscriptionEvent::Item(message) => println!("Received message: {:?}", message.kind),
+///         SubscriptionEvent::Discontinuity { last, new } => {
+///             println!("Missed messages between {:?} and {}", last, new)
+///         }
+///         SubscriptionEvent::Heartbeat => {}
+///         SubscriptionEvent::HeartbeatTimeout => println!("Stream went quiet"),
+///         SubscriptionEvent::Reconnecting { attempt, error } => {
+///             println!("Reconnecting, attempt {}: {:?}", attempt, error)
+///         }
+///         SubscriptionEvent::Reconnected => println!("Reconnected"),
+///     }
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct SubscriptionEvents {
+    subscription: Subscription,
+}
+
+impl SubscriptionEvents {
+    /// The error that ended the subscription, if it has ended.
+    pub fn error(&self) -> Option<&MomentoError> {
+        self.subscription.error()
+    }
+
+    /// Stop receiving from the topic. See [Subscription::unsubscribe].
+    pub fn unsubscribe(&mut self) {
+        self.subscription.unsubscribe()
+    }
+
+    /// Go back to receiving only the published values.
+    pub fn into_values(self) -> Subscription {
+        self.subscription
+    }
+}
+
+impl From<Subscription> for SubscriptionEvents {
+    fn from(subscription: Subscription) -> Self {
+        Self { subscription }
+    }
+}
+
+impl futures::Stream for SubscriptionEvents {
+    type Item = SubscriptionEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        context: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.subscription.poll_event(context)
+    }
+}
+
 /// An item from a topic.
 #[derive(Debug, PartialEq)]
 pub(crate) enum SubscriptionItem {
@@ -398,3 +718,152 @@ impl IntoTopicValue for Vec<u8> {
         pubsub::topic_value::Kind::Binary(self)
     }
 }
+
+/// Fixtures shared by the tests of the topic modules.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use futures::FutureExt;
+    use momento_protos::cache_client::pubsub::pubsub_client::PubsubClient;
+    use tonic::{codegen::InterceptedService, transport::Channel};
+
+    use super::{ChannelType, Subscription, SubscriptionState};
+    use crate::grpc::header_interceptor::HeaderInterceptor;
+
+    /// A pubsub client for an address nothing listens on.
+    pub(crate) fn unconnected_pubsub_client() -> PubsubClient<ChannelType> {
+        let channel = Channel::from_static("http://127.0.0.1:1").connect_lazy();
+        PubsubClient::new(InterceptedService::new(
+            channel,
+            HeaderInterceptor::new("token", "test"),
+        ))
+    }
+
+    /// A subscription to `topic` in `cache`, waiting to resubscribe with an
+    /// [unconnected_pubsub_client]. The pending attempt fails with `first_attempt`, or never
+    /// completes if it is `None`.
+    pub(crate) fn test_subscription(first_attempt: Option<tonic::Status>) -> Subscription {
+        let subscription_future = match first_attempt {
+            Some(status) => async move { Err(status) }.boxed(),
+            None => futures::future::pending().boxed(),
+        };
+        Subscription::new(
+            unconnected_pubsub_client(),
+            "cache".to_string(),
+            "topic".to_string(),
+            0,
+            0,
+            SubscriptionState::Resubscribing {
+                subscription_future,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    use super::test_support::test_subscription;
+    use super::*;
+
+    /// A response body that never sends anything, like a stream the network silently dropped.
+    struct SilentBody;
+
+    impl hyper::body::Body for SilentBody {
+        type Data = hyper::body::Bytes;
+        type Error = tonic::Status;
+
+        fn poll_frame(
+            self: std::pin::Pin<&mut Self>,
+            _context: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Option<Result<hyper::body::Frame<Self::Data>, Self::Error>>> {
+            std::task::Poll::Pending
+        }
+    }
+
+    fn fast_reconnects() -> ReconnectPolicy {
+        ReconnectPolicy::new(Duration::from_millis(1), Duration::from_millis(5)).max_attempts(2)
+    }
+
+    fn unreachable_subscription(first_attempt: tonic::Status) -> Subscription {
+        test_subscription(Some(first_attempt)).with_reconnect_policy(fast_reconnects())
+    }
+
+    #[tokio::test]
+    async fn ends_on_errors_that_reconnecting_cannot_fix() {
+        let mut subscription = unreachable_subscription(tonic::Status::permission_denied("no"));
+        assert_eq!(subscription.next().await, None);
+        let error = subscription
+            .error()
+            .expect("the subscription ended with an error");
+        assert_eq!(error.error_code, MomentoErrorCode::PermissionError);
+        assert_eq!(subscription.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn ends_once_the_reconnect_attempts_run_out() {
+        let mut subscription = unreachable_subscription(tonic::Status::unavailable("down"));
+        let next = tokio::time::timeout(Duration::from_secs(10), subscription.next()).await;
+        assert_eq!(next.ok(), Some(None));
+        assert!(subscription.error().is_some());
+        assert_eq!(subscription.reconnect_attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn reports_each_reconnect_attempt_as_an_event() {
+        let mut events =
+            SubscriptionEvents::from(unreachable_subscription(tonic::Status::unavailable("down")));
+        for expected_attempt in 1..=2 {
+            match events.next().await {
+                Some(SubscriptionEvent::Reconnecting { attempt, error }) => {
+                    assert_eq!(attempt, expected_attempt);
+                    assert!(error.is_some());
+                }
+                other => panic!("expected a reconnect, got {:?}", other),
+            }
+        }
+        assert!(events.next().await.is_none());
+        assert!(events.error().is_some());
+    }
+
+    #[tokio::test]
+    async fn reconnects_when_heartbeats_stop() {
+        let silent_stream = tonic::Streaming::new_response(
+            tonic::codec::Codec::decoder(&mut tonic::codec::ProstCodec::<
+                pubsub::SubscriptionRequest,
+                pubsub::SubscriptionItem,
+            >::default()),
+            SilentBody,
+            tonic::codegen::http::StatusCode::OK,
+            None,
+            None,
+        );
+        let mut subscription = unreachable_subscription(tonic::Status::unavailable("down"));
+        subscription.current_subscription = SubscriptionState::Subscribed(silent_stream);
+        let mut events = SubscriptionEvents::from(
+            subscription.with_heartbeat_timeout(Some(Duration::from_millis(20))),
+        );
+
+        assert!(matches!(
+            events.next().await,
+            Some(SubscriptionEvent::HeartbeatTimeout)
+        ));
+        match events.next().await {
+            Some(SubscriptionEvent::Reconnecting {
+                attempt: 1,
+                error: Some(error),
+            }) => assert_eq!(error.error_code, MomentoErrorCode::TimeoutError),
+            other => panic!("expected a reconnect, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn unsubscribing_ends_the_stream_without_an_error() {
+        let mut subscription = unreachable_subscription(tonic::Status::unavailable("down"));
+        subscription.unsubscribe();
+        assert_eq!(subscription.next().await, None);
+        assert!(subscription.error().is_none());
+    }
+}
@@ -0,0 +1,63 @@
+use crate::topics::{MomentoRequest, WebhookId};
+use crate::utils::prep_request_with_timeout;
+use crate::{MomentoResult, TopicClient};
+
+use crate::topics::webhook_protos;
+
+/// Delete a webhook. Messages published to its topic are no longer delivered.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache the webhook is in.
+/// * `webhook_name` - The name of the webhook.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use momento::topics::DeleteWebhookRequest;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let request = DeleteWebhookRequest::new(&cache_name, "my-webhook");
+/// topic_client.send_request(request).await?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct DeleteWebhookRequest {
+    id: WebhookId,
+}
+
+impl DeleteWebhookRequest {
+    /// Create a new DeleteWebhookRequest.
+    pub fn new(cache_name: impl Into<String>, webhook_name: impl Into<String>) -> Self {
+        Self {
+            id: WebhookId::new(cache_name, webhook_name),
+        }
+    }
+}
+
+impl MomentoRequest for DeleteWebhookRequest {
+    type Response = DeleteWebhookResponse;
+
+    async fn send(self, topic_client: &TopicClient) -> MomentoResult<DeleteWebhookResponse> {
+        let request = prep_request_with_timeout(
+            &self.id.cache_name.clone(),
+            topic_client.configuration.deadline_millis(),
+            webhook_protos::DeleteWebhookRequest {
+                webhook_id: Some(self.id.into_proto()),
+            },
+        )?;
+
+        let _ = topic_client
+            .webhook_client()
+            .delete_webhook(request)
+            .await?;
+        Ok(DeleteWebhookResponse {})
+    }
+}
+
+/// The response type for a successful delete webhook request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DeleteWebhookResponse {}
@@ -0,0 +1,78 @@
+use crate::topics::MomentoRequest;
+use crate::utils::prep_request_with_timeout;
+use crate::{MomentoResult, TopicClient};
+
+use crate::topics::webhook_protos;
+
+/// Get the secret that a webhook's deliveries are signed with.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache the webhook is in.
+/// * `webhook_name` - The name of the webhook.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use momento::topics::GetWebhookSecretRequest;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let request = GetWebhookSecretRequest::new(&cache_name, "my-webhook");
+/// let secret = topic_client.send_request(request).await?.secret_string;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct GetWebhookSecretRequest {
+    cache_name: String,
+    webhook_name: String,
+}
+
+impl GetWebhookSecretRequest {
+    /// Create a new GetWebhookSecretRequest.
+    pub fn new(cache_name: impl Into<String>, webhook_name: impl Into<String>) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            webhook_name: webhook_name.into(),
+        }
+    }
+}
+
+impl MomentoRequest for GetWebhookSecretRequest {
+    type Response = GetWebhookSecretResponse;
+
+    async fn send(self, topic_client: &TopicClient) -> MomentoResult<GetWebhookSecretResponse> {
+        let request = prep_request_with_timeout(
+            &self.cache_name.clone(),
+            topic_client.configuration.deadline_millis(),
+            webhook_protos::GetWebhookSecretRequest {
+                webhook_name: self.webhook_name,
+                cache_name: self.cache_name,
+            },
+        )?;
+
+        let response = topic_client
+            .webhook_client()
+            .get_webhook_secret(request)
+            .await?
+            .into_inner();
+        Ok(GetWebhookSecretResponse {
+            cache_name: response.cache_name,
+            webhook_name: response.webhook_name,
+            secret_string: response.secret_string,
+        })
+    }
+}
+
+/// The response type for a successful get webhook secret request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GetWebhookSecretResponse {
+    /// The cache the webhook is in.
+    pub cache_name: String,
+    /// The name of the webhook.
+    pub webhook_name: String,
+    /// The secret that deliveries of the webhook are signed with.
+    pub secret_string: String,
+}
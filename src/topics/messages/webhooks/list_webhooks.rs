@@ -0,0 +1,87 @@
+use crate::topics::{MomentoRequest, Webhook};
+use crate::utils::prep_request_with_timeout;
+use crate::{MomentoResult, TopicClient};
+
+use crate::topics::webhook_protos;
+
+/// List the webhooks of a cache's topics.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use momento::topics::ListWebhooksRequest;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let request = ListWebhooksRequest::new(&cache_name);
+/// for webhook in topic_client.send_request(request).await?.webhooks {
+///     println!("{} delivers {}", webhook.id.webhook_name, webhook.topic_name);
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct ListWebhooksRequest {
+    cache_name: String,
+}
+
+impl ListWebhooksRequest {
+    /// Create a new ListWebhooksRequest.
+    pub fn new(cache_name: impl Into<String>) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+        }
+    }
+}
+
+impl MomentoRequest for ListWebhooksRequest {
+    type Response = ListWebhooksResponse;
+
+    async fn send(self, topic_client: &TopicClient) -> MomentoResult<ListWebhooksResponse> {
+        let request = prep_request_with_timeout(
+            &self.cache_name.clone(),
+            topic_client.configuration.deadline_millis(),
+            webhook_protos::ListWebhookRequest {
+                cache_name: self.cache_name,
+            },
+        )?;
+
+        let response = topic_client
+            .webhook_client()
+            .list_webhooks(request)
+            .await?
+            .into_inner();
+        Ok(ListWebhooksResponse {
+            webhooks: response
+                .webhook
+                .into_iter()
+                .map(Webhook::from_proto)
+                .collect(),
+        })
+    }
+}
+
+/// The response type for a successful list webhooks request.
+///
+/// You can cast it directly into a `Vec<Webhook>`.
+/// ```
+/// # use momento::topics::{ListWebhooksResponse, Webhook};
+/// # let response = ListWebhooksResponse { webhooks: vec![] };
+/// let webhooks: Vec<Webhook> = response.into();
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ListWebhooksResponse {
+    /// The webhooks of the cache's topics.
+    pub webhooks: Vec<Webhook>,
+}
+
+impl From<ListWebhooksResponse> for Vec<Webhook> {
+    fn from(response: ListWebhooksResponse) -> Self {
+        response.webhooks
+    }
+}
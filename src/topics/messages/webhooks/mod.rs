@@ -0,0 +1,93 @@
+pub mod delete_webhook;
+pub mod get_webhook_secret;
+pub mod list_webhooks;
+pub mod put_webhook;
+pub mod rotate_webhook_secret;
+pub mod validation;
+
+use crate::topics::webhook_protos;
+
+/// Identifies a webhook: its name, and the cache whose topics it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WebhookId {
+    /// The cache the webhook's topic is in.
+    pub cache_name: String,
+    /// The name of the webhook, unique within the cache.
+    pub webhook_name: String,
+}
+
+impl WebhookId {
+    /// Constructs a new WebhookId.
+    pub fn new(cache_name: impl Into<String>, webhook_name: impl Into<String>) -> Self {
+        Self {
+            cache_name: cache_name.into(),
+            webhook_name: webhook_name.into(),
+        }
+    }
+
+    pub(crate) fn into_proto(self) -> webhook_protos::WebhookId {
+        webhook_protos::WebhookId {
+            cache_name: self.cache_name,
+            webhook_name: self.webhook_name,
+        }
+    }
+}
+
+/// Where a webhook delivers the messages published to its topic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookDestination {
+    /// Each message is sent as the body of a POST request to this URL.
+    PostUrl(String),
+}
+
+impl From<&str> for WebhookDestination {
+    fn from(url: &str) -> Self {
+        WebhookDestination::PostUrl(url.to_string())
+    }
+}
+
+impl From<String> for WebhookDestination {
+    fn from(url: String) -> Self {
+        WebhookDestination::PostUrl(url)
+    }
+}
+
+/// A webhook that delivers the messages published to a topic to an HTTP endpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Webhook {
+    /// The webhook's name and cache.
+    pub id: WebhookId,
+    /// The topic whose messages the webhook delivers.
+    pub topic_name: String,
+    /// Where the webhook delivers messages.
+    pub destination: WebhookDestination,
+}
+
+impl Webhook {
+    pub(crate) fn into_proto(self) -> webhook_protos::Webhook {
+        let WebhookDestination::PostUrl(url) = self.destination;
+        webhook_protos::Webhook {
+            webhook_id: Some(self.id.into_proto()),
+            topic_name: self.topic_name,
+            destination: Some(webhook_protos::WebhookDestination {
+                kind: Some(webhook_protos::webhook_destination::Kind::PostUrl(url)),
+            }),
+        }
+    }
+
+    pub(crate) fn from_proto(webhook: webhook_protos::Webhook) -> Self {
+        let id = webhook.webhook_id.unwrap_or_default();
+        let destination = match webhook.destination.and_then(|destination| destination.kind) {
+            Some(webhook_protos::webhook_destination::Kind::PostUrl(url)) => url,
+            None => String::new(),
+        };
+        Self {
+            id: WebhookId {
+                cache_name: id.cache_name,
+                webhook_name: id.webhook_name,
+            },
+            topic_name: webhook.topic_name,
+            destination: WebhookDestination::PostUrl(destination),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::topics::{MomentoRequest, Webhook, WebhookDestination, WebhookId};
+use crate::utils::prep_request_with_timeout;
+use crate::{MomentoResult, TopicClient};
+
+use crate::topics::webhook_protos;
+
+/// Create a webhook, or update the topic and destination of an existing one. Every message
+/// published to the topic is then delivered to the destination.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache the topic is in.
+/// * `webhook_name` - The name of the webhook.
+/// * `topic` - The name of the topic whose messages the webhook delivers.
+/// * `destination` - Where the webhook delivers messages, such as a URL to POST them to.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use momento::topics::PutWebhookRequest;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let request = PutWebhookRequest::new(
+///     &cache_name,
+///     "my-webhook",
+///     "my-topic",
+///     "https://example.com/momento",
+/// );
+/// let response = topic_client.send_request(request).await?;
+/// println!("Verify deliveries with secret {}", response.secret_string);
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct PutWebhookRequest {
+    webhook: Webhook,
+}
+
+impl PutWebhookRequest {
+    /// Create a new PutWebhookRequest.
+    pub fn new(
+        cache_name: impl Into<String>,
+        webhook_name: impl Into<String>,
+        topic: impl Into<String>,
+        destination: impl Into<WebhookDestination>,
+    ) -> Self {
+        Self {
+            webhook: Webhook {
+                id: WebhookId::new(cache_name, webhook_name),
+                topic_name: topic.into(),
+                destination: destination.into(),
+            },
+        }
+    }
+}
+
+impl MomentoRequest for PutWebhookRequest {
+    type Response = PutWebhookResponse;
+
+    async fn send(self, topic_client: &TopicClient) -> MomentoResult<PutWebhookResponse> {
+        let request = prep_request_with_timeout(
+            &self.webhook.id.cache_name.clone(),
+            topic_client.configuration.deadline_millis(),
+            webhook_protos::PutWebhookRequest {
+                webhook: Some(self.webhook.into_proto()),
+            },
+        )?;
+
+        let response = topic_client
+            .webhook_client()
+            .put_webhook(request)
+            .await?
+            .into_inner();
+        Ok(PutWebhookResponse {
+            secret_string: response.secret_string,
+        })
+    }
+}
+
+/// The response type for a successful put webhook request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PutWebhookResponse {
+    /// The secret that deliveries of this webhook are signed with. See
+    /// [validate_webhook_request](crate::topics::validate_webhook_request).
+    pub secret_string: String,
+}
@@ -0,0 +1,70 @@
+use crate::topics::{MomentoRequest, WebhookId};
+use crate::utils::prep_request_with_timeout;
+use crate::{MomentoResult, TopicClient};
+
+use crate::topics::webhook_protos;
+
+/// Replace the secret that a webhook's deliveries are signed with. Deliveries are signed with
+/// the new secret from then on, so update the receiving endpoint before relying on it.
+///
+/// # Arguments
+///
+/// * `cache_name` - The name of the cache the webhook is in.
+/// * `webhook_name` - The name of the webhook.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use momento::topics::RotateWebhookSecretRequest;
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// let request = RotateWebhookSecretRequest::new(&cache_name, "my-webhook");
+/// let new_secret = topic_client.send_request(request).await?.secret_string;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct RotateWebhookSecretRequest {
+    id: WebhookId,
+}
+
+impl RotateWebhookSecretRequest {
+    /// Create a new RotateWebhookSecretRequest.
+    pub fn new(cache_name: impl Into<String>, webhook_name: impl Into<String>) -> Self {
+        Self {
+            id: WebhookId::new(cache_name, webhook_name),
+        }
+    }
+}
+
+impl MomentoRequest for RotateWebhookSecretRequest {
+    type Response = RotateWebhookSecretResponse;
+
+    async fn send(self, topic_client: &TopicClient) -> MomentoResult<RotateWebhookSecretResponse> {
+        let request = prep_request_with_timeout(
+            &self.id.cache_name.clone(),
+            topic_client.configuration.deadline_millis(),
+            webhook_protos::RotateWebhookSecretRequest {
+                webhook_id: Some(self.id.into_proto()),
+            },
+        )?;
+
+        let response = topic_client
+            .webhook_client()
+            .rotate_webhook_secret(request)
+            .await?
+            .into_inner();
+        Ok(RotateWebhookSecretResponse {
+            secret_string: response.secret_string,
+        })
+    }
+}
+
+/// The response type for a successful rotate webhook secret request.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RotateWebhookSecretResponse {
+    /// The new secret that deliveries of the webhook are signed with.
+    pub secret_string: String,
+}
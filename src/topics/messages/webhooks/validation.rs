@@ -0,0 +1,357 @@
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use sha3::Sha3_256;
+
+use crate::topics::ValueKind;
+use crate::utils::parse_string;
+use crate::{ErrorSource, MomentoError, MomentoErrorCode, MomentoResult};
+
+/// The HTTP header a webhook delivery's signature is sent in.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "momento-signature";
+
+/// How far in the future a message's publish timestamp may be, to allow for clock skew between
+/// Momento and the receiving server.
+const MAX_CLOCK_SKEW: Duration = Duration::from_secs(30);
+
+/// A message delivered by a webhook, once its signature has been verified.
+#[derive(Debug, PartialEq, Clone)]
+pub struct WebhookEvent {
+    /// The cache the topic is in.
+    pub cache_name: String,
+    /// The topic the message was published to.
+    pub topic_name: String,
+    /// The published value.
+    pub kind: ValueKind,
+    /// The sequence number of the message in the topic.
+    pub topic_sequence_number: u64,
+    /// Authenticated id from Publisher's disposable token
+    pub publisher_id: String,
+    /// When the message was published.
+    pub publish_timestamp: SystemTime,
+    /// When the webhook delivery was sent.
+    pub event_timestamp: SystemTime,
+}
+
+impl TryFrom<WebhookEvent> for String {
+    type Error = MomentoError;
+
+    fn try_from(value: WebhookEvent) -> Result<Self, Self::Error> {
+        match value.kind {
+            ValueKind::Text(string) => Ok(string),
+            ValueKind::Binary(binary) => parse_string(binary),
+        }
+    }
+}
+
+/// The JSON body of a webhook delivery.
+#[derive(Deserialize)]
+struct WebhookPayload {
+    cache: String,
+    topic: String,
+    event_timestamp: u64,
+    publish_timestamp: u64,
+    topic_sequence_number: u64,
+    #[serde(default)]
+    token_id: String,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    binary: Option<String>,
+}
+
+/// Verify that a webhook delivery was sent by Momento, and parse the message it carries.
+///
+/// Momento signs the body of each delivery with the webhook's secret, and sends the signature in
+/// the [WEBHOOK_SIGNATURE_HEADER] header as the hex encoded HMAC-SHA3-256 of the body. This
+/// checks the signature, then rejects deliveries of messages published more than `max_age` ago,
+/// so a captured delivery cannot be replayed later. Messages claiming to be published more than
+/// 30 seconds in the future are rejected as well.
+///
+/// This does not depend on an HTTP framework: pass it the raw body bytes, exactly as received,
+/// and the value of the signature header.
+///
+/// # Arguments
+///
+/// * `body` - The body of the delivery.
+/// * `signature` - The value of the [WEBHOOK_SIGNATURE_HEADER] header.
+/// * `secret` - The webhook's secret, from [TopicClient::put_webhook](crate::TopicClient::put_webhook)
+///   or [TopicClient::get_webhook_secret](crate::TopicClient::get_webhook_secret).
+/// * `max_age` - How long after a message was published its delivery is accepted.
+///
+/// # Errors
+///
+/// An [AuthenticationError](MomentoErrorCode::AuthenticationError) if the signature does not
+/// match or the message is too old or too far in the future, and an
+/// [InvalidArgumentError](MomentoErrorCode::InvalidArgumentError) if the body is not a webhook
+/// delivery.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// use std::time::Duration;
+/// use momento::topics::{validate_webhook_request, ValueKind};
+///
+/// fn handle_delivery(body: &[u8], signature: &str, secret: &str) -> anyhow::Result<()> {
+///     let event = validate_webhook_request(body, signature, secret, Duration::from_secs(60))?;
+///     if let ValueKind::Text(text) = event.kind {
+///         println!("{} received {text}", event.topic_name);
+///     }
+///     Ok(())
+/// }
+///
+/// assert!(handle_delivery(b"{}", "not a signature", "secret").is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn validate_webhook_request(
+    body: &[u8],
+    signature: &str,
+    secret: &str,
+    max_age: Duration,
+) -> MomentoResult<WebhookEvent> {
+    validate_webhook_request_at(body, signature, secret, max_age, SystemTime::now())
+}
+
+fn validate_webhook_request_at(
+    body: &[u8],
+    signature: &str,
+    secret: &str,
+    max_age: Duration,
+    now: SystemTime,
+) -> MomentoResult<WebhookEvent> {
+    let matches = decode_hex(signature.trim()).is_some_and(|signature| {
+        webhook_mac(secret.as_bytes(), body)
+            .verify_slice(&signature)
+            .is_ok()
+    });
+    if !matches {
+        return Err(authentication_error(
+            "Webhook signature does not match the body".to_string(),
+        ));
+    }
+
+    let payload: WebhookPayload = serde_json::from_slice(body).map_err(|e| MomentoError {
+        message: format!("Webhook body is not a valid delivery: {e}"),
+        error_code: MomentoErrorCode::InvalidArgumentError,
+        inner_error: Some(ErrorSource::Unknown(Box::new(e))),
+    })?;
+
+    let publish_timestamp = UNIX_EPOCH + Duration::from_millis(payload.publish_timestamp);
+    let age = match now.duration_since(publish_timestamp) {
+        Ok(age) => age,
+        Err(error) if error.duration() > MAX_CLOCK_SKEW => {
+            return Err(authentication_error(format!(
+                "Webhook message was published {}ms in the future, more than the allowed clock \
+                 skew of {}ms",
+                error.duration().as_millis(),
+                MAX_CLOCK_SKEW.as_millis()
+            )));
+        }
+        Err(_) => Duration::ZERO,
+    };
+    if age > max_age {
+        return Err(authentication_error(format!(
+            "Webhook message was published {}ms ago, more than the maximum age of {}ms",
+            age.as_millis(),
+            max_age.as_millis()
+        )));
+    }
+
+    let kind = match (payload.text, payload.binary) {
+        (Some(text), _) => ValueKind::Text(text),
+        (None, Some(binary)) => ValueKind::Binary(
+            base64::engine::general_purpose::STANDARD
+                .decode(binary)
+                .map_err(|e| MomentoError {
+                    message: format!("Webhook binary value is not valid base64: {e}"),
+                    error_code: MomentoErrorCode::InvalidArgumentError,
+                    inner_error: Some(ErrorSource::Unknown(Box::new(e))),
+                })?,
+        ),
+        (None, None) => ValueKind::Text(String::new()),
+    };
+
+    Ok(WebhookEvent {
+        cache_name: payload.cache,
+        topic_name: payload.topic,
+        kind,
+        topic_sequence_number: payload.topic_sequence_number,
+        publisher_id: payload.token_id,
+        publish_timestamp,
+        event_timestamp: UNIX_EPOCH + Duration::from_millis(payload.event_timestamp),
+    })
+}
+
+fn authentication_error(message: String) -> MomentoError {
+    MomentoError {
+        message,
+        error_code: MomentoErrorCode::AuthenticationError,
+        inner_error: None,
+    }
+}
+
+fn webhook_mac(secret: &[u8], body: &[u8]) -> Hmac<Sha3_256> {
+    let mut mac = match <Hmac<Sha3_256> as Mac>::new_from_slice(secret) {
+        Ok(mac) => mac,
+        Err(_) => unreachable!("hmac accepts keys of any length"),
+    };
+    mac.update(body);
+    mac
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    let pairs = hex.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return None;
+    }
+    pairs
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    const SECRET: &str = "webhook-secret";
+
+    fn sign(body: &str) -> String {
+        hex(&webhook_mac(SECRET.as_bytes(), body.as_bytes())
+            .finalize()
+            .into_bytes())
+    }
+
+    fn body(publish_timestamp: u64, value: &str) -> String {
+        format!(
+            r#"{{"cache":"cache","topic":"topic","event_timestamp":{},"publish_timestamp":{publish_timestamp},"topic_sequence_number":3,"token_id":"publisher",{value}}}"#,
+            publish_timestamp + 5
+        )
+    }
+
+    #[test]
+    fn webhook_mac_matches_known_mac() {
+        let key: Vec<u8> = (0u8..32).collect();
+        assert_eq!(
+            hex(&webhook_mac(&key, b"Sample message for keylen<blocklen")
+                .finalize()
+                .into_bytes()),
+            "4fe8e202c4f058e8dddc23d8c34e467343e23555e24fc2f025d598f558f67205"
+        );
+    }
+
+    #[test]
+    fn accepts_signed_recent_deliveries() -> MomentoResult<()> {
+        let now = UNIX_EPOCH + Duration::from_millis(1_700_000_010_000);
+        let text = body(1_700_000_000_000, r#""text":"hello""#);
+        let event = validate_webhook_request_at(
+            text.as_bytes(),
+            &sign(&text),
+            SECRET,
+            Duration::from_secs(60),
+            now,
+        )?;
+        assert_eq!(event.cache_name, "cache");
+        assert_eq!(event.topic_name, "topic");
+        assert_eq!(event.kind, ValueKind::Text("hello".to_string()));
+        assert_eq!(event.topic_sequence_number, 3);
+        assert_eq!(event.publisher_id, "publisher");
+        assert_eq!(
+            event.publish_timestamp,
+            UNIX_EPOCH + Duration::from_millis(1_700_000_000_000)
+        );
+
+        let binary = body(1_700_000_000_000, r#""binary":"AAEC""#);
+        let signature = sign(&binary).to_uppercase();
+        let event = validate_webhook_request_at(
+            binary.as_bytes(),
+            &signature,
+            SECRET,
+            Duration::from_secs(60),
+            now,
+        )?;
+        assert_eq!(event.kind, ValueKind::Binary(vec![0, 1, 2]));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_signatures() {
+        let now = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let text = body(1_700_000_000_000, r#""text":"hello""#);
+        let tampered = text.replace("hello", "hellp");
+        for (body, signature) in [
+            (tampered.as_str(), sign(&text)),
+            (text.as_str(), "zz".to_string()),
+            (text.as_str(), String::new()),
+        ] {
+            let error = validate_webhook_request_at(
+                body.as_bytes(),
+                &signature,
+                SECRET,
+                Duration::from_secs(60),
+                now,
+            )
+            .unwrap_err();
+            assert_eq!(error.error_code, MomentoErrorCode::AuthenticationError);
+        }
+    }
+
+    #[test]
+    fn rejects_old_deliveries_and_malformed_bodies() {
+        let now = UNIX_EPOCH + Duration::from_millis(1_700_000_061_000);
+        let text = body(1_700_000_000_000, r#""text":"hello""#);
+        let error = validate_webhook_request_at(
+            text.as_bytes(),
+            &sign(&text),
+            SECRET,
+            Duration::from_secs(60),
+            now,
+        )
+        .unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::AuthenticationError);
+
+        let malformed = r#"{"cache":"cache"}"#;
+        let error = validate_webhook_request_at(
+            malformed.as_bytes(),
+            &sign(malformed),
+            SECRET,
+            Duration::from_secs(60),
+            now,
+        )
+        .unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::InvalidArgumentError);
+    }
+
+    #[test]
+    fn rejects_deliveries_from_the_future() -> MomentoResult<()> {
+        let now = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let skewed = body(1_700_000_010_000, r#""text":"hello""#);
+        validate_webhook_request_at(
+            skewed.as_bytes(),
+            &sign(&skewed),
+            SECRET,
+            Duration::from_secs(60),
+            now,
+        )?;
+
+        let future = body(1_700_003_600_000, r#""text":"hello""#);
+        let error = validate_webhook_request_at(
+            future.as_bytes(),
+            &sign(&future),
+            SECRET,
+            Duration::from_secs(60),
+            now,
+        )
+        .unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::AuthenticationError);
+        Ok(())
+    }
+}
@@ -1,16 +1,44 @@
 mod messages;
+pub use messages::checkpointed_subscription::{
+    CheckpointedSubscribeRequest, CheckpointedSubscription, CommitPolicy,
+};
+pub use messages::multi_topic_subscription::{
+    MultiTopicSubscription, MultiTopicSubscriptionHandle,
+};
 pub use messages::publish::{PublishRequest, TopicPublishResponse};
 pub use messages::subscribe::SubscribeRequest;
 pub use messages::subscription::*;
+pub use messages::webhooks::delete_webhook::{DeleteWebhookRequest, DeleteWebhookResponse};
+pub use messages::webhooks::get_webhook_secret::{
+    GetWebhookSecretRequest, GetWebhookSecretResponse,
+};
+pub use messages::webhooks::list_webhooks::{ListWebhooksRequest, ListWebhooksResponse};
+pub use messages::webhooks::put_webhook::{PutWebhookRequest, PutWebhookResponse};
+pub use messages::webhooks::rotate_webhook_secret::{
+    RotateWebhookSecretRequest, RotateWebhookSecretResponse,
+};
+pub use messages::webhooks::validation::{
+    validate_webhook_request, WebhookEvent, WEBHOOK_SIGNATURE_HEADER,
+};
+pub use messages::webhooks::{Webhook, WebhookDestination, WebhookId};
 pub use messages::MomentoRequest;
 
+mod checkpoint_store;
+pub use checkpoint_store::{
+    CacheCheckpointStore, Checkpoint, CheckpointStore, InMemoryCheckpointStore,
+};
+
 mod config;
 
 pub use config::configuration::Configuration;
 pub use config::configurations;
+pub use config::reconnect_policy::ReconnectPolicy;
 
 mod topic_client;
 mod topic_client_builder;
 pub use topic_client::TopicClient;
 
 mod topic_subscription_manager;
+mod typed_topic;
+pub use typed_topic::{DecodeError, TypedSubscription, TypedSubscriptionValue, TypedTopic};
+mod webhook_protos;
@@ -7,15 +7,31 @@ use tonic::{codegen::InterceptedService, transport::Channel};
 use crate::grpc::header_interceptor::HeaderInterceptor;
 use crate::topics::messages::MomentoRequest;
 use crate::topics::topic_client_builder::{NeedsConfiguration, TopicClientBuilder};
-use crate::topics::{Configuration, IntoTopicValue, PublishRequest, Subscription};
+use crate::topics::{
+    CheckpointStore, CheckpointedSubscribeRequest, CheckpointedSubscription, Configuration,
+    DeleteWebhookRequest, DeleteWebhookResponse, GetWebhookSecretRequest, GetWebhookSecretResponse,
+    IntoTopicValue, ListWebhooksRequest, ListWebhooksResponse, MultiTopicSubscription,
+    PublishRequest, PutWebhookRequest, PutWebhookResponse, RotateWebhookSecretRequest,
+    RotateWebhookSecretResponse, Subscription, SubscriptionEvents, WebhookDestination,
+};
 use crate::{MomentoError, MomentoResult};
 
 use crate::topics::messages::publish::TopicPublishResponse;
 use crate::topics::messages::subscribe::SubscribeRequest;
 
 use super::topic_subscription_manager::{
-    TopicSubscriptionManager, MAX_CONCURRENT_STREAMS_PER_CHANNEL,
+    StreamingChannelPool, SubscriptionSlot, MAX_CONCURRENT_STREAMS_PER_CHANNEL,
 };
+use super::webhook_protos::WebhookClient;
+
+#[cfg(feature = "tower")]
+use crate::grpc::channel_readiness::ChannelReadiness;
+#[cfg(feature = "tower")]
+use std::future::Future;
+#[cfg(feature = "tower")]
+use std::pin::Pin;
+#[cfg(feature = "tower")]
+use std::task::{Context, Poll};
 
 /// Client to work with Momento Topics, the pub/sub service.
 ///
@@ -46,10 +62,12 @@ use super::topic_subscription_manager::{
 #[derive(Clone, Debug)]
 pub struct TopicClient {
     pub(crate) unary_client_index: Arc<AtomicUsize>,
-    pub(crate) streaming_client_index: Arc<AtomicUsize>,
     pub(crate) unary_clients: Vec<PubsubClient<InterceptedService<Channel, HeaderInterceptor>>>,
-    pub(crate) streaming_clients: Vec<TopicSubscriptionManager>,
+    pub(crate) streaming_channels: StreamingChannelPool,
+    pub(crate) webhook_client: WebhookClient,
     pub(crate) configuration: Configuration,
+    #[cfg(feature = "tower")]
+    pub(crate) readiness: ChannelReadiness,
 }
 
 impl TopicClient {
@@ -165,6 +183,319 @@ impl TopicClient {
         request.send(self).await
     }
 
+    /// Subscribe to a topic, receiving discontinuities, heartbeats and reconnects alongside the
+    /// published values.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache to use as a namespace for the topic.
+    /// * `topic` - The name of the topic to subscribe to.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// use futures::StreamExt;
+    /// use momento::topics::SubscriptionEvent;
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    ///
+    /// let mut events = topic_client.subscribe_with_events(cache_name, "topic").await?;
+    /// while let Some(event) = events.next().await {
+    ///     if let SubscriptionEvent::Discontinuity { last, new } = event {
+    ///         println!("Missed messages between {:?} and {}", last, new);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    ///
+    /// Learn more about the [SubscriptionEvents] stream.
+    pub async fn subscribe_with_events(
+        &self,
+        cache_name: impl Into<String> + Clone,
+        topic: impl Into<String> + Clone,
+    ) -> Result<SubscriptionEvents, MomentoError> {
+        self.subscribe(cache_name, topic).await.map(Into::into)
+    }
+
+    /// Subscribe to many topics in a cache at once, receiving their values through one stream
+    /// as `(topic, value)` pairs. Topics can be added and removed later, and each topic's
+    /// subscription reconnects independently.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache to use as a namespace for the topics.
+    /// * `topics` - The names of the topics to subscribe to.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// use futures::StreamExt;
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    ///
+    /// let mut subscription = topic_client
+    ///     .subscribe_many(&cache_name, ["topic-1", "topic-2"])
+    ///     .await?;
+    /// subscription.add_topic("topic-3").await?;
+    ///
+    /// while let Some((topic, message)) = subscription.next().await {
+    ///     println!("{}: {:?}", topic, message.kind);
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    ///
+    /// Learn more about the [MultiTopicSubscription] stream.
+    pub async fn subscribe_many(
+        &self,
+        cache_name: impl Into<String>,
+        topics: impl IntoIterator<Item = impl Into<String>>,
+    ) -> MomentoResult<MultiTopicSubscription> {
+        let cache_name = cache_name.into();
+        let topics: Vec<String> = topics.into_iter().map(Into::into).collect();
+        let subscriptions = futures::future::try_join_all(
+            topics
+                .iter()
+                .map(|topic| self.subscribe(cache_name.as_str(), topic.as_str())),
+        )
+        .await?;
+
+        let mut multi_topic_subscription = MultiTopicSubscription::new(self.clone(), cache_name);
+        for (topic, subscription) in topics.into_iter().zip(subscriptions) {
+            multi_topic_subscription.insert(topic, subscription);
+        }
+        Ok(multi_topic_subscription)
+    }
+
+    /// Subscribe to a topic, resuming from the checkpoint saved in `checkpoint_store` under
+    /// `checkpoint_name`, and saving a new checkpoint each time an item is acknowledged.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache to use as a namespace for the topic.
+    /// * `topic` - The name of the topic to subscribe to.
+    /// * `checkpoint_name` - The name the subscriber's checkpoint is saved under.
+    /// * `checkpoint_store` - Where checkpoints are saved, such as a [CacheCheckpointStore](crate::topics::CacheCheckpointStore).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// use std::time::Duration;
+    /// use momento::topics::CacheCheckpointStore;
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    /// # let (cache_client, _) = momento_test_util::create_doctest_cache_client();
+    ///
+    /// let checkpoint_store = CacheCheckpointStore::new(cache_client, &cache_name)
+    ///     .ttl(Duration::from_secs(24 * 60 * 60));
+    /// let mut subscription = topic_client
+    ///     .subscribe_with_checkpoints(&cache_name, "topic", "order-processor", checkpoint_store)
+    ///     .await?;
+    /// while let Some(message) = subscription.next().await? {
+    ///     println!("Received message: {:?}", message.kind);
+    ///     subscription.ack(&message).await?;
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    ///
+    /// To save checkpoints periodically instead, see [CheckpointedSubscribeRequest].
+    pub async fn subscribe_with_checkpoints<S: CheckpointStore>(
+        &self,
+        cache_name: impl Into<String>,
+        topic: impl Into<String>,
+        checkpoint_name: impl Into<String>,
+        checkpoint_store: S,
+    ) -> MomentoResult<CheckpointedSubscription<S>> {
+        let request =
+            CheckpointedSubscribeRequest::new(cache_name, topic, checkpoint_name, checkpoint_store);
+        request.send(self).await
+    }
+
+    /// Create a webhook, or update the topic and destination of an existing one. Every message
+    /// published to the topic is then delivered to the destination, signed with the secret in
+    /// the response. See [validate_webhook_request](crate::topics::validate_webhook_request)
+    /// for checking deliveries.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache the topic is in.
+    /// * `webhook_name` - The name of the webhook.
+    /// * `topic` - The name of the topic whose messages the webhook delivers.
+    /// * `destination` - Where the webhook delivers messages, such as a URL to POST them to.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    /// let response = topic_client
+    ///     .put_webhook(&cache_name, "my-webhook", "my-topic", "https://example.com/momento")
+    ///     .await?;
+    /// println!("Verify deliveries with secret {}", response.secret_string);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn put_webhook(
+        &self,
+        cache_name: impl Into<String>,
+        webhook_name: impl Into<String>,
+        topic: impl Into<String>,
+        destination: impl Into<WebhookDestination>,
+    ) -> MomentoResult<PutWebhookResponse> {
+        let request = PutWebhookRequest::new(cache_name, webhook_name, topic, destination);
+        request.send(self).await
+    }
+
+    /// List the webhooks of a cache's topics.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    /// for webhook in topic_client.list_webhooks(&cache_name).await?.webhooks {
+    ///     println!("{} delivers {}", webhook.id.webhook_name, webhook.topic_name);
+    /// }
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn list_webhooks(
+        &self,
+        cache_name: impl Into<String>,
+    ) -> MomentoResult<ListWebhooksResponse> {
+        let request = ListWebhooksRequest::new(cache_name);
+        request.send(self).await
+    }
+
+    /// Delete a webhook. Messages published to its topic are no longer delivered.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache the webhook is in.
+    /// * `webhook_name` - The name of the webhook.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    /// topic_client.delete_webhook(&cache_name, "my-webhook").await?;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn delete_webhook(
+        &self,
+        cache_name: impl Into<String>,
+        webhook_name: impl Into<String>,
+    ) -> MomentoResult<DeleteWebhookResponse> {
+        let request = DeleteWebhookRequest::new(cache_name, webhook_name);
+        request.send(self).await
+    }
+
+    /// Get the secret that a webhook's deliveries are signed with.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache the webhook is in.
+    /// * `webhook_name` - The name of the webhook.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    /// let secret = topic_client
+    ///     .get_webhook_secret(&cache_name, "my-webhook")
+    ///     .await?
+    ///     .secret_string;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn get_webhook_secret(
+        &self,
+        cache_name: impl Into<String>,
+        webhook_name: impl Into<String>,
+    ) -> MomentoResult<GetWebhookSecretResponse> {
+        let request = GetWebhookSecretRequest::new(cache_name, webhook_name);
+        request.send(self).await
+    }
+
+    /// Replace the secret that a webhook's deliveries are signed with.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache_name` - The name of the cache the webhook is in.
+    /// * `webhook_name` - The name of the webhook.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    /// let new_secret = topic_client
+    ///     .rotate_webhook_secret(&cache_name, "my-webhook")
+    ///     .await?
+    ///     .secret_string;
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub async fn rotate_webhook_secret(
+        &self,
+        cache_name: impl Into<String>,
+        webhook_name: impl Into<String>,
+    ) -> MomentoResult<RotateWebhookSecretResponse> {
+        let request = RotateWebhookSecretRequest::new(cache_name, webhook_name);
+        request.send(self).await
+    }
+
+    /// The number of active subscriptions on each of the client's streaming gRPC channels.
+    /// Each channel carries at most 100 subscriptions, and a subscription stops counting once
+    /// it is dropped or [unsubscribed](Subscription::unsubscribe). The client opens channels as
+    /// subscriptions need them, up to the configured
+    /// [maximum](crate::topics::Configuration::max_streaming_channels), and closes them again
+    /// once they are idle.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # fn main() -> anyhow::Result<()> {
+    /// # tokio_test::block_on(async {
+    /// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+    /// let subscription = topic_client.subscribe(&cache_name, "topic").await?;
+    /// assert_eq!(topic_client.active_subscriptions_per_channel().iter().sum::<usize>(), 1);
+    ///
+    /// drop(subscription);
+    /// assert_eq!(topic_client.active_subscriptions_per_channel().iter().sum::<usize>(), 0);
+    /// # Ok(())
+    /// # })
+    /// # }
+    /// ```
+    pub fn active_subscriptions_per_channel(&self) -> Vec<usize> {
+        self.streaming_channels.active_subscriptions_per_channel()
+    }
+
     /// Lower-level API to send any type of MomentoRequest to the server. This is used for cases when
     /// you want to set optional fields on a request that are not supported by the short-hand API for
     /// that request type.
@@ -184,59 +515,31 @@ impl TopicClient {
         self.unary_clients[index % num_clients].clone()
     }
 
+    pub(crate) fn webhook_client(&self) -> WebhookClient {
+        self.webhook_client.clone()
+    }
+
     pub(crate) fn get_next_streaming_client(
         &self,
-    ) -> MomentoResult<PubsubClient<InterceptedService<Channel, HeaderInterceptor>>> {
+    ) -> MomentoResult<(
+        PubsubClient<InterceptedService<Channel, HeaderInterceptor>>,
+        SubscriptionSlot,
+    )> {
         // First check if there is enough capacity to make a new subscription.
         self.check_number_of_concurrent_streams()?;
-
-        let max_concurrent_streams =
-            self.streaming_clients.len() * MAX_CONCURRENT_STREAMS_PER_CHANNEL;
-
-        // Max number of attempts is set to the max number of concurrent streams in order to preserve
-        // the round-robin system (incrementing nextManagerIndex) but to not cut short the number
-        //  of attempts in case there are many subscriptions starting up at the same time.
-        for _ in 0..max_concurrent_streams {
-            let next_manager_index = self
-                .streaming_client_index
-                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-            let topic_manager =
-                &self.streaming_clients[next_manager_index % self.streaming_clients.len()];
-            let new_count = topic_manager.increment_num_active_subscriptions();
-            if new_count <= max_concurrent_streams {
-                log::debug!(
-                    "Starting new subscription on grpc channel {} which now has {} streams",
-                    next_manager_index % self.streaming_clients.len(),
-                    new_count
-                );
-                return Ok(topic_manager.client().clone());
-            }
-            topic_manager.decrement_num_active_subscriptions();
-        }
-
-        // If no more streams available, return an error
-        Err(MomentoError::max_concurrent_streams_reached(
-            self.count_number_of_active_subscriptions(),
-            self.streaming_clients.len(),
-            max_concurrent_streams,
-        ))
-    }
-
-    fn count_number_of_active_subscriptions(&self) -> usize {
-        self.streaming_clients
-            .iter()
-            .map(|client| client.get_num_active_subscriptions())
-            .sum()
+        self.streaming_channels.acquire()
     }
 
     fn check_number_of_concurrent_streams(&self) -> MomentoResult<()> {
+        let active_subscriptions_per_channel =
+            self.streaming_channels.active_subscriptions_per_channel();
+        let num_active_subscriptions: usize = active_subscriptions_per_channel.iter().sum();
         let max_concurrent_streams =
-            self.streaming_clients.len() * MAX_CONCURRENT_STREAMS_PER_CHANNEL;
-        let num_active_subscriptions = self.count_number_of_active_subscriptions();
+            self.streaming_channels.max_channels() * MAX_CONCURRENT_STREAMS_PER_CHANNEL;
         if num_active_subscriptions >= max_concurrent_streams {
             return Err(MomentoError::max_concurrent_streams_reached(
+                active_subscriptions_per_channel.len(),
                 num_active_subscriptions,
-                self.streaming_clients.len(),
                 max_concurrent_streams,
             ));
         }
@@ -252,3 +555,66 @@ impl TopicClient {
         Ok(())
     }
 }
+
+/// Sends any topics [MomentoRequest] as a [tower::Service], so tower middleware such as timeouts,
+/// retries, load shedding and concurrency limits can wrap publish and subscribe calls.
+///
+/// `poll_ready` reports whether the client's unary channels can accept another request.
+///
+/// # Example
+/// ```
+/// # fn main() -> anyhow::Result<()> {
+/// # use momento_test_util::create_doctest_topic_client;
+/// # tokio_test::block_on(async {
+/// use std::time::Duration;
+/// use momento::topics::{PublishRequest, TopicPublishResponse};
+/// use tower::{ServiceBuilder, ServiceExt};
+/// # let (topic_client, cache_name) = create_doctest_topic_client();
+///
+/// let service = ServiceBuilder::new()
+///     .timeout(Duration::from_secs(5))
+///     .service(topic_client);
+///
+/// let response: TopicPublishResponse = service
+///     .oneshot(PublishRequest::new(&cache_name, "topic", "value"))
+///     .await
+///     .map_err(|err| anyhow::anyhow!(err))?;
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+#[cfg(feature = "tower")]
+impl<R> tower::Service<R> for TopicClient
+where
+    R: MomentoRequest + Send + 'static,
+    R::Response: Send + 'static,
+{
+    type Response = R::Response;
+    type Error = MomentoError;
+    type Future = Pin<Box<dyn Future<Output = MomentoResult<R::Response>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<MomentoResult<()>> {
+        self.readiness.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: R) -> Self::Future {
+        let client = self.clone();
+        Box::pin(async move { request.send(&client).await })
+    }
+}
+
+/// Fixtures shared by the tests of the topic modules.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use crate::topics::{configurations, TopicClient};
+    use crate::{CredentialProvider, MomentoResult};
+
+    /// A topic client for a fake endpoint. It never connects unless a request is sent.
+    pub(crate) fn test_topic_client() -> MomentoResult<TopicClient> {
+        let v1_token = "eyJlbmRwb2ludCI6Im1vbWVudG9fZW5kcG9pbnQiLCJhcGlfa2V5IjoiZXlKaGJHY2lPaUpJVXpJMU5pSjkuZXlKemRXSWlPaUowWlhOMElITjFZbXBsWTNRaUxDSjJaWElpT2pFc0luQWlPaUlpZlEuaGcyd01iV2Utd2VzUVZ0QTd3dUpjUlVMalJwaFhMUXdRVFZZZlFMM0w3YyJ9Cg==".to_string();
+        TopicClient::builder()
+            .configuration(configurations::Laptop::latest())
+            .credential_provider(CredentialProvider::from_string(v1_token)?)
+            .build()
+    }
+}
@@ -1,15 +1,18 @@
 use std::sync::{atomic::AtomicUsize, Arc};
 
+#[cfg(feature = "tower")]
+use crate::grpc::channel_readiness::ChannelReadiness;
 use crate::{
     grpc::header_interceptor::HeaderInterceptor,
     topics::Configuration,
-    utils::{self, connect_channel_lazily, ChannelConnectError},
+    utils::{self, connect_channel_lazily},
     CredentialProvider, MomentoResult, TopicClient,
 };
 use momento_protos::cache_client::pubsub::pubsub_client::PubsubClient;
 use tonic::{service::interceptor::InterceptedService, transport::Channel};
 
-use super::topic_subscription_manager::TopicSubscriptionManager;
+use super::topic_subscription_manager::StreamingChannelPool;
+use super::webhook_protos::WebhookClient;
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct TopicClientBuilder<State>(pub State);
@@ -55,43 +58,58 @@ impl TopicClientBuilder<ReadyToBuild> {
     pub fn build(self) -> MomentoResult<TopicClient> {
         // Create a pool of grpc channels for unary operations. Default to 4 channels.
         // TODO: Make this configurable.
-        let mut unary_clients = Vec::new();
+        let mut unary_channels = Vec::new();
         for _ in 0..4 {
-            unary_clients.push(create_pubsub_client(
+            unary_channels.push(connect_channel_lazily(
                 &self.0.credential_provider.cache_endpoint,
-                &self.0.credential_provider.auth_token,
             )?);
         }
+        let unary_clients = unary_channels
+            .iter()
+            .map(|channel| {
+                create_pubsub_client(channel.clone(), &self.0.credential_provider.auth_token)
+            })
+            .collect();
 
-        // Create a pool of grpc channels for streaming operations. Default to 4 channels.
-        // TODO: Make this configurable.
-        let mut streaming_clients = Vec::new();
-        let num_stream_clients = 4;
-        for _ in 0..num_stream_clients {
-            let stream_manager = TopicSubscriptionManager::new(create_pubsub_client(
-                &self.0.credential_provider.cache_endpoint,
+        // Create a pool of grpc channels for streaming operations. Start with 4 channels and
+        // open more as subscriptions need them, up to the configured maximum.
+        let cache_endpoint = self.0.credential_provider.cache_endpoint.clone();
+        let auth_token = self.0.credential_provider.auth_token.clone();
+        let streaming_channels = StreamingChannelPool::new(
+            4,
+            self.0.configuration.max_streaming_channels(),
+            move || {
+                let channel = connect_channel_lazily(&cache_endpoint)?;
+                Ok(create_pubsub_client(channel, &auth_token))
+            },
+        )?;
+
+        let webhook_client = WebhookClient::new(InterceptedService::new(
+            connect_channel_lazily(&self.0.credential_provider.control_endpoint)?,
+            HeaderInterceptor::new(
                 &self.0.credential_provider.auth_token,
-            )?);
-            streaming_clients.push(stream_manager);
-        }
+                &utils::user_agent("topic"),
+            ),
+        ));
 
         Ok(TopicClient {
             unary_client_index: Arc::new(AtomicUsize::new(0)),
-            streaming_client_index: Arc::new(AtomicUsize::new(0)),
             unary_clients,
-            streaming_clients,
+            streaming_channels,
+            webhook_client,
             configuration: self.0.configuration,
+            #[cfg(feature = "tower")]
+            readiness: ChannelReadiness::new(unary_channels),
         })
     }
 }
 
 fn create_pubsub_client(
-    endpoint: &str,
+    channel: Channel,
     auth_token: &str,
-) -> Result<PubsubClient<InterceptedService<Channel, HeaderInterceptor>>, ChannelConnectError> {
+) -> PubsubClient<InterceptedService<Channel, HeaderInterceptor>> {
     let agent_value = &utils::user_agent("topic");
-    let channel = connect_channel_lazily(endpoint)?;
     let authorized_channel =
         InterceptedService::new(channel, HeaderInterceptor::new(auth_token, agent_value));
-    Ok(PubsubClient::new(authorized_channel))
+    PubsubClient::new(authorized_channel)
 }
@@ -1,20 +1,23 @@
 use tonic::transport::Channel;
 
 use crate::grpc::header_interceptor::HeaderInterceptor;
+use crate::{MomentoError, MomentoResult};
 use momento_protos::cache_client::pubsub::pubsub_client::PubsubClient;
-use std::sync::{atomic::AtomicUsize, Arc};
+use std::sync::{atomic::AtomicUsize, Arc, Mutex, MutexGuard, Weak};
 use tonic::codegen::InterceptedService;
 
 pub const MAX_CONCURRENT_STREAMS_PER_CHANNEL: usize = 100;
 
+type StreamingClient = PubsubClient<InterceptedService<Channel, HeaderInterceptor>>;
+
 #[derive(Clone, Debug)]
 pub struct TopicSubscriptionManager {
-    client: PubsubClient<InterceptedService<Channel, HeaderInterceptor>>,
+    client: StreamingClient,
     num_active_subscriptions: Arc<AtomicUsize>,
 }
 
 impl TopicSubscriptionManager {
-    pub fn new(client: PubsubClient<InterceptedService<Channel, HeaderInterceptor>>) -> Self {
+    pub fn new(client: StreamingClient) -> Self {
         Self {
             client,
             num_active_subscriptions: Arc::new(AtomicUsize::new(0)),
@@ -36,7 +39,242 @@ impl TopicSubscriptionManager {
             .fetch_sub(1, std::sync::atomic::Ordering::Relaxed)
     }
 
-    pub fn client(&self) -> &PubsubClient<InterceptedService<Channel, HeaderInterceptor>> {
+    /// Claims a stream on this channel, unless it already carries the most it can.
+    fn try_reserve(&self) -> bool {
+        let previous_count = self.increment_num_active_subscriptions();
+        if previous_count < MAX_CONCURRENT_STREAMS_PER_CHANNEL {
+            true
+        } else {
+            self.decrement_num_active_subscriptions();
+            false
+        }
+    }
+
+    pub fn client(&self) -> &StreamingClient {
         &self.client
     }
 }
+
+/// The streaming channels of a topic client. It starts with `min_channels`, opens more while
+/// every channel is carrying as many subscriptions as it can, up to `max_channels`, and closes
+/// the extra ones again once their last subscription ends.
+#[derive(Clone)]
+pub struct StreamingChannelPool {
+    inner: Arc<PoolInner>,
+}
+
+struct PoolInner {
+    channels: Mutex<Vec<TopicSubscriptionManager>>,
+    next_index: AtomicUsize,
+    min_channels: usize,
+    max_channels: usize,
+    open_channel: Box<dyn Fn() -> MomentoResult<StreamingClient> + Send + Sync>,
+}
+
+impl std::fmt::Debug for StreamingChannelPool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingChannelPool")
+            .field("channels", &self.inner.lock().len())
+            .field("min_channels", &self.inner.min_channels)
+            .field("max_channels", &self.inner.max_channels)
+            .finish()
+    }
+}
+
+impl PoolInner {
+    fn lock(&self) -> MutexGuard<'_, Vec<TopicSubscriptionManager>> {
+        // The channels are always valid, so a poisoned lock can be recovered.
+        self.channels
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Closes channels beyond the minimum that no longer carry any subscriptions. Nothing can
+    /// start a subscription on a channel without holding the lock, so an idle channel stays idle
+    /// until it is gone.
+    fn close_idle_channels(&self) {
+        let mut channels = self.lock();
+        let mut excess = channels.len().saturating_sub(self.min_channels);
+        channels.retain(|channel| {
+            if excess > 0 && channel.get_num_active_subscriptions() == 0 {
+                excess -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+impl StreamingChannelPool {
+    pub fn new(
+        min_channels: usize,
+        max_channels: usize,
+        open_channel: impl Fn() -> MomentoResult<StreamingClient> + Send + Sync + 'static,
+    ) -> MomentoResult<Self> {
+        let min_channels = min_channels.max(1);
+        let channels = (0..min_channels)
+            .map(|_| open_channel().map(TopicSubscriptionManager::new))
+            .collect::<MomentoResult<Vec<_>>>()?;
+        Ok(Self {
+            inner: Arc::new(PoolInner {
+                channels: Mutex::new(channels),
+                next_index: AtomicUsize::new(0),
+                min_channels,
+                max_channels: max_channels.max(min_channels),
+                open_channel: Box::new(open_channel),
+            }),
+        })
+    }
+
+    pub fn max_channels(&self) -> usize {
+        self.inner.max_channels
+    }
+
+    pub fn active_subscriptions_per_channel(&self) -> Vec<usize> {
+        self.inner
+            .lock()
+            .iter()
+            .map(|channel| channel.get_num_active_subscriptions())
+            .collect()
+    }
+
+    /// Claims a stream on the next channel with room for one, round-robin, opening a new
+    /// channel if they are all full. The stream is released when the returned slot is dropped.
+    pub fn acquire(&self) -> MomentoResult<(StreamingClient, SubscriptionSlot)> {
+        let mut channels = self.inner.lock();
+        let num_channels = channels.len();
+        let start = self
+            .inner
+            .next_index
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        for offset in 0..num_channels {
+            let index = (start + offset) % num_channels;
+            let channel = &channels[index];
+            if channel.try_reserve() {
+                log::debug!(
+                    "Starting new subscription on grpc channel {} which now has {} streams",
+                    index,
+                    channel.get_num_active_subscriptions()
+                );
+                return Ok((channel.client().clone(), self.slot(channel)));
+            }
+        }
+
+        if num_channels < self.inner.max_channels {
+            let channel = TopicSubscriptionManager::new((self.inner.open_channel)()?);
+            channel.try_reserve();
+            log::debug!(
+                "All {num_channels} streaming grpc channels are full, opening another (at most {})",
+                self.inner.max_channels
+            );
+            let acquired = (channel.client().clone(), self.slot(&channel));
+            channels.push(channel);
+            return Ok(acquired);
+        }
+
+        let num_active_subscriptions = channels
+            .iter()
+            .map(|channel| channel.get_num_active_subscriptions())
+            .sum();
+        Err(MomentoError::max_concurrent_streams_reached(
+            num_channels,
+            num_active_subscriptions,
+            self.inner.max_channels * MAX_CONCURRENT_STREAMS_PER_CHANNEL,
+        ))
+    }
+
+    fn slot(&self, channel: &TopicSubscriptionManager) -> SubscriptionSlot {
+        SubscriptionSlot {
+            channel: channel.clone(),
+            pool: Arc::downgrade(&self.inner),
+        }
+    }
+}
+
+/// One stream counted against a channel's limit, released when dropped.
+pub struct SubscriptionSlot {
+    channel: TopicSubscriptionManager,
+    pool: Weak<PoolInner>,
+}
+
+impl std::fmt::Debug for SubscriptionSlot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubscriptionSlot")
+            .field("channel", &self.channel)
+            .finish()
+    }
+}
+
+impl Drop for SubscriptionSlot {
+    fn drop(&mut self) {
+        let previous_count = self.channel.decrement_num_active_subscriptions();
+        if previous_count == 1 {
+            if let Some(pool) = self.pool.upgrade() {
+                pool.close_idle_channels();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topics::messages::subscription::test_support::unconnected_pubsub_client;
+
+    fn pool(min_channels: usize, max_channels: usize) -> MomentoResult<StreamingChannelPool> {
+        StreamingChannelPool::new(min_channels, max_channels, || {
+            Ok(unconnected_pubsub_client())
+        })
+    }
+
+    #[tokio::test]
+    async fn slots_are_limited_per_channel_and_released_on_drop() -> MomentoResult<()> {
+        let pool = pool(1, 1)?;
+
+        let mut slots = Vec::new();
+        for _ in 0..MAX_CONCURRENT_STREAMS_PER_CHANNEL {
+            slots.push(pool.acquire()?.1);
+        }
+        assert!(pool.acquire().is_err());
+        assert_eq!(
+            pool.active_subscriptions_per_channel(),
+            vec![MAX_CONCURRENT_STREAMS_PER_CHANNEL]
+        );
+
+        slots.pop();
+        assert_eq!(
+            pool.active_subscriptions_per_channel(),
+            vec![MAX_CONCURRENT_STREAMS_PER_CHANNEL - 1]
+        );
+        assert!(pool.acquire().is_ok());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn opens_channels_on_demand_and_closes_them_when_idle() -> MomentoResult<()> {
+        let pool = pool(1, 3)?;
+
+        let mut slots = Vec::new();
+        for _ in 0..MAX_CONCURRENT_STREAMS_PER_CHANNEL * 2 + 1 {
+            slots.push(pool.acquire()?.1);
+        }
+        assert_eq!(
+            pool.active_subscriptions_per_channel(),
+            vec![
+                MAX_CONCURRENT_STREAMS_PER_CHANNEL,
+                MAX_CONCURRENT_STREAMS_PER_CHANNEL,
+                1
+            ]
+        );
+
+        // Releasing the last subscription on the third channel closes it.
+        slots.pop();
+        assert_eq!(pool.active_subscriptions_per_channel().len(), 2);
+
+        // Emptying the rest leaves only the minimum open.
+        slots.clear();
+        assert_eq!(pool.active_subscriptions_per_channel(), vec![0]);
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::{Stream, StreamExt};
+
+use crate::codec::{Codec, JsonCodec};
+use crate::topics::{Subscription, SubscriptionValue, TopicPublishResponse, ValueKind};
+use crate::{MomentoError, MomentoResult, TopicClient};
+
+/// A topic whose messages are values of type `T`, encoded with a [Codec].
+///
+/// Messages are encoded as JSON with [JsonCodec] unless another codec is provided with
+/// [TypedTopic::with_codec]. The `bincode` and `messagepack` features provide
+/// `momento::codec::BincodeCodec` and `momento::codec::MessagePackCodec`, or implement [Codec]
+/// for another format. Encoded messages which are valid UTF-8, like JSON, are published as text
+/// so subscribers using other SDKs can read them; anything else is published as bytes.
+///
+/// # Example
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// # tokio_test::block_on(async {
+/// use futures::StreamExt;
+/// use momento::topics::TypedTopic;
+/// use serde::{Deserialize, Serialize};
+/// # let (topic_client, cache_name) = momento_test_util::create_doctest_topic_client();
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Order {
+///     id: u32,
+///     item: String,
+/// }
+///
+/// let orders: TypedTopic<Order> = TypedTopic::new(topic_client, &cache_name, "orders");
+/// let mut subscription = orders.subscribe().await?;
+/// orders.publish(&Order { id: 1, item: "taco".to_string() }).await?;
+///
+/// while let Some(message) = subscription.next().await {
+///     match message.value {
+///         Ok(order) => println!("Order {} at sequence number {}", order.id, message.topic_sequence_number),
+///         Err(error) => eprintln!("Skipping a message: {}", error),
+///     }
+/// }
+/// # Ok(())
+/// # })
+/// # }
+/// ```
+pub struct TypedTopic<T, C = JsonCodec> {
+    client: TopicClient,
+    cache_name: String,
+    topic: String,
+    codec: C,
+    _message: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedTopic<T, JsonCodec>
+where
+    JsonCodec: Codec<T>,
+{
+    /// Constructs a new TypedTopic which encodes its messages as JSON.
+    pub fn new(
+        client: TopicClient,
+        cache_name: impl Into<String>,
+        topic: impl Into<String>,
+    ) -> Self {
+        Self::with_codec(client, cache_name, topic, JsonCodec)
+    }
+}
+
+impl<T, C: Codec<T>> TypedTopic<T, C> {
+    /// Constructs a new TypedTopic which encodes its messages with the provided codec.
+    pub fn with_codec(
+        client: TopicClient,
+        cache_name: impl Into<String>,
+        topic: impl Into<String>,
+        codec: C,
+    ) -> Self {
+        Self {
+            client,
+            cache_name: cache_name.into(),
+            topic: topic.into(),
+            codec,
+            _message: PhantomData,
+        }
+    }
+
+    /// The name of the topic.
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Encodes and publishes a message to the topic.
+    pub async fn publish(&self, message: &T) -> MomentoResult<TopicPublishResponse> {
+        let value = self.codec.encode(message)?;
+        match String::from_utf8(value) {
+            Ok(text) => {
+                self.client
+                    .publish(self.cache_name.as_str(), self.topic.as_str(), text)
+                    .await
+            }
+            Err(error) => {
+                self.client
+                    .publish(
+                        self.cache_name.as_str(),
+                        self.topic.as_str(),
+                        error.into_bytes(),
+                    )
+                    .await
+            }
+        }
+    }
+}
+
+impl<T, C: Codec<T> + Clone> TypedTopic<T, C> {
+    /// Subscribes to the topic, decoding each message as it arrives.
+    pub async fn subscribe(&self) -> MomentoResult<TypedSubscription<T, C>> {
+        let subscription = self
+            .client
+            .subscribe(self.cache_name.as_str(), self.topic.as_str())
+            .await?;
+        Ok(TypedSubscription {
+            subscription,
+            codec: self.codec.clone(),
+            _message: PhantomData,
+        })
+    }
+}
+
+impl<T, C: Clone> Clone for TypedTopic<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            cache_name: self.cache_name.clone(),
+            topic: self.topic.clone(),
+            codec: self.codec.clone(),
+            _message: PhantomData,
+        }
+    }
+}
+
+impl<T, C: std::fmt::Debug> std::fmt::Debug for TypedTopic<T, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedTopic")
+            .field("cache_name", &self.cache_name)
+            .field("topic", &self.topic)
+            .field("codec", &self.codec)
+            .finish()
+    }
+}
+
+/// A message received from a [TypedTopic], with its position in the topic.
+#[derive(Debug)]
+pub struct TypedSubscriptionValue<T> {
+    /// The decoded message, or why it could not be decoded.
+    pub value: Result<T, DecodeError>,
+    /// The sequence number of the topic.
+    pub topic_sequence_number: u64,
+    /// The page number of the topic.
+    pub topic_sequence_page: u64,
+    /// Authenticated id from Publisher's disposable token
+    pub publisher_id: String,
+}
+
+/// A message on a [TypedTopic] could not be decoded. A subscription carries on after a message
+/// it cannot decode, so one bad publisher does not stop it.
+#[derive(Debug, thiserror::Error)]
+#[error("failed to decode topic message: {error}")]
+pub struct DecodeError {
+    /// The error from the codec.
+    #[source]
+    pub error: MomentoError,
+    /// The message as it was published.
+    pub kind: ValueKind,
+}
+
+/// A stream of decoded messages from a [TypedTopic]. See [Subscription] for how the stream
+/// stays connected.
+pub struct TypedSubscription<T, C = JsonCodec> {
+    subscription: Subscription,
+    codec: C,
+    _message: PhantomData<fn() -> T>,
+}
+
+impl<T, C> TypedSubscription<T, C> {
+    /// The error that ended the subscription, if it has ended.
+    pub fn error(&self) -> Option<&MomentoError> {
+        self.subscription.error()
+    }
+
+    /// Stop receiving from the topic. See [Subscription::unsubscribe].
+    pub fn unsubscribe(&mut self) {
+        self.subscription.unsubscribe()
+    }
+}
+
+impl<T, C: Codec<T>> TypedSubscription<T, C> {
+    fn decode(&self, value: SubscriptionValue) -> TypedSubscriptionValue<T> {
+        let decoded = match &value.kind {
+            ValueKind::Text(text) => self.codec.decode(text.as_bytes()),
+            ValueKind::Binary(bytes) => self.codec.decode(bytes),
+        };
+        let kind = value.kind;
+        TypedSubscriptionValue {
+            value: decoded.map_err(|error| DecodeError { error, kind }),
+            topic_sequence_number: value.topic_sequence_number,
+            topic_sequence_page: value.topic_sequence_page,
+            publisher_id: value.publisher_id,
+        }
+    }
+}
+
+impl<T, C: Codec<T> + Unpin> Stream for TypedSubscription<T, C> {
+    type Item = TypedSubscriptionValue<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.subscription.poll_next_unpin(context) {
+            Poll::Ready(Some(value)) => Poll::Ready(Some(self.decode(value))),
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::topics::messages::subscription::test_support::test_subscription;
+    use crate::MomentoErrorCode;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: u32,
+        item: String,
+    }
+
+    fn typed_subscription() -> TypedSubscription<Order> {
+        TypedSubscription {
+            subscription: test_subscription(None),
+            codec: JsonCodec,
+            _message: PhantomData,
+        }
+    }
+
+    fn value(kind: ValueKind) -> SubscriptionValue {
+        SubscriptionValue {
+            kind,
+            topic_sequence_number: 5,
+            topic_sequence_page: 1,
+            publisher_id: "publisher".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn decodes_text_and_binary_messages() {
+        let subscription = typed_subscription();
+        let json = r#"{"id":7,"item":"taco"}"#;
+        for kind in [
+            ValueKind::Text(json.to_string()),
+            ValueKind::Binary(json.as_bytes().to_vec()),
+        ] {
+            let message = subscription.decode(value(kind));
+            assert_eq!(
+                message.value.ok(),
+                Some(Order {
+                    id: 7,
+                    item: "taco".to_string()
+                })
+            );
+            assert_eq!(message.topic_sequence_number, 5);
+            assert_eq!(message.topic_sequence_page, 1);
+            assert_eq!(message.publisher_id, "publisher");
+        }
+    }
+
+    #[tokio::test]
+    async fn keeps_messages_that_fail_to_decode() {
+        let subscription = typed_subscription();
+        let message = subscription.decode(value(ValueKind::Text("not json".to_string())));
+        let error = message.value.expect_err("the message is not an order");
+        assert_eq!(error.error.error_code, MomentoErrorCode::TypeError);
+        assert_eq!(error.kind, ValueKind::Text("not json".to_string()));
+        assert_eq!(message.topic_sequence_number, 5);
+    }
+}
@@ -0,0 +1,191 @@
+//! Wire types and gRPC client for the webhook service.
+//!
+//! The published `momento-protos` crate does not include the webhook protos yet, so the messages
+//! the client needs are defined here the way `prost` would generate them.
+
+use tonic::codegen::InterceptedService;
+use tonic::transport::Channel;
+
+use crate::grpc::header_interceptor::HeaderInterceptor;
+use crate::grpc::unary::{unary, AuthorizedGrpc};
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct WebhookId {
+    #[prost(string, tag = "1")]
+    pub cache_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub webhook_name: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct WebhookDestination {
+    #[prost(oneof = "webhook_destination::Kind", tags = "1")]
+    pub kind: ::core::option::Option<webhook_destination::Kind>,
+}
+
+pub(crate) mod webhook_destination {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Kind {
+        #[prost(string, tag = "1")]
+        PostUrl(::prost::alloc::string::String),
+    }
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct Webhook {
+    #[prost(message, optional, tag = "1")]
+    pub webhook_id: ::core::option::Option<WebhookId>,
+    #[prost(string, tag = "2")]
+    pub topic_name: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub destination: ::core::option::Option<WebhookDestination>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct PutWebhookRequest {
+    #[prost(message, optional, tag = "1")]
+    pub webhook: ::core::option::Option<Webhook>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct PutWebhookResponse {
+    #[prost(string, tag = "1")]
+    pub secret_string: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct DeleteWebhookRequest {
+    #[prost(message, optional, tag = "1")]
+    pub webhook_id: ::core::option::Option<WebhookId>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct DeleteWebhookResponse {}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct ListWebhookRequest {
+    #[prost(string, tag = "1")]
+    pub cache_name: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct ListWebhooksResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub webhook: ::prost::alloc::vec::Vec<Webhook>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct GetWebhookSecretRequest {
+    #[prost(string, tag = "1")]
+    pub webhook_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cache_name: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct GetWebhookSecretResponse {
+    #[prost(string, tag = "1")]
+    pub webhook_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cache_name: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub secret_string: ::prost::alloc::string::String,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct RotateWebhookSecretRequest {
+    #[prost(message, optional, tag = "1")]
+    pub webhook_id: ::core::option::Option<WebhookId>,
+}
+
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub(crate) struct RotateWebhookSecretResponse {
+    #[prost(string, tag = "1")]
+    pub secret_string: ::prost::alloc::string::String,
+}
+
+const SERVICE: &str = "webhook.Webhook";
+
+/// Client for the `webhook.Webhook` service.
+#[derive(Clone, Debug)]
+pub(crate) struct WebhookClient {
+    inner: AuthorizedGrpc,
+}
+
+impl WebhookClient {
+    pub(crate) fn new(service: InterceptedService<Channel, HeaderInterceptor>) -> Self {
+        Self {
+            inner: tonic::client::Grpc::new(service),
+        }
+    }
+
+    pub(crate) async fn put_webhook(
+        &mut self,
+        request: tonic::Request<PutWebhookRequest>,
+    ) -> Result<tonic::Response<PutWebhookResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            SERVICE,
+            "PutWebhook",
+            "/webhook.Webhook/PutWebhook",
+        )
+        .await
+    }
+
+    pub(crate) async fn delete_webhook(
+        &mut self,
+        request: tonic::Request<DeleteWebhookRequest>,
+    ) -> Result<tonic::Response<DeleteWebhookResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            SERVICE,
+            "DeleteWebhook",
+            "/webhook.Webhook/DeleteWebhook",
+        )
+        .await
+    }
+
+    pub(crate) async fn list_webhooks(
+        &mut self,
+        request: tonic::Request<ListWebhookRequest>,
+    ) -> Result<tonic::Response<ListWebhooksResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            SERVICE,
+            "ListWebhooks",
+            "/webhook.Webhook/ListWebhooks",
+        )
+        .await
+    }
+
+    pub(crate) async fn get_webhook_secret(
+        &mut self,
+        request: tonic::Request<GetWebhookSecretRequest>,
+    ) -> Result<tonic::Response<GetWebhookSecretResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            SERVICE,
+            "GetWebhookSecret",
+            "/webhook.Webhook/GetWebhookSecret",
+        )
+        .await
+    }
+
+    pub(crate) async fn rotate_webhook_secret(
+        &mut self,
+        request: tonic::Request<RotateWebhookSecretRequest>,
+    ) -> Result<tonic::Response<RotateWebhookSecretResponse>, tonic::Status> {
+        unary(
+            &mut self.inner,
+            request,
+            SERVICE,
+            "RotateWebhookSecret",
+            "/webhook.Webhook/RotateWebhookSecret",
+        )
+        .await
+    }
+}
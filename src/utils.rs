@@ -59,6 +59,27 @@ pub(crate) fn prep_leaderboard_request_with_timeout<R>(
     Ok(request)
 }
 
+pub(crate) fn prep_storage_request_with_timeout<R>(
+    store_name: &str,
+    timeout: Duration,
+    request: R,
+) -> MomentoResult<Request<R>> {
+    is_store_name_valid(store_name)?;
+
+    let mut request = Request::new(request);
+    tonic::metadata::AsciiMetadataValue::try_from(store_name)
+        .map(|value| {
+            request.metadata_mut().append("store", value);
+        })
+        .map_err(|e| MomentoError {
+            message: format!("Could not treat store name as a header value: {e}"),
+            error_code: MomentoErrorCode::InvalidArgumentError,
+            inner_error: Some(crate::ErrorSource::Unknown(Box::new(e))),
+        })?;
+    request.set_timeout(timeout);
+    Ok(request)
+}
+
 pub(crate) fn is_ttl_valid(ttl: Duration) -> MomentoResult<()> {
     let max_ttl = Duration::from_millis(u64::MAX);
     if ttl > max_ttl {
@@ -86,6 +107,17 @@ pub(crate) fn is_cache_name_valid(cache_name: &str) -> Result<(), MomentoError>
     Ok(())
 }
 
+pub(crate) fn is_store_name_valid(store_name: &str) -> Result<(), MomentoError> {
+    if store_name.trim().is_empty() {
+        return Err(MomentoError {
+            message: "Store name cannot be empty".into(),
+            error_code: MomentoErrorCode::InvalidArgumentError,
+            inner_error: None,
+        });
+    }
+    Ok(())
+}
+
 pub(crate) fn is_disposable_token_expiry_valid(expires_in: ExpiresIn) -> Result<(), MomentoError> {
     if !expires_in.does_expire() {
         return Err(MomentoError {
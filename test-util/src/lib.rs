@@ -10,6 +10,6 @@ pub use crate::test_data::{
 };
 pub use crate::test_utils::{
     create_doctest_auth_client, create_doctest_cache_client, create_doctest_function_client,
-    create_doctest_topic_client, doctest, get_test_cache_name, get_test_credential_provider,
-    get_test_store_name, DoctestResult,
+    create_doctest_leaderboard_client, create_doctest_storage_client, create_doctest_topic_client,
+    doctest, get_test_cache_name, get_test_credential_provider, get_test_store_name, DoctestResult,
 };
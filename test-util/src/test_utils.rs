@@ -4,7 +4,8 @@ use std::time::Duration;
 
 use momento::cache::configurations;
 use momento::{
-    AuthClient, CacheClient, CredentialProvider, FunctionClient, LeaderboardClient, TopicClient,
+    AuthClient, CacheClient, CredentialProvider, FunctionClient, LeaderboardClient, StorageClient,
+    TopicClient,
 };
 
 use crate::unique_cache_name;
@@ -52,12 +53,28 @@ pub fn create_doctest_cache_client() -> (CacheClient, String) {
     (cache_client, cache_name)
 }
 
+pub fn create_doctest_leaderboard_client() -> (LeaderboardClient, String) {
+    let cache_name = get_test_cache_name();
+    let (_, leaderboard_client, _, _, _) = build_clients_and_credential_provider();
+    (leaderboard_client, cache_name)
+}
+
 pub fn create_doctest_topic_client() -> (TopicClient, String) {
     let cache_name = get_test_cache_name();
     let (_, _, topic_client, _, _) = build_clients_and_credential_provider();
     (topic_client, cache_name)
 }
 
+pub fn create_doctest_storage_client() -> (StorageClient, String) {
+    let store_name = get_test_store_name();
+    let client = StorageClient::builder()
+        .configuration(momento::storage::configurations::Laptop::latest())
+        .credential_provider(get_test_credential_provider())
+        .build()
+        .expect("storage client should be created");
+    (client, store_name)
+}
+
 pub fn create_doctest_auth_client() -> AuthClient {
     let (_, _, _, auth_client, _) = build_clients_and_credential_provider();
     auth_client
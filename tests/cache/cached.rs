@@ -0,0 +1,170 @@
+use momento::{CacheClient, MomentoError, MomentoErrorCode, MomentoResult};
+
+use momento_test_util::{unique_key, CACHE_TEST_STATE};
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct User {
+    id: String,
+    name: String,
+}
+
+static LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+static MISSING_LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+static NEGATIVE_LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+static FAILING_LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+static SQUARES: AtomicUsize = AtomicUsize::new(0);
+static ALIASED_LOOKUPS: AtomicUsize = AtomicUsize::new(0);
+static LABELS: AtomicUsize = AtomicUsize::new(0);
+
+type LookupResult<T> = Result<T, String>;
+
+#[momento::cached(cache = "rust-sdk-test-cache", ttl = "30s", key = "cached-user:{id}")]
+async fn get_user(client: &CacheClient, id: &str) -> MomentoResult<User> {
+    LOOKUPS.fetch_add(1, Ordering::SeqCst);
+    Ok(User {
+        id: id.to_string(),
+        name: format!("user {}", id),
+    })
+}
+
+#[momento::cached(cache = "rust-sdk-test-cache", key = "cached-missing:{id}")]
+async fn find_missing(client: &CacheClient, id: &str) -> MomentoResult<Option<User>> {
+    MISSING_LOOKUPS.fetch_add(1, Ordering::SeqCst);
+    Ok(None)
+}
+
+#[momento::cached(
+    cache = "rust-sdk-test-cache",
+    key = "cached-negative:{id}",
+    cache_negative = true
+)]
+async fn find_negative(client: &CacheClient, id: &str) -> MomentoResult<Option<User>> {
+    NEGATIVE_LOOKUPS.fetch_add(1, Ordering::SeqCst);
+    Ok(None)
+}
+
+#[momento::cached(cache = "rust-sdk-test-cache", key = "cached-failing:{id}")]
+async fn failing(client: &CacheClient, id: &str) -> MomentoResult<u64> {
+    FAILING_LOOKUPS.fetch_add(1, Ordering::SeqCst);
+    Err(MomentoError {
+        message: format!("lookup of {} failed", id),
+        error_code: MomentoErrorCode::UnknownError,
+        inner_error: None,
+    })
+}
+
+#[momento::cached(
+    cache = "rust-sdk-test-cache",
+    key = "cached-aliased:{id}",
+    result = true
+)]
+async fn aliased_failing(client: &CacheClient, id: &str) -> LookupResult<u64> {
+    ALIASED_LOOKUPS.fetch_add(1, Ordering::SeqCst);
+    Err(format!("lookup of {} failed", id))
+}
+
+#[momento::cached(cache = "rust-sdk-test-cache", ttl = "30s")]
+async fn square(client: &CacheClient, nonce: String, value: u64) -> u64 {
+    SQUARES.fetch_add(1, Ordering::SeqCst);
+    value * value
+}
+
+struct Labeler<T>(PhantomData<T>);
+
+impl<T: Display> Labeler<T> {
+    fn label(value: &T) -> String {
+        format!("label {}", value)
+    }
+
+    #[momento::cached(cache = "rust-sdk-test-cache", key = "cached-label:{nonce}:{value}")]
+    async fn cached_label(client: &CacheClient, nonce: &str, value: T) -> String {
+        LABELS.fetch_add(1, Ordering::SeqCst);
+        Self::label(&value)
+    }
+}
+
+#[tokio::test]
+async fn caches_ok_values() -> MomentoResult<()> {
+    assert_eq!(CACHE_TEST_STATE.cache_name, "rust-sdk-test-cache");
+    let client = &CACHE_TEST_STATE.client;
+    let id = unique_key();
+
+    let first = get_user(client, &id).await?;
+    let second = get_user(client, &id).await?;
+    assert_eq!(first, second);
+    assert_eq!(second.name, format!("user {}", id));
+    assert_eq!(LOOKUPS.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_cache_none_by_default() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let id = unique_key();
+
+    assert_eq!(find_missing(client, &id).await?, None);
+    assert_eq!(find_missing(client, &id).await?, None);
+    assert_eq!(MISSING_LOOKUPS.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn caches_none_when_opted_in() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let id = unique_key();
+
+    assert_eq!(find_negative(client, &id).await?, None);
+    assert_eq!(find_negative(client, &id).await?, None);
+    assert_eq!(NEGATIVE_LOOKUPS.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_cache_errors() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let id = unique_key();
+
+    assert!(failing(client, &id).await.is_err());
+    assert!(failing(client, &id).await.is_err());
+    assert_eq!(FAILING_LOOKUPS.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn does_not_cache_errors_of_result_aliases() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let id = unique_key();
+
+    assert!(aliased_failing(client, &id).await.is_err());
+    assert!(aliased_failing(client, &id).await.is_err());
+    assert_eq!(ALIASED_LOOKUPS.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn derives_key_from_arguments() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let nonce = unique_key();
+
+    assert_eq!(square(client, nonce.clone(), 3).await, 9);
+    assert_eq!(square(client, nonce.clone(), 3).await, 9);
+    assert_eq!(square(client, nonce.clone(), 4).await, 16);
+    assert_eq!(SQUARES.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn caches_associated_functions_of_generic_impls() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.client;
+    let nonce = unique_key();
+
+    assert_eq!(Labeler::cached_label(client, &nonce, 7).await, "label 7");
+    assert_eq!(Labeler::cached_label(client, &nonce, 7).await, "label 7");
+    assert_eq!(LABELS.load(Ordering::SeqCst), 1);
+    Ok(())
+}
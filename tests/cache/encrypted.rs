@@ -0,0 +1,337 @@
+use momento::cache::{
+    CollectionTtl, EncryptedCacheClient, EncryptionKeyRing, GetResponse, ListPushBackRequest,
+    SetFetchResponse, SetIfEqualRequest, SetIfEqualResponse, SetIfNotEqualResponse, SetRequest,
+    SortedSetOrder,
+};
+use momento::{MomentoErrorCode, MomentoResult};
+
+use momento_test_util::{unique_key, CACHE_TEST_STATE};
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::time::Duration;
+
+fn encrypted_client(key_ring: EncryptionKeyRing) -> EncryptedCacheClient {
+    CACHE_TEST_STATE.client.encrypted(key_ring)
+}
+
+fn key_ring() -> MomentoResult<EncryptionKeyRing> {
+    EncryptionKeyRing::new("k1", [1u8; 32])
+}
+
+mod scalar {
+    use super::*;
+
+    #[tokio::test]
+    async fn values_are_encrypted_at_rest() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let key = unique_key();
+
+        client.set(cache_name, key.as_str(), "secret").await?;
+
+        let item: String = client.get(cache_name, key.as_str()).await?.try_into()?;
+        assert_eq!(item, "secret");
+
+        let stored: Vec<u8> = client
+            .client()
+            .get(cache_name, key.as_str())
+            .await?
+            .try_into()?;
+        assert_ne!(stored, b"secret");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn miss() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+
+        assert_eq!(
+            client.get(cache_name, unique_key()).await?,
+            GetResponse::Miss
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn reads_values_written_before_rotation() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let key = unique_key();
+
+        encrypted_client(key_ring()?)
+            .set(cache_name, key.as_str(), "old")
+            .await?;
+
+        let rotated = encrypted_client(key_ring()?.rotate("k2", [2u8; 32])?);
+        let item: String = rotated.get(cache_name, key.as_str()).await?.try_into()?;
+        assert_eq!(item, "old");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn unknown_key_is_an_error() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let key = unique_key();
+
+        encrypted_client(key_ring()?)
+            .set(cache_name, key.as_str(), "value")
+            .await?;
+
+        let other = encrypted_client(EncryptionKeyRing::new("k2", [2u8; 32])?);
+        let result = other.get(cache_name, key.as_str()).await;
+        assert_eq!(result.unwrap_err().error_code, MomentoErrorCode::TypeError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn values_copied_to_another_key_do_not_decrypt() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let (key, other_key) = (unique_key(), unique_key());
+
+        client.set(cache_name, key.as_str(), "secret").await?;
+        let stored: Vec<u8> = client
+            .client()
+            .get(cache_name, key.as_str())
+            .await?
+            .try_into()?;
+        client
+            .client()
+            .set(cache_name, other_key.as_str(), stored)
+            .await?;
+
+        let result = client.get(cache_name, other_key.as_str()).await;
+        assert_eq!(result.unwrap_err().error_code, MomentoErrorCode::TypeError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let (key1, key2) = (unique_key(), unique_key());
+
+        client
+            .set_batch(
+                cache_name,
+                vec![(key1.as_str(), "one"), (key2.as_str(), "two")],
+            )
+            .await?;
+
+        let items: HashMap<String, String> = client
+            .get_batch(cache_name, vec![key1.as_str(), key2.as_str()])
+            .await?
+            .try_into()?;
+        assert_eq!(items[&key1], "one");
+        assert_eq!(items[&key2], "two");
+        Ok(())
+    }
+}
+
+mod set_if {
+    use super::*;
+
+    #[tokio::test]
+    async fn set_if_equal_compares_plaintext() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let key = unique_key();
+
+        client.set(cache_name, key.as_str(), "first").await?;
+
+        assert_eq!(
+            client
+                .set_if_equal(cache_name, key.as_str(), "second", "other")
+                .await?,
+            SetIfEqualResponse::NotStored
+        );
+        assert_eq!(
+            client
+                .set_if_equal(cache_name, key.as_str(), "second", "first")
+                .await?,
+            SetIfEqualResponse::Stored
+        );
+
+        let item: String = client.get(cache_name, key.as_str()).await?.try_into()?;
+        assert_eq!(item, "second");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_if_not_equal_stores_when_absent() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let key = unique_key();
+
+        assert_eq!(
+            client
+                .set_if_not_equal(cache_name, key.as_str(), "value", "other")
+                .await?,
+            SetIfNotEqualResponse::Stored
+        );
+        assert_eq!(
+            client
+                .set_if_not_equal(cache_name, key.as_str(), "next", "value")
+                .await?,
+            SetIfNotEqualResponse::NotStored
+        );
+        Ok(())
+    }
+}
+
+mod collections {
+    use super::*;
+
+    #[tokio::test]
+    async fn dictionary_round_trip() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let dictionary_name = unique_key();
+
+        client
+            .dictionary_set_fields(
+                cache_name,
+                dictionary_name.as_str(),
+                vec![("a", "apple"), ("b", "banana")],
+            )
+            .await?;
+
+        let fetched: HashMap<String, String> = client
+            .dictionary_fetch(cache_name, dictionary_name.as_str())
+            .await?
+            .try_into()?;
+        assert_eq!(fetched["a"], "apple");
+        assert_eq!(fetched["b"], "banana");
+
+        let field: String = client
+            .dictionary_get_field(cache_name, dictionary_name.as_str(), "b")
+            .await?
+            .try_into()?;
+        assert_eq!(field, "banana");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_remove_value_matches_encrypted_values() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let list_name = unique_key();
+
+        encrypted_client(key_ring()?)
+            .list_concatenate_back(cache_name, list_name.as_str(), vec!["a", "b"])
+            .await?;
+
+        let rotated = encrypted_client(key_ring()?.rotate("k2", [2u8; 32])?);
+        rotated
+            .list_push_back(cache_name, list_name.as_str(), "a")
+            .await?;
+        rotated
+            .list_remove_value(cache_name, list_name.as_str(), "a")
+            .await?;
+
+        let values: Vec<String> = rotated
+            .list_fetch(cache_name, list_name.as_str())
+            .await?
+            .try_into()?;
+        assert_eq!(values, vec!["b".to_string()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn set_remove_elements_matches_encrypted_elements() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let set_name = unique_key();
+
+        client
+            .set_add_elements(cache_name, set_name.as_str(), vec!["a", "b"])
+            .await?;
+        client
+            .set_remove_elements(cache_name, set_name.as_str(), vec!["a"])
+            .await?;
+
+        match client.set_fetch(cache_name, set_name.as_str()).await? {
+            SetFetchResponse::Hit { values } => {
+                let values: Vec<String> = values.try_into()?;
+                assert_eq!(values, vec!["b".to_string()]);
+            }
+            SetFetchResponse::Miss => panic!("expected a hit"),
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sorted_set_round_trip() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let sorted_set_name = unique_key();
+
+        client
+            .sorted_set_put_elements(
+                cache_name,
+                sorted_set_name.as_str(),
+                vec![("a", 1.0), ("b", 2.0)],
+            )
+            .await?;
+
+        let score: f64 = client
+            .sorted_set_get_score(cache_name, sorted_set_name.as_str(), "b")
+            .await?
+            .try_into()?;
+        assert_eq!(score, 2.0);
+
+        let elements: Vec<(String, f64)> = client
+            .sorted_set_fetch_by_rank(
+                cache_name,
+                sorted_set_name.as_str(),
+                SortedSetOrder::Ascending,
+                None,
+                None,
+            )
+            .await?
+            .try_into()?;
+        assert_eq!(
+            elements,
+            vec![("a".to_string(), 1.0), ("b".to_string(), 2.0)]
+        );
+        Ok(())
+    }
+}
+
+mod send_request {
+    use super::*;
+
+    #[tokio::test]
+    async fn writes_with_ttl() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let client = encrypted_client(key_ring()?);
+        let (key, list_name) = (unique_key(), unique_key());
+        let ttl = Duration::from_secs(30);
+
+        client
+            .send_request(SetRequest::new(cache_name, key.as_str(), "old").ttl(ttl))
+            .await?;
+        let response = client
+            .send_request(SetIfEqualRequest::new(cache_name, key.as_str(), "new", "old").ttl(ttl))
+            .await?;
+        assert_eq!(response, SetIfEqualResponse::Stored);
+        client
+            .send_request(
+                ListPushBackRequest::new(cache_name, list_name.as_str(), "value")
+                    .ttl(CollectionTtl::refresh_on_update(ttl)),
+            )
+            .await?;
+
+        let item: String = client.get(cache_name, key.as_str()).await?.try_into()?;
+        assert_eq!(item, "new");
+        for name in [&key, &list_name] {
+            let remaining: Duration = client
+                .client()
+                .item_get_ttl(cache_name, name.as_str())
+                .await?
+                .try_into()?;
+            assert!(remaining <= ttl, "{:?}", remaining);
+        }
+        Ok(())
+    }
+}
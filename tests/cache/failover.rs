@@ -0,0 +1,127 @@
+use momento::cache::{configurations, FailoverCacheClient, FailoverWriteMode, GetResponse};
+use momento::{CacheClient, MomentoErrorCode, MomentoResult};
+
+use momento_test_util::{get_test_credential_provider, unique_key, CACHE_TEST_STATE};
+
+use std::convert::TryInto;
+use std::time::Duration;
+
+/// A client for a region which is down.
+fn unreachable_client() -> MomentoResult<CacheClient> {
+    CacheClient::builder()
+        .default_ttl(Duration::from_secs(5))
+        .configuration(configurations::Laptop::latest())
+        .credential_provider(get_test_credential_provider().base_endpoint("unreachable.invalid"))
+        .build()
+}
+
+mod read {
+    use super::*;
+
+    #[tokio::test]
+    async fn falls_back_to_secondary() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let key = unique_key();
+        CACHE_TEST_STATE
+            .client
+            .set(cache_name, key.as_str(), "value")
+            .await?;
+
+        let client = FailoverCacheClient::builder()
+            .primary(unreachable_client()?)
+            .secondary((*CACHE_TEST_STATE.client).clone())
+            .failure_threshold(1)
+            .build();
+
+        let item: String = client.get(cache_name, key.as_str()).await?.try_into()?;
+        assert_eq!(item, "value");
+        assert_eq!(client.health(), vec![false, true]);
+
+        // The unhealthy primary is skipped until the fail back interval has passed.
+        let item: String = client.get(cache_name, key.as_str()).await?.try_into()?;
+        assert_eq!(item, "value");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn does_not_fail_over_on_client_errors() -> MomentoResult<()> {
+        let client = FailoverCacheClient::builder()
+            .primary((*CACHE_TEST_STATE.client).clone())
+            .secondary((*CACHE_TEST_STATE.client).clone())
+            .build();
+
+        let result = client.get("", unique_key()).await;
+        assert_eq!(
+            result.unwrap_err().error_code,
+            MomentoErrorCode::InvalidArgumentError
+        );
+        assert_eq!(client.health(), vec![true, true]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn all_clients_unavailable() -> MomentoResult<()> {
+        let client = FailoverCacheClient::builder()
+            .primary(unreachable_client()?)
+            .secondary(unreachable_client()?)
+            .build();
+
+        let result = client.get(&CACHE_TEST_STATE.cache_name, unique_key()).await;
+        assert!(result.is_err());
+        Ok(())
+    }
+}
+
+mod write {
+    use super::*;
+
+    #[tokio::test]
+    async fn synchronous_dual_write_skips_unhealthy_clients() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let key = unique_key();
+
+        let client = FailoverCacheClient::builder()
+            .primary(unreachable_client()?)
+            .secondary((*CACHE_TEST_STATE.client).clone())
+            .write_mode(FailoverWriteMode::SynchronousDualWrite)
+            .failure_threshold(1)
+            .build();
+
+        // The write fails because it did not reach every region.
+        let result = client.set(cache_name, key.as_str(), "value").await;
+        assert!(result.is_err());
+        assert_eq!(client.health(), vec![false, true]);
+
+        // The unhealthy primary is skipped until the fail back interval has passed.
+        client.set(cache_name, key.as_str(), "value").await?;
+
+        let item: String = CACHE_TEST_STATE
+            .client
+            .get(cache_name, key.as_str())
+            .await?
+            .try_into()?;
+        assert_eq!(item, "value");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn primary_only_writes_to_primary() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let key = unique_key();
+
+        let client = FailoverCacheClient::builder()
+            .primary((*CACHE_TEST_STATE.client).clone())
+            .secondary(unreachable_client()?)
+            .build();
+
+        client.set(cache_name, key.as_str(), "value").await?;
+        assert_eq!(client.health(), vec![true, true]);
+
+        client.delete(cache_name, key.as_str()).await?;
+        assert_eq!(
+            client.get(cache_name, key.as_str()).await?,
+            GetResponse::Miss
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use momento::cache::{
+    CollectionTtl, DictionaryGetFieldResponse, GetResponse, ListFetchResponse, ListLengthResponse,
+    SetFetchResponse, SortedSetFetchResponse, SortedSetOrder,
+};
+use momento::{MomentoErrorCode, MomentoResult};
+
+use momento_test_util::{unique_cache_name, unique_key, CACHE_TEST_STATE};
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::time::Duration;
+
+mod cache {
+    use super::*;
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(unique_cache_name());
+
+        let result = cache.get("key").await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let cache = CACHE_TEST_STATE.client.cache(cache_name);
+        assert_eq!(cache.name(), cache_name);
+
+        let key = unique_key();
+        cache.set(key.as_str(), "value").await?;
+
+        let item: String = cache.get(key.as_str()).await?.try_into()?;
+        assert_eq!(item, "value");
+
+        let item: String = CACHE_TEST_STATE
+            .client
+            .get(cache_name, key.as_str())
+            .await?
+            .try_into()?;
+        assert_eq!(item, "value");
+
+        cache.delete(key.as_str()).await?;
+        assert_eq!(cache.get(key.as_str()).await?, GetResponse::Miss);
+        Ok(())
+    }
+}
+
+mod list {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let list = cache.list(unique_key());
+
+        assert_eq!(list.fetch().await?, ListFetchResponse::Miss);
+
+        list.push_back("b").await?;
+        list.push_front("a").await?;
+        list.concatenate_back(vec!["c", "d"]).await?;
+        assert_eq!(list.length().await?, ListLengthResponse::Hit { length: 4 });
+
+        let values: Vec<String> = list.fetch().await?.try_into()?;
+        assert_eq!(values, vec!["a", "b", "c", "d"]);
+
+        list.delete().await?;
+        assert_eq!(list.fetch().await?, ListFetchResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path_with_ttl() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let list = cache
+            .list(unique_key())
+            .with_ttl(CollectionTtl::new(Some(Duration::from_secs(3)), false));
+
+        list.push_back("value").await?;
+        assert_ne!(list.fetch().await?, ListFetchResponse::Miss);
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+        assert_eq!(list.fetch().await?, ListFetchResponse::Miss);
+        Ok(())
+    }
+}
+
+mod dictionary {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let dictionary = cache.dictionary(unique_key());
+
+        dictionary.set_field("field1", "value1").await?;
+        dictionary
+            .set_fields(vec![("field2", "value2"), ("field3", "value3")])
+            .await?;
+
+        let value: String = dictionary.get_field("field1").await?.try_into()?;
+        assert_eq!(value, "value1");
+
+        dictionary.remove_field("field1").await?;
+        assert_eq!(
+            dictionary.get_field("field1").await?,
+            DictionaryGetFieldResponse::Miss
+        );
+
+        let fields: HashMap<String, String> = dictionary.fetch().await?.try_into()?;
+        let expected = HashMap::from([
+            ("field2".to_string(), "value2".to_string()),
+            ("field3".to_string(), "value3".to_string()),
+        ]);
+        assert_eq!(fields, expected);
+
+        dictionary.delete().await?;
+        Ok(())
+    }
+}
+
+mod set {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let set = cache.set_collection(unique_key());
+
+        set.add_elements(vec!["a", "b", "c"]).await?;
+        set.remove_elements(vec!["b"]).await?;
+
+        let mut elements: Vec<String> = set.fetch().await?.try_into()?;
+        elements.sort();
+        assert_eq!(elements, vec!["a", "c"]);
+
+        set.delete().await?;
+        assert_eq!(set.fetch().await?, SetFetchResponse::Miss);
+        Ok(())
+    }
+}
+
+mod sorted_set {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let sorted_set = cache.sorted_set(unique_key());
+
+        sorted_set.put_element("a", 1.0).await?;
+        sorted_set
+            .put_elements(vec![("b", 2.0), ("c", 3.0)])
+            .await?;
+        sorted_set.increment_score("a", 5.0).await?;
+
+        let elements: Vec<(String, f64)> = sorted_set
+            .fetch_by_rank(SortedSetOrder::Ascending, None, None)
+            .await?
+            .try_into()?;
+        assert_eq!(
+            elements,
+            vec![
+                ("b".to_string(), 2.0),
+                ("c".to_string(), 3.0),
+                ("a".to_string(), 6.0)
+            ]
+        );
+
+        sorted_set.delete().await?;
+        assert_eq!(
+            sorted_set.fetch_by_score(SortedSetOrder::Ascending).await?,
+            SortedSetFetchResponse::Miss
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use std::convert::Infallible;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::{header, Request, Response, StatusCode};
+use http_body_util::{BodyExt, Full};
+use momento::cache::HttpCacheLayer;
+use tower::{service_fn, Layer, Service, ServiceExt};
+
+use momento_test_util::{unique_string, CACHE_TEST_STATE};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+fn layer() -> HttpCacheLayer {
+    HttpCacheLayer::new(
+        (*CACHE_TEST_STATE.client).clone(),
+        CACHE_TEST_STATE.cache_name.as_str(),
+    )
+    .key_prefix(format!("{}:", unique_string("http")))
+}
+
+/// A service which responds with the number of times it has been called and the given
+/// `Cache-Control` header.
+fn origin(
+    cache_control: &'static str,
+    calls: Arc<AtomicUsize>,
+) -> impl Service<
+    Request<()>,
+    Response = Response<Full<Bytes>>,
+    Error = Infallible,
+    Future = impl Send,
+> + Clone
+       + Send
+       + 'static {
+    service_fn(move |_request: Request<()>| {
+        let calls = calls.clone();
+        async move {
+            let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            let mut response = Response::new(Full::new(Bytes::from(call.to_string())));
+            response.headers_mut().insert(
+                header::CACHE_CONTROL,
+                header::HeaderValue::from_static(cache_control),
+            );
+            Ok::<_, Infallible>(response)
+        }
+    })
+}
+
+async fn get<S>(
+    service: &mut S,
+    path: &str,
+) -> Result<(StatusCode, Option<String>, String), BoxError>
+where
+    S: Service<
+        Request<()>,
+        Error = BoxError,
+        Response = Response<momento::cache::HttpCacheBody<Full<Bytes>>>,
+    >,
+{
+    let request = Request::get(path).body(())?;
+    let response = service.ready().await?.call(request).await?;
+    let status = response.status();
+    let age = response
+        .headers()
+        .get(header::AGE)
+        .map(|age| age.to_str().unwrap_or_default().to_string());
+    let body = response.into_body().collect().await?.to_bytes();
+    Ok((status, age, String::from_utf8(body.to_vec())?))
+}
+
+#[tokio::test]
+async fn fresh_responses_are_served_from_the_cache() -> Result<(), BoxError> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut service = layer().layer(origin("max-age=60", calls.clone()));
+
+    let (status, age, body) = get(&mut service, "/fresh").await?;
+    assert_eq!((status, age, body.as_str()), (StatusCode::OK, None, "1"));
+
+    let (status, age, body) = get(&mut service, "/fresh").await?;
+    assert_eq!(status, StatusCode::OK);
+    assert!(age.is_some());
+    assert_eq!(body, "1");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    let (_, _, body) = get(&mut service, "/other").await?;
+    assert_eq!(body, "2");
+    Ok(())
+}
+
+#[tokio::test]
+async fn no_store_responses_are_not_cached() -> Result<(), BoxError> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut service = layer()
+        .ttl(Duration::from_secs(60))
+        .layer(origin("no-store", calls.clone()));
+
+    assert_eq!(get(&mut service, "/no-store").await?.2, "1");
+    assert_eq!(get(&mut service, "/no-store").await?.2, "2");
+    Ok(())
+}
+
+#[tokio::test]
+async fn stale_responses_are_served_while_revalidating() -> Result<(), BoxError> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mut service = layer().layer(origin(
+        "max-age=1, stale-while-revalidate=30",
+        calls.clone(),
+    ));
+
+    assert_eq!(get(&mut service, "/stale").await?.2, "1");
+    tokio::time::sleep(Duration::from_millis(1500)).await;
+
+    // Stale, so served from the cache while a background request refreshes it.
+    assert_eq!(get(&mut service, "/stale").await?.2, "1");
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(get(&mut service, "/stale").await?.2, "2");
+    Ok(())
+}
+
+#[tokio::test]
+async fn concurrent_misses_call_the_inner_service_once() -> Result<(), BoxError> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let service = layer().layer(origin("max-age=60", calls.clone()));
+
+    let requests = (0..5).map(|_| {
+        let service = service.clone();
+        async move {
+            let response = service.oneshot(Request::get("/popular").body(())?).await?;
+            let body = response.into_body().collect().await?.to_bytes();
+            Ok::<_, BoxError>(body)
+        }
+    });
+    for body in futures::future::try_join_all(requests).await? {
+        assert_eq!(body, "1");
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    Ok(())
+}
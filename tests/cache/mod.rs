@@ -1,10 +1,25 @@
 mod batch;
+#[cfg(feature = "macros")]
+mod cached;
 mod control;
 mod dictionary;
+#[cfg(feature = "encryption")]
+mod encrypted;
+mod failover;
+mod handles;
+#[cfg(feature = "http-cache")]
+mod http_cache;
 mod item;
 mod key_existence;
 mod list;
+mod namespaced;
 mod scalar;
+#[cfg(feature = "tower-sessions")]
+mod session_store;
 mod set;
+mod sharded;
 mod sorted_set;
+#[cfg(feature = "tower")]
+mod tower;
 mod ttl;
+mod typed;
@@ -0,0 +1,196 @@
+use momento::cache::{
+    CollectionTtl, GetBatchRequest, GetResponse, ListPushBackRequest, SetRequest, SortedSetOrder,
+};
+use momento::MomentoResult;
+
+use momento_test_util::{unique_key, unique_string, CACHE_TEST_STATE};
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::time::Duration;
+
+mod scalar {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let prefix = unique_string("tenant:");
+        let tenant = client.namespaced(prefix.as_str());
+        let key = unique_key();
+
+        tenant.set(cache_name, key.as_str(), "value").await?;
+
+        let item: String = tenant.get(cache_name, key.as_str()).await?.try_into()?;
+        assert_eq!(item, "value");
+
+        let prefixed_key = format!("{}{}", prefix, key);
+        let item: String = client
+            .get(cache_name, prefixed_key.as_str())
+            .await?
+            .try_into()?;
+        assert_eq!(item, "value");
+        assert_eq!(
+            client.get(cache_name, key.as_str()).await?,
+            GetResponse::Miss
+        );
+
+        tenant.delete(cache_name, key.as_str()).await?;
+        assert_eq!(
+            client.get(cache_name, prefixed_key.as_str()).await?,
+            GetResponse::Miss
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn namespaces_are_isolated() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let tenant_a = client.namespaced(unique_string("tenant-a:"));
+        let tenant_b = client.namespaced(unique_string("tenant-b:"));
+        let key = unique_key();
+
+        tenant_a.set(cache_name, key.as_str(), "a").await?;
+        assert_eq!(
+            tenant_b.get(cache_name, key.as_str()).await?,
+            GetResponse::Miss
+        );
+        Ok(())
+    }
+}
+
+mod batch {
+    use super::*;
+
+    #[tokio::test]
+    async fn keys_are_returned_without_prefix() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let tenant = client.namespaced(unique_string("tenant:"));
+        let key1 = unique_key();
+        let key2 = unique_key();
+        let items = HashMap::from([(key1.clone(), "value1"), (key2.clone(), "value2")]);
+
+        let set_results: HashMap<String, _> =
+            tenant.set_batch(cache_name, items).await?.try_into()?;
+        let mut set_keys: Vec<&String> = set_results.keys().collect();
+        set_keys.sort();
+        let mut expected_keys = vec![&key1, &key2];
+        expected_keys.sort();
+        assert_eq!(set_keys, expected_keys);
+
+        let get_results: HashMap<String, String> = tenant
+            .get_batch(cache_name, vec![key1.as_str(), key2.as_str()])
+            .await?
+            .try_into()?;
+        assert_eq!(
+            get_results,
+            HashMap::from([
+                (key1.clone(), "value1".to_string()),
+                (key2.clone(), "value2".to_string())
+            ])
+        );
+
+        let exists: HashMap<String, bool> = tenant
+            .keys_exist(cache_name, vec![key1.as_str(), "missing"])
+            .await?
+            .into();
+        assert_eq!(
+            exists,
+            HashMap::from([(key1.clone(), true), ("missing".to_string(), false)])
+        );
+        Ok(())
+    }
+}
+
+mod collections {
+    use super::*;
+
+    #[tokio::test]
+    async fn sorted_set_union_store_prefixes_sources() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let prefix = unique_string("tenant:");
+        let tenant = client.namespaced(prefix.as_str());
+        let source = unique_key();
+        let destination = unique_key();
+
+        tenant
+            .sorted_set_put_elements(cache_name, source.as_str(), vec![("a", 1.0), ("b", 2.0)])
+            .await?;
+        tenant
+            .sorted_set_union_store(
+                cache_name,
+                destination.as_str(),
+                vec![(source.as_str(), 2.0)],
+            )
+            .await?;
+
+        let elements: Vec<(String, f64)> = client
+            .sorted_set_fetch_by_rank(
+                cache_name,
+                format!("{}{}", prefix, destination),
+                SortedSetOrder::Ascending,
+                None,
+                None,
+            )
+            .await?
+            .try_into()?;
+        assert_eq!(
+            elements,
+            vec![("a".to_string(), 2.0), ("b".to_string(), 4.0)]
+        );
+        Ok(())
+    }
+}
+
+mod send_request {
+    use super::*;
+
+    #[tokio::test]
+    async fn applies_namespace_and_ttl() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let prefix = unique_string("tenant:");
+        let tenant = client.namespaced(prefix.as_str());
+        let key = unique_key();
+        let list_name = unique_key();
+        let ttl = Duration::from_secs(30);
+
+        tenant
+            .send_request(SetRequest::new(cache_name, key.as_str(), "value").ttl(ttl))
+            .await?;
+        tenant
+            .send_request(
+                ListPushBackRequest::new(cache_name, list_name.as_str(), "value")
+                    .ttl(CollectionTtl::refresh_on_update(ttl)),
+            )
+            .await?;
+
+        for name in [&key, &list_name] {
+            let remaining: Duration = client
+                .item_get_ttl(cache_name, format!("{}{}", prefix, name))
+                .await?
+                .try_into()?;
+            assert!(remaining <= ttl, "{:?}", remaining);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_keys_are_returned_without_prefix() -> MomentoResult<()> {
+        let cache_name = &CACHE_TEST_STATE.cache_name;
+        let tenant = CACHE_TEST_STATE.client.namespaced(unique_string("tenant:"));
+        let key = unique_key();
+
+        tenant.set(cache_name, key.as_str(), "value").await?;
+        let results: HashMap<String, String> = tenant
+            .send_request(GetBatchRequest::new(cache_name, vec![key.as_str()]))
+            .await?
+            .try_into()?;
+        assert_eq!(results, HashMap::from([(key, "value".to_string())]));
+        Ok(())
+    }
+}
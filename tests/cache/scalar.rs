@@ -1,15 +1,19 @@
 use momento::cache::{
-    DeleteResponse, GetResponse, SetIfAbsentOrEqualRequest, SetIfAbsentOrEqualResponse,
-    SetIfAbsentRequest, SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse,
-    SetIfNotEqualRequest, SetIfNotEqualResponse, SetIfPresentAndNotEqualRequest,
-    SetIfPresentAndNotEqualResponse, SetIfPresentRequest, SetIfPresentResponse, SetRequest,
-    SetResponse,
+    DeleteResponse, GetResponse, GetWithHashResponse, SetIfAbsentOrEqualRequest,
+    SetIfAbsentOrEqualResponse, SetIfAbsentOrHashEqualResponse, SetIfAbsentRequest,
+    SetIfAbsentResponse, SetIfEqualRequest, SetIfEqualResponse, SetIfNotEqualRequest,
+    SetIfNotEqualResponse, SetIfPresentAndHashEqualRequest, SetIfPresentAndHashEqualResponse,
+    SetIfPresentAndNotEqualRequest, SetIfPresentAndNotEqualResponse, SetIfPresentRequest,
+    SetIfPresentResponse, SetRequest, SetResponse,
 };
 use momento::{MomentoErrorCode, MomentoResult};
 use momento_test_util::{
     unique_cache_name, unique_key, unique_string, TestScalar, CACHE_TEST_STATE,
 };
-use std::{convert::TryInto, time::Duration};
+use std::{
+    convert::{TryFrom, TryInto},
+    time::Duration,
+};
 
 mod get_set_delete {
     use std::time::Duration;
@@ -789,3 +793,330 @@ mod set_if_absent_or_equal {
         Ok(())
     }
 }
+
+mod get_with_hash {
+    use super::*;
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client.get_with_hash("   ", "key").await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_string("fake-cache");
+        let result = client.get_with_hash(cache_name, "key").await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item = TestScalar::new();
+
+        let result = client.get_with_hash(cache_name, item.key()).await?;
+        assert_eq!(result, GetWithHashResponse::Miss);
+
+        client.set(cache_name, item.key(), item.value()).await?;
+
+        let result = client.get_with_hash(cache_name, item.key()).await?;
+        match result {
+            GetWithHashResponse::Hit { value, hash } => {
+                let value: String = value.try_into()?;
+                assert_eq!(value, item.value());
+                assert!(!hash.is_empty(), "Expected a non-empty hash");
+            }
+            GetWithHashResponse::Miss => panic!("Expected a hit"),
+        }
+
+        Ok(())
+    }
+}
+
+mod set_if_present_and_hash_equal {
+    use super::*;
+
+    async fn get_hash(cache_name: &str, key: &str) -> MomentoResult<Vec<u8>> {
+        let client = &CACHE_TEST_STATE.client;
+        match client.get_with_hash(cache_name, key).await? {
+            GetWithHashResponse::Hit { hash, .. } => Ok(hash),
+            GetWithHashResponse::Miss => panic!("Expected a hit for key {}", key),
+        }
+    }
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .set_if_present_and_hash_equal("   ", "key", "value", "hash")
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_string("fake-cache");
+        let result = client
+            .set_if_present_and_hash_equal(cache_name, "key", "value", "hash")
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item1 = TestScalar::new();
+        let item2 = TestScalar::new();
+
+        // Setting a key that doesn't exist should not create it
+        let result = client
+            .set_if_present_and_hash_equal(cache_name, item1.key(), item1.value(), "hash")
+            .await?;
+        assert_eq!(result, SetIfPresentAndHashEqualResponse::NotStored);
+
+        client.set(cache_name, item1.key(), item1.value()).await?;
+        let hash = get_hash(cache_name, item1.key()).await?;
+
+        // Setting a key whose hash matches should overwrite it
+        let result = client
+            .set_if_present_and_hash_equal(cache_name, item1.key(), item2.value(), hash.clone())
+            .await?;
+        let new_hash = match result {
+            SetIfPresentAndHashEqualResponse::Stored { new_hash } => new_hash,
+            SetIfPresentAndHashEqualResponse::NotStored => panic!("Expected value to be stored"),
+        };
+        assert_eq!(new_hash, get_hash(cache_name, item1.key()).await?);
+        let result = client.get(cache_name, item1.key()).await?;
+        assert_eq!(result, GetResponse::from(&item2));
+
+        // Setting a key with a stale hash should NOT overwrite it
+        let result = client
+            .set_if_present_and_hash_equal(cache_name, item1.key(), item1.value(), hash)
+            .await?;
+        assert_eq!(result, SetIfPresentAndHashEqualResponse::NotStored);
+        let result = client.get(cache_name, item1.key()).await?;
+        assert_eq!(result, GetResponse::from(&item2));
+
+        Ok(())
+    }
+
+    // string key and string value with ttl before and after expiration
+    #[tokio::test]
+    async fn happy_path_with_ttl() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let key_uuid = unique_string("key");
+        let key = key_uuid.as_str();
+
+        let result = client.set(cache_name, key, "first_value").await?;
+        assert_eq!(result, SetResponse {});
+        let hash = get_hash(cache_name, key).await?;
+
+        let set_request =
+            SetIfPresentAndHashEqualRequest::new(cache_name, key, "second_value", hash)
+                .ttl(Duration::from_secs(2));
+        let result = client.send_request(set_request).await?;
+        assert!(matches!(
+            result,
+            SetIfPresentAndHashEqualResponse::Stored { .. }
+        ));
+
+        // Should have remaining ttl > 0
+        let result_ttl: Duration = client
+            .item_get_ttl(cache_name, key)
+            .await?
+            .try_into()
+            .expect("Expected to get an item ttl");
+        assert!(
+            result_ttl.as_millis() > 0,
+            "Expected ttl > 0, got {:?}",
+            result_ttl
+        );
+
+        // Wait for ttl to expire
+        tokio::time::sleep(Duration::from_secs(2)).await;
+
+        // Should get a cache miss
+        let result = client.get(cache_name, key).await?;
+        assert_eq!(result, GetResponse::Miss {});
+
+        Ok(())
+    }
+}
+
+mod set_if_absent_or_hash_equal {
+    use super::*;
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .set_if_absent_or_hash_equal("   ", "key", "value", "hash")
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_string("fake-cache");
+        let result = client
+            .set_if_absent_or_hash_equal(cache_name, "key", "value", "hash")
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let item1 = TestScalar::new();
+        let item2 = TestScalar::new();
+
+        // Setting a key that doesn't exist should create it
+        let result = client
+            .set_if_absent_or_hash_equal(cache_name, item1.key(), item1.value(), "hash")
+            .await?;
+        let hash = match result {
+            SetIfAbsentOrHashEqualResponse::Stored { new_hash } => new_hash,
+            SetIfAbsentOrHashEqualResponse::NotStored => panic!("Expected value to be stored"),
+        };
+
+        // Setting a key whose hash matches should overwrite it
+        let result = client
+            .set_if_absent_or_hash_equal(cache_name, item1.key(), item2.value(), hash.clone())
+            .await?;
+        assert!(matches!(
+            result,
+            SetIfAbsentOrHashEqualResponse::Stored { .. }
+        ));
+        let result = client.get(cache_name, item1.key()).await?;
+        assert_eq!(result, GetResponse::from(&item2));
+
+        // Setting a key with a stale hash should NOT overwrite it
+        let result = client
+            .set_if_absent_or_hash_equal(cache_name, item1.key(), item1.value(), hash)
+            .await?;
+        assert_eq!(result, SetIfAbsentOrHashEqualResponse::NotStored);
+        let result = client.get(cache_name, item1.key()).await?;
+        assert_eq!(result, GetResponse::from(&item2));
+
+        Ok(())
+    }
+}
+
+mod update_with {
+    use super::*;
+    use momento::cache::{UpdateWithRequest, UpdateWithResponse};
+
+    #[tokio::test]
+    async fn invalid_cache_name() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let result = client
+            .update_with("   ", "key", None, |_| Some(b"value".to_vec()))
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::InvalidArgumentError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn nonexistent_cache() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = unique_string("fake-cache");
+        let result = client
+            .update_with(cache_name, "key", None, |_| Some(b"value".to_vec()))
+            .await
+            .unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::CacheNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let key = unique_key();
+
+        // Updating a key that doesn't exist should create it
+        let result = client
+            .update_with(cache_name, key.as_str(), None, |current| {
+                assert_eq!(current, None);
+                Some(b"1".to_vec())
+            })
+            .await?;
+        assert_eq!(result, UpdateWithResponse::Stored);
+
+        // Updating a key that exists should see the current value
+        let result = client
+            .update_with(cache_name, key.as_str(), None, |current| {
+                let current: String = current.expect("Expected a value").try_into().ok()?;
+                Some(format!("{}2", current).into_bytes())
+            })
+            .await?;
+        assert_eq!(result, UpdateWithResponse::Stored);
+        let result = client.get(cache_name, key.as_str()).await?;
+        assert_eq!(result, GetResponse::from("12"));
+
+        // Returning None should delete the key
+        let result = client
+            .update_with(cache_name, key.as_str(), None, |_| None)
+            .await?;
+        assert_eq!(result, UpdateWithResponse::Deleted);
+        let result = client.get(cache_name, key.as_str()).await?;
+        assert_eq!(result, GetResponse::Miss);
+
+        // Returning None for an absent key should not write anything
+        let result = client
+            .update_with(cache_name, key.as_str(), None, |_| None)
+            .await?;
+        assert_eq!(result, UpdateWithResponse::NotStored);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_updates_are_not_lost() -> MomentoResult<()> {
+        let client = &CACHE_TEST_STATE.client;
+        let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+        let key = unique_key();
+
+        let increment = |current: Option<momento::cache::messages::data::scalar::get::Value>| {
+            let count: u64 = current
+                .and_then(|value| String::try_from(value).ok())
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(0);
+            Some((count + 1).to_string().into_bytes())
+        };
+        let updates = (0..5).map(|_| {
+            let request = UpdateWithRequest::new(cache_name, key.as_str(), increment)
+                .max_attempts(50)
+                .backoff(Duration::from_millis(5), Duration::from_millis(50));
+            client.send_request(request)
+        });
+        for result in futures::future::join_all(updates).await {
+            assert_eq!(result?, UpdateWithResponse::Stored);
+        }
+
+        let result = client.get(cache_name, key.as_str()).await?;
+        assert_eq!(result, GetResponse::from("5"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use momento::cache::CacheSessionStore;
+use momento::MomentoResult;
+use tower_sessions::cookie::time::{self, OffsetDateTime};
+use tower_sessions::session::{Id, Record};
+use tower_sessions::SessionStore;
+
+use momento_test_util::{unique_string, CACHE_TEST_STATE};
+
+fn session_store() -> CacheSessionStore {
+    CacheSessionStore::new(
+        (*CACHE_TEST_STATE.client).clone(),
+        CACHE_TEST_STATE.cache_name.as_str(),
+    )
+    .key_prefix(format!("{}:", unique_string("session")))
+}
+
+fn record(expires_in: time::Duration) -> Record {
+    let mut data = HashMap::new();
+    data.insert("user".to_string(), serde_json::json!("ferris"));
+    Record {
+        id: Id::default(),
+        data,
+        expiry_date: OffsetDateTime::now_utc() + expires_in,
+    }
+}
+
+#[tokio::test]
+async fn create_load_and_delete() -> MomentoResult<()> {
+    let store = session_store();
+    let mut record = record(time::Duration::minutes(5));
+
+    store
+        .create(&mut record)
+        .await
+        .expect("create should succeed");
+    let loaded = store
+        .load(&record.id)
+        .await
+        .expect("load should succeed")
+        .expect("session should exist");
+    assert_eq!(loaded, record);
+
+    store
+        .delete(&record.id)
+        .await
+        .expect("delete should succeed");
+    assert_eq!(
+        store.load(&record.id).await.expect("load should succeed"),
+        None
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_replaces_session_data() -> MomentoResult<()> {
+    let store = session_store();
+    let mut record = record(time::Duration::minutes(5));
+    store
+        .create(&mut record)
+        .await
+        .expect("create should succeed");
+
+    record.data.clear();
+    record
+        .data
+        .insert("cart".to_string(), serde_json::json!([1, 2, 3]));
+    store.save(&record).await.expect("save should succeed");
+
+    let loaded = store
+        .load(&record.id)
+        .await
+        .expect("load should succeed")
+        .expect("session should exist");
+    assert_eq!(loaded.data, record.data);
+    Ok(())
+}
+
+#[tokio::test]
+async fn create_picks_a_new_id_on_collision() -> MomentoResult<()> {
+    let store = session_store();
+    let mut first = record(time::Duration::minutes(5));
+    store
+        .create(&mut first)
+        .await
+        .expect("create should succeed");
+
+    let mut second = record(time::Duration::minutes(5));
+    second.id = first.id;
+    store
+        .create(&mut second)
+        .await
+        .expect("create should succeed");
+    assert_ne!(second.id, first.id);
+    Ok(())
+}
+
+#[tokio::test]
+async fn expired_sessions_are_not_stored() -> MomentoResult<()> {
+    let store = session_store();
+    let record = record(time::Duration::seconds(-1));
+
+    store.save(&record).await.expect("save should succeed");
+    assert_eq!(
+        store.load(&record.id).await.expect("load should succeed"),
+        None
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn load_refreshes_ttl_with_idle_timeout() -> MomentoResult<()> {
+    let store = session_store().idle_timeout(Duration::from_secs(3));
+    let mut record = record(time::Duration::minutes(5));
+    store
+        .create(&mut record)
+        .await
+        .expect("create should succeed");
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert!(store
+        .load(&record.id)
+        .await
+        .expect("load should succeed")
+        .is_some());
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert!(store
+        .load(&record.id)
+        .await
+        .expect("load should succeed")
+        .is_some());
+
+    tokio::time::sleep(Duration::from_secs(4)).await;
+    assert_eq!(
+        store.load(&record.id).await.expect("load should succeed"),
+        None
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn idle_timeout_does_not_extend_past_expiry_date() -> MomentoResult<()> {
+    let store = session_store().idle_timeout(Duration::from_secs(3));
+    let mut record = record(time::Duration::seconds(4));
+    store
+        .create(&mut record)
+        .await
+        .expect("create should succeed");
+
+    for _ in 0..3 {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        let loaded = store
+            .load(&record.id)
+            .await
+            .expect("load should succeed")
+            .expect("session should exist");
+        assert_eq!(loaded.expiry_date, record.expiry_date);
+    }
+
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert_eq!(
+        store.load(&record.id).await.expect("load should succeed"),
+        None
+    );
+    Ok(())
+}
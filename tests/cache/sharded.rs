@@ -0,0 +1,85 @@
+use momento::cache::{CacheShard, GetResponse, ShardedCacheClient};
+use momento::MomentoResult;
+
+use momento_test_util::{unique_key, CACHE_TEST_STATE};
+
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+fn shard(id: &str) -> CacheShard {
+    CacheShard::new(
+        id,
+        (*CACHE_TEST_STATE.client).clone(),
+        CACHE_TEST_STATE.cache_name.as_str(),
+    )
+}
+
+fn sharded_client() -> MomentoResult<ShardedCacheClient> {
+    ShardedCacheClient::new(vec![shard("shard-1"), shard("shard-2"), shard("shard-3")])
+}
+
+mod scalar {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let client = sharded_client()?;
+        let key = unique_key();
+
+        client.set(key.as_str(), "value").await?;
+        let item: String = client.get(key.as_str()).await?.try_into()?;
+        assert_eq!(item, "value");
+
+        client.delete(key.as_str()).await?;
+        assert_eq!(client.get(key.as_str()).await?, GetResponse::Miss);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn batch_is_split_and_merged() -> MomentoResult<()> {
+        let client = sharded_client()?;
+        let keys: Vec<String> = (0..20).map(|_| unique_key()).collect();
+
+        let response = client
+            .set_batch(keys.iter().map(|key| (key.as_str(), key.as_str())))
+            .await?;
+        let results: HashMap<String, _> = response.try_into()?;
+        assert_eq!(results.len(), keys.len());
+
+        let items: HashMap<String, String> = client
+            .get_batch(keys.iter().map(String::as_str))
+            .await?
+            .try_into()?;
+        assert_eq!(items.len(), keys.len());
+        for key in &keys {
+            assert_eq!(&items[key], key);
+        }
+        Ok(())
+    }
+}
+
+mod rebalance {
+    use super::*;
+
+    #[test]
+    fn duplicate_shard_ids_are_rejected() {
+        let result = ShardedCacheClient::new(vec![shard("shard-1"), shard("shard-1")]);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn report_lists_keys_moved_to_new_shard() -> MomentoResult<()> {
+        let mut client = sharded_client()?;
+        let keys: Vec<String> = (0..1000).map(|i| format!("key-{}", i)).collect();
+
+        let report = client.add_shard(shard("shard-4"), keys.iter().map(String::as_str))?;
+        assert!(!report.is_empty());
+        for moved in report.moved_keys() {
+            assert_eq!(moved.to, "shard-4");
+            assert_ne!(moved.from, "shard-4");
+            assert_eq!(client.shard_for(moved.key.clone()).id(), "shard-4");
+        }
+        assert_eq!(client.shards().len(), 4);
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use momento::cache::{GetRequest, GetResponse, SetRequest};
+use momento::{MomentoErrorCode, MomentoResult};
+use tower::{Service, ServiceBuilder, ServiceExt};
+
+use momento_test_util::{unique_cache_name, unique_key, CACHE_TEST_STATE};
+
+use std::convert::TryInto;
+use std::time::Duration;
+
+#[tokio::test]
+async fn oneshot_sends_request() -> MomentoResult<()> {
+    let client = (*CACHE_TEST_STATE.client).clone();
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+
+    client
+        .clone()
+        .oneshot(SetRequest::new(cache_name, key.clone(), "value"))
+        .await?;
+    let item: String = client
+        .oneshot(GetRequest::new(cache_name, key))
+        .await?
+        .try_into()?;
+    assert_eq!(item, "value");
+    Ok(())
+}
+
+#[tokio::test]
+async fn middleware_wraps_requests() -> MomentoResult<()> {
+    let cache_name = CACHE_TEST_STATE.cache_name.as_str();
+    let key = unique_key();
+    let mut service = ServiceBuilder::new()
+        .concurrency_limit(4)
+        .timeout(Duration::from_secs(15))
+        .service((*CACHE_TEST_STATE.client).clone());
+
+    let response = ServiceExt::<GetRequest<String>>::ready(&mut service)
+        .await
+        .expect("service should become ready")
+        .call(GetRequest::new(cache_name, key))
+        .await
+        .expect("get should succeed");
+    assert_eq!(response, GetResponse::Miss);
+    Ok(())
+}
+
+#[tokio::test]
+async fn errors_are_returned_from_call() {
+    let cache_name = unique_cache_name();
+    let result = (*CACHE_TEST_STATE.client)
+        .clone()
+        .oneshot(GetRequest::new(cache_name, unique_key()))
+        .await;
+    assert_eq!(
+        result
+            .expect_err("get from a missing cache should fail")
+            .error_code,
+        MomentoErrorCode::CacheNotFoundError
+    );
+}
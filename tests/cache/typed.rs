@@ -0,0 +1,147 @@
+use momento::cache::{SortedSetOrder, TypedDictionary, TypedList, TypedSortedSet};
+use momento::{MomentoErrorCode, MomentoResult};
+use serde::{Deserialize, Serialize};
+
+use momento_test_util::{unique_key, CACHE_TEST_STATE};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Order {
+    id: u32,
+    item: String,
+}
+
+fn order(id: u32, item: &str) -> Order {
+    Order {
+        id,
+        item: item.to_string(),
+    }
+}
+
+mod typed_list {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let list: TypedList<Order> = TypedList::new(cache.list(unique_key()));
+
+        assert_eq!(list.fetch().await?, None);
+
+        list.push_back(&order(2, "burrito")).await?;
+        list.push_front(&order(1, "taco")).await?;
+        list.concatenate_back(&[order(3, "nachos")]).await?;
+        assert_eq!(
+            list.fetch().await?,
+            Some(vec![
+                order(1, "taco"),
+                order(2, "burrito"),
+                order(3, "nachos")
+            ])
+        );
+
+        assert_eq!(list.pop_front().await?, Some(order(1, "taco")));
+        list.remove_value(&order(3, "nachos")).await?;
+        assert_eq!(list.fetch().await?, Some(vec![order(2, "burrito")]));
+
+        list.delete().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn decode_error_reports_element() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let raw = cache.list(unique_key());
+        raw.push_back(r#"{"id":1,"item":"taco"}"#).await?;
+        raw.push_back("not json").await?;
+
+        let list: TypedList<Order> = TypedList::new(raw);
+        let result = list.fetch().await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::TypeError);
+        assert!(
+            result.message.starts_with("list element 1"),
+            "{}",
+            result.message
+        );
+
+        list.delete().await?;
+        Ok(())
+    }
+}
+
+mod typed_dictionary {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let dictionary: TypedDictionary<Order> =
+            TypedDictionary::new(cache.dictionary(unique_key()));
+
+        assert_eq!(dictionary.fetch().await?, None);
+
+        dictionary.set(&order(1, "taco")).await?;
+        assert_eq!(dictionary.fetch().await?, Some(order(1, "taco")));
+
+        dictionary.set_field("item", &"burrito").await?;
+        let item: Option<String> = dictionary.get_field("item").await?;
+        assert_eq!(item, Some("burrito".to_string()));
+        assert_eq!(dictionary.fetch().await?, Some(order(1, "burrito")));
+
+        dictionary.delete().await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn decode_error_reports_field() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let raw = cache.dictionary(unique_key());
+        raw.set_fields(vec![("id", "\"one\""), ("item", "\"taco\"")])
+            .await?;
+
+        let dictionary: TypedDictionary<Order> = TypedDictionary::new(raw);
+        let result = dictionary.fetch().await.unwrap_err();
+        assert_eq!(result.error_code, MomentoErrorCode::TypeError);
+        assert!(result.message.contains("`id`"), "{}", result.message);
+
+        dictionary.delete().await?;
+        Ok(())
+    }
+}
+
+mod typed_sorted_set {
+    use super::*;
+
+    #[tokio::test]
+    async fn happy_path() -> MomentoResult<()> {
+        let cache = CACHE_TEST_STATE.client.cache(&CACHE_TEST_STATE.cache_name);
+        let sorted_set: TypedSortedSet<Order> = TypedSortedSet::new(cache.sorted_set(unique_key()));
+
+        assert_eq!(
+            sorted_set.fetch_by_score(SortedSetOrder::Ascending).await?,
+            None
+        );
+
+        sorted_set.put_element(&order(1, "taco"), 1.0).await?;
+        sorted_set
+            .put_elements(&[(order(2, "burrito"), 2.0), (order(3, "nachos"), 3.0)])
+            .await?;
+        assert_eq!(
+            sorted_set.increment_score(&order(1, "taco"), 5.0).await?,
+            6.0
+        );
+
+        assert_eq!(sorted_set.get_score(&order(2, "burrito")).await?, Some(2.0));
+        assert_eq!(sorted_set.get_rank(&order(1, "taco")).await?, Some(2));
+
+        sorted_set.remove_elements(&[order(3, "nachos")]).await?;
+        assert_eq!(
+            sorted_set
+                .fetch_by_rank(SortedSetOrder::Descending, None, None)
+                .await?,
+            Some(vec![(order(1, "taco"), 6.0), (order(2, "burrito"), 2.0)])
+        );
+
+        sorted_set.delete().await?;
+        Ok(())
+    }
+}
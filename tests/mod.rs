@@ -1,4 +1,5 @@
 mod auth;
 mod cache;
 mod leaderboard;
+mod storage;
 mod topics;
@@ -0,0 +1,112 @@
+use std::convert::TryInto;
+
+use momento::storage::{configurations, CreateStoreResponse, GetResponse, StorageValue};
+use momento::{MomentoErrorCode, MomentoResult, StorageClient};
+use momento_test_util::{get_test_credential_provider, unique_key, unique_store_name};
+
+fn storage_client() -> StorageClient {
+    StorageClient::builder()
+        .configuration(configurations::Laptop::latest())
+        .credential_provider(get_test_credential_provider())
+        .build()
+        .expect("storage client should be created")
+}
+
+mod control {
+    use super::*;
+
+    #[tokio::test]
+    async fn create_list_and_delete_store() -> MomentoResult<()> {
+        let client = storage_client();
+        let store_name = unique_store_name();
+
+        let response = client.create_store(&store_name).await?;
+        assert_eq!(response, CreateStoreResponse::Created);
+        let response = client.create_store(&store_name).await?;
+        assert_eq!(response, CreateStoreResponse::AlreadyExists);
+
+        let stores = client.list_stores().await?.stores;
+        assert!(
+            stores.iter().any(|store| store.name == store_name),
+            "Expected {} to be listed",
+            store_name
+        );
+
+        client.delete_store(&store_name).await?;
+        let error = client.delete_store(&store_name).await.unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::StoreNotFoundError);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn create_store_with_empty_name() {
+        let client = storage_client();
+        let error = client.create_store("  ").await.unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::InvalidArgumentError);
+    }
+}
+
+mod data {
+    use super::*;
+
+    #[tokio::test]
+    async fn put_get_and_delete_each_type() -> MomentoResult<()> {
+        let client = storage_client();
+        let store_name = unique_store_name();
+        client.create_store(&store_name).await?;
+
+        let values = [
+            StorageValue::from("hello"),
+            StorageValue::from(vec![0xde, 0xad, 0xbe, 0xef]),
+            StorageValue::from(42),
+            StorageValue::from(4.2),
+        ];
+        let result = async {
+            for value in values {
+                let key = unique_key();
+                client.put(&store_name, &key, value.clone()).await?;
+                let response = client.get(&store_name, &key).await?;
+                assert_eq!(response, GetResponse::Found { value });
+
+                client.delete(&store_name, &key).await?;
+                let response = client.get(&store_name, &key).await?;
+                assert_eq!(response, GetResponse::NotFound);
+            }
+            MomentoResult::Ok(())
+        }
+        .await;
+        client.delete_store(&store_name).await?;
+        result
+    }
+
+    #[tokio::test]
+    async fn get_converts_to_the_stored_type() -> MomentoResult<()> {
+        let client = storage_client();
+        let store_name = unique_store_name();
+        client.create_store(&store_name).await?;
+
+        let result = async {
+            client.put(&store_name, "count", 7).await?;
+            let count: i64 = client.get(&store_name, "count").await?.try_into()?;
+            assert_eq!(count, 7);
+
+            let error =
+                TryInto::<String>::try_into(client.get(&store_name, "count").await?).unwrap_err();
+            assert_eq!(error.error_code, MomentoErrorCode::TypeError);
+            MomentoResult::Ok(())
+        }
+        .await;
+        client.delete_store(&store_name).await?;
+        result
+    }
+
+    #[tokio::test]
+    async fn get_from_nonexistent_store() {
+        let client = storage_client();
+        let error = client
+            .get(unique_store_name(), unique_key())
+            .await
+            .unwrap_err();
+        assert_eq!(error.error_code, MomentoErrorCode::StoreNotFoundError);
+    }
+}
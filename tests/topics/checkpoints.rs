@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use momento::topics::{CacheCheckpointStore, Checkpoint, CheckpointStore};
+use momento::MomentoResult;
+use momento_test_util::{unique_topic_name, CACHE_TEST_STATE};
+
+#[tokio::test]
+async fn cache_checkpoint_store_round_trips_checkpoints() -> MomentoResult<()> {
+    let client = CACHE_TEST_STATE.client.as_ref().clone();
+    let cache_name = &CACHE_TEST_STATE.cache_name;
+    let checkpoint_name = unique_topic_name();
+    let store = CacheCheckpointStore::new(client, cache_name).ttl(Duration::from_secs(60));
+
+    assert_eq!(store.load(&checkpoint_name).await?, None);
+
+    let checkpoint = Checkpoint {
+        topic_sequence_number: 42,
+        topic_sequence_page: 3,
+    };
+    store.save(&checkpoint_name, checkpoint).await?;
+    assert_eq!(store.load(&checkpoint_name).await?, Some(checkpoint));
+    Ok(())
+}
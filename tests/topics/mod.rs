@@ -1 +1,3 @@
+mod checkpoints;
 mod pubsub;
+mod webhooks;
@@ -0,0 +1,45 @@
+use momento::topics::WebhookDestination;
+use momento::MomentoResult;
+use momento_test_util::{unique_string, unique_topic_name, CACHE_TEST_STATE};
+
+#[tokio::test]
+async fn put_list_rotate_and_delete_webhook() -> MomentoResult<()> {
+    let client = &CACHE_TEST_STATE.topic_client;
+    let cache_name = &CACHE_TEST_STATE.cache_name;
+    let webhook_name = unique_string("webhook");
+    let topic_name = unique_topic_name();
+    let url = "https://example.com/momento-webhook";
+
+    let secret = client
+        .put_webhook(cache_name, &webhook_name, &topic_name, url)
+        .await?
+        .secret_string;
+    assert!(!secret.is_empty(), "Expected a webhook secret");
+
+    let webhooks = client.list_webhooks(cache_name).await?.webhooks;
+    let webhook = webhooks
+        .iter()
+        .find(|webhook| webhook.id.webhook_name == webhook_name)
+        .expect("Expected the webhook to be listed");
+    assert_eq!(webhook.topic_name, topic_name);
+    assert_eq!(
+        webhook.destination,
+        WebhookDestination::PostUrl(url.to_string())
+    );
+
+    let response = client.get_webhook_secret(cache_name, &webhook_name).await?;
+    assert_eq!(response.secret_string, secret);
+
+    let rotated = client
+        .rotate_webhook_secret(cache_name, &webhook_name)
+        .await?
+        .secret_string;
+    assert_ne!(rotated, secret);
+
+    client.delete_webhook(cache_name, &webhook_name).await?;
+    let webhooks = client.list_webhooks(cache_name).await?.webhooks;
+    assert!(webhooks
+        .iter()
+        .all(|webhook| webhook.id.webhook_name != webhook_name));
+    Ok(())
+}